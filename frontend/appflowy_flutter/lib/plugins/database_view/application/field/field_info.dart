@@ -40,6 +40,12 @@ class FieldInfo with _$FieldInfo {
       case FieldType.MultiSelect:
       case FieldType.SingleSelect:
       case FieldType.DateTime:
+      case FieldType.Number:
+      case FieldType.RichText:
+      case FieldType.Checklist:
+      case FieldType.Person:
+      case FieldType.LastEditedTime:
+      case FieldType.CreatedTime:
         return true;
       default:
         return false;
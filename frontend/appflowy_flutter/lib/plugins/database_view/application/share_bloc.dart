@@ -24,7 +24,7 @@ class DatabaseShareBloc extends Bloc<DatabaseShareEvent, DatabaseShareState> {
   ) async {
     emit(const DatabaseShareState.loading());
 
-    final result = await BackendExportService.exportDatabaseAsCSV(view.id);
+    final result = await BackendExportService.exportViewAsCSV(view.id);
     result.fold(
       (l) => _saveCSVToPath(l.data, event.path),
       (r) => Log.error(r),
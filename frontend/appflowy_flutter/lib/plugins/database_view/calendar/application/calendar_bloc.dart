@@ -181,6 +181,10 @@ class CalendarBloc extends Bloc<CalendarEvent, CalendarState> {
       ),
       timestamp: timestamp,
     );
+    // Moving an occurrence of a recurring event shifts the whole series
+    if (event.event.isRecurring) {
+      payload.occurrence = event.event.timestamp;
+    }
     return DatabaseEventMoveCalendarEvent(payload).send().then((result) {
       return result.fold(
         (_) async {
@@ -89,6 +89,16 @@ class ReminderBloc extends Bloc<ReminderEvent, ReminderState> {
               final reminders = [...state.reminders];
               reminders.replaceRange(index, index + 1, [newReminder]);
               emit(state.copyWith(reminders: reminders));
+
+              // The backend schedules the next occurrence of a recurring date
+              // when the reminder of a date cell is updated after it fired
+              final scheduledAt = DateTime.fromMillisecondsSinceEpoch(
+                newReminder.scheduledAt.toInt() * 1000,
+              );
+              if (newReminder.meta.containsKey('row_id') &&
+                  scheduledAt.isBefore(DateTime.now())) {
+                add(const ReminderEvent.started());
+              }
             },
           );
         },
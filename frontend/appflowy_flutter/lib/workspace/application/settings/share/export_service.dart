@@ -11,4 +11,13 @@ class BackendExportService {
     final payload = DatabaseViewIdPB.create()..value = viewId;
     return DatabaseEventExportCSV(payload).send();
   }
+
+  /// Exports the rows and the fields shown in the view, with its filters and
+  /// sorts applied.
+  static Future<Either<DatabaseExportDataPB, FlowyError>> exportViewAsCSV(
+    String viewId,
+  ) async {
+    final payload = DatabaseExportPayloadPB.create()..viewId = viewId;
+    return DatabaseEventExportViewCSV(payload).send();
+  }
 }
@@ -28,6 +28,7 @@ extension on ImportTypePB {
         return ViewLayoutPB.Document;
       case ImportTypePB.HistoryDatabase ||
             ImportTypePB.CSV ||
+            ImportTypePB.XLSX ||
             ImportTypePB.RawDatabase:
         return ViewLayoutPB.Grid;
       default:
@@ -537,6 +537,36 @@ impl FlowyCoreTest {
       .error()
   }
 
+  pub async fn preview_field_type_switch(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    field_type: FieldType,
+  ) -> FieldTypeSwitchPreviewPB {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::PreviewFieldTypeSwitch)
+      .payload(UpdateFieldTypePayloadPB {
+        view_id: view_id.to_string(),
+        field_id: field_id.to_string(),
+        field_type,
+      })
+      .async_send()
+      .await
+      .parse::<FieldTypeSwitchPreviewPB>()
+  }
+
+  pub async fn get_csv_import_field_types(&self, content: &str) -> Vec<CSVColumnPB> {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::GetCSVImportFieldTypes)
+      .payload(CSVImportContentPB {
+        content: content.to_string(),
+      })
+      .async_send()
+      .await
+      .parse::<RepeatedCSVColumnPB>()
+      .items
+  }
+
   pub async fn duplicate_field(&self, view_id: &str, field_id: &str) -> Option<FlowyError> {
     EventBuilder::new(self.clone())
       .event(DatabaseEvent::DuplicateField)
@@ -684,6 +714,71 @@ impl FlowyCoreTest {
       .parse::<CellPB>()
   }
 
+  pub async fn get_row_history(&self, view_id: &str, row_id: &str) -> Vec<CellChangePB> {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::GetRowHistory)
+      .payload(RowIdPB {
+        view_id: view_id.to_string(),
+        row_id: row_id.to_string(),
+        group_id: None,
+      })
+      .async_send()
+      .await
+      .parse::<RepeatedCellChangePB>()
+      .items
+  }
+
+  pub async fn restore_cell_change(
+    &self,
+    view_id: &str,
+    change_id: &str,
+    whole_row: bool,
+  ) -> Option<FlowyError> {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::RestoreCellChange)
+      .payload(RestoreCellChangePayloadPB {
+        view_id: view_id.to_string(),
+        change_id: change_id.to_string(),
+        whole_row,
+      })
+      .async_send()
+      .await
+      .error()
+  }
+
+  pub async fn undo_database(&self, view_id: &str) -> DatabaseUndoRedoResponsePB {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::Undo)
+      .payload(DatabaseViewIdPB {
+        value: view_id.to_string(),
+      })
+      .async_send()
+      .await
+      .parse::<DatabaseUndoRedoResponsePB>()
+  }
+
+  pub async fn redo_database(&self, view_id: &str) -> DatabaseUndoRedoResponsePB {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::Redo)
+      .payload(DatabaseViewIdPB {
+        value: view_id.to_string(),
+      })
+      .async_send()
+      .await
+      .parse::<DatabaseUndoRedoResponsePB>()
+  }
+
+  pub async fn can_undo_redo_database(&self, view_id: &str) -> DatabaseUndoRedoResponsePB {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::CanUndoRedo)
+      .payload(DatabaseViewIdPB {
+        value: view_id.to_string(),
+      })
+      .async_send()
+      .await
+      .parse::<DatabaseUndoRedoResponsePB>()
+  }
+
   pub async fn get_date_cell(&self, view_id: &str, row_id: &str, field_id: &str) -> DateCellDataPB {
     let cell = self.get_cell(view_id, row_id, field_id).await;
     DateCellDataPB::try_from(Bytes::from(cell.data)).unwrap()
@@ -719,6 +814,81 @@ impl FlowyCoreTest {
       .error()
   }
 
+  pub async fn create_field_with_type_option(
+    &self,
+    view_id: &str,
+    field_type: FieldType,
+    type_option_data: Vec<u8>,
+  ) -> FieldPB {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::CreateTypeOption)
+      .payload(CreateFieldPayloadPB {
+        view_id: view_id.to_string(),
+        field_type,
+        type_option_data: Some(type_option_data),
+      })
+      .async_send()
+      .await
+      .parse::<TypeOptionPB>()
+      .field
+  }
+
+  pub async fn get_relation_cell(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    row_id: &str,
+  ) -> RelationCellDataPB {
+    let cell = self.get_cell(view_id, row_id, field_id).await;
+    RelationCellDataPB::try_from(Bytes::from(cell.data)).unwrap()
+  }
+
+  pub async fn update_relation_cell(
+    &self,
+    changeset: RelationCellChangesetPB,
+  ) -> Option<FlowyError> {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::UpdateRelationCell)
+      .payload(changeset)
+      .async_send()
+      .await
+      .error()
+  }
+
+  pub async fn get_related_row_datas(
+    &self,
+    database_id: &str,
+    row_ids: Vec<String>,
+  ) -> Vec<RelatedRowDataPB> {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::GetRelatedRowDatas)
+      .payload(GetRelatedRowDataPB {
+        database_id: database_id.to_string(),
+        row_ids,
+      })
+      .async_send()
+      .await
+      .parse::<RepeatedRelatedRowDataPB>()
+      .rows
+  }
+
+  pub async fn create_reciprocal_relation_field(
+    &self,
+    view_id: &str,
+    field_id: &str,
+  ) -> Option<FlowyError> {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::CreateReciprocalRelationField)
+      .payload(CreateReciprocalRelationPayloadPB {
+        view_id: view_id.to_string(),
+        field_id: field_id.to_string(),
+        name: None,
+      })
+      .async_send()
+      .await
+      .error()
+  }
+
   pub async fn insert_option(
     &self,
     view_id: &str,
@@ -781,6 +951,7 @@ impl FlowyCoreTest {
       .payload(GroupByFieldPayloadPB {
         field_id: field_id.to_string(),
         view_id: view_id.to_string(),
+        setting_content: None,
       })
       .async_send()
       .await
@@ -816,11 +987,49 @@ impl FlowyCoreTest {
       .error()
   }
 
+  pub async fn get_calendar_events_in_window(
+    &self,
+    view_id: &str,
+    start: i64,
+    end: i64,
+  ) -> Vec<CalendarEventPB> {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::GetAllCalendarEvents)
+      .payload(CalendarEventRequestPB {
+        view_id: view_id.to_string(),
+        start: Some(start),
+        end: Some(end),
+      })
+      .async_send()
+      .await
+      .parse::<RepeatedCalendarEventPB>()
+      .items
+  }
+
+  pub async fn move_calendar_event(
+    &self,
+    cell_id: CellIdPB,
+    timestamp: i64,
+    occurrence: Option<i64>,
+  ) -> Option<FlowyError> {
+    EventBuilder::new(self.clone())
+      .event(DatabaseEvent::MoveCalendarEvent)
+      .payload(MoveCalendarEventPB {
+        cell_path: cell_id,
+        timestamp,
+        occurrence,
+      })
+      .async_send()
+      .await
+      .error()
+  }
+
   pub async fn get_all_calendar_events(&self, view_id: &str) -> Vec<CalendarEventPB> {
     EventBuilder::new(self.clone())
       .event(DatabaseEvent::GetAllCalendarEvents)
       .payload(CalendarEventRequestPB {
         view_id: view_id.to_string(),
+        ..Default::default()
       })
       .async_send()
       .await
@@ -1,4 +1,5 @@
 use std::convert::TryFrom;
+use std::time::Duration;
 
 use bytes::Bytes;
 
@@ -6,9 +7,15 @@ use event_integration::event_builder::EventBuilder;
 use event_integration::FlowyCoreTest;
 use flowy_database2::entities::{
   CellChangesetPB, CellIdPB, ChecklistCellDataChangesetPB, DatabaseLayoutPB,
-  DatabaseSettingChangesetPB, DatabaseViewIdPB, DateChangesetPB, FieldType, SelectOptionCellDataPB,
-  UpdateRowMetaChangesetPB,
+  DatabaseSettingChangesetPB, DatabaseViewIdPB, DateChangesetPB, DateRecurrencePB, FieldType,
+  LookupAggregationPB, LookupTypeOptionPB, RecurrenceFrequencyPB, RelationCellChangesetPB,
+  RelationTypeOptionPB, RepeatedFilterPB, RepeatedSortPB, SelectOptionCellDataPB, SortConditionPB,
+  TextFilterConditionPB, TextFilterPB, UpdateFilterPayloadPB, UpdateRowMetaChangesetPB,
+  UpdateSortPayloadPB,
 };
+use flowy_database2::event_map::DatabaseEvent;
+use flowy_user::entities::{ReminderPB, RepeatedReminderPB};
+use flowy_user::event_map::UserEvent::{GetAllReminders, UpdateReminder};
 use lib_infra::util::timestamp;
 
 #[tokio::test]
@@ -446,6 +453,437 @@ async fn update_text_cell_event_test() {
   assert_eq!(s, "hello world");
 }
 
+#[tokio::test]
+async fn restore_cell_from_row_history_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let grid_view = test
+    .create_grid(&current_workspace.id, "my grid view".to_owned(), vec![])
+    .await;
+  let database = test.get_database(&grid_view.id).await;
+  let fields = test.get_all_database_fields(&grid_view.id).await.items;
+  let row_id = database.rows[0].id.clone();
+  let text_field_id = fields[0].id.clone();
+  let checkbox_field_id = fields[2].id.clone();
+
+  for (field_id, cell_changeset) in [
+    (&text_field_id, "draft"),
+    (&text_field_id, "final"),
+    (&checkbox_field_id, "yes"),
+  ] {
+    let error = test
+      .update_cell(CellChangesetPB {
+        view_id: grid_view.id.clone(),
+        row_id: row_id.clone(),
+        field_id: field_id.clone(),
+        cell_changeset: cell_changeset.to_string(),
+      })
+      .await;
+    assert!(error.is_none());
+  }
+
+  let history = test.get_row_history(&grid_view.id, &row_id).await;
+  assert_eq!(history.len(), 3);
+  assert_eq!(history[1].field_id, text_field_id);
+  assert_eq!(history[1].old_content, "draft");
+  assert_eq!(history[1].new_content, "final");
+  assert_eq!(history[1].uid, test.get_user_profile().await.unwrap().id);
+
+  // Restore the text cell only
+  let error = test
+    .restore_cell_change(&grid_view.id, &history[1].id, false)
+    .await;
+  assert!(error.is_none());
+  let cell = test.get_cell(&grid_view.id, &row_id, &text_field_id).await;
+  assert_eq!(String::from_utf8(cell.data).unwrap(), "draft");
+  let cell = test
+    .get_cell(&grid_view.id, &row_id, &checkbox_field_id)
+    .await;
+  assert_eq!(String::from_utf8(cell.data).unwrap(), "Yes");
+
+  // The restoration is recorded too. Restoring the whole row reverts all the changes since the
+  // first one.
+  let history = test.get_row_history(&grid_view.id, &row_id).await;
+  assert_eq!(history.len(), 4);
+  let error = test
+    .restore_cell_change(&grid_view.id, &history[0].id, true)
+    .await;
+  assert!(error.is_none());
+  let cell = test.get_cell(&grid_view.id, &row_id, &text_field_id).await;
+  assert_eq!(
+    String::from_utf8(cell.data).unwrap(),
+    history[0].old_content
+  );
+  let cell = test
+    .get_cell(&grid_view.id, &row_id, &checkbox_field_id)
+    .await;
+  assert_eq!(
+    String::from_utf8(cell.data).unwrap(),
+    history[2].old_content
+  );
+}
+
+#[tokio::test]
+async fn undo_is_not_recorded_in_row_history_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let grid_view = test
+    .create_grid(&current_workspace.id, "my grid view".to_owned(), vec![])
+    .await;
+  let database = test.get_database(&grid_view.id).await;
+  let fields = test.get_all_database_fields(&grid_view.id).await.items;
+  let row_id = database.rows[0].id.clone();
+
+  let error = test
+    .update_cell(CellChangesetPB {
+      view_id: grid_view.id.clone(),
+      row_id: row_id.clone(),
+      field_id: fields[0].id.clone(),
+      cell_changeset: "draft".to_string(),
+    })
+    .await;
+  assert!(error.is_none());
+  assert!(test.undo_database(&grid_view.id).await.is_success);
+
+  let history = test.get_row_history(&grid_view.id, &row_id).await;
+  assert_eq!(history.len(), 1);
+  assert_eq!(history[0].new_content, "draft");
+}
+
+#[tokio::test]
+async fn preview_and_switch_back_field_type_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let grid_view = test
+    .create_grid(&current_workspace.id, "my grid view".to_owned(), vec![])
+    .await;
+  let database = test.get_database(&grid_view.id).await;
+  let text_field = test.create_field(&grid_view.id, FieldType::RichText).await;
+  for (row, content) in database.rows.iter().zip(["12", "abc", "7"]) {
+    let error = test
+      .update_cell(CellChangesetPB {
+        view_id: grid_view.id.clone(),
+        row_id: row.id.clone(),
+        field_id: text_field.id.clone(),
+        cell_changeset: content.to_string(),
+      })
+      .await;
+    assert!(error.is_none());
+  }
+
+  // The dry run doesn't change the field
+  let preview = test
+    .preview_field_type_switch(&grid_view.id, &text_field.id, FieldType::Number)
+    .await;
+  assert_eq!(preview.converted_count, 2);
+  assert_eq!(preview.lost_count, 1);
+  let lost_sample = preview
+    .samples
+    .iter()
+    .find(|sample| sample.after.is_empty())
+    .unwrap();
+  assert_eq!(lost_sample.before, "abc");
+  let fields = test.get_all_database_fields(&grid_view.id).await.items;
+  let field = fields
+    .iter()
+    .find(|field| field.id == text_field.id)
+    .unwrap();
+  assert_eq!(field.field_type, FieldType::RichText);
+
+  // Edit a cell after switching to number. Switching back restores the text of the cell.
+  let error = test
+    .update_field_type(&grid_view.id, &text_field.id, FieldType::Number)
+    .await;
+  assert!(error.is_none());
+  let row_id = database.rows[0].id.clone();
+  let error = test
+    .update_cell(CellChangesetPB {
+      view_id: grid_view.id.clone(),
+      row_id: row_id.clone(),
+      field_id: text_field.id.clone(),
+      cell_changeset: "99".to_string(),
+    })
+    .await;
+  assert!(error.is_none());
+  let error = test
+    .update_field_type(&grid_view.id, &text_field.id, FieldType::RichText)
+    .await;
+  assert!(error.is_none());
+  for (row, content) in database.rows.iter().zip(["12", "abc", "7"]) {
+    let cell = test.get_cell(&grid_view.id, &row.id, &text_field.id).await;
+    assert_eq!(String::from_utf8(cell.data).unwrap(), content);
+  }
+}
+
+#[tokio::test]
+async fn undo_redo_database_changes_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let grid_view = test
+    .create_grid(&current_workspace.id, "my grid view".to_owned(), vec![])
+    .await;
+  let database = test.get_database(&grid_view.id).await;
+  let fields = test.get_all_database_fields(&grid_view.id).await.items;
+  let row_id = database.rows[0].id.clone();
+  let text_field_id = fields[0].id.clone();
+  let state = test.can_undo_redo_database(&grid_view.id).await;
+  assert!(!state.can_undo);
+  assert!(!state.can_redo);
+
+  // Undo and redo a cell edit
+  let old_content = String::from_utf8(
+    test
+      .get_cell(&grid_view.id, &row_id, &text_field_id)
+      .await
+      .data,
+  )
+  .unwrap();
+  let error = test
+    .update_cell(CellChangesetPB {
+      view_id: grid_view.id.clone(),
+      row_id: row_id.clone(),
+      field_id: text_field_id.clone(),
+      cell_changeset: "hello".to_string(),
+    })
+    .await;
+  assert!(error.is_none());
+  let state = test.undo_database(&grid_view.id).await;
+  assert!(state.is_success);
+  assert!(state.can_redo);
+  let cell = test.get_cell(&grid_view.id, &row_id, &text_field_id).await;
+  assert_eq!(String::from_utf8(cell.data).unwrap(), old_content);
+  let state = test.redo_database(&grid_view.id).await;
+  assert!(state.is_success);
+  assert!(!state.can_redo);
+  let cell = test.get_cell(&grid_view.id, &row_id, &text_field_id).await;
+  assert_eq!(String::from_utf8(cell.data).unwrap(), "hello");
+
+  // Undo and redo the creation of a row. The row is created again with the same id.
+  let row_count = database.rows.len();
+  let new_row = test.create_row(&grid_view.id, None, None).await;
+  let state = test.undo_database(&grid_view.id).await;
+  assert!(state.is_success);
+  let rows = test.get_database(&grid_view.id).await.rows;
+  assert_eq!(rows.len(), row_count);
+  test.redo_database(&grid_view.id).await;
+  let rows = test.get_database(&grid_view.id).await.rows;
+  assert_eq!(rows.len(), row_count + 1);
+  assert!(rows.iter().any(|row| row.id == new_row.id));
+
+  // Undo the deletion of a field. The field comes back at the same position.
+  let error = test.delete_field(&grid_view.id, &fields[1].id).await;
+  assert!(error.is_none());
+  let state = test.undo_database(&grid_view.id).await;
+  assert!(state.is_success);
+  let restored_fields = test.get_all_database_fields(&grid_view.id).await.items;
+  assert_eq!(restored_fields.len(), fields.len());
+  assert_eq!(restored_fields[1].id, fields[1].id);
+
+  // A new change clears the changes to redo
+  test.create_row(&grid_view.id, None, None).await;
+  let state = test.can_undo_redo_database(&grid_view.id).await;
+  assert!(state.can_undo);
+  assert!(!state.can_redo);
+}
+
+#[tokio::test]
+async fn undo_field_deletion_restores_filters_and_sorts_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let grid_view = test
+    .create_grid(&current_workspace.id, "my grid view".to_owned(), vec![])
+    .await;
+  let text_field = test.create_field(&grid_view.id, FieldType::RichText).await;
+
+  // Filter and sort the rows by the new field
+  let filter_data: Bytes = TextFilterPB {
+    condition: TextFilterConditionPB::Contains,
+    content: "hello".to_string(),
+    ..Default::default()
+  }
+  .try_into()
+  .unwrap();
+  let error = test
+    .update_setting(DatabaseSettingChangesetPB {
+      view_id: grid_view.id.clone(),
+      update_filter: Some(UpdateFilterPayloadPB {
+        field_id: text_field.id.clone(),
+        field_type: FieldType::RichText,
+        data: filter_data.to_vec(),
+        view_id: grid_view.id.clone(),
+        ..Default::default()
+      }),
+      ..Default::default()
+    })
+    .await;
+  assert!(error.is_none());
+  let error = test
+    .update_setting(DatabaseSettingChangesetPB {
+      view_id: grid_view.id.clone(),
+      update_sort: Some(UpdateSortPayloadPB {
+        view_id: grid_view.id.clone(),
+        field_id: text_field.id.clone(),
+        field_type: FieldType::RichText,
+        condition: SortConditionPB::Descending,
+        ..Default::default()
+      }),
+      ..Default::default()
+    })
+    .await;
+  assert!(error.is_none());
+
+  // Undoing the deletion brings the filter and the sort back with the field
+  let error = test.delete_field(&grid_view.id, &text_field.id).await;
+  assert!(error.is_none());
+  let state = test.undo_database(&grid_view.id).await;
+  assert!(state.is_success);
+
+  let filters = EventBuilder::new(test.clone())
+    .event(DatabaseEvent::GetAllFilters)
+    .payload(DatabaseViewIdPB {
+      value: grid_view.id.clone(),
+    })
+    .async_send()
+    .await
+    .parse::<RepeatedFilterPB>()
+    .items;
+  assert_eq!(filters.len(), 1);
+  assert_eq!(filters[0].field_id, text_field.id);
+  let sorts = EventBuilder::new(test.clone())
+    .event(DatabaseEvent::GetAllSorts)
+    .payload(DatabaseViewIdPB {
+      value: grid_view.id.clone(),
+    })
+    .async_send()
+    .await
+    .parse::<RepeatedSortPB>()
+    .items;
+  assert_eq!(sorts.len(), 1);
+  assert_eq!(sorts[0].field_id, text_field.id);
+  assert_eq!(sorts[0].condition, SortConditionPB::Descending);
+}
+
+#[tokio::test]
+async fn undo_row_creation_unlinks_relation_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let project_view = test
+    .create_grid(&current_workspace.id, "projects".to_owned(), vec![])
+    .await;
+  let task_view = test
+    .create_grid(&current_workspace.id, "tasks".to_owned(), vec![])
+    .await;
+  let projects = test.get_database(&project_view.id).await;
+  let tasks = test.get_database(&task_view.id).await;
+
+  let type_option: Bytes = RelationTypeOptionPB {
+    database_id: projects.id.clone(),
+    reciprocal_field_id: None,
+  }
+  .try_into()
+  .unwrap();
+  let relation_field = test
+    .create_field_with_type_option(&task_view.id, FieldType::Relation, type_option.to_vec())
+    .await;
+
+  // Link the first task to a new project
+  let project = test.create_row(&project_view.id, None, None).await;
+  let error = test
+    .update_relation_cell(RelationCellChangesetPB {
+      cell_id: CellIdPB {
+        view_id: task_view.id.clone(),
+        field_id: relation_field.id.clone(),
+        row_id: tasks.rows[0].id.clone(),
+      },
+      inserted_row_ids: vec![project.id.clone()],
+      removed_row_ids: vec![],
+    })
+    .await;
+  assert!(error.is_none());
+
+  // Undoing the creation deletes the project through the same path as the other deletions, so
+  // it's unlinked from the task
+  let state = test.undo_database(&project_view.id).await;
+  assert!(state.is_success);
+  let cell = test
+    .get_relation_cell(&task_view.id, &relation_field.id, &tasks.rows[0].id)
+    .await;
+  assert!(cell.row_ids.is_empty());
+
+  // Unlinking the project is not a change of the user, so it can't be undone in the tasks view
+  let state = test.undo_database(&task_view.id).await;
+  assert!(state.is_success);
+  let state = test.can_undo_redo_database(&task_view.id).await;
+  assert!(state.can_redo);
+  let cell = test
+    .get_relation_cell(&task_view.id, &relation_field.id, &tasks.rows[0].id)
+    .await;
+  assert!(cell.row_ids.is_empty());
+}
+
+#[tokio::test]
+async fn undo_row_deletion_restores_relation_links_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let project_view = test
+    .create_grid(&current_workspace.id, "projects".to_owned(), vec![])
+    .await;
+  let task_view = test
+    .create_grid(&current_workspace.id, "tasks".to_owned(), vec![])
+    .await;
+  let projects = test.get_database(&project_view.id).await;
+  let tasks = test.get_database(&task_view.id).await;
+
+  let type_option: Bytes = RelationTypeOptionPB {
+    database_id: projects.id.clone(),
+    reciprocal_field_id: None,
+  }
+  .try_into()
+  .unwrap();
+  let relation_field = test
+    .create_field_with_type_option(&task_view.id, FieldType::Relation, type_option.to_vec())
+    .await;
+
+  // Link the first task to the first project
+  let project_id = projects.rows[0].id.clone();
+  let error = test
+    .update_relation_cell(RelationCellChangesetPB {
+      cell_id: CellIdPB {
+        view_id: task_view.id.clone(),
+        field_id: relation_field.id.clone(),
+        row_id: tasks.rows[0].id.clone(),
+      },
+      inserted_row_ids: vec![project_id.clone()],
+      removed_row_ids: vec![],
+    })
+    .await;
+  assert!(error.is_none());
+
+  // Deleting the project unlinks it from the task
+  let error = test.delete_row(&project_view.id, &project_id).await;
+  assert!(error.is_none());
+  let cell = test
+    .get_relation_cell(&task_view.id, &relation_field.id, &tasks.rows[0].id)
+    .await;
+  assert!(cell.row_ids.is_empty());
+
+  // Undoing the deletion links the project to the task again
+  let state = test.undo_database(&project_view.id).await;
+  assert!(state.is_success);
+  let cell = test
+    .get_relation_cell(&task_view.id, &relation_field.id, &tasks.rows[0].id)
+    .await;
+  assert_eq!(cell.row_ids, vec![project_id.clone()]);
+
+  // Redoing it unlinks the project again
+  let state = test.redo_database(&project_view.id).await;
+  assert!(state.is_success);
+  let cell = test
+    .get_relation_cell(&task_view.id, &relation_field.id, &tasks.rows[0].id)
+    .await;
+  assert!(cell.row_ids.is_empty());
+}
+
 #[tokio::test]
 async fn update_checkbox_cell_event_test() {
   let test = FlowyCoreTest::new_with_guest_user().await;
@@ -898,3 +1336,421 @@ async fn create_calendar_event_test() {
   let events = test.get_all_calendar_events(&calendar_view.id).await;
   assert_eq!(events.len(), 1);
 }
+
+#[tokio::test]
+async fn recurring_calendar_event_with_reminder_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let calendar_view = test
+    .create_calendar(&current_workspace.id, "my calendar view".to_owned(), vec![])
+    .await;
+  let fields = test.get_all_database_fields(&calendar_view.id).await.items;
+  let date_field = fields
+    .iter()
+    .find(|field| field.field_type == FieldType::DateTime)
+    .unwrap();
+  let row = test.create_row(&calendar_view.id, None, None).await;
+
+  // Repeat the date every week and remind one hour before each occurrence.
+  let start = timestamp();
+  let error = test
+    .update_date_cell(DateChangesetPB {
+      cell_id: CellIdPB {
+        view_id: calendar_view.id.clone(),
+        field_id: date_field.id.clone(),
+        row_id: row.id.clone(),
+      },
+      date: Some(start),
+      recurrence: Some(DateRecurrencePB {
+        frequency: RecurrenceFrequencyPB::Weekly,
+        interval: 1,
+        ..Default::default()
+      }),
+      reminder_offset: Some(3600),
+      ..Default::default()
+    })
+    .await;
+  assert!(error.is_none());
+
+  let week = 7 * 24 * 60 * 60;
+  let events = test
+    .get_calendar_events_in_window(&calendar_view.id, start, start + 3 * week)
+    .await;
+  let timestamps = events
+    .iter()
+    .map(|event| event.timestamp)
+    .collect::<Vec<_>>();
+  assert_eq!(
+    timestamps,
+    vec![start, start + week, start + 2 * week, start + 3 * week]
+  );
+  assert!(events.iter().all(|event| event.is_recurring));
+
+  // The first occurrence starts within the hour, so the reminder is scheduled for the next one.
+  let reminders = EventBuilder::new(test.clone())
+    .event(GetAllReminders)
+    .async_send()
+    .await
+    .parse::<RepeatedReminderPB>()
+    .items;
+  let reminder = reminders
+    .iter()
+    .find(|reminder| reminder.id == format!("{}-{}", row.id, date_field.id))
+    .unwrap();
+  assert_eq!(reminder.scheduled_at, start + week - 3600);
+  assert_eq!(reminder.object_id, calendar_view.id);
+
+  // Acknowledging a fired reminder schedules the reminder of the next occurrence.
+  let fired_reminder = ReminderPB {
+    scheduled_at: start - 3600,
+    is_ack: true,
+    ..reminder.clone()
+  };
+  EventBuilder::new(test.clone())
+    .event(UpdateReminder)
+    .payload(fired_reminder)
+    .async_send()
+    .await;
+  let reminders = EventBuilder::new(test.clone())
+    .event(GetAllReminders)
+    .async_send()
+    .await
+    .parse::<RepeatedReminderPB>()
+    .items;
+  let reminder = reminders
+    .iter()
+    .find(|reminder| reminder.id == format!("{}-{}", row.id, date_field.id))
+    .unwrap();
+  assert_eq!(reminder.scheduled_at, start + week - 3600);
+  assert!(!reminder.is_ack);
+
+  // Deleting the row removes its reminder.
+  let error = test.delete_row(&calendar_view.id, &row.id).await;
+  assert!(error.is_none());
+  let reminders = EventBuilder::new(test.clone())
+    .event(GetAllReminders)
+    .async_send()
+    .await
+    .parse::<RepeatedReminderPB>()
+    .items;
+  assert!(reminders
+    .iter()
+    .all(|reminder| reminder.id != format!("{}-{}", row.id, date_field.id)));
+}
+
+#[tokio::test]
+async fn multi_day_calendar_event_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let calendar_view = test
+    .create_calendar(&current_workspace.id, "my calendar view".to_owned(), vec![])
+    .await;
+  let fields = test.get_all_database_fields(&calendar_view.id).await.items;
+  let date_field = fields
+    .iter()
+    .find(|field| field.field_type == FieldType::DateTime)
+    .unwrap();
+  let row = test.create_row(&calendar_view.id, None, None).await;
+  let cell_id = CellIdPB {
+    view_id: calendar_view.id.clone(),
+    field_id: date_field.id.clone(),
+    row_id: row.id.clone(),
+  };
+
+  // Nov 15, 2023 00:00 → Nov 17, 2023 00:00
+  let day = 24 * 60 * 60;
+  let start = 1700006400;
+  let error = test
+    .update_date_cell(DateChangesetPB {
+      cell_id: cell_id.clone(),
+      date: Some(start),
+      end_date: Some(start + 2 * day),
+      is_range: Some(true),
+      ..Default::default()
+    })
+    .await;
+  assert!(error.is_none());
+
+  // The event started before the window, but it's returned because it ends inside the window.
+  let events = test
+    .get_calendar_events_in_window(&calendar_view.id, start + day, start + 7 * day)
+    .await;
+  assert_eq!(events.len(), 1);
+  assert_eq!(events[0].timestamp, start);
+  assert_eq!(events[0].end_timestamp, start + 2 * day);
+
+  let events = test
+    .get_calendar_events_in_window(&calendar_view.id, start + 3 * day, start + 7 * day)
+    .await;
+  assert!(events.is_empty());
+
+  // Moving the event keeps its duration.
+  let error = test
+    .move_calendar_event(cell_id, start + 5 * day, None)
+    .await;
+  assert!(error.is_none());
+  let events = test
+    .get_calendar_events_in_window(&calendar_view.id, start + 3 * day, start + 7 * day)
+    .await;
+  assert_eq!(events.len(), 1);
+  assert_eq!(events[0].timestamp, start + 5 * day);
+  assert_eq!(events[0].end_timestamp, start + 7 * day);
+}
+
+#[tokio::test]
+async fn move_recurring_calendar_event_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let calendar_view = test
+    .create_calendar(&current_workspace.id, "my calendar view".to_owned(), vec![])
+    .await;
+  let fields = test.get_all_database_fields(&calendar_view.id).await.items;
+  let date_field = fields
+    .iter()
+    .find(|field| field.field_type == FieldType::DateTime)
+    .unwrap();
+  let row = test.create_row(&calendar_view.id, None, None).await;
+  let cell_id = CellIdPB {
+    view_id: calendar_view.id.clone(),
+    field_id: date_field.id.clone(),
+    row_id: row.id.clone(),
+  };
+
+  // Every Wednesday, starting on Nov 15, 2023
+  let day = 24 * 60 * 60;
+  let start = 1700006400;
+  let error = test
+    .update_date_cell(DateChangesetPB {
+      cell_id: cell_id.clone(),
+      date: Some(start),
+      recurrence: Some(DateRecurrencePB {
+        frequency: RecurrenceFrequencyPB::Weekly,
+        interval: 1,
+        weekdays: vec![2],
+        ..Default::default()
+      }),
+      ..Default::default()
+    })
+    .await;
+  assert!(error.is_none());
+
+  // Dragging the third occurrence to Thursday moves the whole series by one day, including the
+  // occurrences before the dragged one.
+  let error = test
+    .move_calendar_event(cell_id, start + 15 * day, Some(start + 14 * day))
+    .await;
+  assert!(error.is_none());
+  let timestamps = test
+    .get_calendar_events_in_window(&calendar_view.id, start, start + 30 * day)
+    .await
+    .iter()
+    .map(|event| event.timestamp)
+    .collect::<Vec<_>>();
+  assert_eq!(
+    timestamps,
+    vec![
+      start + day,
+      start + 8 * day,
+      start + 15 * day,
+      start + 22 * day,
+      start + 29 * day
+    ]
+  );
+}
+
+#[tokio::test]
+async fn update_relation_cell_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let project_view = test
+    .create_grid(&current_workspace.id, "projects".to_owned(), vec![])
+    .await;
+  let task_view = test
+    .create_grid(&current_workspace.id, "tasks".to_owned(), vec![])
+    .await;
+  let projects = test.get_database(&project_view.id).await;
+  let tasks = test.get_database(&task_view.id).await;
+
+  // Name the first project
+  let primary_field = test.get_primary_field(&project_view.id).await;
+  let error = test
+    .update_cell(CellChangesetPB {
+      view_id: project_view.id.clone(),
+      row_id: projects.rows[0].id.clone(),
+      field_id: primary_field.id.clone(),
+      cell_changeset: "AppFlowy".to_string(),
+    })
+    .await;
+  assert!(error.is_none());
+
+  // Create a relation field in the tasks database that links to the projects database
+  let type_option: Bytes = RelationTypeOptionPB {
+    database_id: projects.id.clone(),
+    reciprocal_field_id: None,
+  }
+  .try_into()
+  .unwrap();
+  let relation_field = test
+    .create_field_with_type_option(&task_view.id, FieldType::Relation, type_option.to_vec())
+    .await;
+  let error = test
+    .create_reciprocal_relation_field(&task_view.id, &relation_field.id)
+    .await;
+  assert!(error.is_none());
+
+  // Link the first task to the first project
+  let error = test
+    .update_relation_cell(RelationCellChangesetPB {
+      cell_id: CellIdPB {
+        view_id: task_view.id.clone(),
+        field_id: relation_field.id.clone(),
+        row_id: tasks.rows[0].id.clone(),
+      },
+      inserted_row_ids: vec![projects.rows[0].id.clone()],
+      removed_row_ids: vec![],
+    })
+    .await;
+  assert!(error.is_none());
+
+  let cell = test
+    .get_relation_cell(&task_view.id, &relation_field.id, &tasks.rows[0].id)
+    .await;
+  assert_eq!(cell.row_ids, vec![projects.rows[0].id.clone()]);
+
+  let related_rows = test
+    .get_related_row_datas(&projects.id, cell.row_ids.clone())
+    .await;
+  assert_eq!(related_rows.len(), 1);
+  assert_eq!(related_rows[0].name, "AppFlowy");
+
+  // The reciprocal field links the project back to the task
+  let reciprocal_field = test
+    .get_all_database_fields(&project_view.id)
+    .await
+    .items
+    .into_iter()
+    .find(|field| field.field_type == FieldType::Relation)
+    .unwrap();
+  let cell = test
+    .get_relation_cell(&project_view.id, &reciprocal_field.id, &projects.rows[0].id)
+    .await;
+  assert_eq!(cell.row_ids, vec![tasks.rows[0].id.clone()]);
+
+  // Deleting the project unlinks it from the task
+  let error = test
+    .delete_row(&project_view.id, &projects.rows[0].id)
+    .await;
+  assert!(error.is_none());
+  let cell = test
+    .get_relation_cell(&task_view.id, &relation_field.id, &tasks.rows[0].id)
+    .await;
+  assert!(cell.row_ids.is_empty());
+}
+
+#[tokio::test]
+async fn lookup_cell_pulls_value_from_source_database_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let current_workspace = test.get_current_workspace().await.workspace;
+  let price_view = test
+    .create_grid(&current_workspace.id, "prices".to_owned(), vec![])
+    .await;
+  let order_view = test
+    .create_grid(&current_workspace.id, "orders".to_owned(), vec![])
+    .await;
+  let prices = test.get_database(&price_view.id).await;
+  let orders = test.get_database(&order_view.id).await;
+
+  // The price list has a product named Apple that costs 3
+  let price_primary_field = test.get_primary_field(&price_view.id).await;
+  let price_field = test.create_field(&price_view.id, FieldType::Number).await;
+  for (field_id, content) in [(&price_primary_field.id, "Apple"), (&price_field.id, "3")] {
+    let error = test
+      .update_cell(CellChangesetPB {
+        view_id: price_view.id.clone(),
+        row_id: prices.rows[0].id.clone(),
+        field_id: field_id.clone(),
+        cell_changeset: content.to_string(),
+      })
+      .await;
+    assert!(error.is_none());
+  }
+
+  // The first order is an order of Apple
+  let order_primary_field = test.get_primary_field(&order_view.id).await;
+  let error = test
+    .update_cell(CellChangesetPB {
+      view_id: order_view.id.clone(),
+      row_id: orders.rows[0].id.clone(),
+      field_id: order_primary_field.id.clone(),
+      cell_changeset: "Apple".to_string(),
+    })
+    .await;
+  assert!(error.is_none());
+
+  let type_option: Bytes = LookupTypeOptionPB {
+    database_id: prices.id.clone(),
+    key_field_id: order_primary_field.id.clone(),
+    source_key_field_id: price_primary_field.id.clone(),
+    value_field_id: price_field.id.clone(),
+    aggregation: LookupAggregationPB::First,
+  }
+  .try_into()
+  .unwrap();
+  let lookup_field = test
+    .create_field_with_type_option(&order_view.id, FieldType::Lookup, type_option.to_vec())
+    .await;
+  let cell = test
+    .get_cell(&order_view.id, &orders.rows[0].id, &lookup_field.id)
+    .await;
+  assert_eq!(String::from_utf8(cell.data).unwrap(), "3");
+
+  // The lookup cells can't be edited
+  let error = test
+    .update_cell(CellChangesetPB {
+      view_id: order_view.id.clone(),
+      row_id: orders.rows[0].id.clone(),
+      field_id: lookup_field.id.clone(),
+      cell_changeset: "4".to_string(),
+    })
+    .await;
+  assert!(error.is_some());
+
+  // Changing the price in the price list refreshes the order
+  let error = test
+    .update_cell(CellChangesetPB {
+      view_id: price_view.id.clone(),
+      row_id: prices.rows[0].id.clone(),
+      field_id: price_field.id.clone(),
+      cell_changeset: "5".to_string(),
+    })
+    .await;
+  assert!(error.is_none());
+  tokio::time::sleep(Duration::from_millis(300)).await;
+  let cell = test
+    .get_cell(&order_view.id, &orders.rows[0].id, &lookup_field.id)
+    .await;
+  assert_eq!(String::from_utf8(cell.data).unwrap(), "5");
+}
+
+#[tokio::test]
+async fn infer_csv_import_field_types_test() {
+  let test = FlowyCoreTest::new_with_guest_user().await;
+  let content = r#"Name,Amount,Due,Done,Status
+a,12%,2023-05-26,yes,Todo
+b,7.5%,2023-05-22,no,Todo"#;
+  let columns = test.get_csv_import_field_types(content).await;
+  let field_types = columns
+    .into_iter()
+    .map(|column| column.field_type)
+    .collect::<Vec<FieldType>>();
+  assert_eq!(
+    field_types,
+    vec![
+      FieldType::RichText,
+      FieldType::Number,
+      FieldType::DateTime,
+      FieldType::Checkbox,
+      FieldType::SingleSelect,
+    ]
+  );
+}
@@ -1,14 +1,26 @@
+use std::collections::HashMap;
 use std::sync::{Arc, Weak};
 
+use diesel::SqliteConnection;
 use tokio::sync::RwLock;
 
 use collab_integrate::collab_builder::AppFlowyCollabBuilder;
 use collab_integrate::RocksCollabDB;
+use flowy_database2::services::field::{DateReminder, PersonMember, UserDateTimeSettings};
+use flowy_database2::services::row_history::{CellChange, RowHistoryPersistence};
 use flowy_database2::{DatabaseManager, DatabaseUser};
 use flowy_database_deps::cloud::DatabaseCloudService;
-use flowy_error::FlowyError;
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::{
+  insert_or_ignore_into,
+  prelude::*,
+  schema::{database_row_history, database_row_history::dsl},
+};
+use flowy_storage::FileStorageService;
 use flowy_task::TaskDispatcher;
+use flowy_user::entities::ReminderPB;
 use flowy_user::manager::UserManager;
+use lib_infra::future::FutureResult;
 
 pub struct DatabaseDepsResolver();
 
@@ -18,13 +30,17 @@ impl DatabaseDepsResolver {
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
     collab_builder: Arc<AppFlowyCollabBuilder>,
     cloud_service: Arc<dyn DatabaseCloudService>,
+    storage_service: Weak<dyn FileStorageService>,
   ) -> Arc<DatabaseManager> {
-    let user = Arc::new(DatabaseUserImpl(user_manager));
+    let user = Arc::new(DatabaseUserImpl(user_manager.clone()));
+    let row_history = Arc::new(RowHistoryDBImpl(user_manager));
     Arc::new(DatabaseManager::new(
       user,
       task_scheduler,
       collab_builder,
       cloud_service,
+      storage_service,
+      row_history,
     ))
   }
 }
@@ -54,4 +70,191 @@ impl DatabaseUser for DatabaseUserImpl {
       .ok_or(FlowyError::internal().with_context("Unexpected error: UserSession is None"))?
       .get_collab_db(uid)
   }
+
+  fn add_reminder(&self, reminder: DateReminder) -> FutureResult<(), FlowyError> {
+    let user_manager = self.0.upgrade();
+    FutureResult::new(async move {
+      let meta = HashMap::from([
+        ("view_id".to_string(), reminder.view_id.clone()),
+        ("row_id".to_string(), reminder.row_id),
+        ("field_id".to_string(), reminder.field_id),
+      ]);
+      let reminder = ReminderPB {
+        id: reminder.id,
+        object_id: reminder.view_id,
+        scheduled_at: reminder.scheduled_at,
+        is_ack: false,
+        is_read: false,
+        title: reminder.title,
+        message: reminder.message,
+        meta,
+      };
+      user_manager
+        .ok_or(FlowyError::internal().with_context("Unexpected error: UserSession is None"))?
+        .add_reminder(reminder)
+        .await
+    })
+  }
+
+  fn remove_reminder(&self, reminder_id: &str) -> FutureResult<(), FlowyError> {
+    let user_manager = self.0.upgrade();
+    let reminder_id = reminder_id.to_string();
+    FutureResult::new(async move {
+      user_manager
+        .ok_or(FlowyError::internal().with_context("Unexpected error: UserSession is None"))?
+        .remove_reminder(&reminder_id)
+        .await
+    })
+  }
+
+  fn get_date_time_settings(&self) -> UserDateTimeSettings {
+    match self.0.upgrade() {
+      None => UserDateTimeSettings::default(),
+      Some(user_manager) => {
+        let settings = user_manager.get_date_time_settings();
+        UserDateTimeSettings {
+          timezone_id: settings.timezone_id,
+          first_day_of_week: settings.first_day_of_week.weekday(),
+        }
+      },
+    }
+  }
+
+  fn workspace_id(&self) -> Result<String, FlowyError> {
+    let session = self
+      .0
+      .upgrade()
+      .ok_or(FlowyError::internal().with_context("Unexpected error: UserSession is None"))?
+      .get_session()?;
+    Ok(session.user_workspace.id)
+  }
+
+  fn get_workspace_members(&self) -> FutureResult<Vec<PersonMember>, FlowyError> {
+    let user_manager = self.0.upgrade();
+    FutureResult::new(async move {
+      let members = user_manager
+        .ok_or(FlowyError::internal().with_context("Unexpected error: UserSession is None"))?
+        .get_workspace_members()
+        .await?
+        .into_iter()
+        .map(|member| PersonMember {
+          uid: member.uid,
+          name: member.name,
+          email: member.email,
+          icon_url: member.icon_url,
+        })
+        .collect();
+      Ok(members)
+    })
+  }
+}
+
+struct RowHistoryDBImpl(Weak<UserManager>);
+
+impl RowHistoryDBImpl {
+  fn with_conn<F, T>(&self, uid: i64, f: F) -> FlowyResult<T>
+  where
+    F: FnOnce(&SqliteConnection) -> FlowyResult<T>,
+  {
+    let pool = self
+      .0
+      .upgrade()
+      .ok_or(FlowyError::internal().with_context("Unexpected error: UserSession is None"))?
+      .db_pool(uid)?;
+    let conn = pool.get()?;
+    f(&conn)
+  }
+}
+
+impl RowHistoryPersistence for RowHistoryDBImpl {
+  fn insert_cell_changes(&self, uid: i64, changes: Vec<CellChange>) -> FlowyResult<()> {
+    self.with_conn(uid, |conn| {
+      conn.immediate_transaction(|| {
+        for change in changes {
+          insert_or_ignore_into(dsl::database_row_history)
+            .values(CellChangeRow::from(change))
+            .execute(conn)?;
+        }
+        Ok(())
+      })
+    })
+  }
+
+  fn get_row_history(&self, uid: i64, row_id: &str) -> FlowyResult<Vec<CellChange>> {
+    self.with_conn(uid, |conn| {
+      let rows = dsl::database_row_history
+        .filter(dsl::row_id.eq(row_id))
+        .order(dsl::timestamp.asc())
+        .load::<CellChangeRow>(conn)?;
+      Ok(rows.into_iter().map(CellChange::from).collect())
+    })
+  }
+
+  fn get_cell_change(&self, uid: i64, change_id: &str) -> FlowyResult<Option<CellChange>> {
+    self.with_conn(uid, |conn| {
+      let row = dsl::database_row_history
+        .filter(dsl::id.eq(change_id))
+        .first::<CellChangeRow>(conn)
+        .ok();
+      Ok(row.map(CellChange::from))
+    })
+  }
+
+  fn delete_changes_before(&self, uid: i64, timestamp: i64) -> FlowyResult<()> {
+    self.with_conn(uid, |conn| {
+      diesel::delete(dsl::database_row_history.filter(dsl::timestamp.lt(timestamp)))
+        .execute(conn)?;
+      Ok(())
+    })
+  }
+}
+
+/// The cells of the change are saved as json strings.
+#[derive(Clone, Debug, Queryable, Insertable)]
+#[table_name = "database_row_history"]
+struct CellChangeRow {
+  id: String,
+  database_id: String,
+  row_id: String,
+  field_id: String,
+  uid: i64,
+  old_cell: String,
+  new_cell: String,
+  old_content: String,
+  new_content: String,
+  timestamp: i64,
+}
+
+impl From<CellChange> for CellChangeRow {
+  fn from(change: CellChange) -> Self {
+    Self {
+      id: change.id,
+      database_id: change.database_id,
+      row_id: change.row_id,
+      field_id: change.field_id,
+      uid: change.uid,
+      old_cell: serde_json::to_string(&change.old_cell).unwrap_or_default(),
+      new_cell: serde_json::to_string(&change.new_cell).unwrap_or_default(),
+      old_content: change.old_content,
+      new_content: change.new_content,
+      timestamp: change.timestamp,
+    }
+  }
+}
+
+impl From<CellChangeRow> for CellChange {
+  fn from(row: CellChangeRow) -> Self {
+    Self {
+      id: row.id,
+      database_id: row.database_id,
+      row_id: row.row_id,
+      field_id: row.field_id,
+      uid: row.uid,
+      old_cell: serde_json::from_str(&row.old_cell).unwrap_or_default(),
+      new_cell: serde_json::from_str(&row.new_cell).unwrap_or_default(),
+      old_content: row.old_content,
+      new_content: row.new_content,
+      timestamp: row.timestamp,
+    }
+  }
 }
@@ -2,29 +2,48 @@ use std::sync::{Arc, Weak};
 
 use collab_integrate::collab_builder::AppFlowyCollabBuilder;
 use collab_integrate::RocksCollabDB;
-use flowy_database2::DatabaseManager;
+use flowy_database2::{DatabaseManager, RowDocumentReader};
 use flowy_document2::manager::{DocumentManager, DocumentUser};
 use flowy_document_deps::cloud::DocumentCloudService;
 use flowy_error::FlowyError;
 use flowy_storage::FileStorageService;
 use flowy_user::manager::UserManager;
+use lib_infra::future::FutureResult;
 
 pub struct DocumentDepsResolver();
 impl DocumentDepsResolver {
   pub fn resolve(
     user_manager: Weak<UserManager>,
-    _database_manager: &Arc<DatabaseManager>,
+    database_manager: &Arc<DatabaseManager>,
     collab_builder: Arc<AppFlowyCollabBuilder>,
     cloud_service: Arc<dyn DocumentCloudService>,
     storage_service: Weak<dyn FileStorageService>,
   ) -> Arc<DocumentManager> {
     let user: Arc<dyn DocumentUser> = Arc::new(DocumentUserImpl(user_manager));
-    Arc::new(DocumentManager::new(
+    let manager = Arc::new(DocumentManager::new(
       user.clone(),
       collab_builder,
       cloud_service,
       storage_service,
-    ))
+    ));
+    database_manager
+      .set_row_document_reader(Arc::new(RowDocumentReaderImpl(Arc::downgrade(&manager))));
+    manager
+  }
+}
+
+struct RowDocumentReaderImpl(Weak<DocumentManager>);
+impl RowDocumentReader for RowDocumentReaderImpl {
+  fn get_document_text(&self, document_id: &str) -> FutureResult<Option<String>, FlowyError> {
+    let manager = self.0.upgrade();
+    let document_id = document_id.to_string();
+    FutureResult::new(async move {
+      let manager =
+        manager.ok_or(FlowyError::internal().with_context("DocumentManager is None"))?;
+      // The document of a row is only created when the row is opened, so the rows without a
+      // document are exported with an empty text.
+      Ok(manager.get_document_text(&document_id).await.ok())
+    })
   }
 }
 
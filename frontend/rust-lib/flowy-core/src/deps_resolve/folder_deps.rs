@@ -7,8 +7,8 @@ use tokio::sync::RwLock;
 
 use collab_integrate::collab_builder::AppFlowyCollabBuilder;
 use collab_integrate::RocksCollabDB;
-use flowy_database2::entities::DatabaseLayoutPB;
-use flowy_database2::services::share::csv::CSVFormat;
+use flowy_database2::entities::{DatabaseLayoutPB, FieldType};
+use flowy_database2::services::share::csv::{CSVFormat, CSVImportOptions, ImportResult};
 use flowy_database2::template::{make_default_board, make_default_calendar, make_default_grid};
 use flowy_database2::DatabaseManager;
 use flowy_document2::entities::DocumentDataPB;
@@ -17,7 +17,7 @@ use flowy_document2::parser::json::parser::JsonToDocumentParser;
 use flowy_error::FlowyError;
 use flowy_folder2::entities::ViewLayoutPB;
 use flowy_folder2::manager::{FolderManager, FolderUser};
-use flowy_folder2::share::ImportType;
+use flowy_folder2::share::{ImportType, ImportedView};
 use flowy_folder2::view_operation::{
   FolderOperationHandler, FolderOperationHandlers, View, WorkspaceViewBuilder,
 };
@@ -197,7 +197,8 @@ impl FolderOperationHandler for DocumentFolderOperation {
     _name: &str,
     _import_type: ImportType,
     bytes: Vec<u8>,
-  ) -> FutureResult<(), FlowyError> {
+    _field_types: HashMap<usize, i64>,
+  ) -> FutureResult<Vec<ImportedView>, FlowyError> {
     let view_id = view_id.to_string();
     let manager = self.0.clone();
     FutureResult::new(async move {
@@ -205,7 +206,7 @@ impl FolderOperationHandler for DocumentFolderOperation {
       manager
         .create_document(uid, &view_id, Some(data.into()))
         .await?;
-      Ok(())
+      Ok(vec![])
     })
   }
 
@@ -214,9 +215,11 @@ impl FolderOperationHandler for DocumentFolderOperation {
     &self,
     _view_id: &str,
     _name: &str,
+    _import_type: ImportType,
     _path: String,
-  ) -> FutureResult<(), FlowyError> {
-    FutureResult::new(async move { Ok(()) })
+    _field_types: HashMap<usize, i64>,
+  ) -> FutureResult<Vec<ImportedView>, FlowyError> {
+    FutureResult::new(async move { Ok(vec![]) })
   }
 }
 
@@ -324,40 +327,64 @@ impl FolderOperationHandler for DatabaseFolderOperation {
     &self,
     _uid: i64,
     view_id: &str,
-    _name: &str,
+    name: &str,
     import_type: ImportType,
     bytes: Vec<u8>,
-  ) -> FutureResult<(), FlowyError> {
+    field_types: HashMap<usize, i64>,
+  ) -> FutureResult<Vec<ImportedView>, FlowyError> {
     let database_manager = self.0.clone();
     let view_id = view_id.to_string();
-    let format = match import_type {
-      ImportType::CSV => CSVFormat::Original,
-      ImportType::HistoryDatabase => CSVFormat::META,
-      ImportType::RawDatabase => CSVFormat::META,
-      _ => CSVFormat::Original,
-    };
+    let name = name.to_string();
     FutureResult::new(async move {
-      let content =
-        String::from_utf8(bytes).map_err(|err| FlowyError::internal().with_context(err))?;
-      database_manager
-        .import_csv(view_id, content, format)
-        .await?;
-      Ok(())
+      if let ImportType::XLSX = import_type {
+        let sheets = database_manager.import_xlsx(view_id, bytes).await?;
+        return Ok(imported_sheet_views(&name, sheets));
+      }
+
+      if let ImportType::JSON = import_type {
+        let content =
+          String::from_utf8(bytes).map_err(|err| FlowyError::internal().with_context(err))?;
+        database_manager.import_json(view_id, content).await?;
+      } else {
+        let (format, options) = csv_format_and_options(import_type, field_types);
+        database_manager
+          .import_csv_from_bytes(view_id, bytes, format, options)
+          .await?;
+      }
+      Ok(vec![])
     })
   }
 
   fn import_from_file_path(
     &self,
-    _view_id: &str,
-    _name: &str,
+    view_id: &str,
+    name: &str,
+    import_type: ImportType,
     path: String,
-  ) -> FutureResult<(), FlowyError> {
+    field_types: HashMap<usize, i64>,
+  ) -> FutureResult<Vec<ImportedView>, FlowyError> {
     let database_manager = self.0.clone();
+    let view_id = view_id.to_string();
+    let name = name.to_string();
     FutureResult::new(async move {
-      database_manager
-        .import_csv_from_file(path, CSVFormat::META)
-        .await?;
-      Ok(())
+      match import_type {
+        ImportType::XLSX => {
+          let bytes = tokio::fs::read(&path).await?;
+          let sheets = database_manager.import_xlsx(view_id, bytes).await?;
+          return Ok(imported_sheet_views(&name, sheets));
+        },
+        ImportType::JSON => {
+          let content = tokio::fs::read_to_string(&path).await?;
+          database_manager.import_json(view_id, content).await?;
+        },
+        _ => {
+          let (format, options) = csv_format_and_options(import_type, field_types);
+          database_manager
+            .import_csv_from_file(view_id, path, format, options)
+            .await?;
+        },
+      }
+      Ok(vec![])
     })
   }
 
@@ -388,6 +415,39 @@ impl FolderOperationHandler for DatabaseFolderOperation {
   }
 }
 
+/// Returns the views of the sheets of an imported workbook. The first sheet is imported as the
+/// view of the import, so the views of the other sheets are named after the workbook and the sheet.
+fn imported_sheet_views(name: &str, sheets: Vec<(String, ImportResult)>) -> Vec<ImportedView> {
+  sheets
+    .into_iter()
+    .skip(1)
+    .map(|(sheet_name, result)| ImportedView {
+      view_id: result.view_id,
+      name: format!("{} - {}", name, sheet_name),
+    })
+    .collect()
+}
+
+fn csv_format_and_options(
+  import_type: ImportType,
+  field_types: HashMap<usize, i64>,
+) -> (CSVFormat, CSVImportOptions) {
+  let format = match import_type {
+    ImportType::CSV => CSVFormat::Original,
+    ImportType::HistoryDatabase => CSVFormat::META,
+    ImportType::RawDatabase => CSVFormat::META,
+    _ => CSVFormat::Original,
+  };
+  let options = CSVImportOptions {
+    field_types: field_types
+      .into_iter()
+      .map(|(index, field_type)| (index, FieldType::from(field_type)))
+      .collect(),
+    ..Default::default()
+  };
+  (format, options)
+}
+
 #[derive(Debug, serde::Deserialize)]
 struct CreateDatabaseExtParams {
   database_id: String,
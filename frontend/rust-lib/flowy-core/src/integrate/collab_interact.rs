@@ -6,11 +6,12 @@ use flowy_database2::DatabaseManager;
 use flowy_document2::manager::DocumentManager;
 use flowy_document2::reminder::{DocumentReminder, DocumentReminderAction};
 use flowy_folder_deps::cloud::Error;
+use flowy_user::entities::ReminderPB;
 use flowy_user::services::collab_interact::CollabInteract;
 use lib_infra::future::FutureResult;
+use lib_infra::util::timestamp;
 
 pub struct CollabInteractImpl {
-  #[allow(dead_code)]
   pub(crate) database_manager: Weak<DatabaseManager>,
   #[allow(dead_code)]
   pub(crate) document_manager: Weak<DocumentManager>,
@@ -48,7 +49,27 @@ impl CollabInteract for CollabInteractImpl {
 
   fn update_reminder(&self, reminder: Reminder) -> FutureResult<(), Error> {
     let cloned_document_manager = self.document_manager.clone();
+    let cloned_database_manager = self.database_manager.clone();
     FutureResult::new(async move {
+      // The reminder of a date cell is updated after it fired, e.g. when it's acknowledged or
+      // read, then the reminder of the next occurrence of a recurring date is scheduled.
+      let reminder_pb = ReminderPB::from(reminder.clone());
+      if reminder_pb.scheduled_at <= timestamp() {
+        if let (Some(database_manager), Some(view_id), Some(row_id), Some(field_id)) = (
+          cloned_database_manager.upgrade(),
+          reminder_pb.meta.get("view_id"),
+          reminder_pb.meta.get("row_id"),
+          reminder_pb.meta.get("field_id"),
+        ) {
+          if let Err(err) = database_manager
+            .did_fire_date_reminder(view_id, row_id, field_id, reminder_pb.scheduled_at)
+            .await
+          {
+            tracing::error!("Failed to schedule the next reminder: {:?}", err);
+          }
+        }
+      }
+
       if let Some(document_manager) = cloned_document_manager.upgrade() {
         match DocumentReminder::try_from(reminder) {
           Ok(reminder) => {
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
 use std::sync::{Arc, Weak};
 
 use parking_lot::RwLock;
@@ -108,7 +109,8 @@ impl ServerProvider {
         let local_db = Arc::new(LocalServerDBImpl {
           storage_path: self.config.storage_path.clone(),
         });
-        let server = Arc::new(LocalServer::new(local_db));
+        let file_storage_path = PathBuf::from(&self.config.storage_path).join("files");
+        let server = Arc::new(LocalServer::new(local_db, file_storage_path));
         Ok::<Arc<dyn AppFlowyServer>, FlowyError>(server)
       },
       ServerType::AFCloud => {
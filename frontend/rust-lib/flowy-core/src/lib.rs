@@ -133,6 +133,7 @@ impl AppFlowyCore {
         task_dispatcher.clone(),
         collab_builder.clone(),
         server_provider.clone(),
+        Arc::downgrade(&(server_provider.clone() as Arc<dyn FileStorageService>)),
       )
       .await;
 
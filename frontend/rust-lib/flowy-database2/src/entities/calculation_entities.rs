@@ -0,0 +1,78 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+use crate::entities::FieldType;
+use crate::impl_into_calculation_type;
+
+/// The calculation that is shown below a field in the grid. It's evaluated over the rows that
+/// are visible after applying the filters of the view.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, ProtoBuf_Enum, Eq, PartialEq)]
+pub enum CalculationType {
+  #[default]
+  None = 0,
+  Count = 1,
+  CountEmpty = 2,
+  CountUnique = 3,
+  Sum = 4,
+  Average = 5,
+  Median = 6,
+  Min = 7,
+  Max = 8,
+  Earliest = 9,
+  Latest = 10,
+  PercentChecked = 11,
+}
+
+impl CalculationType {
+  /// Returns true if the calculation can be applied to the cells of the given field type.
+  pub fn is_supported_by(&self, field_type: &FieldType) -> bool {
+    match self {
+      CalculationType::None
+      | CalculationType::Count
+      | CalculationType::CountEmpty
+      | CalculationType::CountUnique => true,
+      CalculationType::Sum
+      | CalculationType::Average
+      | CalculationType::Median
+      | CalculationType::Min
+      | CalculationType::Max => field_type.is_number() || field_type.is_formula(),
+      CalculationType::Earliest | CalculationType::Latest => matches!(
+        field_type,
+        FieldType::DateTime | FieldType::LastEditedTime | FieldType::CreatedTime
+      ),
+      CalculationType::PercentChecked => field_type.is_checkbox(),
+    }
+  }
+}
+
+impl_into_calculation_type!(i64);
+impl_into_calculation_type!(u8);
+
+impl From<CalculationType> for i64 {
+  fn from(value: CalculationType) -> Self {
+    (value as u8) as i64
+  }
+}
+
+/// The result of the calculation of a field in a view.
+#[derive(Debug, Default, Clone, ProtoBuf, Eq, PartialEq)]
+pub struct CalculationPB {
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  #[pb(index = 2)]
+  pub calculation_type: CalculationType,
+
+  /// The formatted result. It's empty if there are no cells to calculate over.
+  #[pb(index = 3)]
+  pub value: String,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf, Eq, PartialEq)]
+pub struct RepeatedCalculationsPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub items: Vec<CalculationPB>,
+}
@@ -81,11 +81,28 @@ impl std::convert::From<CalendarLayout> for CalendarLayoutPB {
 pub struct CalendarEventRequestPB {
   #[pb(index = 1)]
   pub view_id: String,
+
+  /// The start of the time window. Only the events that overlap with the window are returned, and
+  /// the recurring dates are expanded into the events of the window. All the rows are returned,
+  /// and the recurring dates only once at their first occurrence, if the window is not provided.
+  #[pb(index = 2, one_of)]
+  pub start: Option<i64>,
+
+  #[pb(index = 3, one_of)]
+  pub end: Option<i64>,
+}
+
+/// The time window of a calendar query. Both ends are timestamps and are inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarWindow {
+  pub start: i64,
+  pub end: i64,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct CalendarEventRequestParams {
   pub view_id: String,
+  pub window: Option<CalendarWindow>,
 }
 
 impl TryInto<CalendarEventRequestParams> for CalendarEventRequestPB {
@@ -93,7 +110,15 @@ impl TryInto<CalendarEventRequestParams> for CalendarEventRequestPB {
 
   fn try_into(self) -> Result<CalendarEventRequestParams, Self::Error> {
     let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
-    Ok(CalendarEventRequestParams { view_id: view_id.0 })
+    let window = match (self.start, self.end) {
+      (None, None) => None,
+      (Some(start), Some(end)) if start <= end => Some(CalendarWindow { start, end }),
+      _ => return Err(ErrorCode::InvalidParams),
+    };
+    Ok(CalendarEventRequestParams {
+      view_id: view_id.0,
+      window,
+    })
   }
 }
 
@@ -113,6 +138,16 @@ pub struct CalendarEventPB {
 
   #[pb(index = 5)]
   pub is_scheduled: bool,
+
+  /// Whether the event is an occurrence of a recurring date. The [timestamp] is the start of
+  /// the occurrence instead of the date saved in the cell.
+  #[pb(index = 6)]
+  pub is_recurring: bool,
+
+  /// The end of the event. It's after the [timestamp] if the date is a range that lasts for
+  /// multiple days, otherwise it's the same as the [timestamp].
+  #[pb(index = 7)]
+  pub end_timestamp: i64,
 }
 
 #[derive(Debug, Clone, Default, ProtoBuf)]
@@ -126,8 +161,15 @@ pub struct MoveCalendarEventPB {
   #[pb(index = 1)]
   pub cell_path: CellIdPB,
 
+  /// The new start of the event. The end of a date range is moved by the same amount, so the
+  /// duration of the event is kept.
   #[pb(index = 2)]
   pub timestamp: i64,
+
+  /// The start of the dragged occurrence if the date is recurring. The whole series is shifted by
+  /// the difference between the occurrence and the new start.
+  #[pb(index = 3, one_of)]
+  pub occurrence: Option<i64>,
 }
 
 #[derive(Debug, Clone, Default, ProtoBuf)]
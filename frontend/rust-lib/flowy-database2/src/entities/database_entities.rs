@@ -78,6 +78,18 @@ impl AsRef<str> for DatabaseViewIdPB {
   }
 }
 
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DatabaseUndoRedoResponsePB {
+  #[pb(index = 1)]
+  pub can_undo: bool,
+
+  #[pb(index = 2)]
+  pub can_redo: bool,
+
+  #[pb(index = 3)]
+  pub is_success: bool,
+}
+
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct MoveFieldPayloadPB {
   #[pb(index = 1)]
@@ -162,6 +174,10 @@ pub struct MoveGroupRowPayloadPB {
 
   #[pb(index = 4, one_of)]
   pub to_row_id: Option<String>,
+
+  /// The sub-group (swimlane) that the row is moved to. Only used if the board is sub-grouped.
+  #[pb(index = 5, one_of)]
+  pub to_sub_group_id: Option<String>,
 }
 
 pub struct MoveGroupRowParams {
@@ -169,6 +185,7 @@ pub struct MoveGroupRowParams {
   pub from_row_id: RowId,
   pub to_group_id: String,
   pub to_row_id: Option<RowId>,
+  pub to_sub_group_id: Option<String>,
 }
 
 impl TryInto<MoveGroupRowParams> for MoveGroupRowPayloadPB {
@@ -184,6 +201,7 @@ impl TryInto<MoveGroupRowParams> for MoveGroupRowPayloadPB {
       to_group_id: to_group_id.0,
       from_row_id: RowId::from(self.from_row_id),
       to_row_id: self.to_row_id.map(RowId::from),
+      to_sub_group_id: self.to_sub_group_id,
     })
   }
 }
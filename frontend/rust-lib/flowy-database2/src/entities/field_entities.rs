@@ -215,6 +215,32 @@ impl TryInto<EditFieldParams> for UpdateFieldTypePayloadPB {
   }
 }
 
+/// The result of a dry run of switching the field to another type. The cells that are empty
+/// before the switch are not counted.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct FieldTypeSwitchPreviewPB {
+  #[pb(index = 1)]
+  pub converted_count: i32,
+
+  #[pb(index = 2)]
+  pub lost_count: i32,
+
+  #[pb(index = 3)]
+  pub samples: Vec<CellConversionSamplePB>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct CellConversionSamplePB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub before: String,
+
+  #[pb(index = 3)]
+  pub after: String,
+}
+
 #[derive(Debug, Default, ProtoBuf)]
 pub struct TypeOptionPathPB {
   #[pb(index = 1)]
@@ -495,6 +521,11 @@ pub enum FieldType {
   Checklist = 7,
   LastEditedTime = 8,
   CreatedTime = 9,
+  Formula = 10,
+  Relation = 11,
+  Lookup = 12,
+  Person = 13,
+  Files = 14,
 }
 
 impl Display for FieldType {
@@ -540,6 +571,11 @@ impl FieldType {
       FieldType::Checklist => "Checklist",
       FieldType::LastEditedTime => "Last edited time",
       FieldType::CreatedTime => "Created time",
+      FieldType::Formula => "Formula",
+      FieldType::Relation => "Relation",
+      FieldType::Lookup => "Lookup",
+      FieldType::Person => "Person",
+      FieldType::Files => "Files",
     };
     s.to_string()
   }
@@ -588,8 +624,42 @@ impl FieldType {
     matches!(self, FieldType::Checklist)
   }
 
+  pub fn is_formula(&self) -> bool {
+    matches!(self, FieldType::Formula)
+  }
+
+  pub fn is_relation(&self) -> bool {
+    matches!(self, FieldType::Relation)
+  }
+
+  pub fn is_lookup(&self) -> bool {
+    matches!(self, FieldType::Lookup)
+  }
+
+  pub fn is_person(&self) -> bool {
+    matches!(self, FieldType::Person)
+  }
+
+  pub fn is_files(&self) -> bool {
+    matches!(self, FieldType::Files)
+  }
+
+  /// Returns true if the rows can be grouped by the field.
   pub fn can_be_group(&self) -> bool {
-    self.is_select_option() || self.is_checkbox() || self.is_url()
+    self.can_be_default_group()
+      || self.is_date()
+      || self.is_number()
+      || self.is_text()
+      || self.is_checklist()
+      || self.is_created_time()
+      || self.is_last_edited_time()
+  }
+
+  /// Returns true if a board that isn't grouped yet picks the field to group by. The fields whose
+  /// groups are derived from free-form values, e.g. texts or numbers, are only grouped by when
+  /// the user picks them.
+  pub fn can_be_default_group(&self) -> bool {
+    self.is_select_option() || self.is_checkbox() || self.is_url() || self.is_person()
   }
 
   pub fn is_auto_update(&self) -> bool {
@@ -3,7 +3,7 @@ use flowy_error::ErrorCode;
 use std::ops::Deref;
 
 use crate::entities::parser::NotEmptyStr;
-use crate::entities::RepeatedFieldIdPB;
+use crate::entities::{CalculationType, RepeatedFieldIdPB};
 use crate::impl_into_field_visibility;
 use crate::services::field_settings::{FieldSettings, FieldSettingsChangesetParams};
 
@@ -15,6 +15,9 @@ pub struct FieldSettingsPB {
 
   #[pb(index = 2)]
   pub visibility: FieldVisibility,
+
+  #[pb(index = 3)]
+  pub calculation_type: CalculationType,
 }
 
 impl From<FieldSettings> for FieldSettingsPB {
@@ -22,6 +25,7 @@ impl From<FieldSettings> for FieldSettingsPB {
     Self {
       field_id: value.field_id,
       visibility: value.visibility,
+      calculation_type: value.calculation_type,
     }
   }
 }
@@ -99,6 +103,9 @@ pub struct FieldSettingsChangesetPB {
 
   #[pb(index = 3, one_of)]
   pub visibility: Option<FieldVisibility>,
+
+  #[pb(index = 4, one_of)]
+  pub calculation_type: Option<CalculationType>,
 }
 
 impl From<FieldSettingsChangesetParams> for FieldSettingsChangesetPB {
@@ -107,6 +114,7 @@ impl From<FieldSettingsChangesetParams> for FieldSettingsChangesetPB {
       view_id: value.view_id,
       field_id: value.field_id,
       visibility: value.visibility,
+      calculation_type: value.calculation_type,
     }
   }
 }
@@ -119,6 +127,7 @@ impl TryFrom<FieldSettingsChangesetPB> for FieldSettingsChangesetParams {
       view_id: value.view_id,
       field_id: value.field_id,
       visibility: value.visibility,
+      calculation_type: value.calculation_type,
     })
   }
 }
@@ -20,6 +20,14 @@ pub struct DateFilterPB {
 
   #[pb(index = 4, one_of)]
   pub timestamp: Option<i64>,
+
+  /// The number of units of the [DateFilterConditionPB::DateWithinPast] and the
+  /// [DateFilterConditionPB::DateWithinNext] conditions.
+  #[pb(index = 5, one_of)]
+  pub relative_count: Option<i64>,
+
+  #[pb(index = 6)]
+  pub relative_unit: DateFilterRelativeUnitPB,
 }
 
 #[derive(Deserialize, Serialize, Default, Clone, Debug)]
@@ -27,6 +35,10 @@ pub struct DateFilterContentPB {
   pub start: Option<i64>,
   pub end: Option<i64>,
   pub timestamp: Option<i64>,
+  #[serde(default)]
+  pub relative_count: Option<i64>,
+  #[serde(default)]
+  pub relative_unit: i64,
 }
 
 impl ToString for DateFilterContentPB {
@@ -56,6 +68,52 @@ pub enum DateFilterConditionPB {
   DateWithIn = 5,
   DateIsEmpty = 6,
   DateIsNotEmpty = 7,
+  DateIsToday = 8,
+  DateIsYesterday = 9,
+  DateIsTomorrow = 10,
+  DateIsThisWeek = 11,
+  DateIsThisMonth = 12,
+  DateIsThisYear = 13,
+  DateWithinPast = 14,
+  DateWithinNext = 15,
+}
+
+impl DateFilterConditionPB {
+  /// Returns true if the condition is resolved against the current date when the filter is
+  /// evaluated, so the result changes when the day rolls over.
+  pub fn is_relative(&self) -> bool {
+    matches!(
+      self,
+      DateFilterConditionPB::DateIsToday
+        | DateFilterConditionPB::DateIsYesterday
+        | DateFilterConditionPB::DateIsTomorrow
+        | DateFilterConditionPB::DateIsThisWeek
+        | DateFilterConditionPB::DateIsThisMonth
+        | DateFilterConditionPB::DateIsThisYear
+        | DateFilterConditionPB::DateWithinPast
+        | DateFilterConditionPB::DateWithinNext
+    )
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+#[derive(Default)]
+pub enum DateFilterRelativeUnitPB {
+  #[default]
+  Day = 0,
+  Week = 1,
+  Month = 2,
+}
+
+impl std::convert::From<i64> for DateFilterRelativeUnitPB {
+  fn from(value: i64) -> Self {
+    match value {
+      1 => DateFilterRelativeUnitPB::Week,
+      2 => DateFilterRelativeUnitPB::Month,
+      _ => DateFilterRelativeUnitPB::Day,
+    }
+  }
 }
 
 impl std::convert::From<DateFilterConditionPB> for u32 {
@@ -76,6 +134,15 @@ impl std::convert::TryFrom<u8> for DateFilterConditionPB {
       4 => Ok(DateFilterConditionPB::DateOnOrAfter),
       5 => Ok(DateFilterConditionPB::DateWithIn),
       6 => Ok(DateFilterConditionPB::DateIsEmpty),
+      7 => Ok(DateFilterConditionPB::DateIsNotEmpty),
+      8 => Ok(DateFilterConditionPB::DateIsToday),
+      9 => Ok(DateFilterConditionPB::DateIsYesterday),
+      10 => Ok(DateFilterConditionPB::DateIsTomorrow),
+      11 => Ok(DateFilterConditionPB::DateIsThisWeek),
+      12 => Ok(DateFilterConditionPB::DateIsThisMonth),
+      13 => Ok(DateFilterConditionPB::DateIsThisYear),
+      14 => Ok(DateFilterConditionPB::DateWithinPast),
+      15 => Ok(DateFilterConditionPB::DateWithinNext),
       _ => Err(ErrorCode::InvalidParams),
     }
   }
@@ -96,6 +163,8 @@ impl FromFilterString for DateFilterPB {
       date_filter.start = content.start;
       date_filter.end = content.end;
       date_filter.timestamp = content.timestamp;
+      date_filter.relative_count = content.relative_count;
+      date_filter.relative_unit = DateFilterRelativeUnitPB::from(content.relative_unit);
     };
 
     date_filter
@@ -114,6 +183,8 @@ impl std::convert::From<&Filter> for DateFilterPB {
       date_filter.start = content.start;
       date_filter.end = content.end;
       date_filter.timestamp = content.timestamp;
+      date_filter.relative_count = content.relative_count;
+      date_filter.relative_unit = DateFilterRelativeUnitPB::from(content.relative_unit);
     };
 
     date_filter
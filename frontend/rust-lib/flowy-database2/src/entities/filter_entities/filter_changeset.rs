@@ -1,4 +1,4 @@
-use crate::entities::FilterPB;
+use crate::entities::{FilterGroupPB, FilterPB};
 use flowy_derive::ProtoBuf;
 
 #[derive(Debug, Default, ProtoBuf)]
@@ -14,6 +14,13 @@ pub struct FilterChangesetNotificationPB {
 
   #[pb(index = 4)]
   pub update_filters: Vec<UpdatedFilter>,
+
+  /// The filter groups that were created or updated
+  #[pb(index = 5)]
+  pub update_filter_groups: Vec<FilterGroupPB>,
+
+  #[pb(index = 6)]
+  pub delete_filter_groups: Vec<FilterGroupPB>,
 }
 
 #[derive(Debug, Default, ProtoBuf)]
@@ -32,6 +39,8 @@ impl FilterChangesetNotificationPB {
       insert_filters: filters,
       delete_filters: Default::default(),
       update_filters: Default::default(),
+      update_filter_groups: Default::default(),
+      delete_filter_groups: Default::default(),
     }
   }
   pub fn from_delete(view_id: &str, filters: Vec<FilterPB>) -> Self {
@@ -40,6 +49,8 @@ impl FilterChangesetNotificationPB {
       insert_filters: Default::default(),
       delete_filters: filters,
       update_filters: Default::default(),
+      update_filter_groups: Default::default(),
+      delete_filter_groups: Default::default(),
     }
   }
 
@@ -49,6 +60,35 @@ impl FilterChangesetNotificationPB {
       insert_filters: Default::default(),
       delete_filters: Default::default(),
       update_filters: filters,
+      update_filter_groups: Default::default(),
+      delete_filter_groups: Default::default(),
+    }
+  }
+
+  pub fn from_update_groups(view_id: &str, groups: Vec<FilterGroupPB>) -> Self {
+    Self {
+      view_id: view_id.to_string(),
+      insert_filters: Default::default(),
+      delete_filters: Default::default(),
+      update_filters: Default::default(),
+      update_filter_groups: groups,
+      delete_filter_groups: Default::default(),
+    }
+  }
+
+  /// The filters that were contained by the deleted groups are deleted too.
+  pub fn from_delete_groups(
+    view_id: &str,
+    groups: Vec<FilterGroupPB>,
+    filters: Vec<FilterPB>,
+  ) -> Self {
+    Self {
+      view_id: view_id.to_string(),
+      insert_filters: Default::default(),
+      delete_filters: filters,
+      update_filters: Default::default(),
+      update_filter_groups: Default::default(),
+      delete_filter_groups: groups,
     }
   }
 }
@@ -0,0 +1,160 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+use crate::entities::parser::NotEmptyStr;
+use crate::services::filter::{FilterGroup, FilterOperator};
+
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum FilterOperatorPB {
+  #[default]
+  And = 0,
+  Or = 1,
+}
+
+impl std::convert::From<FilterOperator> for FilterOperatorPB {
+  fn from(operator: FilterOperator) -> Self {
+    match operator {
+      FilterOperator::And => FilterOperatorPB::And,
+      FilterOperator::Or => FilterOperatorPB::Or,
+    }
+  }
+}
+
+impl std::convert::From<FilterOperatorPB> for FilterOperator {
+  fn from(operator: FilterOperatorPB) -> Self {
+    match operator {
+      FilterOperatorPB::And => FilterOperator::And,
+      FilterOperatorPB::Or => FilterOperator::Or,
+    }
+  }
+}
+
+/// A group of filters. The filters and the nested groups that belong to the group are combined
+/// with the group's operator.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct FilterGroupPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  /// The id of the parent group. None if the group is at the root of the view.
+  #[pb(index = 2, one_of)]
+  pub parent_id: Option<String>,
+
+  #[pb(index = 3)]
+  pub operator: FilterOperatorPB,
+}
+
+impl std::convert::From<&FilterGroup> for FilterGroupPB {
+  fn from(group: &FilterGroup) -> Self {
+    Self {
+      id: group.id.clone(),
+      parent_id: group.parent_id.clone(),
+      operator: group.operator.into(),
+    }
+  }
+}
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct RepeatedFilterGroupPB {
+  #[pb(index = 1)]
+  pub items: Vec<FilterGroupPB>,
+}
+
+impl std::convert::From<Vec<FilterGroup>> for RepeatedFilterGroupPB {
+  fn from(groups: Vec<FilterGroup>) -> Self {
+    Self {
+      items: groups.iter().map(FilterGroupPB::from).collect(),
+    }
+  }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct UpdateFilterGroupPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// Create a new group if the group_id is None
+  #[pb(index = 2, one_of)]
+  pub group_id: Option<String>,
+
+  /// The group is put at the root of the view if the parent_id is None
+  #[pb(index = 3, one_of)]
+  pub parent_id: Option<String>,
+
+  #[pb(index = 4)]
+  pub operator: FilterOperatorPB,
+}
+
+impl TryInto<UpdateFilterGroupParams> for UpdateFilterGroupPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateFilterGroupParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+      .0;
+    let group_id = match self.group_id {
+      None => None,
+      Some(group_id) => Some(
+        NotEmptyStr::parse(group_id)
+          .map_err(|_| ErrorCode::FilterIdIsEmpty)?
+          .0,
+      ),
+    };
+    let parent_id = match self.parent_id {
+      None => None,
+      Some(parent_id) => Some(
+        NotEmptyStr::parse(parent_id)
+          .map_err(|_| ErrorCode::FilterIdIsEmpty)?
+          .0,
+      ),
+    };
+
+    Ok(UpdateFilterGroupParams {
+      view_id,
+      group_id,
+      parent_id,
+      operator: self.operator.into(),
+    })
+  }
+}
+
+#[derive(Debug)]
+pub struct UpdateFilterGroupParams {
+  pub view_id: String,
+  /// Create a new group if the group_id is None
+  pub group_id: Option<String>,
+  pub parent_id: Option<String>,
+  pub operator: FilterOperator,
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct DeleteFilterGroupPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub group_id: String,
+}
+
+impl TryInto<DeleteFilterGroupParams> for DeleteFilterGroupPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<DeleteFilterGroupParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+      .0;
+    let group_id = NotEmptyStr::parse(self.group_id)
+      .map_err(|_| ErrorCode::FilterIdIsEmpty)?
+      .0;
+
+    Ok(DeleteFilterGroupParams { view_id, group_id })
+  }
+}
+
+/// Deleting a group deletes all the filters and the groups that it contains.
+#[derive(Debug)]
+pub struct DeleteFilterGroupParams {
+  pub view_id: String,
+  pub group_id: String,
+}
@@ -0,0 +1,79 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+use crate::services::filter::{Filter, FromFilterString};
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct FormulaFilterPB {
+  #[pb(index = 1)]
+  pub condition: FormulaFilterConditionPB,
+
+  #[pb(index = 2)]
+  pub content: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+#[derive(Default)]
+pub enum FormulaFilterConditionPB {
+  #[default]
+  Is = 0,
+  IsNot = 1,
+  Contains = 2,
+  DoesNotContain = 3,
+  StartsWith = 4,
+  EndsWith = 5,
+  IsEmpty = 6,
+  IsNotEmpty = 7,
+  GreaterThan = 8,
+  LessThan = 9,
+  GreaterThanOrEqualTo = 10,
+  LessThanOrEqualTo = 11,
+}
+
+impl std::convert::From<FormulaFilterConditionPB> for u32 {
+  fn from(value: FormulaFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::convert::TryFrom<u8> for FormulaFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      0 => Ok(FormulaFilterConditionPB::Is),
+      1 => Ok(FormulaFilterConditionPB::IsNot),
+      2 => Ok(FormulaFilterConditionPB::Contains),
+      3 => Ok(FormulaFilterConditionPB::DoesNotContain),
+      4 => Ok(FormulaFilterConditionPB::StartsWith),
+      5 => Ok(FormulaFilterConditionPB::EndsWith),
+      6 => Ok(FormulaFilterConditionPB::IsEmpty),
+      7 => Ok(FormulaFilterConditionPB::IsNotEmpty),
+      8 => Ok(FormulaFilterConditionPB::GreaterThan),
+      9 => Ok(FormulaFilterConditionPB::LessThan),
+      10 => Ok(FormulaFilterConditionPB::GreaterThanOrEqualTo),
+      11 => Ok(FormulaFilterConditionPB::LessThanOrEqualTo),
+      _ => Err(ErrorCode::InvalidParams),
+    }
+  }
+}
+
+impl FromFilterString for FormulaFilterPB {
+  fn from_filter(filter: &Filter) -> Self
+  where
+    Self: Sized,
+  {
+    FormulaFilterPB::from(filter)
+  }
+}
+
+impl std::convert::From<&Filter> for FormulaFilterPB {
+  fn from(filter: &Filter) -> Self {
+    FormulaFilterPB {
+      condition: FormulaFilterConditionPB::try_from(filter.condition as u8)
+        .unwrap_or(FormulaFilterConditionPB::Is),
+      content: filter.content.clone(),
+    }
+  }
+}
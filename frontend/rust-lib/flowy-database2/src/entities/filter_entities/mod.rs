@@ -2,7 +2,11 @@ mod checkbox_filter;
 mod checklist_filter;
 mod date_filter;
 mod filter_changeset;
+mod filter_group;
+mod formula_filter;
 mod number_filter;
+mod person_filter;
+mod relation_filter;
 mod select_option_filter;
 mod text_filter;
 mod util;
@@ -11,7 +15,11 @@ pub use checkbox_filter::*;
 pub use checklist_filter::*;
 pub use date_filter::*;
 pub use filter_changeset::*;
+pub use filter_group::*;
+pub use formula_filter::*;
 pub use number_filter::*;
+pub use person_filter::*;
+pub use relation_filter::*;
 pub use select_option_filter::*;
 pub use text_filter::*;
 pub use util::*;
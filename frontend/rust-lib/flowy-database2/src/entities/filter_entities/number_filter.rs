@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
 
@@ -8,10 +12,46 @@ pub struct NumberFilterPB {
   #[pb(index = 1)]
   pub condition: NumberFilterConditionPB,
 
+  /// The value to compare with. It's a [NumberFilterRangePB] in JSON for the
+  /// [NumberFilterConditionPB::NumberIsBetween] condition and a JSON array of values for the
+  /// [NumberFilterConditionPB::NumberIsOneOf] condition, e.g. `["$1,200", "$1,500"]`.
+  ///
+  /// The values are parsed with the format of the field, so they might contain the currency
+  /// symbol or the percent sign.
   #[pb(index = 2)]
   pub content: String,
 }
 
+/// The bounds of the [NumberFilterConditionPB::NumberIsBetween] condition. The bounds are
+/// inclusive by default.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct NumberFilterRangePB {
+  pub start: String,
+  pub end: String,
+  #[serde(default = "default_inclusive")]
+  pub start_inclusive: bool,
+  #[serde(default = "default_inclusive")]
+  pub end_inclusive: bool,
+}
+
+fn default_inclusive() -> bool {
+  true
+}
+
+impl ToString for NumberFilterRangePB {
+  fn to_string(&self) -> String {
+    serde_json::to_string(self).unwrap()
+  }
+}
+
+impl FromStr for NumberFilterRangePB {
+  type Err = serde_json::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    serde_json::from_str(s)
+  }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
 #[repr(u8)]
 #[derive(Default)]
@@ -25,6 +65,8 @@ pub enum NumberFilterConditionPB {
   LessThanOrEqualTo = 5,
   NumberIsEmpty = 6,
   NumberIsNotEmpty = 7,
+  NumberIsBetween = 8,
+  NumberIsOneOf = 9,
 }
 
 impl std::convert::From<NumberFilterConditionPB> for u32 {
@@ -45,6 +87,8 @@ impl std::convert::TryFrom<u8> for NumberFilterConditionPB {
       5 => Ok(NumberFilterConditionPB::LessThanOrEqualTo),
       6 => Ok(NumberFilterConditionPB::NumberIsEmpty),
       7 => Ok(NumberFilterConditionPB::NumberIsNotEmpty),
+      8 => Ok(NumberFilterConditionPB::NumberIsBetween),
+      9 => Ok(NumberFilterConditionPB::NumberIsOneOf),
       _ => Err(ErrorCode::InvalidParams),
     }
   }
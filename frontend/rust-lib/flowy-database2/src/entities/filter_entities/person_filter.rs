@@ -0,0 +1,67 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+use crate::services::field::PersonCellData;
+use crate::services::filter::{Filter, FromFilterString};
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct PersonFilterPB {
+  #[pb(index = 1)]
+  pub condition: PersonFilterConditionPB,
+
+  /// The ids of the users used by the [PersonFilterConditionPB::ContainsAnyOf] condition. It's
+  /// not saved for the [PersonFilterConditionPB::IsMe] condition, because each user of a shared
+  /// database sees their own rows.
+  #[pb(index = 2)]
+  pub user_ids: Vec<i64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+#[derive(Default)]
+pub enum PersonFilterConditionPB {
+  #[default]
+  IsMe = 0,
+  ContainsAnyOf = 1,
+  IsEmpty = 2,
+  IsNotEmpty = 3,
+}
+
+impl std::convert::From<PersonFilterConditionPB> for u32 {
+  fn from(value: PersonFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::convert::TryFrom<u8> for PersonFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      0 => Ok(PersonFilterConditionPB::IsMe),
+      1 => Ok(PersonFilterConditionPB::ContainsAnyOf),
+      2 => Ok(PersonFilterConditionPB::IsEmpty),
+      3 => Ok(PersonFilterConditionPB::IsNotEmpty),
+      _ => Err(ErrorCode::InvalidParams),
+    }
+  }
+}
+
+impl FromFilterString for PersonFilterPB {
+  fn from_filter(filter: &Filter) -> Self
+  where
+    Self: Sized,
+  {
+    PersonFilterPB::from(filter)
+  }
+}
+
+impl std::convert::From<&Filter> for PersonFilterPB {
+  fn from(filter: &Filter) -> Self {
+    PersonFilterPB {
+      condition: PersonFilterConditionPB::try_from(filter.condition as u8)
+        .unwrap_or(PersonFilterConditionPB::IsMe),
+      user_ids: PersonCellData::from(filter.content.clone()).user_ids,
+    }
+  }
+}
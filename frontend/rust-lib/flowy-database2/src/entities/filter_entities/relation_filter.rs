@@ -0,0 +1,66 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+use crate::services::field::SelectOptionIds;
+use crate::services::filter::{Filter, FromFilterString};
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct RelationFilterPB {
+  #[pb(index = 1)]
+  pub condition: RelationFilterConditionPB,
+
+  /// The ids of the related rows used by the [RelationFilterConditionPB::Contains] and
+  /// [RelationFilterConditionPB::DoesNotContain] conditions.
+  #[pb(index = 2)]
+  pub row_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+#[derive(Default)]
+pub enum RelationFilterConditionPB {
+  #[default]
+  IsEmpty = 0,
+  IsNotEmpty = 1,
+  Contains = 2,
+  DoesNotContain = 3,
+}
+
+impl std::convert::From<RelationFilterConditionPB> for u32 {
+  fn from(value: RelationFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::convert::TryFrom<u8> for RelationFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      0 => Ok(RelationFilterConditionPB::IsEmpty),
+      1 => Ok(RelationFilterConditionPB::IsNotEmpty),
+      2 => Ok(RelationFilterConditionPB::Contains),
+      3 => Ok(RelationFilterConditionPB::DoesNotContain),
+      _ => Err(ErrorCode::InvalidParams),
+    }
+  }
+}
+
+impl FromFilterString for RelationFilterPB {
+  fn from_filter(filter: &Filter) -> Self
+  where
+    Self: Sized,
+  {
+    RelationFilterPB::from(filter)
+  }
+}
+
+impl std::convert::From<&Filter> for RelationFilterPB {
+  fn from(filter: &Filter) -> Self {
+    RelationFilterPB {
+      condition: RelationFilterConditionPB::try_from(filter.condition as u8)
+        .unwrap_or(RelationFilterConditionPB::IsEmpty),
+      row_ids: SelectOptionIds::from(filter.content.clone()).into_inner(),
+    }
+  }
+}
@@ -1,3 +1,7 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
 
@@ -10,6 +14,50 @@ pub struct TextFilterPB {
 
   #[pb(index = 2)]
   pub content: String,
+
+  /// The text is compared case-insensitively unless it's true.
+  #[pb(index = 3)]
+  pub case_sensitive: bool,
+
+  /// Only matches the content as a whole word. It applies to the contains, starts with, ends with
+  /// and regex conditions.
+  #[pb(index = 4)]
+  pub word_boundary: bool,
+}
+
+/// The content of the text filter that is stored in the [Filter]. The filters that were stored
+/// before the options were added only contain the text.
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct TextFilterContentPB {
+  pub text: String,
+  #[serde(default)]
+  pub case_sensitive: bool,
+  #[serde(default)]
+  pub word_boundary: bool,
+}
+
+impl ToString for TextFilterContentPB {
+  fn to_string(&self) -> String {
+    serde_json::to_string(self).unwrap()
+  }
+}
+
+impl FromStr for TextFilterContentPB {
+  type Err = serde_json::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    serde_json::from_str(s)
+  }
+}
+
+impl std::convert::From<&TextFilterPB> for TextFilterContentPB {
+  fn from(filter: &TextFilterPB) -> Self {
+    Self {
+      text: filter.content.clone(),
+      case_sensitive: filter.case_sensitive,
+      word_boundary: filter.word_boundary,
+    }
+  }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
@@ -25,6 +73,7 @@ pub enum TextFilterConditionPB {
   EndsWith = 5,
   TextIsEmpty = 6,
   TextIsNotEmpty = 7,
+  MatchesRegex = 8,
 }
 
 impl std::convert::From<TextFilterConditionPB> for u32 {
@@ -46,6 +95,7 @@ impl std::convert::TryFrom<u8> for TextFilterConditionPB {
       5 => Ok(TextFilterConditionPB::EndsWith),
       6 => Ok(TextFilterConditionPB::TextIsEmpty),
       7 => Ok(TextFilterConditionPB::TextIsNotEmpty),
+      8 => Ok(TextFilterConditionPB::MatchesRegex),
       _ => Err(ErrorCode::InvalidParams),
     }
   }
@@ -56,20 +106,23 @@ impl FromFilterString for TextFilterPB {
   where
     Self: Sized,
   {
-    TextFilterPB {
-      condition: TextFilterConditionPB::try_from(filter.condition as u8)
-        .unwrap_or(TextFilterConditionPB::Is),
-      content: filter.content.clone(),
-    }
+    TextFilterPB::from(filter)
   }
 }
 
 impl std::convert::From<&Filter> for TextFilterPB {
   fn from(filter: &Filter) -> Self {
+    let content =
+      TextFilterContentPB::from_str(&filter.content).unwrap_or_else(|_| TextFilterContentPB {
+        text: filter.content.clone(),
+        ..Default::default()
+      });
     TextFilterPB {
       condition: TextFilterConditionPB::try_from(filter.condition as u8)
         .unwrap_or(TextFilterConditionPB::Is),
-      content: filter.content.clone(),
+      content: content.text,
+      case_sensitive: content.case_sensitive,
+      word_boundary: content.word_boundary,
     }
   }
 }
@@ -10,9 +10,10 @@ use flowy_error::ErrorCode;
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{
   CheckboxFilterPB, ChecklistFilterPB, DateFilterContentPB, DateFilterPB, FieldType,
-  NumberFilterPB, SelectOptionFilterPB, TextFilterPB,
+  FormulaFilterPB, NumberFilterPB, PersonFilterPB, RelationFilterPB, SelectOptionFilterPB,
+  TextFilterContentPB, TextFilterPB,
 };
-use crate::services::field::SelectOptionIds;
+use crate::services::field::{PersonCellData, SelectOptionIds};
 use crate::services::filter::{Filter, FilterType};
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
@@ -28,6 +29,10 @@ pub struct FilterPB {
 
   #[pb(index = 4)]
   pub data: Vec<u8>,
+
+  /// The id of the filter group that contains the filter. None if the filter is at the root.
+  #[pb(index = 5, one_of)]
+  pub parent_id: Option<String>,
 }
 
 impl std::convert::From<&Filter> for FilterPB {
@@ -42,13 +47,19 @@ impl std::convert::From<&Filter> for FilterPB {
       FieldType::MultiSelect => SelectOptionFilterPB::from(filter).try_into().unwrap(),
       FieldType::Checklist => ChecklistFilterPB::from(filter).try_into().unwrap(),
       FieldType::Checkbox => CheckboxFilterPB::from(filter).try_into().unwrap(),
-      FieldType::URL => TextFilterPB::from(filter).try_into().unwrap(),
+      FieldType::URL | FieldType::Lookup | FieldType::Files => {
+        TextFilterPB::from(filter).try_into().unwrap()
+      },
+      FieldType::Formula => FormulaFilterPB::from(filter).try_into().unwrap(),
+      FieldType::Relation => RelationFilterPB::from(filter).try_into().unwrap(),
+      FieldType::Person => PersonFilterPB::from(filter).try_into().unwrap(),
     };
     Self {
       id: filter.id.clone(),
       field_id: filter.field_id.clone(),
       field_type: filter.field_type.clone(),
       data: bytes.to_vec(),
+      parent_id: filter.parent_id.clone(),
     }
   }
 }
@@ -141,6 +152,11 @@ pub struct UpdateFilterPayloadPB {
 
   #[pb(index = 5)]
   pub view_id: String,
+
+  /// The id of the filter group that the filter belongs to. The filter is put at the root of
+  /// the view if the parent_id is None.
+  #[pb(index = 6, one_of)]
+  pub parent_id: Option<String>,
 }
 
 impl UpdateFilterPayloadPB {
@@ -158,8 +174,14 @@ impl UpdateFilterPayloadPB {
       field_type,
       filter_id: None,
       data: data.to_vec(),
+      parent_id: None,
     }
   }
+
+  pub fn with_parent_id(mut self, parent_id: &str) -> Self {
+    self.parent_id = Some(parent_id.to_owned());
+    self
+  }
 }
 
 impl TryInto<UpdateFilterParams> for UpdateFilterPayloadPB {
@@ -181,15 +203,24 @@ impl TryInto<UpdateFilterParams> for UpdateFilterPayloadPB {
           .0,
       ),
     };
+    let parent_id = match self.parent_id {
+      None => None,
+      Some(parent_id) => Some(
+        NotEmptyStr::parse(parent_id)
+          .map_err(|_| ErrorCode::FilterIdIsEmpty)?
+          .0,
+      ),
+    };
     let condition;
     let mut content = "".to_string();
     let bytes: &[u8] = self.data.as_ref();
 
     match self.field_type {
-      FieldType::RichText | FieldType::URL => {
+      FieldType::RichText | FieldType::URL | FieldType::Lookup | FieldType::Files => {
         let filter = TextFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        filter.validate().map_err(|_| ErrorCode::InvalidRegex)?;
         condition = filter.condition as u8;
-        content = filter.content;
+        content = TextFilterContentPB::from(&filter).to_string();
       },
       FieldType::Checkbox => {
         let filter = CheckboxFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
@@ -207,6 +238,8 @@ impl TryInto<UpdateFilterParams> for UpdateFilterPayloadPB {
           start: filter.start,
           end: filter.end,
           timestamp: filter.timestamp,
+          relative_count: filter.relative_count,
+          relative_unit: filter.relative_unit as i64,
         }
         .to_string();
       },
@@ -215,6 +248,24 @@ impl TryInto<UpdateFilterParams> for UpdateFilterPayloadPB {
         condition = filter.condition as u8;
         content = SelectOptionIds::from(filter.option_ids).to_string();
       },
+      FieldType::Formula => {
+        let filter = FormulaFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        condition = filter.condition as u8;
+        content = filter.content;
+      },
+      FieldType::Relation => {
+        let filter = RelationFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        condition = filter.condition as u8;
+        content = SelectOptionIds::from(filter.row_ids).to_string();
+      },
+      FieldType::Person => {
+        let filter = PersonFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
+        condition = filter.condition as u8;
+        content = PersonCellData {
+          user_ids: filter.user_ids,
+        }
+        .to_string();
+      },
     }
 
     Ok(UpdateFilterParams {
@@ -224,6 +275,7 @@ impl TryInto<UpdateFilterParams> for UpdateFilterPayloadPB {
       field_type: self.field_type,
       condition: condition as i64,
       content,
+      parent_id,
     })
   }
 }
@@ -237,4 +289,5 @@ pub struct UpdateFilterParams {
   pub field_type: FieldType,
   pub condition: i64,
   pub content: String,
+  pub parent_id: Option<String>,
 }
@@ -14,6 +14,9 @@ pub struct GroupSettingPB {
 
   #[pb(index = 2)]
   pub field_id: String,
+
+  #[pb(index = 3)]
+  pub is_sub_group: bool,
 }
 
 impl std::convert::From<&GroupSetting> for GroupSettingPB {
@@ -21,6 +24,7 @@ impl std::convert::From<&GroupSetting> for GroupSettingPB {
     GroupSettingPB {
       id: rev.id.clone(),
       field_id: rev.field_id.clone(),
+      is_sub_group: rev.is_sub_group,
     }
   }
 }
@@ -108,6 +112,11 @@ pub struct GroupByFieldPayloadPB {
 
   #[pb(index = 2)]
   pub view_id: String,
+
+  /// The json configuration of the groups, for example, the bucket width of the number groups.
+  /// The default configuration of the field type is used if it's `None`.
+  #[pb(index = 3, one_of)]
+  pub setting_content: Option<String>,
 }
 
 impl TryInto<GroupByFieldParams> for GroupByFieldPayloadPB {
@@ -121,13 +130,54 @@ impl TryInto<GroupByFieldParams> for GroupByFieldPayloadPB {
       .map_err(|_| ErrorCode::ViewIdIsInvalid)?
       .0;
 
-    Ok(GroupByFieldParams { field_id, view_id })
+    Ok(GroupByFieldParams {
+      field_id,
+      view_id,
+      setting_content: self.setting_content,
+    })
   }
 }
 
 pub struct GroupByFieldParams {
   pub field_id: String,
   pub view_id: String,
+  pub setting_content: Option<String>,
+}
+
+/// Groups the rows of the board by a second field. The groups of the sub-group field are the
+/// swimlanes of the board. Passing `None` as the field_id removes the sub-grouping.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct SubGroupByFieldPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2, one_of)]
+  pub field_id: Option<String>,
+}
+
+impl TryInto<SubGroupByFieldParams> for SubGroupByFieldPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SubGroupByFieldParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    let field_id = match self.field_id {
+      None => None,
+      Some(field_id) => Some(
+        NotEmptyStr::parse(field_id)
+          .map_err(|_| ErrorCode::FieldIdIsEmpty)?
+          .0,
+      ),
+    };
+
+    Ok(SubGroupByFieldParams { view_id, field_id })
+  }
+}
+
+pub struct SubGroupByFieldParams {
+  pub view_id: String,
+  pub field_id: Option<String>,
 }
 
 pub struct DeleteGroupParams {
@@ -14,6 +14,11 @@ macro_rules! impl_into_field_type {
           7 => FieldType::Checklist,
           8 => FieldType::LastEditedTime,
           9 => FieldType::CreatedTime,
+          10 => FieldType::Formula,
+          11 => FieldType::Relation,
+          12 => FieldType::Lookup,
+          13 => FieldType::Person,
+          14 => FieldType::Files,
           _ => {
             tracing::error!("🔴Can't parser FieldType from value: {}", ty);
             FieldType::RichText
@@ -42,3 +47,31 @@ macro_rules! impl_into_field_visibility {
     }
   };
 }
+
+#[macro_export]
+macro_rules! impl_into_calculation_type {
+  ($target: ident) => {
+    impl std::convert::From<$target> for CalculationType {
+      fn from(ty: $target) -> Self {
+        match ty {
+          0 => CalculationType::None,
+          1 => CalculationType::Count,
+          2 => CalculationType::CountEmpty,
+          3 => CalculationType::CountUnique,
+          4 => CalculationType::Sum,
+          5 => CalculationType::Average,
+          6 => CalculationType::Median,
+          7 => CalculationType::Min,
+          8 => CalculationType::Max,
+          9 => CalculationType::Earliest,
+          10 => CalculationType::Latest,
+          11 => CalculationType::PercentChecked,
+          _ => {
+            tracing::error!("🔴Can't parser CalculationType from value: {}", ty);
+            CalculationType::None
+          },
+        }
+      }
+    }
+  };
+}
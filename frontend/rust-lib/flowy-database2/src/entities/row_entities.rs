@@ -8,6 +8,7 @@ use flowy_error::ErrorCode;
 
 use crate::entities::parser::NotEmptyStr;
 use crate::services::database::{InsertedRow, UpdatedRow};
+use crate::services::row_history::CellChange;
 
 /// [RowPB] Describes a row. Has the id of the parent Block. Has the metadata of the row.
 #[derive(Debug, Default, Clone, ProtoBuf, Eq, PartialEq)]
@@ -367,3 +368,84 @@ impl TryInto<CreateRowParams> for CreateRowPayloadPB {
     })
   }
 }
+
+/// A change of a cell in the history of a row.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct CellChangePB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub field_id: String,
+
+  /// The id of the user who made the change. It's 0 if the change was synced without the user.
+  #[pb(index = 4)]
+  pub uid: i64,
+
+  #[pb(index = 5)]
+  pub old_content: String,
+
+  #[pb(index = 6)]
+  pub new_content: String,
+
+  /// The time of the change in milliseconds
+  #[pb(index = 7)]
+  pub timestamp: i64,
+}
+
+impl From<CellChange> for CellChangePB {
+  fn from(change: CellChange) -> Self {
+    Self {
+      id: change.id,
+      row_id: change.row_id,
+      field_id: change.field_id,
+      uid: change.uid,
+      old_content: change.old_content,
+      new_content: change.new_content,
+      timestamp: change.timestamp,
+    }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedCellChangePB {
+  #[pb(index = 1)]
+  pub items: Vec<CellChangePB>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RestoreCellChangePayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub change_id: String,
+
+  /// Restores all the cells of the row that were changed since the change, instead of only the
+  /// cell of the change.
+  #[pb(index = 3)]
+  pub whole_row: bool,
+}
+
+pub struct RestoreCellChangeParams {
+  pub view_id: String,
+  pub change_id: String,
+  pub whole_row: bool,
+}
+
+impl TryInto<RestoreCellChangeParams> for RestoreCellChangePayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<RestoreCellChangeParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let change_id = NotEmptyStr::parse(self.change_id).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(RestoreCellChangeParams {
+      view_id: view_id.0,
+      change_id: change_id.0,
+      whole_row: self.whole_row,
+    })
+  }
+}
@@ -8,10 +8,11 @@ use flowy_error::ErrorCode;
 
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{
-  CalendarLayoutSettingPB, DeleteFilterParams, DeleteFilterPayloadPB, DeleteSortParams,
-  DeleteSortPayloadPB, RepeatedFieldSettingsPB, RepeatedFilterPB, RepeatedGroupSettingPB,
-  RepeatedSortPB, UpdateFilterParams, UpdateFilterPayloadPB, UpdateGroupPB, UpdateSortParams,
-  UpdateSortPayloadPB,
+  CalendarLayoutSettingPB, DeleteFilterGroupParams, DeleteFilterGroupPayloadPB, DeleteFilterParams,
+  DeleteFilterPayloadPB, DeleteSortParams, DeleteSortPayloadPB, RepeatedFieldSettingsPB,
+  RepeatedFilterGroupPB, RepeatedFilterPB, RepeatedGroupSettingPB, RepeatedSortPB,
+  UpdateFilterGroupParams, UpdateFilterGroupPayloadPB, UpdateFilterParams, UpdateFilterPayloadPB,
+  UpdateGroupPB, UpdateSortParams, UpdateSortPayloadPB,
 };
 use crate::services::setting::CalendarLayoutSetting;
 
@@ -35,6 +36,9 @@ pub struct DatabaseViewSettingPB {
 
   #[pb(index = 6)]
   pub field_settings: RepeatedFieldSettingsPB,
+
+  #[pb(index = 7)]
+  pub filter_groups: RepeatedFilterGroupPB,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum, EnumIter)]
@@ -89,6 +93,12 @@ pub struct DatabaseSettingChangesetPB {
 
   #[pb(index = 7, one_of)]
   pub delete_sort: Option<DeleteSortPayloadPB>,
+
+  #[pb(index = 8, one_of)]
+  pub update_filter_group: Option<UpdateFilterGroupPayloadPB>,
+
+  #[pb(index = 9, one_of)]
+  pub delete_filter_group: Option<DeleteFilterGroupPayloadPB>,
 }
 
 impl TryInto<DatabaseSettingChangesetParams> for DatabaseSettingChangesetPB {
@@ -119,6 +129,16 @@ impl TryInto<DatabaseSettingChangesetParams> for DatabaseSettingChangesetPB {
       Some(payload) => Some(payload.try_into()?),
     };
 
+    let insert_filter_group = match self.update_filter_group {
+      None => None,
+      Some(payload) => Some(payload.try_into()?),
+    };
+
+    let delete_filter_group = match self.delete_filter_group {
+      None => None,
+      Some(payload) => Some(payload.try_into()?),
+    };
+
     Ok(DatabaseSettingChangesetParams {
       view_id,
       layout_type: self.layout_type.map(|ty| ty.into()),
@@ -126,6 +146,8 @@ impl TryInto<DatabaseSettingChangesetParams> for DatabaseSettingChangesetPB {
       delete_filter,
       alert_sort,
       delete_sort,
+      insert_filter_group,
+      delete_filter_group,
     })
   }
 }
@@ -137,11 +159,16 @@ pub struct DatabaseSettingChangesetParams {
   pub delete_filter: Option<DeleteFilterParams>,
   pub alert_sort: Option<UpdateSortParams>,
   pub delete_sort: Option<DeleteSortParams>,
+  pub insert_filter_group: Option<UpdateFilterGroupParams>,
+  pub delete_filter_group: Option<DeleteFilterGroupParams>,
 }
 
 impl DatabaseSettingChangesetParams {
   pub fn is_filter_changed(&self) -> bool {
-    self.insert_filter.is_some() || self.delete_filter.is_some()
+    self.insert_filter.is_some()
+      || self.delete_filter.is_some()
+      || self.insert_filter_group.is_some()
+      || self.delete_filter_group.is_some()
   }
 }
 
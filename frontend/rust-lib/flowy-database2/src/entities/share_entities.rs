@@ -1,9 +1,25 @@
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 
+use crate::entities::FieldType;
+use crate::services::share::csv::{CSVColumn, CSVImportReport, CSVSkippedLine};
+
 #[derive(Debug, ProtoBuf_Enum, Clone, Default)]
 pub enum DatabaseExportDataType {
   #[default]
   CSV = 0,
+  JSON = 1,
+  Markdown = 2,
+  XLSX = 3,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct DatabaseExportPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// Appends the id, the created time, the last edited time and the document text of the rows
+  #[pb(index = 2)]
+  pub include_row_meta: bool,
 }
 
 #[derive(Debug, ProtoBuf, Default, Clone)]
@@ -13,4 +29,100 @@ pub struct DatabaseExportDataPB {
 
   #[pb(index = 2)]
   pub data: String,
+
+  /// The data of the binary export types, like XLSX. The `data` is empty for them.
+  #[pb(index = 3)]
+  pub bytes: Vec<u8>,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct CSVImportContentPB {
+  #[pb(index = 1)]
+  pub content: String,
+}
+
+/// A column of the CSV with the field type it's imported as, unless it's overridden.
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct CSVColumnPB {
+  #[pb(index = 1)]
+  pub name: String,
+
+  #[pb(index = 2)]
+  pub field_type: FieldType,
+}
+
+impl From<CSVColumn> for CSVColumnPB {
+  fn from(column: CSVColumn) -> Self {
+    Self {
+      name: column.name,
+      field_type: column.field_type,
+    }
+  }
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct RepeatedCSVColumnPB {
+  #[pb(index = 1)]
+  pub items: Vec<CSVColumnPB>,
+}
+
+/// The progress of importing a CSV file. It's notified with the path of the file as the id.
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct CSVImportProgressPB {
+  #[pb(index = 1)]
+  pub file_path: String,
+
+  #[pb(index = 2)]
+  pub imported_rows: i64,
+
+  #[pb(index = 3)]
+  pub read_bytes: i64,
+
+  #[pb(index = 4)]
+  pub total_bytes: i64,
+}
+
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct CSVSkippedLinePB {
+  #[pb(index = 1)]
+  pub line: i64,
+
+  #[pb(index = 2)]
+  pub error: String,
+}
+
+impl From<CSVSkippedLine> for CSVSkippedLinePB {
+  fn from(skipped_line: CSVSkippedLine) -> Self {
+    Self {
+      line: skipped_line.line as i64,
+      error: skipped_line.error,
+    }
+  }
+}
+
+/// The report of importing a CSV file, listing the lines that couldn't be parsed.
+#[derive(Debug, ProtoBuf, Default, Clone)]
+pub struct CSVImportReportPB {
+  #[pb(index = 1)]
+  pub file_path: String,
+
+  #[pb(index = 2)]
+  pub imported_rows: i64,
+
+  #[pb(index = 3)]
+  pub skipped_lines: Vec<CSVSkippedLinePB>,
+}
+
+impl CSVImportReportPB {
+  pub fn new(file_path: String, report: CSVImportReport) -> Self {
+    Self {
+      file_path,
+      imported_rows: report.imported_rows as i64,
+      skipped_lines: report
+        .skipped_lines
+        .into_iter()
+        .map(CSVSkippedLinePB::from)
+        .collect(),
+    }
+  }
 }
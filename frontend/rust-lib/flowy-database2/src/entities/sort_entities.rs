@@ -18,6 +18,12 @@ pub struct SortPB {
 
   #[pb(index = 4)]
   pub condition: SortConditionPB,
+
+  #[pb(index = 5)]
+  pub by_option_order: bool,
+
+  #[pb(index = 6)]
+  pub empty_first: bool,
 }
 
 impl std::convert::From<&Sort> for SortPB {
@@ -27,6 +33,8 @@ impl std::convert::From<&Sort> for SortPB {
       field_id: sort.field_id.clone(),
       field_type: sort.field_type.clone(),
       condition: sort.condition.into(),
+      by_option_order: sort.by_option_order,
+      empty_first: sort.empty_first,
     }
   }
 }
@@ -38,6 +46,8 @@ impl std::convert::From<Sort> for SortPB {
       field_id: sort.field_id,
       field_type: sort.field_type,
       condition: sort.condition.into(),
+      by_option_order: sort.by_option_order,
+      empty_first: sort.empty_first,
     }
   }
 }
@@ -108,6 +118,15 @@ pub struct UpdateSortPayloadPB {
 
   #[pb(index = 5)]
   pub condition: SortConditionPB,
+
+  /// Sorts the select option fields by the positions of the options, e.g. Low, Medium, High,
+  /// instead of their names.
+  #[pb(index = 6)]
+  pub by_option_order: bool,
+
+  /// Puts the empty cells first instead of last.
+  #[pb(index = 7)]
+  pub empty_first: bool,
 }
 
 impl TryInto<UpdateSortParams> for UpdateSortPayloadPB {
@@ -137,6 +156,8 @@ impl TryInto<UpdateSortParams> for UpdateSortPayloadPB {
       sort_id,
       field_type: self.field_type,
       condition: self.condition.into(),
+      by_option_order: self.by_option_order,
+      empty_first: self.empty_first,
     })
   }
 }
@@ -149,6 +170,8 @@ pub struct UpdateSortParams {
   pub sort_id: Option<String>,
   pub field_type: FieldType,
   pub condition: SortCondition,
+  pub by_option_order: bool,
+  pub empty_first: bool,
 }
 
 #[derive(ProtoBuf, Debug, Default, Clone)]
@@ -5,7 +5,9 @@ use strum_macros::EnumIter;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 
 use crate::entities::CellIdPB;
-use crate::services::field::{DateFormat, DateTypeOption, TimeFormat};
+use crate::services::field::{
+  DateFormat, DateRecurrence, DateTypeOption, RecurrenceEnd, RecurrenceFrequency, TimeFormat,
+};
 
 #[derive(Clone, Debug, Default, ProtoBuf)]
 pub struct DateCellDataPB {
@@ -32,6 +34,13 @@ pub struct DateCellDataPB {
 
   #[pb(index = 8)]
   pub is_range: bool,
+
+  #[pb(index = 9, one_of)]
+  pub recurrence: Option<DateRecurrencePB>,
+
+  /// The reminder of each occurrence is scheduled the given number of seconds before it
+  #[pb(index = 10, one_of)]
+  pub reminder_offset: Option<i64>,
 }
 
 #[derive(Clone, Debug, Default, ProtoBuf)]
@@ -59,6 +68,112 @@ pub struct DateChangesetPB {
 
   #[pb(index = 8, one_of)]
   pub clear_flag: Option<bool>,
+
+  #[pb(index = 9, one_of)]
+  pub recurrence: Option<DateRecurrencePB>,
+
+  #[pb(index = 10, one_of)]
+  pub clear_recurrence: Option<bool>,
+
+  #[pb(index = 11, one_of)]
+  pub reminder_offset: Option<i64>,
+
+  #[pb(index = 12, one_of)]
+  pub clear_reminder: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct DateRecurrencePB {
+  #[pb(index = 1)]
+  pub frequency: RecurrenceFrequencyPB,
+
+  #[pb(index = 2)]
+  pub interval: i32,
+
+  /// The days of the week that a weekly recurrence repeats on. 0 is Monday and 6 is Sunday.
+  #[pb(index = 3)]
+  pub weekdays: Vec<i32>,
+
+  /// Stops after the given number of occurrences
+  #[pb(index = 4, one_of)]
+  pub end_count: Option<i32>,
+
+  /// Stops after the given timestamp
+  #[pb(index = 5, one_of)]
+  pub end_until: Option<i64>,
+}
+
+impl From<DateRecurrencePB> for DateRecurrence {
+  fn from(data: DateRecurrencePB) -> Self {
+    let end = match (data.end_count, data.end_until) {
+      (Some(count), _) => RecurrenceEnd::Count(count.max(1) as u32),
+      (None, Some(until)) => RecurrenceEnd::Until(until),
+      (None, None) => RecurrenceEnd::Never,
+    };
+    Self {
+      frequency: data.frequency.into(),
+      interval: data.interval.max(1) as u32,
+      weekdays: data
+        .weekdays
+        .into_iter()
+        .filter(|weekday| (0..7).contains(weekday))
+        .map(|weekday| weekday as u32)
+        .collect(),
+      end,
+    }
+  }
+}
+
+impl From<DateRecurrence> for DateRecurrencePB {
+  fn from(data: DateRecurrence) -> Self {
+    let (end_count, end_until) = match data.end {
+      RecurrenceEnd::Never => (None, None),
+      RecurrenceEnd::Count(count) => (Some(count as i32), None),
+      RecurrenceEnd::Until(until) => (None, Some(until)),
+    };
+    Self {
+      frequency: data.frequency.into(),
+      interval: data.interval as i32,
+      weekdays: data
+        .weekdays
+        .into_iter()
+        .map(|weekday| weekday as i32)
+        .collect(),
+      end_count,
+      end_until,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Copy, PartialEq, Eq, ProtoBuf_Enum, Default)]
+pub enum RecurrenceFrequencyPB {
+  #[default]
+  Daily = 0,
+  Weekly = 1,
+  Monthly = 2,
+  Yearly = 3,
+}
+
+impl From<RecurrenceFrequencyPB> for RecurrenceFrequency {
+  fn from(data: RecurrenceFrequencyPB) -> Self {
+    match data {
+      RecurrenceFrequencyPB::Daily => RecurrenceFrequency::Daily,
+      RecurrenceFrequencyPB::Weekly => RecurrenceFrequency::Weekly,
+      RecurrenceFrequencyPB::Monthly => RecurrenceFrequency::Monthly,
+      RecurrenceFrequencyPB::Yearly => RecurrenceFrequency::Yearly,
+    }
+  }
+}
+
+impl From<RecurrenceFrequency> for RecurrenceFrequencyPB {
+  fn from(data: RecurrenceFrequency) -> Self {
+    match data {
+      RecurrenceFrequency::Daily => RecurrenceFrequencyPB::Daily,
+      RecurrenceFrequency::Weekly => RecurrenceFrequencyPB::Weekly,
+      RecurrenceFrequency::Monthly => RecurrenceFrequencyPB::Monthly,
+      RecurrenceFrequency::Yearly => RecurrenceFrequencyPB::Yearly,
+    }
+  }
 }
 
 // Date
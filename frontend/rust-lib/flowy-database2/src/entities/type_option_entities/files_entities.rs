@@ -0,0 +1,115 @@
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError};
+
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::{CellIdPB, CellIdParams};
+use crate::services::field::{FileAttachment, FilesCellData, FilesTypeOption};
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct FileAttachmentPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub mime: String,
+
+  /// The size of the file in bytes
+  #[pb(index = 4)]
+  pub size: i64,
+
+  /// The url returned by the file storage service
+  #[pb(index = 5)]
+  pub url: String,
+
+  #[pb(index = 6)]
+  pub uploaded_at: i64,
+}
+
+impl From<FileAttachment> for FileAttachmentPB {
+  fn from(file: FileAttachment) -> Self {
+    Self {
+      id: file.id,
+      name: file.name,
+      mime: file.mime,
+      size: file.size,
+      url: file.url,
+      uploaded_at: file.uploaded_at,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct FilesCellDataPB {
+  #[pb(index = 1)]
+  pub files: Vec<FileAttachmentPB>,
+}
+
+impl From<FilesCellData> for FilesCellDataPB {
+  fn from(data: FilesCellData) -> Self {
+    Self {
+      files: data.files.into_iter().map(FileAttachmentPB::from).collect(),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct FilesTypeOptionPB {
+  /// Shows the images as thumbnails instead of the names of the files
+  #[pb(index = 1)]
+  pub show_thumbnail: bool,
+}
+
+impl From<FilesTypeOption> for FilesTypeOptionPB {
+  fn from(data: FilesTypeOption) -> Self {
+    Self {
+      show_thumbnail: data.show_thumbnail,
+    }
+  }
+}
+
+impl From<FilesTypeOptionPB> for FilesTypeOption {
+  fn from(data: FilesTypeOptionPB) -> Self {
+    Self {
+      show_thumbnail: data.show_thumbnail,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct UploadFilePayloadPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  /// The path of the file on the device
+  #[pb(index = 2)]
+  pub file_path: String,
+}
+
+pub struct UploadFileParams {
+  pub cell_id: CellIdParams,
+  pub file_path: String,
+}
+
+impl TryInto<UploadFileParams> for UploadFilePayloadPB {
+  type Error = FlowyError;
+
+  fn try_into(self) -> Result<UploadFileParams, Self::Error> {
+    let file_path = NotEmptyStr::parse(self.file_path).map_err(|_| ErrorCode::InvalidParams)?;
+    Ok(UploadFileParams {
+      cell_id: self.cell_id.try_into()?,
+      file_path: file_path.0,
+    })
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct DeleteFilePayloadPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub file_id: String,
+}
@@ -0,0 +1,41 @@
+use flowy_derive::ProtoBuf;
+
+use crate::entities::DateFormatPB;
+use crate::services::field::FormulaTypeOption;
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct FormulaCellDataPB {
+  #[pb(index = 1)]
+  pub data: String,
+
+  /// Describes why the formula couldn't be evaluated
+  #[pb(index = 2, one_of)]
+  pub error: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct FormulaTypeOptionPB {
+  #[pb(index = 1)]
+  pub expression: String,
+
+  #[pb(index = 2)]
+  pub date_format: DateFormatPB,
+}
+
+impl From<FormulaTypeOption> for FormulaTypeOptionPB {
+  fn from(data: FormulaTypeOption) -> Self {
+    Self {
+      expression: data.expression,
+      date_format: data.date_format.into(),
+    }
+  }
+}
+
+impl From<FormulaTypeOptionPB> for FormulaTypeOption {
+  fn from(data: FormulaTypeOptionPB) -> Self {
+    Self {
+      expression: data.expression,
+      date_format: data.date_format.into(),
+    }
+  }
+}
@@ -0,0 +1,80 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+use crate::services::field::{LookupAggregation, LookupTypeOption};
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct LookupTypeOptionPB {
+  /// The id of the database that the values are pulled from
+  #[pb(index = 1)]
+  pub database_id: String,
+
+  /// The id of the field in this database whose content is used as the key
+  #[pb(index = 2)]
+  pub key_field_id: String,
+
+  /// The id of the field in the source database that is matched against the key
+  #[pb(index = 3)]
+  pub source_key_field_id: String,
+
+  /// The id of the field in the source database whose content is pulled
+  #[pb(index = 4)]
+  pub value_field_id: String,
+
+  #[pb(index = 5)]
+  pub aggregation: LookupAggregationPB,
+}
+
+impl From<LookupTypeOption> for LookupTypeOptionPB {
+  fn from(data: LookupTypeOption) -> Self {
+    Self {
+      database_id: data.database_id,
+      key_field_id: data.key_field_id,
+      source_key_field_id: data.source_key_field_id,
+      value_field_id: data.value_field_id,
+      aggregation: data.aggregation.into(),
+    }
+  }
+}
+
+impl From<LookupTypeOptionPB> for LookupTypeOption {
+  fn from(data: LookupTypeOptionPB) -> Self {
+    Self {
+      database_id: data.database_id,
+      key_field_id: data.key_field_id,
+      source_key_field_id: data.source_key_field_id,
+      value_field_id: data.value_field_id,
+      aggregation: data.aggregation.into(),
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ProtoBuf_Enum, Default)]
+pub enum LookupAggregationPB {
+  #[default]
+  First = 0,
+  Count = 1,
+  Sum = 2,
+  Concatenate = 3,
+}
+
+impl From<LookupAggregationPB> for LookupAggregation {
+  fn from(data: LookupAggregationPB) -> Self {
+    match data {
+      LookupAggregationPB::First => LookupAggregation::First,
+      LookupAggregationPB::Count => LookupAggregation::Count,
+      LookupAggregationPB::Sum => LookupAggregation::Sum,
+      LookupAggregationPB::Concatenate => LookupAggregation::Concatenate,
+    }
+  }
+}
+
+impl From<LookupAggregation> for LookupAggregationPB {
+  fn from(data: LookupAggregation) -> Self {
+    match data {
+      LookupAggregation::First => LookupAggregationPB::First,
+      LookupAggregation::Count => LookupAggregationPB::Count,
+      LookupAggregation::Sum => LookupAggregationPB::Sum,
+      LookupAggregation::Concatenate => LookupAggregationPB::Concatenate,
+    }
+  }
+}
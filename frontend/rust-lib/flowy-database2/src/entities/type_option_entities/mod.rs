@@ -1,7 +1,12 @@
 mod checkbox_entities;
 mod checklist_entities;
 mod date_entities;
+mod files_entities;
+mod formula_entities;
+mod lookup_entities;
 mod number_entities;
+mod person_entities;
+mod relation_entities;
 mod select_option;
 mod text_entities;
 mod timestamp_entities;
@@ -10,7 +15,12 @@ mod url_entities;
 pub use checkbox_entities::*;
 pub use checklist_entities::*;
 pub use date_entities::*;
+pub use files_entities::*;
+pub use formula_entities::*;
+pub use lookup_entities::*;
 pub use number_entities::*;
+pub use person_entities::*;
+pub use relation_entities::*;
 pub use select_option::*;
 pub use text_entities::*;
 pub use timestamp_entities::*;
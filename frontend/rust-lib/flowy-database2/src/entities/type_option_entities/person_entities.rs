@@ -0,0 +1,97 @@
+use flowy_derive::ProtoBuf;
+
+use crate::entities::CellIdPB;
+use crate::services::field::{PersonMember, PersonTypeOption};
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct PersonMemberPB {
+  #[pb(index = 1)]
+  pub uid: i64,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub email: String,
+
+  #[pb(index = 4)]
+  pub icon_url: String,
+}
+
+impl From<PersonMember> for PersonMemberPB {
+  fn from(member: PersonMember) -> Self {
+    Self {
+      uid: member.uid,
+      name: member.name,
+      email: member.email,
+      icon_url: member.icon_url,
+    }
+  }
+}
+
+impl From<PersonMemberPB> for PersonMember {
+  fn from(member: PersonMemberPB) -> Self {
+    Self {
+      uid: member.uid,
+      name: member.name,
+      email: member.email,
+      icon_url: member.icon_url,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedPersonMemberPB {
+  #[pb(index = 1)]
+  pub items: Vec<PersonMemberPB>,
+}
+
+impl From<Vec<PersonMember>> for RepeatedPersonMemberPB {
+  fn from(members: Vec<PersonMember>) -> Self {
+    Self {
+      items: members.into_iter().map(PersonMemberPB::from).collect(),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct PersonCellDataPB {
+  /// The members assigned to the cell
+  #[pb(index = 1)]
+  pub members: Vec<PersonMemberPB>,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct PersonTypeOptionPB {
+  /// The members that were assigned to the cells of the field
+  #[pb(index = 1)]
+  pub members: Vec<PersonMemberPB>,
+}
+
+impl From<PersonTypeOption> for PersonTypeOptionPB {
+  fn from(data: PersonTypeOption) -> Self {
+    Self {
+      members: data.members.into_iter().map(PersonMemberPB::from).collect(),
+    }
+  }
+}
+
+impl From<PersonTypeOptionPB> for PersonTypeOption {
+  fn from(data: PersonTypeOptionPB) -> Self {
+    Self {
+      members: data.members.into_iter().map(PersonMember::from).collect(),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct PersonCellChangesetPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub inserted_user_ids: Vec<i64>,
+
+  #[pb(index = 3)]
+  pub removed_user_ids: Vec<i64>,
+}
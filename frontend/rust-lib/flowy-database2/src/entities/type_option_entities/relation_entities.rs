@@ -0,0 +1,147 @@
+use collab_database::rows::RowId;
+
+use flowy_derive::ProtoBuf;
+use flowy_error::{ErrorCode, FlowyError};
+
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::CellIdPB;
+use crate::services::field::{RelationCellData, RelationTypeOption};
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationCellDataPB {
+  /// The ids of the rows in the related database
+  #[pb(index = 1)]
+  pub row_ids: Vec<String>,
+}
+
+impl From<RelationCellData> for RelationCellDataPB {
+  fn from(data: RelationCellData) -> Self {
+    Self {
+      row_ids: data
+        .row_ids
+        .into_iter()
+        .map(|row_id| row_id.into_inner())
+        .collect(),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationTypeOptionPB {
+  /// The id of the database that the field links to
+  #[pb(index = 1)]
+  pub database_id: String,
+
+  /// The id of the relation field in the related database that links back to this field.
+  #[pb(index = 2, one_of)]
+  pub reciprocal_field_id: Option<String>,
+}
+
+impl From<RelationTypeOption> for RelationTypeOptionPB {
+  fn from(data: RelationTypeOption) -> Self {
+    Self {
+      database_id: data.database_id,
+      reciprocal_field_id: data.reciprocal_field_id,
+    }
+  }
+}
+
+impl From<RelationTypeOptionPB> for RelationTypeOption {
+  fn from(data: RelationTypeOptionPB) -> Self {
+    Self {
+      database_id: data.database_id,
+      reciprocal_field_id: data
+        .reciprocal_field_id
+        .filter(|field_id| !field_id.is_empty()),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationCellChangesetPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub inserted_row_ids: Vec<String>,
+
+  #[pb(index = 3)]
+  pub removed_row_ids: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelatedRowDataPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  /// The content of the primary field of the related row
+  #[pb(index = 2)]
+  pub name: String,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedRelatedRowDataPB {
+  #[pb(index = 1)]
+  pub rows: Vec<RelatedRowDataPB>,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct GetRelatedRowDataPB {
+  #[pb(index = 1)]
+  pub database_id: String,
+
+  #[pb(index = 2)]
+  pub row_ids: Vec<String>,
+}
+
+pub struct GetRelatedRowDataParams {
+  pub database_id: String,
+  pub row_ids: Vec<RowId>,
+}
+
+impl TryInto<GetRelatedRowDataParams> for GetRelatedRowDataPB {
+  type Error = FlowyError;
+
+  fn try_into(self) -> Result<GetRelatedRowDataParams, Self::Error> {
+    let database_id =
+      NotEmptyStr::parse(self.database_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+    Ok(GetRelatedRowDataParams {
+      database_id: database_id.0,
+      row_ids: self.row_ids.into_iter().map(RowId::from).collect(),
+    })
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct CreateReciprocalRelationPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The id of the relation field that the reciprocal field is created for
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  /// The name of the reciprocal field. Uses the default name of the field type if it's None.
+  #[pb(index = 3, one_of)]
+  pub name: Option<String>,
+}
+
+pub struct CreateReciprocalRelationParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub name: Option<String>,
+}
+
+impl TryInto<CreateReciprocalRelationParams> for CreateReciprocalRelationPayloadPB {
+  type Error = FlowyError;
+
+  fn try_into(self) -> Result<CreateReciprocalRelationParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    Ok(CreateReciprocalRelationParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+      name: self.name,
+    })
+  }
+}
@@ -12,11 +12,12 @@ use crate::manager::DatabaseManager;
 use crate::services::cell::CellBuilder;
 use crate::services::field::checklist_type_option::ChecklistCellChangeset;
 use crate::services::field::{
-  type_option_data_from_pb_or_default, DateCellChangeset, SelectOptionCellChangeset,
+  type_option_data_from_pb_or_default, DateCellChangeset, DateRecurrence, PersonCellChangeset,
+  RelationCellChangeset, SelectOptionCellChangeset,
 };
 use crate::services::field_settings::FieldSettingsChangesetParams;
 use crate::services::group::{GroupChangeset, GroupSettingChangeset};
-use crate::services::share::csv::CSVFormat;
+use crate::services::share::csv::{CSVExportOptions, CSVFormat};
 
 fn upgrade_manager(
   database_manager: AFPluginState<Weak<DatabaseManager>>,
@@ -89,6 +90,12 @@ pub(crate) async fn update_database_setting_handler(
   let params: DatabaseSettingChangesetParams = data.into_inner().try_into()?;
   let editor = manager.get_database_with_view_id(&params.view_id).await?;
 
+  if let Some(update_filter_group) = params.insert_filter_group {
+    let _ = editor
+      .create_or_update_filter_group(update_filter_group)
+      .await?;
+  }
+
   if let Some(update_filter) = params.insert_filter {
     editor.create_or_update_filter(update_filter).await?;
   }
@@ -97,6 +104,10 @@ pub(crate) async fn update_database_setting_handler(
     editor.delete_filter(delete_filter).await?;
   }
 
+  if let Some(delete_filter_group) = params.delete_filter_group {
+    editor.delete_filter_group(delete_filter_group).await?;
+  }
+
   if let Some(update_sort) = params.alert_sort {
     let _ = editor.create_or_update_sort(update_sort).await?;
   }
@@ -124,6 +135,20 @@ pub(crate) async fn get_all_filters_handler(
   data_result_ok(filters)
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_all_filter_groups_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedFilterGroupPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager.get_database_with_view_id(view_id.as_ref()).await?;
+  let filter_groups = database_editor
+    .get_all_filter_groups(view_id.as_ref())
+    .await;
+  data_result_ok(filter_groups)
+}
+
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
 pub(crate) async fn get_all_sorts_handler(
   data: AFPluginData<DatabaseViewIdPB>,
@@ -226,6 +251,11 @@ pub(crate) async fn update_field_type_option_handler(
         old_field,
       )
       .await?;
+    if field_type.is_lookup() {
+      manager
+        .refresh_lookup_field(&params.view_id, &params.field_id)
+        .await?;
+    }
   }
   Ok(())
 }
@@ -238,10 +268,24 @@ pub(crate) async fn delete_field_handler(
   let manager = upgrade_manager(manager)?;
   let params: FieldIdParams = data.into_inner().try_into()?;
   let database_editor = manager.get_database_with_view_id(&params.view_id).await?;
-  database_editor.delete_field(&params.field_id).await?;
+  database_editor
+    .delete_field(&params.view_id, &params.field_id)
+    .await?;
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn preview_field_type_switch_handler(
+  data: AFPluginData<UpdateFieldTypePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<FieldTypeSwitchPreviewPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: EditFieldParams = data.into_inner().try_into()?;
+  let database_editor = manager.get_database_with_view_id(&params.view_id).await?;
+  let preview = database_editor.preview_field_type_switch(&params.field_id, &params.field_type)?;
+  data_result_ok(preview)
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn switch_to_field_handler(
   data: AFPluginData<UpdateFieldTypePayloadPB>,
@@ -252,7 +296,7 @@ pub(crate) async fn switch_to_field_handler(
   let database_editor = manager.get_database_with_view_id(&params.view_id).await?;
   let old_field = database_editor.get_field(&params.field_id);
   database_editor
-    .switch_to_field_type(&params.field_id, &params.field_type)
+    .switch_to_field_type(&params.view_id, &params.field_id, &params.field_type)
     .await?;
 
   if let Some(new_type_option) = database_editor
@@ -275,6 +319,11 @@ pub(crate) async fn switch_to_field_handler(
       },
     }
   }
+  if params.field_type.is_lookup() {
+    manager
+      .refresh_lookup_field(&params.view_id, &params.field_id)
+      .await?;
+  }
   Ok(())
 }
 
@@ -328,6 +377,11 @@ pub(crate) async fn create_field_type_option_data_handler(
   let (field, data) = database_editor
     .create_field_with_type_option(&params.view_id, &params.field_type, params.type_option_data)
     .await;
+  if params.field_type.is_lookup() {
+    manager
+      .refresh_lookup_field(&params.view_id, &field.id)
+      .await?;
+  }
 
   let data = TypeOptionPB {
     view_id: params.view_id,
@@ -402,8 +456,7 @@ pub(crate) async fn delete_row_handler(
 ) -> Result<(), FlowyError> {
   let manager = upgrade_manager(manager)?;
   let params: RowIdParams = data.into_inner().try_into()?;
-  let database_editor = manager.get_database_with_view_id(&params.view_id).await?;
-  database_editor.delete_row(&params.row_id).await;
+  manager.delete_row(&params.view_id, params.row_id).await?;
   Ok(())
 }
 
@@ -630,6 +683,101 @@ pub(crate) async fn update_checklist_cell_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_relation_cell_handler(
+  data: AFPluginData<RelationCellChangesetPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let data = data.into_inner();
+  let cell_id: CellIdParams = data.cell_id.try_into()?;
+  let changeset = RelationCellChangeset {
+    inserted_row_ids: data.inserted_row_ids,
+    removed_row_ids: data.removed_row_ids,
+  };
+  manager
+    .update_relation_cell(
+      &cell_id.view_id,
+      cell_id.row_id,
+      &cell_id.field_id,
+      changeset,
+    )
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_related_row_datas_handler(
+  data: AFPluginData<GetRelatedRowDataPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedRelatedRowDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: GetRelatedRowDataParams = data.into_inner().try_into()?;
+  let rows = manager
+    .get_related_rows(&params.database_id, &params.row_ids)
+    .await?;
+  data_result_ok(RepeatedRelatedRowDataPB { rows })
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn create_reciprocal_relation_field_handler(
+  data: AFPluginData<CreateReciprocalRelationPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: CreateReciprocalRelationParams = data.into_inner().try_into()?;
+  manager.create_reciprocal_relation_field(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_workspace_members_handler(
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedPersonMemberPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let members = manager.get_workspace_members().await?;
+  data_result_ok(RepeatedPersonMemberPB::from(members))
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_person_cell_handler(
+  data: AFPluginData<PersonCellChangesetPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let data = data.into_inner();
+  let cell_id: CellIdParams = data.cell_id.try_into()?;
+  let changeset = PersonCellChangeset {
+    inserted_user_ids: data.inserted_user_ids,
+    removed_user_ids: data.removed_user_ids,
+  };
+  manager.update_person_cell(cell_id, changeset).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn upload_file_handler(
+  data: AFPluginData<UploadFilePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<FileAttachmentPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: UploadFileParams = data.into_inner().try_into()?;
+  let file = manager.upload_file(params).await?;
+  data_result_ok(FileAttachmentPB::from(file))
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn delete_file_handler(
+  data: AFPluginData<DeleteFilePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let data = data.into_inner();
+  let cell_id: CellIdParams = data.cell_id.try_into()?;
+  manager.delete_file(cell_id, data.file_id).await?;
+  Ok(())
+}
+
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn update_date_cell_handler(
   data: AFPluginData<DateChangesetPB>,
@@ -646,16 +794,12 @@ pub(crate) async fn update_date_cell_handler(
     include_time: data.include_time,
     is_range: data.is_range,
     clear_flag: data.clear_flag,
+    recurrence: data.recurrence.map(DateRecurrence::from),
+    clear_recurrence: data.clear_recurrence,
+    reminder_offset: data.reminder_offset,
+    clear_reminder: data.clear_reminder,
   };
-  let database_editor = manager.get_database_with_view_id(&cell_id.view_id).await?;
-  database_editor
-    .update_cell_with_changeset(
-      &cell_id.view_id,
-      cell_id.row_id,
-      &cell_id.field_id,
-      cell_changeset,
-    )
-    .await?;
+  manager.update_date_cell(cell_id, cell_changeset).await?;
   Ok(())
 }
 
@@ -694,11 +838,37 @@ pub(crate) async fn set_group_by_field_handler(
   let params: GroupByFieldParams = data.into_inner().try_into()?;
   let database_editor = manager.get_database_with_view_id(&params.view_id).await?;
   database_editor
-    .set_group_by_field(&params.view_id, &params.field_id)
+    .set_group_by_field(&params.view_id, &params.field_id, params.setting_content)
     .await?;
   Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn set_sub_group_by_field_handler(
+  data: AFPluginData<SubGroupByFieldPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_manager(manager)?;
+  let params: SubGroupByFieldParams = data.into_inner().try_into()?;
+  let database_editor = manager.get_database_with_view_id(&params.view_id).await?;
+  database_editor
+    .set_sub_group_by_field(&params.view_id, params.field_id)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_sub_groups_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedGroupPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager.get_database_with_view_id(params.as_ref()).await?;
+  let groups = database_editor.load_sub_groups(params.as_ref()).await?;
+  data_result_ok(groups)
+}
+
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn update_group_handler(
   data: AFPluginData<UpdateGroupPB>,
@@ -745,6 +915,7 @@ pub(crate) async fn move_group_row_handler(
       &params.to_group_id,
       params.from_row_id,
       params.to_row_id,
+      params.to_sub_group_id.as_deref(),
     )
     .await?;
   Ok(())
@@ -801,7 +972,7 @@ pub(crate) async fn get_calendar_events_handler(
   let params: CalendarEventRequestParams = data.into_inner().try_into()?;
   let database_editor = manager.get_database_with_view_id(&params.view_id).await?;
   let events = database_editor
-    .get_all_calendar_events(&params.view_id)
+    .get_all_calendar_events(&params.view_id, params.window)
     .await;
   data_result_ok(RepeatedCalendarEventPB { items: events })
 }
@@ -845,18 +1016,8 @@ pub(crate) async fn move_calendar_event_handler(
   let manager = upgrade_manager(manager)?;
   let data = data.into_inner();
   let cell_id: CellIdParams = data.cell_path.try_into()?;
-  let cell_changeset = DateCellChangeset {
-    date: Some(data.timestamp),
-    ..Default::default()
-  };
-  let database_editor = manager.get_database_with_view_id(&cell_id.view_id).await?;
-  database_editor
-    .update_cell_with_changeset(
-      &cell_id.view_id,
-      cell_id.row_id,
-      &cell_id.field_id,
-      cell_changeset,
-    )
+  manager
+    .move_calendar_event(cell_id, data.timestamp, data.occurrence)
     .await?;
   Ok(())
 }
@@ -882,6 +1043,83 @@ pub(crate) async fn export_csv_handler(
   data_result_ok(DatabaseExportDataPB {
     export_type: DatabaseExportDataType::CSV,
     data,
+    ..Default::default()
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_view_csv_handler(
+  data: AFPluginData<DatabaseExportPayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params = data.into_inner();
+  let options = CSVExportOptions {
+    include_row_meta: params.include_row_meta,
+  };
+  let data = manager.export_view_csv(&params.view_id, options).await?;
+  data_result_ok(DatabaseExportDataPB {
+    export_type: DatabaseExportDataType::CSV,
+    data,
+    ..Default::default()
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_json_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let data = manager.export_json(&view_id).await?;
+  data_result_ok(DatabaseExportDataPB {
+    export_type: DatabaseExportDataType::JSON,
+    data,
+    ..Default::default()
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_markdown_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let data = manager.export_markdown(&view_id).await?;
+  data_result_ok(DatabaseExportDataPB {
+    export_type: DatabaseExportDataType::Markdown,
+    data,
+    ..Default::default()
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn export_xlsx_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseExportDataPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner().value;
+  let bytes = manager.export_xlsx(&view_id).await?;
+  data_result_ok(DatabaseExportDataPB {
+    export_type: DatabaseExportDataType::XLSX,
+    data: "".to_string(),
+    bytes,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_csv_import_field_types_handler(
+  data: AFPluginData<CSVImportContentPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedCSVColumnPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let content = data.into_inner().content;
+  let columns = manager.infer_csv_field_types(content).await?;
+  data_result_ok(RepeatedCSVColumnPB {
+    items: columns.into_iter().map(CSVColumnPB::from).collect(),
   })
 }
 
@@ -896,6 +1134,37 @@ pub(crate) async fn get_snapshots_handler(
   data_result_ok(RepeatedDatabaseSnapshotPB { items: snapshots })
 }
 
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_row_history_handler(
+  data: AFPluginData<RowIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedCellChangePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let database_editor = manager.get_database_with_view_id(&params.view_id).await?;
+  let items = database_editor
+    .get_row_history(&params.row_id)
+    .await?
+    .into_iter()
+    .map(CellChangePB::from)
+    .collect();
+  data_result_ok(RepeatedCellChangePB { items })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn restore_cell_change_handler(
+  data: AFPluginData<RestoreCellChangePayloadPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let manager = upgrade_manager(manager)?;
+  let params: RestoreCellChangeParams = data.into_inner().try_into()?;
+  let database_editor = manager.get_database_with_view_id(&params.view_id).await?;
+  database_editor
+    .restore_cell_change(&params.view_id, &params.change_id, params.whole_row)
+    .await?;
+  Ok(())
+}
+
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub(crate) async fn get_field_settings_handler(
   data: AFPluginData<FieldIdsPB>,
@@ -955,3 +1224,65 @@ pub(crate) async fn update_field_settings_handler(
     .await?;
   Ok(())
 }
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn get_calculations_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<RepeatedCalculationsPB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner();
+  let database_editor = manager.get_database_with_view_id(view_id.as_ref()).await?;
+  let calculations = database_editor.get_calculations(view_id.as_ref()).await?;
+  data_result_ok(calculations)
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn undo_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseUndoRedoResponsePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner();
+  let is_success = manager.undo(view_id.as_ref()).await?;
+  let database_editor = manager.get_database_with_view_id(view_id.as_ref()).await?;
+  let (can_undo, can_redo) = database_editor.can_undo_redo(view_id.as_ref());
+  data_result_ok(DatabaseUndoRedoResponsePB {
+    can_undo,
+    can_redo,
+    is_success,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn redo_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseUndoRedoResponsePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner();
+  let is_success = manager.redo(view_id.as_ref()).await?;
+  let database_editor = manager.get_database_with_view_id(view_id.as_ref()).await?;
+  let (can_undo, can_redo) = database_editor.can_undo_redo(view_id.as_ref());
+  data_result_ok(DatabaseUndoRedoResponsePB {
+    can_undo,
+    can_redo,
+    is_success,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip_all, err)]
+pub(crate) async fn can_undo_redo_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Weak<DatabaseManager>>,
+) -> DataResult<DatabaseUndoRedoResponsePB, FlowyError> {
+  let manager = upgrade_manager(manager)?;
+  let view_id = data.into_inner();
+  let database_editor = manager.get_database_with_view_id(view_id.as_ref()).await?;
+  let (can_undo, can_redo) = database_editor.can_undo_redo(view_id.as_ref());
+  data_result_ok(DatabaseUndoRedoResponsePB {
+    can_undo,
+    can_redo,
+    is_success: true,
+  })
+}
@@ -19,6 +19,7 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
         .event(DatabaseEvent::GetDatabaseSetting, get_database_setting_handler)
         .event(DatabaseEvent::UpdateDatabaseSetting, update_database_setting_handler)
         .event(DatabaseEvent::GetAllFilters, get_all_filters_handler)
+        .event(DatabaseEvent::GetAllFilterGroups, get_all_filter_groups_handler)
         .event(DatabaseEvent::GetAllSorts, get_all_sorts_handler)
         .event(DatabaseEvent::DeleteAllSorts, delete_all_sorts_handler)
         // Field
@@ -28,6 +29,7 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
         .event(DatabaseEvent::UpdateFieldTypeOption, update_field_type_option_handler)
         .event(DatabaseEvent::DeleteField, delete_field_handler)
         .event(DatabaseEvent::UpdateFieldType, switch_to_field_handler)
+        .event(DatabaseEvent::PreviewFieldTypeSwitch, preview_field_type_switch_handler)
         .event(DatabaseEvent::DuplicateField, duplicate_field_handler)
         .event(DatabaseEvent::MoveField, move_field_handler)
         .event(DatabaseEvent::GetTypeOption, get_field_type_option_data_handler)
@@ -52,6 +54,16 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
         // Checklist
         .event(DatabaseEvent::GetChecklistCellData, get_checklist_cell_data_handler)
         .event(DatabaseEvent::UpdateChecklistCell, update_checklist_cell_handler)
+        // Relation
+        .event(DatabaseEvent::UpdateRelationCell, update_relation_cell_handler)
+        .event(DatabaseEvent::GetRelatedRowDatas, get_related_row_datas_handler)
+        .event(DatabaseEvent::CreateReciprocalRelationField, create_reciprocal_relation_field_handler)
+        // Person
+        .event(DatabaseEvent::GetWorkspaceMembers, get_workspace_members_handler)
+        .event(DatabaseEvent::UpdatePersonCell, update_person_cell_handler)
+        // Files
+        .event(DatabaseEvent::UploadFile, upload_file_handler)
+        .event(DatabaseEvent::DeleteFile, delete_file_handler)
         // Date
         .event(DatabaseEvent::UpdateDateCell, update_date_cell_handler)
         // Group
@@ -61,6 +73,8 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
         .event(DatabaseEvent::GetGroup, get_group_handler)
         .event(DatabaseEvent::SetGroupByField, set_group_by_field_handler)
         .event(DatabaseEvent::UpdateGroup, update_group_handler)
+        .event(DatabaseEvent::SetSubGroupByField, set_sub_group_by_field_handler)
+        .event(DatabaseEvent::GetSubGroups, get_sub_groups_handler)
         // Database
         .event(DatabaseEvent::GetDatabases, get_databases_handler)
         // Calendar
@@ -74,11 +88,24 @@ pub fn init(database_manager: Weak<DatabaseManager>) -> AFPlugin {
         .event(DatabaseEvent::CreateDatabaseView, create_database_view)
         // Export
         .event(DatabaseEvent::ExportCSV, export_csv_handler)
+        .event(DatabaseEvent::GetCSVImportFieldTypes, get_csv_import_field_types_handler)
+        .event(DatabaseEvent::ExportViewCSV, export_view_csv_handler)
+        .event(DatabaseEvent::ExportJSON, export_json_handler)
+        .event(DatabaseEvent::ExportMarkdown, export_markdown_handler)
+        .event(DatabaseEvent::ExportXLSX, export_xlsx_handler)
         .event(DatabaseEvent::GetDatabaseSnapshots, get_snapshots_handler)
+        .event(DatabaseEvent::GetRowHistory, get_row_history_handler)
+        .event(DatabaseEvent::RestoreCellChange, restore_cell_change_handler)
         // Field settings
         .event(DatabaseEvent::GetFieldSettings, get_field_settings_handler)
         .event(DatabaseEvent::GetAllFieldSettings, get_all_field_settings_handler)
         .event(DatabaseEvent::UpdateFieldSettings, update_field_settings_handler)
+        // Calculations
+        .event(DatabaseEvent::GetCalculations, get_calculations_handler)
+        // Undo and redo
+        .event(DatabaseEvent::Undo, undo_handler)
+        .event(DatabaseEvent::Redo, redo_handler)
+        .event(DatabaseEvent::CanUndoRedo, can_undo_redo_handler)
 }
 
 /// [DatabaseEvent] defines events that are used to interact with the Grid. You could check [this](https://appflowy.gitbook.io/docs/essential-documentation/contribute-to-appflowy/architecture/backend/protobuf)
@@ -120,6 +147,11 @@ pub enum DatabaseEvent {
   #[event(input = "DatabaseViewIdPB")]
   OpenDatabase = 7,
 
+  /// [GetAllFilterGroups] event is used to get the filter groups of the view. The filters are
+  /// combined according to the operators of the groups they belong to.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedFilterGroupPB")]
+  GetAllFilterGroups = 8,
+
   /// [GetFields] event is used to get the database's fields.
   ///
   /// The event handler accepts a [GetFieldPayloadPB] and returns a [RepeatedFieldPB]
@@ -188,6 +220,16 @@ pub enum DatabaseEvent {
   #[event(input = "DatabaseViewIdPB", output = "FieldPB")]
   GetPrimaryField = 25,
 
+  /// [PreviewFieldTypeSwitch] event is a dry run of [UpdateFieldType]. It returns how many cells
+  /// would convert to the new type and how many would become empty, with samples of the cells
+  /// before and after the switch. The cells that become empty are not removed, switching the
+  /// field back restores them.
+  #[event(
+    input = "UpdateFieldTypePayloadPB",
+    output = "FieldTypeSwitchPreviewPB"
+  )]
+  PreviewFieldTypeSwitch = 26,
+
   /// [CreateSelectOption] event is used to create a new select option. Returns a [SelectOptionPB] if
   /// there are no errors.
   #[event(input = "CreateSelectOptionPayloadPB", output = "SelectOptionPB")]
@@ -262,6 +304,41 @@ pub enum DatabaseEvent {
   #[event(input = "ChecklistCellDataChangesetPB")]
   UpdateChecklistCell = 74,
 
+  /// [UpdateRelationCell] event is used to link or unlink the rows of the related database.
+  /// The cells of the reciprocal relation field are updated too if the field has one.
+  #[event(input = "RelationCellChangesetPB")]
+  UpdateRelationCell = 75,
+
+  /// [GetRelatedRowDatas] event is used to get the content of the primary field of the
+  /// rows in the related database.
+  #[event(input = "GetRelatedRowDataPB", output = "RepeatedRelatedRowDataPB")]
+  GetRelatedRowDatas = 76,
+
+  /// [CreateReciprocalRelationField] event is used to create a relation field in the related
+  /// database that links back to the given relation field.
+  #[event(input = "CreateReciprocalRelationPayloadPB")]
+  CreateReciprocalRelationField = 77,
+
+  /// [GetWorkspaceMembers] event is used to get the members of the current workspace that can be
+  /// assigned to the person cells.
+  #[event(output = "RepeatedPersonMemberPB")]
+  GetWorkspaceMembers = 78,
+
+  /// [UpdatePersonCell] event is used to assign or unassign the members of the workspace to a
+  /// person cell.
+  #[event(input = "PersonCellChangesetPB")]
+  UpdatePersonCell = 79,
+
+  /// [UploadFile] event is used to upload a file with the file storage service and attach it to
+  /// a files cell. Returns the record of the uploaded file.
+  #[event(input = "UploadFilePayloadPB", output = "FileAttachmentPB")]
+  UploadFile = 80,
+
+  /// [DeleteFile] event is used to remove a file from a files cell. The uploaded file is deleted
+  /// from the file storage service too.
+  #[event(input = "DeleteFilePayloadPB")]
+  DeleteFile = 81,
+
   /// [UpdateDateCell] event is used to update a date cell's data. [DateChangesetPB]
   /// contains the date and the time string. It can be cast to [CellChangesetPB] that
   /// will be used by the `update_cell` function.
@@ -286,6 +363,14 @@ pub enum DatabaseEvent {
   #[event(input = "UpdateGroupPB")]
   UpdateGroup = 114,
 
+  /// [SetSubGroupByField] event is used to group the rows of a board by a second field. The
+  /// groups of the sub-grouping field are the swimlanes of the board.
+  #[event(input = "SubGroupByFieldPayloadPB")]
+  SetSubGroupByField = 115,
+
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedGroupPB")]
+  GetSubGroups = 116,
+
   /// Returns all the databases
   #[event(output = "RepeatedDatabaseDescriptionPB")]
   GetDatabases = 120,
@@ -317,10 +402,43 @@ pub enum DatabaseEvent {
   #[event(input = "DatabaseViewIdPB", output = "DatabaseExportDataPB")]
   ExportCSV = 141,
 
+  /// Returns the columns of the CSV with the field types inferred from their values, so the
+  /// field types can be reviewed and overridden before the CSV is imported.
+  #[event(input = "CSVImportContentPB", output = "RepeatedCSVColumnPB")]
+  GetCSVImportFieldTypes = 142,
+
+  /// Exports the rows and the fields shown in the view as CSV, so the hidden fields are skipped
+  /// and the filters and sorts of the view are applied. [DatabaseEvent::ExportCSV] exports all
+  /// the rows and fields of the database.
+  #[event(input = "DatabaseExportPayloadPB", output = "DatabaseExportDataPB")]
+  ExportViewCSV = 143,
+
+  /// Exports the view with its fields, rows and settings as versioned JSON, which can be
+  /// imported again with the JSON import type of the folder.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseExportDataPB")]
+  ExportJSON = 144,
+
+  /// Exports the rows and the fields shown in the view as a Markdown table.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseExportDataPB")]
+  ExportMarkdown = 145,
+
+  /// Exports the rows and the fields shown in the view as an XLSX workbook.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseExportDataPB")]
+  ExportXLSX = 146,
+
   /// Returns all the snapshots of the database view.
   #[event(input = "DatabaseViewIdPB", output = "RepeatedDatabaseSnapshotPB")]
   GetDatabaseSnapshots = 150,
 
+  /// Returns the changes of the cells in the row that were made on this device, ordered by the
+  /// time of the change.
+  #[event(input = "RowIdPB", output = "RepeatedCellChangePB")]
+  GetRowHistory = 151,
+
+  /// Restores a cell, or all the cells of its row, to the value before the given change.
+  #[event(input = "RestoreCellChangePayloadPB")]
+  RestoreCellChange = 152,
+
   /// Returns the field settings for the provided fields in the given view
   #[event(input = "FieldIdsPB", output = "RepeatedFieldSettingsPB")]
   GetFieldSettings = 160,
@@ -331,4 +449,23 @@ pub enum DatabaseEvent {
   /// Updates the field settings for a field in the given view
   #[event(input = "FieldSettingsChangesetPB")]
   UpdateFieldSettings = 162,
+
+  /// Returns the calculations of the fields in the given view. The calculations are evaluated
+  /// over the rows that pass the filters of the view. Use [UpdateFieldSettings] to set the
+  /// calculation of a field.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedCalculationsPB")]
+  GetCalculations = 170,
+
+  /// Reverts the last change made in the view. The cell edits, the creation, deletion and move
+  /// of the rows, the creation, deletion and type switch of the fields and the moves of the
+  /// groups can be undone.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseUndoRedoResponsePB")]
+  Undo = 180,
+
+  /// Reapplies the last change that was undone in the view.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseUndoRedoResponsePB")]
+  Redo = 181,
+
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseUndoRedoResponsePB")]
+  CanUndoRedo = 182,
 }
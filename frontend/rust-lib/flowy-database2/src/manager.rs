@@ -1,10 +1,12 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Weak};
 
 use collab::core::collab::{CollabRawData, MutexCollab};
 use collab_database::blocks::BlockEvent;
 use collab_database::database::{DatabaseData, YrsDocAction};
 use collab_database::error::DatabaseError;
+use collab_database::fields::Field;
+use collab_database::rows::{CreateRowParams, RowId};
 use collab_database::user::{
   CollabFuture, CollabObjectUpdate, CollabObjectUpdateByOid, DatabaseCollabService,
   WorkspaceDatabase,
@@ -18,32 +20,82 @@ use tracing::{instrument, trace};
 use collab_integrate::collab_builder::AppFlowyCollabBuilder;
 use collab_integrate::{CollabPersistenceConfig, RocksCollabDB};
 use flowy_database_deps::cloud::DatabaseCloudService;
-use flowy_error::{internal_error, FlowyError, FlowyResult};
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
+use flowy_storage::FileStorageService;
 use flowy_task::TaskDispatcher;
+use lib_infra::future::FutureResult;
+use lib_infra::util::timestamp;
 
 use crate::entities::{
-  DatabaseDescriptionPB, DatabaseLayoutPB, DatabaseSnapshotPB, DidFetchRowPB,
-  RepeatedDatabaseDescriptionPB,
+  CSVImportProgressPB, CSVImportReportPB, CellIdParams, CreateReciprocalRelationParams,
+  DatabaseDescriptionPB, DatabaseLayoutPB, DatabaseSnapshotPB, DidFetchRowPB, FieldChangesetParams,
+  FieldType, RelatedRowDataPB, RepeatedDatabaseDescriptionPB, UploadFileParams,
 };
 use crate::notification::{send_notification, DatabaseNotification};
-use crate::services::database::DatabaseEditor;
+use crate::services::database::{ChangeOrigin, DatabaseAction, DatabaseEditor};
 use crate::services::database_view::DatabaseLayoutDepsResolver;
+use crate::services::field::{
+  type_option_to_pb, DateCellChangeset, DateCellData, DateReminder, DateTypeOption, FileAttachment,
+  FormulaEvaluator, PersonCellChangeset, PersonMember, RelationCellChangeset, RelationCellData,
+  RelationTypeOption, UserDateTimeSettings,
+};
 use crate::services::field_settings::default_field_settings_by_layout_map;
-use crate::services::share::csv::{CSVFormat, CSVImporter, ImportResult};
+use crate::services::file::FileController;
+use crate::services::lookup::LookupController;
+use crate::services::relation::RelationController;
+use crate::services::row_history::{RowHistoryPersistence, RowHistoryRecorder};
+use crate::services::share::csv::{
+  CSVColumn, CSVExport, CSVExportOptions, CSVFormat, CSVImportOptions, CSVImportReport,
+  CSVImporter, ImportResult,
+};
+use crate::services::share::json::JSONImporter;
+use crate::services::share::markdown::MarkdownExport;
+use crate::services::share::xlsx::{XLSXExport, XLSXImporter};
 
 pub trait DatabaseUser: Send + Sync {
   fn user_id(&self) -> Result<i64, FlowyError>;
   fn token(&self) -> Result<Option<String>, FlowyError>;
   fn collab_db(&self, uid: i64) -> Result<Weak<RocksCollabDB>, FlowyError>;
+  /// Returns the members of the current workspace, which can be assigned to the person cells.
+  fn get_workspace_members(&self) -> FutureResult<Vec<PersonMember>, FlowyError>;
+  /// Returns the id of the current workspace. The uploaded files are stored under it.
+  fn workspace_id(&self) -> Result<String, FlowyError>;
+  /// Adds the reminder of a date cell to the reminder store of the user.
+  fn add_reminder(&self, reminder: DateReminder) -> FutureResult<(), FlowyError>;
+  fn remove_reminder(&self, reminder_id: &str) -> FutureResult<(), FlowyError>;
+  /// Returns the timezone and the first day of the week that the user picked in the settings.
+  fn get_date_time_settings(&self) -> UserDateTimeSettings;
+}
+
+/// Reads the documents of the rows. The documents are managed by the document manager, which is
+/// created after the database manager, so the reader is set with
+/// [DatabaseManager::set_row_document_reader].
+pub trait RowDocumentReader: Send + Sync {
+  /// Returns the plain text of the document, or None if the document doesn't exist.
+  fn get_document_text(&self, document_id: &str) -> FutureResult<Option<String>, FlowyError>;
 }
 
 pub struct DatabaseManager {
   user: Arc<dyn DatabaseUser>,
   workspace_database: Arc<RwLock<Option<Arc<WorkspaceDatabase>>>>,
   task_scheduler: Arc<RwLock<TaskDispatcher>>,
-  editors: RwLock<HashMap<String, Arc<DatabaseEditor>>>,
+  editors: Arc<RwLock<HashMap<String, Arc<DatabaseEditor>>>>,
+  /// The databases that no view opened, but that the open databases need, e.g. the source
+  /// databases of their lookup fields. Each one is mapped to the ids of the databases that need
+  /// it, and it's closed when none of them is open.
+  implicit_editors: parking_lot::Mutex<HashMap<String, HashSet<String>>>,
+  lookup_controller: Arc<LookupController>,
+  relation_controller: Arc<RelationController>,
+  file_controller: Arc<FileController>,
   collab_builder: Arc<AppFlowyCollabBuilder>,
   cloud_service: Arc<dyn DatabaseCloudService>,
+  storage_service: Weak<dyn FileStorageService>,
+  row_history: Arc<dyn RowHistoryPersistence>,
+  /// Created for the user when the workspace is opened
+  row_history_recorder: parking_lot::RwLock<Option<Arc<RowHistoryRecorder>>>,
+  row_document_reader: parking_lot::RwLock<Option<Arc<dyn RowDocumentReader>>>,
+  /// The members of the current workspace, cached by [DatabaseManager::get_workspace_members]
+  workspace_members: parking_lot::RwLock<Option<Vec<PersonMember>>>,
 }
 
 impl DatabaseManager {
@@ -52,17 +104,39 @@ impl DatabaseManager {
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
     collab_builder: Arc<AppFlowyCollabBuilder>,
     cloud_service: Arc<dyn DatabaseCloudService>,
+    storage_service: Weak<dyn FileStorageService>,
+    row_history: Arc<dyn RowHistoryPersistence>,
   ) -> Self {
+    let editors = Arc::new(RwLock::new(HashMap::new()));
+    let lookup_controller = Arc::new(LookupController::new(editors.clone()));
+    let relation_controller = Arc::new(RelationController::new(editors.clone()));
+    let file_controller = Arc::new(FileController::new(
+      editors.clone(),
+      storage_service.clone(),
+    ));
     Self {
       user: database_user,
       workspace_database: Default::default(),
       task_scheduler,
-      editors: Default::default(),
+      editors,
+      implicit_editors: Default::default(),
+      lookup_controller,
+      relation_controller,
+      file_controller,
       collab_builder,
       cloud_service,
+      storage_service,
+      row_history,
+      row_history_recorder: Default::default(),
+      row_document_reader: Default::default(),
+      workspace_members: Default::default(),
     }
   }
 
+  pub fn set_row_document_reader(&self, reader: Arc<dyn RowDocumentReader>) {
+    *self.row_document_reader.write() = Some(reader);
+  }
+
   fn is_collab_exist(&self, uid: i64, collab_db: &Weak<RocksCollabDB>, object_id: &str) -> bool {
     match collab_db.upgrade() {
       None => false,
@@ -80,9 +154,16 @@ impl DatabaseManager {
     database_views_aggregate_id: String,
   ) -> FlowyResult<()> {
     let collab_db = self.user.collab_db(uid)?;
+    let row_history_recorder = Arc::new(RowHistoryRecorder::new(
+      uid,
+      self.editors.clone(),
+      self.row_history.clone(),
+    ));
+    *self.row_history_recorder.write() = Some(row_history_recorder.clone());
     let collab_builder = UserDatabaseCollabServiceImpl {
       collab_builder: self.collab_builder.clone(),
       cloud_service: self.cloud_service.clone(),
+      row_history_recorder,
     };
     let config = CollabPersistenceConfig::new().snapshot_per_update(10);
     let mut collab_raw_data = CollabRawData::default();
@@ -117,13 +198,24 @@ impl DatabaseManager {
       collab_raw_data,
       &config,
     );
+    // The files removed from the cells of the open databases are deleted before the databases
+    // of the previous workspace are closed
+    if let Ok(wdb) = self.get_workspace_database().await {
+      for editor in self.editors.read().await.values() {
+        self.delete_removed_files(wdb.clone(), editor);
+      }
+    }
+
     let workspace_database =
       WorkspaceDatabase::open(uid, collab, collab_db, config, collab_builder);
-    subscribe_block_event(&workspace_database);
+    subscribe_block_event(&workspace_database, self.editors.clone());
     *self.workspace_database.write().await = Some(Arc::new(workspace_database));
 
     // Remove all existing editors
     self.editors.write().await.clear();
+    self.implicit_editors.lock().clear();
+    self.lookup_controller.clear_key_indexes();
+    *self.workspace_members.write() = None;
     Ok(())
   }
 
@@ -173,6 +265,128 @@ impl DatabaseManager {
   }
 
   pub async fn open_database(&self, database_id: &str) -> FlowyResult<Arc<DatabaseEditor>> {
+    let editor = self.open_database_editor(database_id).await?;
+    // The database is opened by a view, so it's closed with its views
+    self.implicit_editors.lock().remove(database_id);
+
+    // Open the source databases of the lookup fields and pull the latest values, because the
+    // source rows might have changed while this database was closed.
+    for (field, type_option) in editor.get_lookup_fields() {
+      if let Err(err) = self
+        .open_needed_database_editor(database_id, &type_option.database_id)
+        .await
+      {
+        tracing::warn!(
+          "Failed to open the source database {} of the lookup field: {}",
+          type_option.database_id,
+          err
+        );
+        continue;
+      }
+      self
+        .lookup_controller
+        .refresh_lookup_field(&editor, &field.id)
+        .await;
+    }
+
+    // Unlink the rows that were deleted from the related databases while this database was
+    // closed.
+    let mut related_database_ids = editor
+      .get_relation_fields()
+      .into_iter()
+      .map(|(_, type_option)| type_option.database_id)
+      .collect::<Vec<String>>();
+    related_database_ids.sort();
+    related_database_ids.dedup();
+    for related_database_id in related_database_ids {
+      let related_editor = match self
+        .open_needed_database_editor(database_id, &related_database_id)
+        .await
+      {
+        Ok(related_editor) => related_editor,
+        Err(err) => {
+          tracing::warn!(
+            "Failed to open the related database {}: {}",
+            related_database_id,
+            err
+          );
+          continue;
+        },
+      };
+      self
+        .relation_controller
+        .unlink_deleted_rows(&editor, &related_editor)
+        .await;
+    }
+    Ok(editor)
+  }
+
+  /// Returns the editor of the database of `needed_database_id` that the database of
+  /// `database_id` needs. If it's not open, it's opened until the database of `database_id` and
+  /// the other databases that need it are closed, see [Self::close_unneeded_editors].
+  async fn open_needed_database_editor(
+    &self,
+    database_id: &str,
+    needed_database_id: &str,
+  ) -> FlowyResult<Arc<DatabaseEditor>> {
+    let editor = self.editors.read().await.get(needed_database_id).cloned();
+    let editor = match editor {
+      Some(editor) => {
+        if let Some(database_ids) = self.implicit_editors.lock().get_mut(needed_database_id) {
+          database_ids.insert(database_id.to_string());
+        }
+        editor
+      },
+      None => {
+        let editor = self.open_database_editor(needed_database_id).await?;
+        self
+          .implicit_editors
+          .lock()
+          .entry(needed_database_id.to_string())
+          .or_default()
+          .insert(database_id.to_string());
+        editor
+      },
+    };
+    Ok(editor)
+  }
+
+  /// Closes the editors that were opened for the closed database, if no other open database
+  /// needs them. The ones whose views were opened since are kept, they are closed with the views.
+  async fn close_unneeded_editors(
+    &self,
+    wdb: &Arc<WorkspaceDatabase>,
+    editors: &mut HashMap<String, Arc<DatabaseEditor>>,
+    closed_database_id: &str,
+  ) {
+    let unneeded_database_ids = {
+      let mut implicit_editors = self.implicit_editors.lock();
+      implicit_editors.remove(closed_database_id);
+      let mut unneeded_database_ids = vec![];
+      implicit_editors.retain(|database_id, database_ids| {
+        database_ids.remove(closed_database_id);
+        if database_ids.is_empty() {
+          unneeded_database_ids.push(database_id.clone());
+        }
+        !database_ids.is_empty()
+      });
+      unneeded_database_ids
+    };
+
+    for database_id in unneeded_database_ids {
+      if let Some(editor) = editors.get(&database_id).cloned() {
+        if editor.has_open_views().await {
+          continue;
+        }
+        wdb.close_database(&database_id);
+        editor.close().await;
+        self.delete_removed_files(wdb.clone(), &editor);
+        editors.remove(&database_id);
+      }
+    }
+  }
+
+  async fn open_database_editor(&self, database_id: &str) -> FlowyResult<Arc<DatabaseEditor>> {
     trace!("create new editor for database {}", database_id);
     let mut editors = self.editors.write().await;
 
@@ -182,7 +396,27 @@ impl DatabaseManager {
       .await
       .ok_or_else(FlowyError::collab_not_sync)?;
 
-    let editor = Arc::new(DatabaseEditor::new(database, self.task_scheduler.clone()).await?);
+    let uid = self.user.user_id()?;
+    let row_history_recorder = self
+      .row_history_recorder
+      .read()
+      .clone()
+      .ok_or_else(|| FlowyError::internal().with_context("The row history is not initialized"))?;
+    let editor = Arc::new(
+      DatabaseEditor::new(
+        database,
+        self.task_scheduler.clone(),
+        uid,
+        self.user.clone(),
+        self.storage_service.clone(),
+        self.row_history.clone(),
+        row_history_recorder,
+      )
+      .await?,
+    );
+    self.lookup_controller.listen_row_events(&editor);
+    self.relation_controller.index_database(&editor);
+    self.lookup_controller.remove_key_indexes(database_id);
     editors.insert(database_id.to_string(), editor.clone());
     Ok(editor)
   }
@@ -202,7 +436,11 @@ impl DatabaseManager {
       if let Some(editor) = editors.get(&database_id) {
         if editor.close_view_editor(view_id).await {
           editor.close().await;
+          self.delete_removed_files(wdb.clone(), editor);
           editors.remove(&database_id);
+          self
+            .close_unneeded_editors(&wdb, &mut editors, &database_id)
+            .await;
         }
       }
     }
@@ -210,6 +448,26 @@ impl DatabaseManager {
     Ok(())
   }
 
+  /// Deletes the files that were removed from the cells of the closed database in the
+  /// background. The files that its cells or the other databases still reference are kept.
+  fn delete_removed_files(&self, wdb: Arc<WorkspaceDatabase>, editor: &DatabaseEditor) {
+    let mut file_urls = editor.take_removed_file_urls();
+    if file_urls.is_empty() {
+      return;
+    }
+    let used_file_urls = editor.get_used_file_urls();
+    file_urls.retain(|url| !used_file_urls.contains(url));
+
+    self
+      .file_controller
+      .spawn_delete_unused_files(wdb, editor.get_database_id(), file_urls);
+  }
+
+  /// Waits until the files removed from the databases closed before the call are deleted.
+  pub async fn flush_file_deletions(&self) {
+    self.file_controller.flush().await;
+  }
+
   pub async fn delete_database_view(&self, view_id: &str) -> FlowyResult<()> {
     let database = self.get_database_with_view_id(view_id).await?;
     let _ = database.delete_database_view(view_id).await?;
@@ -232,13 +490,26 @@ impl DatabaseManager {
   ) -> FlowyResult<()> {
     let mut database_data = DatabaseData::from_json_bytes(data)?;
     database_data.view.id = view_id.to_string();
+    evaluate_formula_cells(
+      &database_data.fields,
+      &mut database_data.rows,
+      &self.timezone_id(),
+    );
 
     let wdb = self.get_workspace_database().await?;
     let _ = wdb.create_database_with_data(database_data)?;
     Ok(())
   }
 
-  pub async fn create_database_with_params(&self, params: CreateDatabaseParams) -> FlowyResult<()> {
+  pub async fn create_database_with_params(
+    &self,
+    mut params: CreateDatabaseParams,
+  ) -> FlowyResult<()> {
+    evaluate_formula_cells(
+      &params.fields,
+      &mut params.created_rows,
+      &self.timezone_id(),
+    );
     let wdb = self.get_workspace_database().await?;
     let _ = wdb.create_database(params)?;
     Ok(())
@@ -274,27 +545,214 @@ impl DatabaseManager {
     view_id: String,
     content: String,
     format: CSVFormat,
+    options: CSVImportOptions,
+  ) -> FlowyResult<ImportResult> {
+    self
+      .import_csv_from_bytes(view_id, content.into_bytes(), format, options)
+      .await
+  }
+
+  /// Imports the CSV content, decoding it with the encoding in the options or the detected one.
+  pub async fn import_csv_from_bytes(
+    &self,
+    view_id: String,
+    bytes: Vec<u8>,
+    format: CSVFormat,
+    options: CSVImportOptions,
   ) -> FlowyResult<ImportResult> {
-    let params = tokio::task::spawn_blocking(move || {
-      CSVImporter.import_csv_from_string(view_id, content, format)
+    let (params, report) = tokio::task::spawn_blocking(move || {
+      CSVImporter.import_csv_from_bytes(view_id, &bytes, format, options)
     })
     .await
     .map_err(internal_error)??;
     let result = ImportResult {
       database_id: params.database_id.clone(),
       view_id: params.view_id.clone(),
+      report,
     };
     self.create_database_with_params(params).await?;
     Ok(result)
   }
 
-  // will implement soon
+  /// Imports the database of the JSON exported by [DatabaseManager::export_json].
+  pub async fn import_json(&self, view_id: String, content: String) -> FlowyResult<ImportResult> {
+    let params =
+      tokio::task::spawn_blocking(move || JSONImporter.import_json_from_string(view_id, &content))
+        .await
+        .map_err(internal_error)??;
+    let result = ImportResult {
+      database_id: params.database_id.clone(),
+      view_id: params.view_id.clone(),
+      report: CSVImportReport {
+        imported_rows: params.created_rows.len(),
+        skipped_lines: vec![],
+      },
+    };
+    self.create_database_with_params(params).await?;
+    Ok(result)
+  }
+
+  /// Imports each sheet of the workbook that has data as a grid. The first sheet is imported as
+  /// the view of the `view_id`. Returns the names of the sheets with their imported databases.
+  pub async fn import_xlsx(
+    &self,
+    view_id: String,
+    bytes: Vec<u8>,
+  ) -> FlowyResult<Vec<(String, ImportResult)>> {
+    let sheets =
+      tokio::task::spawn_blocking(move || XLSXImporter.import_xlsx_from_bytes(view_id, bytes))
+        .await
+        .map_err(internal_error)??;
+    let mut results = vec![];
+    for sheet in sheets {
+      let result = ImportResult {
+        database_id: sheet.params.database_id.clone(),
+        view_id: sheet.params.view_id.clone(),
+        report: CSVImportReport {
+          imported_rows: sheet.params.created_rows.len(),
+          skipped_lines: vec![],
+        },
+      };
+      if let Err(err) = self.create_database_with_params(sheet.params).await {
+        // The sheets are imported together, so the ones that were already imported are deleted
+        let wdb = self.get_workspace_database().await?;
+        for (_, result) in results {
+          wdb.delete_database(&result.database_id);
+        }
+        return Err(err);
+      }
+      results.push((sheet.name, result));
+    }
+    Ok(results)
+  }
+
+  /// Returns the columns of the CSV with the field types that they would be imported as.
+  pub async fn infer_csv_field_types(&self, content: String) -> FlowyResult<Vec<CSVColumn>> {
+    tokio::task::spawn_blocking(move || CSVImporter.infer_field_types(content))
+      .await
+      .map_err(internal_error)?
+  }
+
+  /// Imports the CSV file in batches. The file is read in a blocking task while the rows that are
+  /// read are inserted into the database, so a large file is never loaded in memory at once.
+  /// The progress of the import and its report are notified with the path of the file as the id.
+  #[tracing::instrument(level = "debug", skip(self, options), err)]
   pub async fn import_csv_from_file(
     &self,
-    _file_path: String,
-    _format: CSVFormat,
-  ) -> FlowyResult<()> {
-    Ok(())
+    view_id: String,
+    file_path: String,
+    format: CSVFormat,
+    options: CSVImportOptions,
+  ) -> FlowyResult<ImportResult> {
+    let wdb = self.get_workspace_database().await?;
+    let total_bytes = std::fs::metadata(&file_path)?.len() as i64;
+    let (tx, mut rx) = tokio::sync::mpsc::channel(2);
+    let cloned_file_path = file_path.clone();
+    let timezone_id = self.timezone_id();
+    let read_task = tokio::task::spawn_blocking(move || {
+      let send = |batch: CSVImportBatch| {
+        tx.blocking_send(batch)
+          .map_err(|_| FlowyError::internal().with_context("The CSV import was cancelled"))
+      };
+      let mut import = CSVImporter.import_csv_from_file(&cloned_file_path, format, &options)?;
+      let mut params = import.database_params(&view_id);
+      evaluate_formula_cells(&params.fields, &mut params.created_rows, &timezone_id);
+      send(CSVImportBatch::Database(params))?;
+      while let Some(mut rows) = import.next_rows()? {
+        evaluate_formula_cells(&import.fields(), &mut rows, &timezone_id);
+        send(CSVImportBatch::Rows {
+          rows,
+          imported_rows: import.imported_rows(),
+          read_bytes: import.read_bytes(),
+        })?;
+      }
+      send(CSVImportBatch::UpdatedFields(import.take_updated_fields()))?;
+      Ok::<_, FlowyError>(import.finish())
+    });
+
+    let notify_progress = |imported_rows: usize, read_bytes: u64| {
+      send_notification(&file_path, DatabaseNotification::DidUpdateImportProgress)
+        .payload(CSVImportProgressPB {
+          file_path: file_path.clone(),
+          imported_rows: imported_rows as i64,
+          read_bytes: read_bytes as i64,
+          total_bytes,
+        })
+        .send();
+    };
+    let mut imported_database = None;
+    let mut created_database_id = None;
+    let result = async {
+      while let Some(batch) = rx.recv().await {
+        match batch {
+          CSVImportBatch::Database(params) => {
+            let database_id = params.database_id.clone();
+            let view_id = params.view_id.clone();
+            let imported_rows = params.created_rows.len();
+            wdb.create_database(params)?;
+            created_database_id = Some(database_id.clone());
+            let database = wdb.get_database(&database_id).await.ok_or_else(|| {
+              FlowyError::record_not_found()
+                .with_context(format!("The database: {} not found", database_id))
+            })?;
+            notify_progress(imported_rows, 0);
+            imported_database = Some((database_id, view_id, database));
+          },
+          CSVImportBatch::Rows {
+            rows,
+            imported_rows,
+            read_bytes,
+          } => {
+            if let Some((_, view_id, database)) = &imported_database {
+              let database = database.lock();
+              for row in rows {
+                database.create_row_in_view(view_id, row);
+              }
+            }
+            notify_progress(imported_rows, read_bytes);
+          },
+          CSVImportBatch::UpdatedFields(fields) => {
+            if let Some((_, _, database)) = &imported_database {
+              let database = database.lock();
+              // The options of the select fields are extended, and the columns whose values don't
+              // fit their field type fall back to text.
+              for field in fields {
+                if let Some(type_option_data) = field.get_any_type_option(field.field_type) {
+                  database.fields.update_field(&field.id, |update| {
+                    update
+                      .set_field_type(field.field_type)
+                      .set_type_option(field.field_type, Some(type_option_data));
+                  });
+                }
+              }
+            }
+          },
+        }
+      }
+      read_task.await.map_err(internal_error)?
+    }
+    .await;
+
+    let report = match result {
+      Ok(report) => report,
+      Err(err) => {
+        // The rows imported before the failure are not kept, the import can be retried instead
+        if let Some(database_id) = created_database_id {
+          wdb.delete_database(&database_id);
+        }
+        return Err(err);
+      },
+    };
+    send_notification(&file_path, DatabaseNotification::DidFinishImport)
+      .payload(CSVImportReportPB::new(file_path.clone(), report.clone()))
+      .send();
+    let (database_id, view_id, _) = imported_database
+      .ok_or_else(|| FlowyError::internal().with_context("The CSV import was interrupted"))?;
+    Ok(ImportResult {
+      database_id,
+      view_id,
+      report,
+    })
   }
 
   pub async fn export_csv(&self, view_id: &str, style: CSVFormat) -> FlowyResult<String> {
@@ -302,6 +760,61 @@ impl DatabaseManager {
     database.export_csv(style).await
   }
 
+  /// Exports the view with its fields, rows and settings as JSON. See
+  /// [DatabaseJSON](crate::services::share::json::DatabaseJSON) for the schema.
+  pub async fn export_json(&self, view_id: &str) -> FlowyResult<String> {
+    let database = self.get_database_with_view_id(view_id).await?;
+    database.export_json(view_id).await
+  }
+
+  /// Exports the rows and the fields shown in the view as a Markdown table.
+  pub async fn export_markdown(&self, view_id: &str) -> FlowyResult<String> {
+    let database = self.get_database_with_view_id(view_id).await?;
+    let (fields, rows) = database.get_visible_fields_and_rows(view_id).await?;
+    Ok(MarkdownExport.export_rows(&fields, &rows))
+  }
+
+  /// Exports the rows and the fields shown in the view as a workbook, with the cells written with
+  /// the types of the spreadsheet.
+  pub async fn export_xlsx(&self, view_id: &str) -> FlowyResult<Vec<u8>> {
+    let database = self.get_database_with_view_id(view_id).await?;
+    let (fields, rows) = database.get_visible_fields_and_rows(view_id).await?;
+    let sheet_name = database.get_view_name(view_id).unwrap_or_default();
+    tokio::task::spawn_blocking(move || XLSXExport.export_rows(&sheet_name, &fields, &rows))
+      .await
+      .map_err(internal_error)?
+  }
+
+  /// Exports the view as it's shown: the hidden fields are skipped, and the rows are filtered
+  /// and sorted by the settings of the view.
+  pub async fn export_view_csv(
+    &self,
+    view_id: &str,
+    options: CSVExportOptions,
+  ) -> FlowyResult<String> {
+    let database = self.get_database_with_view_id(view_id).await?;
+    let (fields, rows) = database.get_visible_fields_and_rows(view_id).await?;
+    let mut row_documents = HashMap::new();
+    let reader = self.row_document_reader.read().clone();
+    if let (true, Some(reader)) = (options.include_row_meta, reader) {
+      for row_detail in rows.iter() {
+        match reader.get_document_text(&row_detail.document_id).await {
+          Ok(Some(text)) => {
+            row_documents.insert(row_detail.row.id.to_string(), text);
+          },
+          Ok(None) => {},
+          Err(err) => tracing::warn!("Failed to read the document of the row: {}", err),
+        }
+      }
+    }
+
+    tokio::task::spawn_blocking(move || {
+      CSVExport.export_rows(&fields, &rows, &row_documents, &options)
+    })
+    .await
+    .map_err(internal_error)?
+  }
+
   pub async fn update_database_layout(
     &self,
     view_id: &str,
@@ -333,6 +846,468 @@ impl DatabaseManager {
     Ok(snapshots)
   }
 
+  /// Returns the content of the primary field of the rows in the database with `database_id`.
+  pub async fn get_related_rows(
+    &self,
+    database_id: &str,
+    row_ids: &[RowId],
+  ) -> FlowyResult<Vec<RelatedRowDataPB>> {
+    let database = self.get_database(database_id).await?;
+    Ok(database.get_related_rows(row_ids))
+  }
+
+  /// Returns the members of the current workspace. The members are kept in memory, so the person
+  /// cells can be assigned to them without fetching them again.
+  pub async fn get_workspace_members(&self) -> FlowyResult<Vec<PersonMember>> {
+    let members = self.user.get_workspace_members().await?;
+    *self.workspace_members.write() = Some(members.clone());
+    Ok(members)
+  }
+
+  /// Assigns the members of the workspace to the person cell. The assigned members are saved in
+  /// the type option of the field, so their names can be resolved without being online.
+  pub async fn update_person_cell(
+    &self,
+    cell_id: CellIdParams,
+    changeset: PersonCellChangeset,
+  ) -> FlowyResult<()> {
+    let database = self.get_database_with_view_id(&cell_id.view_id).await?;
+    if !changeset.inserted_user_ids.is_empty() {
+      let cached_members = self.workspace_members.read().clone();
+      let members = match cached_members {
+        Some(members) => members,
+        None => self.get_workspace_members().await?,
+      };
+      let inserted_members = members
+        .into_iter()
+        .filter(|member| changeset.inserted_user_ids.contains(&member.uid))
+        .collect::<Vec<_>>();
+      database
+        .add_person_members(&cell_id.view_id, &cell_id.field_id, inserted_members)
+        .await?;
+    }
+    database
+      .update_cell_with_changeset(
+        &cell_id.view_id,
+        cell_id.row_id,
+        &cell_id.field_id,
+        changeset,
+      )
+      .await
+  }
+
+  /// Updates the date cell and registers its reminder in the reminder store of the user. The
+  /// reminder is scheduled before the next occurrence of the date, and it replaces the previous
+  /// reminder of the cell.
+  pub async fn update_date_cell(
+    &self,
+    cell_id: CellIdParams,
+    changeset: DateCellChangeset,
+  ) -> FlowyResult<()> {
+    let database = self.get_database_with_view_id(&cell_id.view_id).await?;
+    let had_reminder = database
+      .get_cell(&cell_id.field_id, &cell_id.row_id)
+      .await
+      .and_then(|cell| DateCellData::from(&cell).reminder_offset)
+      .is_some();
+    database
+      .update_cell_with_changeset(
+        &cell_id.view_id,
+        cell_id.row_id.clone(),
+        &cell_id.field_id,
+        changeset,
+      )
+      .await?;
+
+    if had_reminder {
+      let reminder_id = DateReminder::reminder_id(cell_id.row_id.as_str(), &cell_id.field_id);
+      self.user.remove_reminder(&reminder_id).await?;
+    }
+    let reminder = database
+      .get_date_reminder(
+        &cell_id.view_id,
+        &cell_id.row_id,
+        &cell_id.field_id,
+        timestamp(),
+      )
+      .await;
+    if let Some(reminder) = reminder {
+      self.user.add_reminder(reminder).await?;
+    }
+    Ok(())
+  }
+
+  /// Schedules the reminder of the next occurrence of a recurring date after the reminder of the
+  /// previous occurrence with `scheduled_at` fired. Nothing is scheduled if the date doesn't
+  /// repeat anymore, so the fired reminder is kept.
+  pub async fn did_fire_date_reminder(
+    &self,
+    view_id: &str,
+    row_id: &str,
+    field_id: &str,
+    scheduled_at: i64,
+  ) -> FlowyResult<()> {
+    let database = self.get_database_with_view_id(view_id).await?;
+    let row_id = RowId::from(row_id.to_string());
+    // The occurrences that were missed while the app was closed are skipped
+    let now = timestamp().max(scheduled_at.saturating_add(1));
+    let reminder = database
+      .get_date_reminder(view_id, &row_id, field_id, now)
+      .await;
+    if let Some(reminder) = reminder {
+      self.user.remove_reminder(&reminder.id).await?;
+      self.user.add_reminder(reminder).await?;
+    }
+    Ok(())
+  }
+
+  /// Moves the event of the calendar to the `timestamp`. The end of a date range is moved by the
+  /// same amount, so the duration of the event is kept. `occurrence` is the start of the dragged
+  /// occurrence of a recurring date: the whole series is shifted by the difference between the
+  /// occurrence and the `timestamp`, so the other occurrences move with it.
+  pub async fn move_calendar_event(
+    &self,
+    cell_id: CellIdParams,
+    timestamp: i64,
+    occurrence: Option<i64>,
+  ) -> FlowyResult<()> {
+    let database = self.get_database_with_view_id(&cell_id.view_id).await?;
+    let type_option = database
+      .get_field(&cell_id.field_id)
+      .and_then(|field| field.get_type_option::<DateTypeOption>(FieldType::DateTime))
+      .unwrap_or_default();
+    let cell_data = database
+      .get_cell(&cell_id.field_id, &cell_id.row_id)
+      .await
+      .map(|cell| DateCellData::from(&cell))
+      .unwrap_or_default();
+    let delta = timestamp - occurrence.or(cell_data.timestamp).unwrap_or_default();
+    let changeset = type_option.moved_date_changeset(&cell_data, delta);
+    self.update_date_cell(cell_id, changeset).await
+  }
+
+  /// Uploads the file and attaches it to the files cell.
+  pub async fn upload_file(&self, params: UploadFileParams) -> FlowyResult<FileAttachment> {
+    let workspace_id = self.user.workspace_id()?;
+    let database = self
+      .get_database_with_view_id(&params.cell_id.view_id)
+      .await?;
+    database.upload_file(params, &workspace_id).await
+  }
+
+  pub async fn delete_file(&self, cell_id: CellIdParams, file_id: String) -> FlowyResult<()> {
+    let database = self.get_database_with_view_id(&cell_id.view_id).await?;
+    database.delete_file(cell_id, file_id).await
+  }
+
+  /// Links or unlinks the rows of the related database. If the relation field has a reciprocal
+  /// field, the cells of the related rows are updated too.
+  pub async fn update_relation_cell(
+    &self,
+    view_id: &str,
+    row_id: RowId,
+    field_id: &str,
+    changeset: RelationCellChangeset,
+  ) -> FlowyResult<()> {
+    let database = self.get_database_with_view_id(view_id).await?;
+    let type_option = database.get_relation_type_option(field_id)?;
+    database
+      .update_cell_with_changeset(view_id, row_id.clone(), field_id, changeset.clone())
+      .await?;
+    self.relation_controller.index_database(&database);
+
+    if let Some(reciprocal_field_id) = type_option.reciprocal_field_id {
+      let related_database = self.get_database(&type_option.database_id).await?;
+      let related_view_id = related_database.get_inline_view_id();
+      let row_id = row_id.into_inner();
+      let reciprocal_changesets = changeset
+        .inserted_row_ids
+        .into_iter()
+        .map(|related_row_id| {
+          let changeset = RelationCellChangeset {
+            inserted_row_ids: vec![row_id.clone()],
+            ..Default::default()
+          };
+          (related_row_id, changeset)
+        })
+        .chain(changeset.removed_row_ids.into_iter().map(|related_row_id| {
+          let changeset = RelationCellChangeset {
+            removed_row_ids: vec![row_id.clone()],
+            ..Default::default()
+          };
+          (related_row_id, changeset)
+        }));
+      for (related_row_id, changeset) in reciprocal_changesets {
+        if let Err(err) = related_database
+          .update_cell_with_changeset(
+            &related_view_id,
+            RowId::from(related_row_id),
+            &reciprocal_field_id,
+            changeset,
+          )
+          .await
+        {
+          tracing::error!("Failed to update the reciprocal relation cell: {}", err);
+        }
+      }
+    }
+    Ok(())
+  }
+
+  /// Creates a relation field in the related database that links back to the relation field
+  /// with `field_id`. The existing links are copied to the new field, and the cells of both
+  /// fields are kept in sync afterwards.
+  pub async fn create_reciprocal_relation_field(
+    &self,
+    params: CreateReciprocalRelationParams,
+  ) -> FlowyResult<()> {
+    let database = self.get_database_with_view_id(&params.view_id).await?;
+    let type_option = database.get_relation_type_option(&params.field_id)?;
+    if type_option.database_id.is_empty() {
+      return Err(FlowyError::new(
+        ErrorCode::FieldInvalidOperation,
+        "The relation field isn't linked to any database",
+      ));
+    }
+    if type_option.reciprocal_field_id.is_some() {
+      return Err(FlowyError::new(
+        ErrorCode::FieldInvalidOperation,
+        "The relation field already has a reciprocal field",
+      ));
+    }
+
+    // Create the reciprocal field in the related database
+    let related_database = self.get_database(&type_option.database_id).await?;
+    let related_view_id = related_database.get_inline_view_id();
+    let reciprocal_type_option = RelationTypeOption {
+      database_id: database.get_database_id(),
+      reciprocal_field_id: Some(params.field_id.clone()),
+    };
+    let type_option_data = type_option_to_pb(reciprocal_type_option.into(), &FieldType::Relation);
+    let (reciprocal_field, _) = related_database
+      .create_field_with_type_option(
+        &related_view_id,
+        &FieldType::Relation,
+        Some(type_option_data.to_vec()),
+      )
+      .await;
+    if let Some(name) = params.name {
+      related_database
+        .update_field(FieldChangesetParams {
+          field_id: reciprocal_field.id.clone(),
+          view_id: related_view_id.clone(),
+          name: Some(name),
+          ..Default::default()
+        })
+        .await?;
+    }
+
+    // Link the relation field to the reciprocal field
+    let old_field = database.get_field(&params.field_id).ok_or_else(|| {
+      FlowyError::record_not_found()
+        .with_context(format!("Field with id:{} not found", params.field_id))
+    })?;
+    let type_option = RelationTypeOption {
+      reciprocal_field_id: Some(reciprocal_field.id.clone()),
+      ..type_option
+    };
+    database
+      .update_field_type_option(
+        &params.view_id,
+        &params.field_id,
+        type_option.into(),
+        old_field,
+      )
+      .await?;
+    self.relation_controller.index_database(&related_database);
+
+    // Copy the existing links to the reciprocal field
+    let mut linked_row_ids: HashMap<String, Vec<String>> = HashMap::new();
+    let inline_view_id = database.get_inline_view_id();
+    for row_cell in database
+      .get_cells_for_field(&inline_view_id, &params.field_id)
+      .await
+    {
+      if let Some(cell) = row_cell.cell.as_ref() {
+        for related_row_id in RelationCellData::from(cell).row_ids {
+          linked_row_ids
+            .entry(related_row_id.into_inner())
+            .or_default()
+            .push(row_cell.row_id.clone().into_inner());
+        }
+      }
+    }
+    for (related_row_id, row_ids) in linked_row_ids {
+      let changeset = RelationCellChangeset {
+        inserted_row_ids: row_ids,
+        ..Default::default()
+      };
+      if let Err(err) = related_database
+        .update_cell_with_changeset(
+          &related_view_id,
+          RowId::from(related_row_id),
+          &reciprocal_field.id,
+          changeset,
+        )
+        .await
+      {
+        tracing::error!(
+          "Failed to copy the relation to the reciprocal field: {}",
+          err
+        );
+      }
+    }
+    Ok(())
+  }
+
+  /// Pulls the values of the lookup field from its source database. It's called after the lookup
+  /// field was created or its type option changed.
+  pub async fn refresh_lookup_field(&self, view_id: &str, field_id: &str) -> FlowyResult<()> {
+    let database = self.get_database_with_view_id(view_id).await?;
+    let type_option = database.get_lookup_type_option(field_id)?;
+    if !type_option.is_configured() {
+      return Ok(());
+    }
+
+    let _ = self.get_database(&type_option.database_id).await?;
+    self
+      .lookup_controller
+      .refresh_lookup_field(&database, field_id)
+      .await;
+    Ok(())
+  }
+
+  /// Deletes the row and unlinks it from the relation fields of the databases that link to its
+  /// database. All the deletions of the rows go through this method.
+  pub async fn delete_row(&self, view_id: &str, row_id: RowId) -> FlowyResult<()> {
+    let database = self.get_database_with_view_id(view_id).await?;
+    self
+      .delete_row_with_origin(&database, view_id, row_id, ChangeOrigin::User)
+      .await;
+    Ok(())
+  }
+
+  /// Deletes the row and returns the action that creates it again, along with the links that the
+  /// other databases had to it. Returns None if the row is not in the view.
+  async fn delete_row_with_origin(
+    &self,
+    database: &DatabaseEditor,
+    view_id: &str,
+    row_id: RowId,
+    origin: ChangeOrigin,
+  ) -> Option<DatabaseAction> {
+    let reminder_ids = database.get_row_reminder_ids(&row_id);
+    let mut action = database.remove_row(view_id, &row_id).await;
+    let unlinked_cells = self
+      .relation_controller
+      .did_delete_rows(&database.get_database_id(), &[row_id])
+      .await;
+    if let Some(DatabaseAction::CreateRow { related_cells, .. }) = action.as_mut() {
+      *related_cells = unlinked_cells;
+    }
+    if let Some(action) = action.clone() {
+      database.record_action(view_id, origin, action);
+    }
+    for reminder_id in reminder_ids {
+      if let Err(err) = self.user.remove_reminder(&reminder_id).await {
+        tracing::warn!("Failed to remove the reminder {}: {}", reminder_id, err);
+      }
+    }
+    action
+  }
+
+  /// Reverts the last change made in the view. Returns false if there is nothing to undo or the
+  /// change can't be reverted anymore, e.g. the row was deleted by another user. The change is
+  /// kept in the undo stack if reverting it fails.
+  pub async fn undo(&self, view_id: &str) -> FlowyResult<bool> {
+    let database = self.get_database_with_view_id(view_id).await?;
+    let action = match database.pop_undo_action(view_id) {
+      None => return Ok(false),
+      Some(action) => action,
+    };
+    let reverse_action = self
+      .apply_database_action(&database, view_id, action.clone())
+      .await
+      .map_err(|err| {
+        database.push_undo_action(view_id, action);
+        err
+      })?;
+    match reverse_action {
+      None => Ok(false),
+      Some(reverse_action) => {
+        database.push_redo_action(view_id, reverse_action);
+        Ok(true)
+      },
+    }
+  }
+
+  /// Reapplies the last change that was undone in the view. The change is kept in the redo stack
+  /// if reapplying it fails.
+  pub async fn redo(&self, view_id: &str) -> FlowyResult<bool> {
+    let database = self.get_database_with_view_id(view_id).await?;
+    let action = match database.pop_redo_action(view_id) {
+      None => return Ok(false),
+      Some(action) => action,
+    };
+    let reverse_action = self
+      .apply_database_action(&database, view_id, action.clone())
+      .await
+      .map_err(|err| {
+        database.push_redo_action(view_id, action);
+        err
+      })?;
+    match reverse_action {
+      None => Ok(false),
+      Some(reverse_action) => {
+        database.push_undo_action(view_id, reverse_action);
+        Ok(true)
+      },
+    }
+  }
+
+  /// Applies the action and returns the action that reverts it. The rows are deleted through
+  /// [DatabaseManager::delete_row], so their relation links and reminders are removed too, and
+  /// the links are restored when the rows are created again.
+  async fn apply_database_action(
+    &self,
+    database: &DatabaseEditor,
+    view_id: &str,
+    action: DatabaseAction,
+  ) -> FlowyResult<Option<DatabaseAction>> {
+    match action {
+      DatabaseAction::DeleteRow { row_id } => {
+        if database.get_row(view_id, &row_id).is_none() {
+          return Ok(None);
+        }
+        Ok(
+          self
+            .delete_row_with_origin(database, view_id, row_id, ChangeOrigin::Undo)
+            .await,
+        )
+      },
+      DatabaseAction::CreateRow {
+        row,
+        prev_row_id,
+        related_cells,
+      } => {
+        let action = DatabaseAction::CreateRow {
+          row,
+          prev_row_id,
+          related_cells: vec![],
+        };
+        let reverse_action = database.apply_action(view_id, action).await?;
+        if reverse_action.is_some() {
+          self
+            .relation_controller
+            .restore_related_cells(related_cells)
+            .await;
+        }
+        Ok(reverse_action)
+      },
+      action => database.apply_action(view_id, action).await,
+    }
+  }
+
   async fn get_workspace_database(&self) -> FlowyResult<Arc<WorkspaceDatabase>> {
     let database = self.workspace_database.read().await;
     match &*database {
@@ -341,6 +1316,11 @@ impl DatabaseManager {
     }
   }
 
+  /// The timezone of the user that the formulas are evaluated in
+  fn timezone_id(&self) -> String {
+    self.user.get_date_time_settings().timezone_id
+  }
+
   /// Only expose this method for testing
   #[cfg(debug_assertions)]
   pub fn get_cloud_service(&self) -> &Arc<dyn DatabaseCloudService> {
@@ -348,21 +1328,43 @@ impl DatabaseManager {
   }
 }
 
+/// Evaluates the formula cells of the rows that are created with the database, because they are
+/// not created through the [DatabaseEditor].
+fn evaluate_formula_cells(fields: &[Field], rows: &mut [CreateRowParams], timezone_id: &str) {
+  let evaluator = FormulaEvaluator::new(fields, timezone_id);
+  if evaluator.is_empty() {
+    return;
+  }
+  for row in rows.iter_mut() {
+    evaluator.evaluate(&mut row.cells, row.timestamp, row.timestamp, None);
+  }
+}
+
 /// Send notification to all clients that are listening to the given object.
-fn subscribe_block_event(workspace_database: &WorkspaceDatabase) {
+fn subscribe_block_event(
+  workspace_database: &WorkspaceDatabase,
+  editors: Arc<RwLock<HashMap<String, Arc<DatabaseEditor>>>>,
+) {
   let mut block_event_rx = workspace_database.subscribe_block_event();
   tokio::spawn(async move {
     while let Ok(event) = block_event_rx.recv().await {
       match event {
         BlockEvent::DidFetchRow(row_details) => {
+          let mut row_ids = vec![];
           for row_detail in row_details {
             trace!("Did fetch row: {:?}", row_detail.row.id);
             let row_id = row_detail.row.id.clone();
+            row_ids.push(row_id.clone());
             let pb = DidFetchRowPB::from(row_detail);
             send_notification(&row_id, DatabaseNotification::DidFetchRow)
               .payload(pb)
               .send();
           }
+
+          let editors = editors.read().await.values().cloned().collect::<Vec<_>>();
+          for editor in editors {
+            editor.did_fetch_rows(row_ids.clone()).await;
+          }
         },
       }
     }
@@ -372,6 +1374,7 @@ fn subscribe_block_event(workspace_database: &WorkspaceDatabase) {
 struct UserDatabaseCollabServiceImpl {
   collab_builder: Arc<AppFlowyCollabBuilder>,
   cloud_service: Arc<dyn DatabaseCloudService>,
+  row_history_recorder: Arc<RowHistoryRecorder>,
 }
 
 impl DatabaseCollabService for UserDatabaseCollabServiceImpl {
@@ -429,7 +1432,8 @@ impl DatabaseCollabService for UserDatabaseCollabServiceImpl {
     collab_raw_data: CollabRawData,
     config: &CollabPersistenceConfig,
   ) -> Arc<MutexCollab> {
-    block_on(self.collab_builder.build_with_config(
+    let is_row = matches!(object_type, CollabType::DatabaseRow);
+    let collab = block_on(self.collab_builder.build_with_config(
       uid,
       object_id,
       object_type,
@@ -437,6 +1441,21 @@ impl DatabaseCollabService for UserDatabaseCollabServiceImpl {
       collab_raw_data,
       config,
     ))
-    .unwrap()
+    .unwrap();
+    if is_row {
+      self.row_history_recorder.observe_row(object_id, &collab);
+    }
+    collab
   }
 }
+
+/// The data that the blocking task reading the CSV file sends to insert into the database.
+enum CSVImportBatch {
+  Database(CreateDatabaseParams),
+  Rows {
+    rows: Vec<CreateRowParams>,
+    imported_rows: usize,
+    read_bytes: u64,
+  },
+  UpdatedFields(Vec<Field>),
+}
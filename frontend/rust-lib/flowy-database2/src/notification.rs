@@ -52,6 +52,18 @@ pub enum DatabaseNotification {
   DidUpdateDatabaseSnapshotState = 86,
   // Trigger when the field setting is changed
   DidUpdateFieldSettings = 87,
+  // Trigger when the result of a calculation in the view is changed
+  DidUpdateCalculations = 88,
+  // Trigger after the number of sub-groups (the swimlanes of a board) is changed
+  DidUpdateNumOfSubGroups = 89,
+  // Trigger after inserting/deleting/updating/moving a row of a sub-group
+  DidUpdateSubGroupRow = 90,
+  // Trigger when setting a new sub-grouping field
+  DidSubGroupByField = 91,
+  // Trigger after a batch of rows of the imported CSV file is inserted
+  DidUpdateImportProgress = 92,
+  // Trigger when the import of a CSV file is finished, with the report of the import
+  DidFinishImport = 93,
 }
 
 impl std::convert::From<DatabaseNotification> for i32 {
@@ -84,6 +96,12 @@ impl std::convert::From<i32> for DatabaseNotification {
       83 => DatabaseNotification::DidDeleteDatabaseView,
       84 => DatabaseNotification::DidMoveDatabaseViewToTrash,
       87 => DatabaseNotification::DidUpdateFieldSettings,
+      88 => DatabaseNotification::DidUpdateCalculations,
+      89 => DatabaseNotification::DidUpdateNumOfSubGroups,
+      90 => DatabaseNotification::DidUpdateSubGroupRow,
+      91 => DatabaseNotification::DidSubGroupByField,
+      92 => DatabaseNotification::DidUpdateImportProgress,
+      93 => DatabaseNotification::DidFinishImport,
       _ => DatabaseNotification::Unknown,
     }
   }
@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, Row, RowDetail};
+use rust_decimal::Decimal;
+
+use crate::entities::{CalculationType, FieldType};
+use crate::services::cell::stringify_cell_data;
+use crate::services::field::{
+  CheckboxCellData, DateCellData, FormulaCellData, NumberCellData, NumberTypeOption,
+  TimestampCellData,
+};
+
+/// The number of decimal places of the average and the percentage
+const RESULT_SCALE: u32 = 2;
+
+/// Evaluates the calculation over the cells of the field in the rows. The rows should be the rows
+/// that are visible in the view, i.e. the rows that pass the filters of the view.
+///
+/// Returns an empty string if the calculation doesn't apply to the field type or if there are no
+/// values to calculate over, for example, the sum of a field whose cells are all empty.
+pub fn calculate(
+  calculation_type: CalculationType,
+  field: &Field,
+  rows: &[Arc<RowDetail>],
+) -> String {
+  let field_type = FieldType::from(field.field_type);
+  if !calculation_type.is_supported_by(&field_type) {
+    return "".to_string();
+  }
+
+  let cells = rows
+    .iter()
+    .map(|row_detail| cell_from_row(&row_detail.row, field, &field_type))
+    .collect::<Vec<Option<Cell>>>();
+
+  match calculation_type {
+    CalculationType::None => "".to_string(),
+    CalculationType::Count => cells.len().to_string(),
+    CalculationType::CountEmpty => cells
+      .iter()
+      .filter(|cell| is_cell_empty(cell.as_ref(), field, &field_type))
+      .count()
+      .to_string(),
+    CalculationType::CountUnique => cells
+      .iter()
+      .flatten()
+      .map(|cell| text_from_cell(cell, field, &field_type))
+      .filter(|s| !s.is_empty())
+      .collect::<HashSet<String>>()
+      .len()
+      .to_string(),
+    CalculationType::Sum
+    | CalculationType::Average
+    | CalculationType::Median
+    | CalculationType::Min
+    | CalculationType::Max => {
+      let mut numbers = numbers_from_cells(&cells, field, &field_type);
+      if numbers.is_empty() {
+        return "".to_string();
+      }
+
+      let value = match calculation_type {
+        CalculationType::Sum => numbers.iter().sum(),
+        CalculationType::Average => {
+          let sum: Decimal = numbers.iter().sum();
+          (sum / Decimal::from(numbers.len())).round_dp(RESULT_SCALE)
+        },
+        CalculationType::Median => {
+          numbers.sort();
+          let middle = numbers.len() / 2;
+          if numbers.len() % 2 == 0 {
+            (numbers[middle - 1] + numbers[middle]) / Decimal::from(2)
+          } else {
+            numbers[middle]
+          }
+        },
+        CalculationType::Min => numbers.iter().min().cloned().unwrap_or_default(),
+        _ => numbers.iter().max().cloned().unwrap_or_default(),
+      };
+      format_number(value, field, &field_type)
+    },
+    CalculationType::Earliest | CalculationType::Latest => {
+      let cells_with_timestamp = cells.iter().flatten().filter_map(|cell| {
+        timestamp_from_cell(cell, &field_type).map(|timestamp| (timestamp, cell))
+      });
+      let cell = if calculation_type == CalculationType::Earliest {
+        cells_with_timestamp.min_by_key(|(timestamp, _)| *timestamp)
+      } else {
+        cells_with_timestamp.max_by_key(|(timestamp, _)| *timestamp)
+      };
+      cell
+        .map(|(_, cell)| text_from_cell(cell, field, &field_type))
+        .unwrap_or_default()
+    },
+    CalculationType::PercentChecked => {
+      if cells.is_empty() {
+        return "".to_string();
+      }
+      let num_of_checked = cells
+        .iter()
+        .flatten()
+        .filter(|cell| CheckboxCellData::from(*cell).is_check())
+        .count();
+      let percent = Decimal::from(num_of_checked * 100) / Decimal::from(cells.len());
+      format!("{}%", percent.round_dp(RESULT_SCALE).normalize())
+    },
+  }
+}
+
+/// The cells of the [FieldType::LastEditedTime] and [FieldType::CreatedTime] are not stored in the
+/// row, they are built from the timestamps of the row.
+fn cell_from_row(row: &Row, field: &Field, field_type: &FieldType) -> Option<Cell> {
+  match field_type {
+    FieldType::LastEditedTime => Some(Cell::from(TimestampCellData::new(row.modified_at))),
+    FieldType::CreatedTime => Some(Cell::from(TimestampCellData::new(row.created_at))),
+    _ => row.cells.get(&field.id).cloned(),
+  }
+}
+
+fn text_from_cell(cell: &Cell, field: &Field, field_type: &FieldType) -> String {
+  stringify_cell_data(cell, field_type, field_type, field)
+    .trim()
+    .to_string()
+}
+
+/// An unchecked checkbox is considered empty.
+fn is_cell_empty(cell: Option<&Cell>, field: &Field, field_type: &FieldType) -> bool {
+  match cell {
+    None => true,
+    Some(cell) if field_type.is_checkbox() => !CheckboxCellData::from(cell).is_check(),
+    Some(cell) => text_from_cell(cell, field, field_type).is_empty(),
+  }
+}
+
+fn numbers_from_cells(
+  cells: &[Option<Cell>],
+  field: &Field,
+  field_type: &FieldType,
+) -> Vec<Decimal> {
+  let number_type_option = field.get_type_option::<NumberTypeOption>(field_type);
+  cells
+    .iter()
+    .flatten()
+    .filter_map(|cell| match field_type {
+      FieldType::Number => number_type_option
+        .as_ref()?
+        .format_cell_data(&NumberCellData::from(cell))
+        .ok()
+        .and_then(|cell_format| *cell_format.decimal()),
+      FieldType::Formula => FormulaCellData::from(cell).0.as_decimal(),
+      _ => None,
+    })
+    .collect()
+}
+
+/// The result of a number field is formatted with the format of the field, e.g. the currency.
+fn format_number(value: Decimal, field: &Field, field_type: &FieldType) -> String {
+  let value = value.normalize();
+  if field_type.is_number() {
+    if let Some(cell_format) = field
+      .get_type_option::<NumberTypeOption>(field_type)
+      .and_then(|type_option| {
+        type_option
+          .format_cell_data(&NumberCellData(value.to_string()))
+          .ok()
+      })
+    {
+      return cell_format.to_string();
+    }
+  }
+  value.to_string()
+}
+
+fn timestamp_from_cell(cell: &Cell, field_type: &FieldType) -> Option<i64> {
+  match field_type {
+    FieldType::DateTime => DateCellData::from(cell).timestamp,
+    _ => TimestampCellData::from(cell).timestamp,
+  }
+}
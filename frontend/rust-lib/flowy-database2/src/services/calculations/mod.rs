@@ -0,0 +1,3 @@
+mod calculate;
+
+pub use calculate::*;
@@ -232,6 +232,33 @@ pub fn insert_checklist_cell(insert_options: Vec<String>, field: &Field) -> Cell
   apply_cell_changeset(changeset, None, field, None).unwrap()
 }
 
+pub fn insert_relation_cell(row_ids: Vec<String>, field: &Field) -> Cell {
+  let changeset = RelationCellChangeset {
+    inserted_row_ids: row_ids,
+    ..Default::default()
+  }
+  .to_cell_changeset_str();
+  apply_cell_changeset(changeset, None, field, None).unwrap()
+}
+
+pub fn insert_person_cell(user_ids: Vec<i64>, field: &Field) -> Cell {
+  let changeset = PersonCellChangeset {
+    inserted_user_ids: user_ids,
+    ..Default::default()
+  }
+  .to_cell_changeset_str();
+  apply_cell_changeset(changeset, None, field, None).unwrap()
+}
+
+pub fn insert_files_cell(files: Vec<FileAttachment>, field: &Field) -> Cell {
+  let changeset = FilesCellChangeset {
+    inserted_files: files,
+    ..Default::default()
+  }
+  .to_cell_changeset_str();
+  apply_cell_changeset(changeset, None, field, None).unwrap()
+}
+
 pub fn delete_select_option_cell(option_ids: Vec<String>, field: &Field) -> Cell {
   let changeset =
     SelectOptionCellChangeset::from_delete_options(option_ids).to_cell_changeset_str();
@@ -332,6 +359,9 @@ impl<'a> CellBuilder<'a> {
           FieldType::LastEditedTime | FieldType::CreatedTime => {
             tracing::warn!("Shouldn't insert cell data to cell whose field type is LastEditedTime or CreatedTime");
           },
+          FieldType::Formula => {
+            tracing::warn!("Shouldn't insert cell data to cell whose field type is Formula");
+          },
           FieldType::SingleSelect | FieldType::MultiSelect => {
             if let Ok(ids) = SelectOptionIds::from_cell_str(&cell_str) {
               cells.insert(field_id, insert_select_option_cell(ids.into_inner(), field));
@@ -350,6 +380,26 @@ impl<'a> CellBuilder<'a> {
               cells.insert(field_id, insert_select_option_cell(ids.into_inner(), field));
             }
           },
+          FieldType::Relation => {
+            let row_ids = RelationCellData::from(cell_str)
+              .row_ids
+              .into_iter()
+              .map(|row_id| row_id.into_inner())
+              .collect();
+            cells.insert(field_id, insert_relation_cell(row_ids, field));
+          },
+          FieldType::Lookup => {
+            // The value is refreshed from the source database once the database is opened
+            cells.insert(field_id, Cell::from(LookupCellData::from(cell_str)));
+          },
+          FieldType::Person => {
+            let user_ids = PersonCellData::from(cell_str).user_ids;
+            cells.insert(field_id, insert_person_cell(user_ids, field));
+          },
+          FieldType::Files => {
+            let files = FilesCellData::from(cell_str).files;
+            cells.insert(field_id, insert_files_cell(files, field));
+          },
         }
       }
     }
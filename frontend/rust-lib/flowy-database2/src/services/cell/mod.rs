@@ -1,7 +1,9 @@
 mod cell_data_cache;
 mod cell_operation;
+mod original_cell;
 mod type_cell_data;
 
 pub use cell_data_cache::*;
 pub use cell_operation::*;
+pub use original_cell::*;
 pub use type_cell_data::*;
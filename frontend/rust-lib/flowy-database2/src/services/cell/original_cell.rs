@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use collab::preclude::Any;
+use collab_database::rows::{get_field_type_from_cell, Cell};
+
+use crate::entities::FieldType;
+
+/// The key of the cell that keeps the raw cells written with the other field types of the field.
+/// The cells are stored as a nested map, keyed by the field type.
+const ORIGINAL_CELLS: &str = "original_cells";
+
+/// Returns the new cell carrying the original cells of the old cell. If the old cell was written
+/// with another field type than the new cell, the old cell is kept as the original cell of its
+/// field type. So switching the field back to that type restores the cell, even if the cell was
+/// edited in between.
+pub fn carry_original_cells(old_cell: Option<&Cell>, mut new_cell: Cell) -> Cell {
+  let old_cell = match old_cell {
+    None => return new_cell,
+    Some(old_cell) => old_cell,
+  };
+  let mut original_cells = get_original_cells(old_cell);
+  let old_field_type = get_field_type_from_cell::<FieldType>(old_cell);
+  let new_field_type = get_field_type_from_cell::<FieldType>(&new_cell);
+  if let Some(old_field_type) = old_field_type {
+    if Some(&old_field_type) != new_field_type.as_ref() {
+      let mut old_cell = old_cell.clone();
+      old_cell.remove(ORIGINAL_CELLS);
+      original_cells.insert(field_type_key(&old_field_type), old_cell);
+    }
+  }
+  if let Some(new_field_type) = new_field_type {
+    original_cells.remove(&field_type_key(&new_field_type));
+  }
+
+  if original_cells.is_empty() {
+    new_cell.remove(ORIGINAL_CELLS);
+  } else {
+    let original_cells = original_cells
+      .into_iter()
+      .map(|(key, cell)| (key, Any::Map(Box::new(cell_to_map(&cell)))))
+      .collect::<HashMap<String, Any>>();
+    new_cell.insert(
+      ORIGINAL_CELLS.to_string(),
+      Any::Map(Box::new(original_cells)),
+    );
+  }
+  new_cell
+}
+
+/// Returns the raw cell that was written with the field type before the field was switched to
+/// another type, if the cell keeps one.
+pub fn get_original_cell(cell: &Cell, field_type: &FieldType) -> Option<Cell> {
+  get_original_cells(cell).remove(&field_type_key(field_type))
+}
+
+fn get_original_cells(cell: &Cell) -> HashMap<String, Cell> {
+  match cell.get(ORIGINAL_CELLS) {
+    Some(Any::Map(original_cells)) => original_cells
+      .iter()
+      .filter_map(|(key, value)| match value {
+        Any::Map(map) => {
+          let mut original_cell = Cell::default();
+          original_cell.extend(map.iter().map(|(key, value)| (key.clone(), value.clone())));
+          Some((key.clone(), original_cell))
+        },
+        _ => None,
+      })
+      .collect(),
+    _ => HashMap::new(),
+  }
+}
+
+fn cell_to_map(cell: &Cell) -> HashMap<String, Any> {
+  cell
+    .iter()
+    .map(|(key, value)| (key.clone(), value.clone()))
+    .collect()
+}
+
+fn field_type_key(field_type: &FieldType) -> String {
+  field_type.value().to_string()
+}
@@ -1,59 +1,98 @@
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::{Arc, Weak};
 
 use bytes::Bytes;
-use collab_database::database::MutexDatabase;
+use collab_database::database::{Database, MutexDatabase};
 use collab_database::fields::{Field, TypeOptionData};
-use collab_database::rows::{Cell, Cells, CreateRowParams, Row, RowCell, RowDetail, RowId};
+use collab_database::rows::{
+  get_field_type_from_cell, new_cell_builder, Cell, Cells, CreateRowParams, Row, RowCell,
+  RowDetail, RowId,
+};
 use collab_database::views::{DatabaseLayout, DatabaseView, LayoutSetting};
 use futures::StreamExt;
 use tokio::sync::{broadcast, RwLock};
 
 use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
+use flowy_storage::{FileStorageService, StorageObject};
 use flowy_task::TaskDispatcher;
 use lib_infra::future::{to_fut, Fut};
 
 use crate::entities::*;
+use crate::manager::DatabaseUser;
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::cell::{
-  apply_cell_changeset, get_cell_protobuf, AnyTypeCache, CellCache, ToCellChangeset,
+  apply_cell_changeset, carry_original_cells, get_cell_protobuf, get_original_cell,
+  stringify_cell_data, AnyTypeCache, CellCache, CellDataDecoder, ToCellChangeset,
+};
+use crate::services::database::undo::{
+  DatabaseAction, DatabaseUndoManager, FieldViewSettings, RelatedCell,
 };
 use crate::services::database::util::database_view_setting_pb_from_view;
-use crate::services::database::UpdatedRow;
-use crate::services::database_view::{DatabaseViewChanged, DatabaseViewData, DatabaseViews};
+use crate::services::database::{ChangeOrigin, DatabaseRowEvent, InsertedRow, UpdatedRow};
+use crate::services::database_view::{
+  DatabaseViewChanged, DatabaseViewData, DatabaseViews, RowEventReceiver, RowEventSender,
+};
 use crate::services::field::checklist_type_option::{ChecklistCellChangeset, ChecklistCellData};
 use crate::services::field::{
   default_type_option_data_from_type, select_type_option_from_field, transform_type_option,
-  type_option_data_from_pb_or_default, type_option_to_pb, SelectOptionCellChangeset,
-  SelectOptionIds, TimestampCellData, TypeOptionCellDataHandler, TypeOptionCellExt,
+  type_option_data_from_pb_or_default, type_option_to_pb, DateCellData, DateReminder,
+  DateTypeOption, FileAttachment, FilesCellChangeset, FilesCellData, FormulaEvaluator,
+  LookupTypeOption, PersonMember, PersonTypeOption, RelationCellChangeset, RelationCellData,
+  RelationTypeOption, SelectOptionCellChangeset, SelectOptionIds, TimestampCellData,
+  TypeOptionCellDataHandler, TypeOptionCellExt, UserDateTimeSettings,
 };
 use crate::services::field_settings::{
   default_field_settings_by_layout_map, FieldSettings, FieldSettingsChangesetParams,
 };
-use crate::services::filter::Filter;
+use crate::services::file::get_file_urls;
+use crate::services::filter::{Filter, FilterGroup};
 use crate::services::group::{
   default_group_setting, GroupSetting, GroupSettingChangeset, RowChangeset,
 };
+use crate::services::row_history::{CellChange, RowHistoryPersistence, RowHistoryRecorder};
 use crate::services::share::csv::{CSVExport, CSVFormat};
+use crate::services::share::json::JSONExport;
 use crate::services::sort::Sort;
 
+/// The maximum number of the converted and of the lost cells that are sampled in the preview of
+/// a field type switch.
+const MAX_CONVERSION_SAMPLES: usize = 3;
+
 #[derive(Clone)]
 pub struct DatabaseEditor {
   database: Arc<MutexDatabase>,
   pub cell_cache: CellCache,
   database_views: Arc<DatabaseViews>,
+  row_event_tx: RowEventSender,
+  file_storage: Weak<dyn FileStorageService>,
+  /// The urls of the files that were removed from the cells. They are deleted from the storage
+  /// after the database is closed, if no other cell references them.
+  removed_file_urls: Arc<parking_lot::Mutex<HashSet<String>>>,
+  row_history: Arc<dyn RowHistoryPersistence>,
+  row_history_recorder: Arc<RowHistoryRecorder>,
+  undo_manager: Arc<DatabaseUndoManager>,
+  uid: i64,
+  user: Arc<dyn DatabaseUser>,
 }
 
 impl DatabaseEditor {
   pub async fn new(
     database: Arc<MutexDatabase>,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
+    uid: i64,
+    user: Arc<dyn DatabaseUser>,
+    file_storage: Weak<dyn FileStorageService>,
+    row_history: Arc<dyn RowHistoryPersistence>,
+    row_history_recorder: Arc<RowHistoryRecorder>,
   ) -> FlowyResult<Self> {
     let cell_cache = AnyTypeCache::<u64>::new();
     let database_view_data = Arc::new(DatabaseViewDataImpl {
       database: database.clone(),
       task_scheduler: task_scheduler.clone(),
       cell_cache: cell_cache.clone(),
+      uid,
+      user: user.clone(),
     });
 
     let database_id = database.lock().get_database_id();
@@ -94,10 +133,19 @@ impl DatabaseEditor {
 
     let database_views =
       Arc::new(DatabaseViews::new(database.clone(), cell_cache.clone(), database_view_data).await?);
+    let (row_event_tx, _) = broadcast::channel(100);
     Ok(Self {
       database,
       cell_cache,
       database_views,
+      row_event_tx,
+      file_storage,
+      removed_file_urls: Default::default(),
+      row_history,
+      row_history_recorder,
+      undo_manager: Arc::new(DatabaseUndoManager::default()),
+      uid,
+      user,
     })
   }
 
@@ -106,7 +154,22 @@ impl DatabaseEditor {
     self.database_views.close_view(view_id).await
   }
 
-  pub async fn close(&self) {}
+  pub async fn close(&self) {
+    let row_ids = {
+      let database = self.database.lock();
+      database
+        .get_rows_for_view(&database.get_inline_view_id())
+        .into_iter()
+        .map(|row| row.id)
+        .collect()
+    };
+    self.row_history_recorder.forget_rows(row_ids);
+  }
+
+  /// Whether a view of the database is open
+  pub async fn has_open_views(&self) -> bool {
+    !self.database_views.editors().await.is_empty()
+  }
 
   pub async fn get_layout_type(&self, view_id: &str) -> DatabaseLayout {
     let view = self.database_views.get_view_editor(view_id).await.ok();
@@ -136,24 +199,95 @@ impl DatabaseEditor {
     Ok(view_editor.notifier.subscribe())
   }
 
+  /// Subscribes to the rows that are inserted, updated or deleted in the database.
+  pub fn subscribe_row_event(&self) -> RowEventReceiver {
+    self.row_event_tx.subscribe()
+  }
+
   pub fn get_field(&self, field_id: &str) -> Option<Field> {
     self.database.lock().fields.get_field(field_id)
   }
 
-  pub async fn set_group_by_field(&self, view_id: &str, field_id: &str) -> FlowyResult<()> {
+  /// Groups the rows of the view by the field. The `setting_content` is the json configuration of
+  /// the groups, passing it regroups the rows even if the view is already grouped by the field.
+  pub async fn set_group_by_field(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    setting_content: Option<String>,
+  ) -> FlowyResult<()> {
+    let is_regrouping = setting_content.is_some();
     {
       let database = self.database.lock();
       let field = database.fields.get_field(field_id);
       if let Some(field) = field {
-        let group_setting = default_group_setting(&field);
+        let group_setting = match setting_content {
+          None => default_group_setting(&field),
+          Some(content) => GroupSetting::new(field.id.clone(), field.field_type, content),
+        };
+        // Keep the sub-grouping setting unless the rows are now grouped by the same field
+        let settings: Vec<GroupSetting> = database.get_all_group_setting(view_id);
+        let sub_group_setting = settings
+          .into_iter()
+          .find(|setting| setting.is_sub_group && setting.field_id != field_id);
         database.views.update_database_view(view_id, |view| {
-          view.set_groups(vec![group_setting.into()]);
+          let mut settings = vec![group_setting.into()];
+          settings.extend(sub_group_setting.map(|setting| setting.into()));
+          view.set_groups(settings);
         });
       }
     }
 
     let view_editor = self.database_views.get_view_editor(view_id).await?;
-    view_editor.v_initialize_new_group(field_id).await?;
+    if view_editor.is_sub_grouping_field(field_id).await {
+      view_editor.v_sub_grouping_by_field(None).await?;
+    }
+    if is_regrouping && view_editor.is_grouping_field(field_id).await {
+      view_editor.v_grouping_by_field(field_id).await?;
+    } else {
+      view_editor.v_initialize_new_group(field_id).await?;
+    }
+    Ok(())
+  }
+
+  /// Groups the rows of the board by a second field, the groups of which are the swimlanes of
+  /// the board. Passing `None` removes the sub-grouping.
+  pub async fn set_sub_group_by_field(
+    &self,
+    view_id: &str,
+    field_id: Option<String>,
+  ) -> FlowyResult<()> {
+    let view_editor = self.database_views.get_view_editor(view_id).await?;
+    if let Some(field_id) = field_id.as_ref() {
+      if view_editor.is_grouping_field(field_id).await {
+        return Err(
+          FlowyError::invalid_data()
+            .with_context("The sub-grouping field must be different from the grouping field"),
+        );
+      }
+    }
+
+    {
+      let database = self.database.lock();
+      let mut settings: Vec<GroupSetting> = database.get_all_group_setting(view_id);
+      settings.retain(|setting| !setting.is_sub_group);
+      if let Some(field_id) = field_id.as_ref() {
+        let field = database
+          .fields
+          .get_field(field_id)
+          .ok_or_else(|| FlowyError::record_not_found().with_context("Can't find the field"))?;
+        let mut sub_group_setting = default_group_setting(&field);
+        sub_group_setting.is_sub_group = true;
+        settings.push(sub_group_setting);
+      }
+      database.views.update_database_view(view_id, |view| {
+        view.set_groups(settings.into_iter().map(|setting| setting.into()).collect());
+      });
+    }
+
+    view_editor
+      .v_sub_grouping_by_field(field_id.as_deref())
+      .await?;
     Ok(())
   }
 
@@ -201,6 +335,21 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  pub async fn create_or_update_filter_group(
+    &self,
+    params: UpdateFilterGroupParams,
+  ) -> FlowyResult<FilterGroup> {
+    let view_editor = self.database_views.get_view_editor(&params.view_id).await?;
+    let filter_group = view_editor.v_insert_filter_group(params).await?;
+    Ok(filter_group)
+  }
+
+  pub async fn delete_filter_group(&self, params: DeleteFilterGroupParams) -> FlowyResult<()> {
+    let view_editor = self.database_views.get_view_editor(&params.view_id).await?;
+    view_editor.v_delete_filter_group(params).await?;
+    Ok(())
+  }
+
   pub async fn create_or_update_sort(&self, params: UpdateSortParams) -> FlowyResult<Sort> {
     let view_editor = self.database_views.get_view_editor(&params.view_id).await?;
     let sort = view_editor.v_insert_sort(params).await?;
@@ -228,6 +377,15 @@ impl DatabaseEditor {
       None
     }
   }
+
+  pub async fn get_all_filter_groups(&self, view_id: &str) -> RepeatedFilterGroupPB {
+    if let Ok(view_editor) = self.database_views.get_view_editor(view_id).await {
+      view_editor.v_get_all_filter_groups().await.into()
+    } else {
+      RepeatedFilterGroupPB { items: vec![] }
+    }
+  }
+
   pub async fn get_all_sorts(&self, view_id: &str) -> RepeatedSortPB {
     if let Ok(view_editor) = self.database_views.get_view_editor(view_id).await {
       view_editor.v_get_all_sorts().await.into()
@@ -275,7 +433,19 @@ impl DatabaseEditor {
     Ok(())
   }
 
-  pub async fn delete_field(&self, field_id: &str) -> FlowyResult<()> {
+  pub async fn delete_field(&self, view_id: &str, field_id: &str) -> FlowyResult<()> {
+    self
+      .delete_field_with_origin(view_id, field_id, ChangeOrigin::User)
+      .await
+  }
+
+  /// Same as [DatabaseEditor::delete_field], but only the changes made by the user are undoable.
+  pub async fn delete_field_with_origin(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    origin: ChangeOrigin,
+  ) -> FlowyResult<()> {
     let is_primary = self
       .database
       .lock()
@@ -291,6 +461,10 @@ impl DatabaseEditor {
       ));
     }
 
+    if let Some(action) = self.create_field_action(view_id, field_id).await {
+      self.undo_manager.record(view_id, origin, action);
+    }
+
     let database_id = {
       let database = self.database.lock();
       database.delete_field(field_id);
@@ -328,14 +502,32 @@ impl DatabaseEditor {
       .database_views
       .did_update_field_type_option(view_id, field_id, &old_field)
       .await?;
+    if field_type.is_formula() {
+      self.refresh_formula_field(view_id, field_id).await;
+    }
     let _ = self.notify_did_update_database_field(field_id).await;
     Ok(())
   }
 
   pub async fn switch_to_field_type(
     &self,
+    view_id: &str,
+    field_id: &str,
+    new_field_type: &FieldType,
+  ) -> FlowyResult<()> {
+    self
+      .switch_to_field_type_with_origin(view_id, field_id, new_field_type, ChangeOrigin::User)
+      .await
+  }
+
+  /// Same as [DatabaseEditor::switch_to_field_type], but only the changes made by the user are
+  /// undoable.
+  pub async fn switch_to_field_type_with_origin(
+    &self,
+    view_id: &str,
     field_id: &str,
     new_field_type: &FieldType,
+    origin: ChangeOrigin,
   ) -> FlowyResult<()> {
     let field = self.database.lock().fields.get_field(field_id);
     match field {
@@ -349,17 +541,17 @@ impl DatabaseEditor {
         }
 
         let old_field_type = FieldType::from(field.field_type);
-        let old_type_option = field.get_any_type_option(old_field_type.clone());
-        let new_type_option = field
-          .get_any_type_option(new_field_type)
-          .unwrap_or_else(|| default_type_option_data_from_type(new_field_type));
-
-        let transformed_type_option = transform_type_option(
-          &new_type_option,
-          new_field_type,
-          old_type_option,
-          old_field_type,
-        );
+        if &old_field_type != new_field_type {
+          self.undo_manager.record(
+            view_id,
+            origin,
+            DatabaseAction::SwitchFieldType {
+              field_id: field_id.to_string(),
+              field_type: old_field_type.clone(),
+            },
+          );
+        }
+        let transformed_type_option = switched_type_option(&field, new_field_type);
         self
           .database
           .lock()
@@ -369,6 +561,12 @@ impl DatabaseEditor {
               .set_field_type(new_field_type.into())
               .set_type_option(new_field_type.into(), Some(transformed_type_option));
           });
+        self.restore_original_cells(field_id, new_field_type).await;
+
+        if new_field_type.is_formula() {
+          let inline_view_id = self.database.lock().get_inline_view_id();
+          self.refresh_formula_field(&inline_view_id, field_id).await;
+        }
       },
     }
 
@@ -376,6 +574,97 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  /// Returns how the cells of the field would look after switching the field to the given type,
+  /// without changing anything. The cells that would become empty are counted as lost. They are
+  /// still kept in the database and come back when the field is switched back.
+  pub fn preview_field_type_switch(
+    &self,
+    field_id: &str,
+    new_field_type: &FieldType,
+  ) -> FlowyResult<FieldTypeSwitchPreviewPB> {
+    let field = self.get_field(field_id).ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("The field: {} not found", field_id))
+    })?;
+    let old_field_type = FieldType::from(field.field_type);
+    let mut new_field = field.clone();
+    new_field.field_type = new_field_type.into();
+    new_field.type_options.insert(
+      new_field_type.to_string(),
+      switched_type_option(&field, new_field_type),
+    );
+
+    let row_cells = {
+      let database = self.database.lock();
+      database.get_cells_for_field(&database.get_inline_view_id(), field_id)
+    };
+    let mut preview = FieldTypeSwitchPreviewPB::default();
+    let mut converted_samples = vec![];
+    let mut lost_samples = vec![];
+    for row_cell in row_cells {
+      let cell = match row_cell.cell {
+        None => continue,
+        Some(cell) => cell,
+      };
+      let before = stringify_cell_with_field_type(&cell, &field, &old_field_type);
+      if before.is_empty() {
+        continue;
+      }
+      let new_cell = get_original_cell(&cell, new_field_type).unwrap_or(cell);
+      let after = stringify_cell_with_field_type(&new_cell, &new_field, new_field_type);
+      let sample = CellConversionSamplePB {
+        row_id: row_cell.row_id.into_inner(),
+        before,
+        after,
+      };
+      if sample.after.is_empty() {
+        preview.lost_count += 1;
+        if lost_samples.len() < MAX_CONVERSION_SAMPLES {
+          lost_samples.push(sample);
+        }
+      } else {
+        preview.converted_count += 1;
+        if converted_samples.len() < MAX_CONVERSION_SAMPLES {
+          converted_samples.push(sample);
+        }
+      }
+    }
+    preview.samples = converted_samples;
+    preview.samples.extend(lost_samples);
+    Ok(preview)
+  }
+
+  /// Restores the cells that were written with the field type before the field was switched to
+  /// another type. See [carry_original_cells].
+  async fn restore_original_cells(&self, field_id: &str, field_type: &FieldType) {
+    let mut changesets = vec![];
+    {
+      let database = self.database.lock();
+      let inline_view_id = database.get_inline_view_id();
+      for row_cell in database.get_cells_for_field(&inline_view_id, field_id) {
+        let original_cell = match row_cell
+          .cell
+          .as_ref()
+          .and_then(|cell| get_original_cell(cell, field_type))
+        {
+          None => continue,
+          Some(original_cell) => original_cell,
+        };
+        let cell = carry_original_cells(row_cell.cell.as_ref(), original_cell);
+        database.update_row(&row_cell.row_id, |row_update| {
+          row_update.update_cells(|cell_update| {
+            cell_update.insert(field_id, cell);
+          });
+        });
+        changesets.push(CellChangesetNotifyPB {
+          view_id: inline_view_id.clone(),
+          row_id: row_cell.row_id.into_inner(),
+          field_id: field_id.to_string(),
+        });
+      }
+    }
+    notify_did_update_cell(changesets).await;
+  }
+
   pub async fn duplicate_field(&self, view_id: &str, field_id: &str) -> FlowyResult<()> {
     let is_primary = self
       .database
@@ -397,6 +686,13 @@ impl DatabaseEditor {
       .lock()
       .duplicate_field(view_id, field_id, |field| format!("{} (copy)", field.name));
     if let Some((index, duplicated_field)) = value {
+      self.undo_manager.record(
+        view_id,
+        ChangeOrigin::User,
+        DatabaseAction::DeleteField {
+          field_id: duplicated_field.id.clone(),
+        },
+      );
       let _ = self
         .notify_did_insert_database_field(duplicated_field, index)
         .await;
@@ -418,6 +714,19 @@ impl DatabaseEditor {
   }
 
   pub async fn move_row(&self, view_id: &str, from: RowId, to: RowId) {
+    self
+      .move_row_with_origin(view_id, from, to, ChangeOrigin::User)
+      .await
+  }
+
+  /// Same as [DatabaseEditor::move_row], but only the changes made by the user are undoable.
+  pub async fn move_row_with_origin(
+    &self,
+    view_id: &str,
+    from: RowId,
+    to: RowId,
+    origin: ChangeOrigin,
+  ) {
     let database = self.database.lock();
     if let (Some(row_detail), Some(from_index), Some(to_index)) = (
       database.get_row_detail(&from),
@@ -427,8 +736,24 @@ impl DatabaseEditor {
       database.views.update_database_view(view_id, |view| {
         view.move_row_order(from_index as u32, to_index as u32);
       });
+      // The row that takes the old position of the moved row is used to move it back.
+      let restore_to = database
+        .get_rows_for_view(view_id)
+        .get(from_index)
+        .map(|row| row.id.clone());
       drop(database);
 
+      if let Some(restore_to) = restore_to {
+        self.undo_manager.record(
+          view_id,
+          origin,
+          DatabaseAction::MoveRow {
+            row_id: from.clone(),
+            to_row_id: restore_to,
+          },
+        );
+      }
+
       let delete_row_id = from.into_inner();
       let insert_row = InsertedRowPB::new(RowMetaPB::from(row_detail)).with_index(to_index as i32);
       let changes = RowsChangePB::from_move(vec![delete_row_id], vec![insert_row]);
@@ -439,10 +764,23 @@ impl DatabaseEditor {
   }
 
   pub async fn create_row(
+    &self,
+    view_id: &str,
+    group_id: Option<String>,
+    params: CreateRowParams,
+  ) -> FlowyResult<Option<RowDetail>> {
+    self
+      .create_row_with_origin(view_id, group_id, params, ChangeOrigin::User)
+      .await
+  }
+
+  /// Same as [DatabaseEditor::create_row], but only the changes made by the user are undoable.
+  pub async fn create_row_with_origin(
     &self,
     view_id: &str,
     group_id: Option<String>,
     mut params: CreateRowParams,
+    origin: ChangeOrigin,
   ) -> FlowyResult<Option<RowDetail>> {
     for view in self.database_views.editors().await {
       view.v_will_create_row(&mut params.cells, &group_id).await;
@@ -450,11 +788,26 @@ impl DatabaseEditor {
     let result = self.database.lock().create_row_in_view(view_id, params);
     if let Some((index, row_order)) = result {
       tracing::trace!("create row: {:?} at {}", row_order, index);
+      self.update_formula_cells(&row_order.id, None);
       let row_detail = self.database.lock().get_row_detail(&row_order.id);
       if let Some(row_detail) = row_detail {
         for view in self.database_views.editors().await {
           view.v_did_create_row(&row_detail, &group_id, index).await;
         }
+        let _ = self
+          .row_event_tx
+          .send(DatabaseRowEvent::InsertRow(InsertedRow {
+            row_detail: row_detail.clone(),
+            index: Some(index as i32),
+            is_new: true,
+          }));
+        self.undo_manager.record(
+          view_id,
+          origin,
+          DatabaseAction::DeleteRow {
+            row_id: row_order.id.clone(),
+          },
+        );
         return Ok(Some(row_detail));
       }
     }
@@ -495,6 +848,13 @@ impl DatabaseEditor {
       },
       default_field_settings_by_layout_map(),
     );
+    self.undo_manager.record(
+      view_id,
+      ChangeOrigin::User,
+      DatabaseAction::DeleteField {
+        field_id: field.id.clone(),
+      },
+    );
 
     let _ = self
       .notify_did_insert_database_field(field.clone(), index)
@@ -564,6 +924,17 @@ impl DatabaseEditor {
     }
   }
 
+  /// Returns the cells of the row, or None if the row is not in the database.
+  pub fn get_row_cells(&self, row_id: &RowId) -> Option<Cells> {
+    let database = self.database.lock();
+    let inline_view_id = database.get_inline_view_id();
+    if database.views.is_row_exist(&inline_view_id, row_id) {
+      Some(database.get_row(row_id).cells)
+    } else {
+      None
+    }
+  }
+
   pub fn get_row_detail(&self, view_id: &str, row_id: &RowId) -> Option<RowDetail> {
     if self.database.lock().views.is_row_exist(view_id, row_id) {
       self.database.lock().get_row_detail(row_id)
@@ -573,14 +944,39 @@ impl DatabaseEditor {
     }
   }
 
-  pub async fn delete_row(&self, row_id: &RowId) {
+  /// Deletes the row from the database. The rows should be deleted through
+  /// [crate::manager::DatabaseManager::delete_row], which also unlinks them from the relation
+  /// fields of the other databases.
+  pub async fn delete_row(&self, view_id: &str, row_id: &RowId) {
+    self
+      .delete_row_with_origin(view_id, row_id, ChangeOrigin::User)
+      .await
+  }
+
+  /// Same as [DatabaseEditor::delete_row], but only the changes made by the user are undoable.
+  pub async fn delete_row_with_origin(&self, view_id: &str, row_id: &RowId, origin: ChangeOrigin) {
+    if let Some(action) = self.remove_row(view_id, row_id).await {
+      self.undo_manager.record(view_id, origin, action);
+    }
+  }
+
+  /// Deletes the row without recording it, and returns the action that creates it again. Returns
+  /// None if the row is not in the view.
+  pub(crate) async fn remove_row(&self, view_id: &str, row_id: &RowId) -> Option<DatabaseAction> {
+    let action = self.create_row_action(view_id, row_id);
     let row = self.database.lock().remove_row(row_id);
     if let Some(row) = row {
       tracing::trace!("Did delete row:{:?}", row);
+      self.did_remove_files(self.get_file_urls_in_row(&row));
+      self.row_history_recorder.forget_rows(vec![row_id.clone()]);
       for view in self.database_views.editors().await {
         view.v_did_delete_row(&row).await;
       }
+      let _ = self
+        .row_event_tx
+        .send(DatabaseRowEvent::DeleteRow(row_id.clone()));
     }
+    action
   }
 
   #[tracing::instrument(level = "trace", skip_all)]
@@ -643,29 +1039,7 @@ impl DatabaseEditor {
 
   pub async fn get_cells_for_field(&self, view_id: &str, field_id: &str) -> Vec<RowCell> {
     let database = self.database.lock();
-    if let Some(field) = database.fields.get_field(field_id) {
-      let field_type = FieldType::from(field.field_type);
-      match field_type {
-        FieldType::LastEditedTime | FieldType::CreatedTime => database
-          .get_rows_for_view(view_id)
-          .into_iter()
-          .map(|row| {
-            let data = if field_type.is_created_time() {
-              TimestampCellData::new(row.created_at)
-            } else {
-              TimestampCellData::new(row.modified_at)
-            };
-            RowCell {
-              row_id: row.id,
-              cell: Some(Cell::from(data)),
-            }
-          })
-          .collect(),
-        _ => database.get_cells_for_field(view_id, field_id),
-      }
-    } else {
-      vec![]
-    }
+    cells_for_field(&database, view_id, field_id)
   }
 
   pub async fn update_cell_with_changeset<T>(
@@ -702,24 +1076,77 @@ impl DatabaseEditor {
     row_id: RowId,
     field_id: &str,
     new_cell: Cell,
+  ) -> FlowyResult<()> {
+    self
+      .update_cell_with_origin(view_id, row_id, field_id, new_cell, ChangeOrigin::User)
+      .await
+  }
+
+  /// Same as [DatabaseEditor::update_cell], but the row event tells where the change comes from.
+  pub async fn update_cell_with_origin(
+    &self,
+    view_id: &str,
+    row_id: RowId,
+    field_id: &str,
+    new_cell: Cell,
+    origin: ChangeOrigin,
   ) -> FlowyResult<()> {
     // Get the old row before updating the cell. It would be better to get the old cell
     let old_row = { self.get_row_detail(view_id, &row_id) };
+    let new_cell = carry_original_cells(
+      old_row
+        .as_ref()
+        .and_then(|row_detail| row_detail.row.cells.get(field_id)),
+      new_cell,
+    );
+    let removed_file_urls = self.get_removed_file_urls(old_row.as_ref(), field_id, &new_cell);
 
     // Get all auto updated fields. It will be used to notify the frontend
     // that the fields have been updated.
     let auto_updated_fields = self.get_auto_updated_fields(view_id);
 
+    if let Some(old_row) = old_row.as_ref() {
+      let old_cell = old_row.row.cells.get(field_id).cloned();
+      if old_cell.as_ref() != Some(&new_cell) {
+        // Only the changes made by the users are kept in the row history
+        if origin != ChangeOrigin::User {
+          self
+            .row_history_recorder
+            .skip_changes(&row_id, &[field_id.to_string()]);
+        }
+        self.undo_manager.record(
+          view_id,
+          origin,
+          DatabaseAction::UpdateCell {
+            row_id: row_id.clone(),
+            field_id: field_id.to_string(),
+            cell: old_cell,
+          },
+        );
+      }
+    }
+
     self.database.lock().update_row(&row_id, |row_update| {
       row_update.update_cells(|cell_update| {
         cell_update.insert(field_id, new_cell);
       });
     });
 
+    self.did_remove_files(removed_file_urls);
+
+    // Recompute the formula cells that depend on the updated cell.
+    let formula_field_ids = self.update_formula_cells(&row_id, Some(&[field_id.to_string()]));
+
     let option_row = self.get_row_detail(view_id, &row_id);
     if let Some(new_row_detail) = option_row {
-      let updated_row =
-        UpdatedRow::new(&new_row_detail.row.id).with_field_ids(vec![field_id.to_string()]);
+      let mut updated_field_ids = vec![field_id.to_string()];
+      updated_field_ids.extend(formula_field_ids.iter().cloned());
+      let updated_row = UpdatedRow::new(&new_row_detail.row.id)
+        .with_field_ids(updated_field_ids)
+        .with_origin(origin);
+      let _ = self
+        .row_event_tx
+        .send(DatabaseRowEvent::UpdateRow(updated_row.clone()));
       let changes = RowsChangePB::from_update(updated_row.into());
       send_notification(view_id, DatabaseNotification::DidUpdateViewRows)
         .payload(changes)
@@ -737,6 +1164,7 @@ impl DatabaseEditor {
       .into_iter()
       .map(|field| field.id)
       .collect::<Vec<String>>();
+    auto_updated_field_ids.extend(formula_field_ids);
     auto_updated_field_ids.push(field_id.to_string());
     let changeset = auto_updated_field_ids
       .into_iter()
@@ -748,7 +1176,515 @@ impl DatabaseEditor {
       .collect();
     notify_did_update_cell(changeset).await;
 
-    Ok(())
+    Ok(())
+  }
+
+  /// Uploads the file with the [FileStorageService] and attaches it to the files cell. The
+  /// storage service rejects the file if it exceeds the limits of the storage plan.
+  pub async fn upload_file(
+    &self,
+    params: UploadFileParams,
+    workspace_id: &str,
+  ) -> FlowyResult<FileAttachment> {
+    let file_storage = self.file_storage.upgrade().ok_or_else(|| {
+      FlowyError::internal().with_context("The file storage service is not available")
+    })?;
+    let path = Path::new(&params.file_path);
+    let metadata = std::fs::metadata(path).map_err(|err| {
+      FlowyError::new(
+        ErrorCode::InvalidParams,
+        format!("Can't read the file: {}, {}", params.file_path, err),
+      )
+    })?;
+    let file_name = path
+      .file_name()
+      .map(|name| name.to_string_lossy().to_string())
+      .ok_or_else(|| FlowyError::new(ErrorCode::InvalidParams, "The file name is empty"))?;
+
+    let object = StorageObject::from_file(workspace_id, &file_name, &params.file_path);
+    let mime = object.value.mime_type();
+    let url = file_storage.create_object(object).await?;
+    let file = FileAttachment::new(file_name, mime, metadata.len() as i64, url);
+
+    let changeset = FilesCellChangeset {
+      inserted_files: vec![file.clone()],
+      ..Default::default()
+    };
+    let cell_id = params.cell_id;
+    self
+      .update_cell_with_changeset(
+        &cell_id.view_id,
+        cell_id.row_id,
+        &cell_id.field_id,
+        changeset,
+      )
+      .await?;
+    Ok(file)
+  }
+
+  /// Removes the file from the files cell. The stored object is deleted after the database is
+  /// closed if no other cell references it.
+  pub async fn delete_file(&self, cell_id: CellIdParams, file_id: String) -> FlowyResult<()> {
+    let changeset = FilesCellChangeset {
+      removed_file_ids: vec![file_id],
+      ..Default::default()
+    };
+    self
+      .update_cell_with_changeset(
+        &cell_id.view_id,
+        cell_id.row_id,
+        &cell_id.field_id,
+        changeset,
+      )
+      .await
+  }
+
+  /// Returns the urls of the files that are attached to the old cell but not to the new one.
+  fn get_removed_file_urls(
+    &self,
+    old_row: Option<&RowDetail>,
+    field_id: &str,
+    new_cell: &Cell,
+  ) -> Vec<String> {
+    let is_files = self
+      .database
+      .lock()
+      .fields
+      .get_field(field_id)
+      .map(|field| FieldType::from(field.field_type).is_files())
+      .unwrap_or(false);
+    if !is_files {
+      return vec![];
+    }
+
+    let old_urls = old_row
+      .and_then(|row_detail| row_detail.row.cells.get(field_id))
+      .map(|cell| FilesCellData::from(cell).urls())
+      .unwrap_or_default();
+    let new_urls = FilesCellData::from(new_cell).urls();
+    old_urls
+      .into_iter()
+      .filter(|url| !new_urls.contains(url))
+      .collect()
+  }
+
+  /// Returns the changes of the cells in the row, ordered by the time of the change.
+  pub async fn get_row_history(&self, row_id: &RowId) -> FlowyResult<Vec<CellChange>> {
+    self.row_history_recorder.flush().await;
+    self.row_history.get_row_history(self.uid, row_id.as_str())
+  }
+
+  /// Restores the cell of the change to the value it had before the change. If `whole_row` is
+  /// true, the other cells of the row that were changed since then are restored as well. The
+  /// restoration is recorded in the history like any other change, so it can be reverted.
+  pub async fn restore_cell_change(
+    &self,
+    view_id: &str,
+    change_id: &str,
+    whole_row: bool,
+  ) -> FlowyResult<()> {
+    self.row_history_recorder.flush().await;
+    let change = self
+      .row_history
+      .get_cell_change(self.uid, change_id)?
+      .ok_or_else(|| {
+        FlowyError::record_not_found()
+          .with_context(format!("The change of the cell: {} not found", change_id))
+      })?;
+    let row_id = RowId::from(change.row_id.clone());
+    let changes = if whole_row {
+      self
+        .row_history
+        .get_row_history(self.uid, &change.row_id)?
+        .into_iter()
+        .filter(|other| other.timestamp >= change.timestamp)
+        .collect()
+    } else {
+      vec![change]
+    };
+
+    // The first change of each cell holds the value that the cell had before the changes.
+    let mut old_cells: Vec<(String, Option<Cell>)> = vec![];
+    for change in changes {
+      if !old_cells
+        .iter()
+        .any(|(field_id, _)| field_id == &change.field_id)
+      {
+        old_cells.push((change.field_id, change.old_cell));
+      }
+    }
+
+    for (field_id, old_cell) in old_cells {
+      // The cells of the deleted fields can't be restored.
+      let field = match self.get_field(&field_id) {
+        None => continue,
+        Some(field) => field,
+      };
+      let cell =
+        old_cell.unwrap_or_else(|| new_cell_builder(FieldType::from(field.field_type)).build());
+      self
+        .update_cell(view_id, row_id.clone(), &field_id, cell)
+        .await?;
+    }
+    Ok(())
+  }
+
+  /// Pops the action that reverts the last change made in the view.
+  pub(crate) fn pop_undo_action(&self, view_id: &str) -> Option<DatabaseAction> {
+    self.undo_manager.pop_undo(view_id)
+  }
+
+  /// Pops the action that reapplies the last change that was undone in the view.
+  pub(crate) fn pop_redo_action(&self, view_id: &str) -> Option<DatabaseAction> {
+    self.undo_manager.pop_redo(view_id)
+  }
+
+  /// Records the action that reverts a change, see [DatabaseUndoManager::record].
+  pub(crate) fn record_action(&self, view_id: &str, origin: ChangeOrigin, action: DatabaseAction) {
+    self.undo_manager.record(view_id, origin, action);
+  }
+
+  pub(crate) fn push_undo_action(&self, view_id: &str, action: DatabaseAction) {
+    self.undo_manager.push_undo(view_id, action);
+  }
+
+  pub(crate) fn push_redo_action(&self, view_id: &str, action: DatabaseAction) {
+    self.undo_manager.push_redo(view_id, action);
+  }
+
+  /// Returns whether the view has changes to undo and to redo.
+  pub fn can_undo_redo(&self, view_id: &str) -> (bool, bool) {
+    (
+      self.undo_manager.can_undo(view_id),
+      self.undo_manager.can_redo(view_id),
+    )
+  }
+
+  /// Returns the action that creates the row again after it's deleted, or None if the row is not
+  /// in the view.
+  fn create_row_action(&self, view_id: &str, row_id: &RowId) -> Option<DatabaseAction> {
+    let database = self.database.lock();
+    let rows = database.get_rows_for_view(view_id);
+    let index = rows.iter().position(|row| &row.id == row_id)?;
+    let prev_row_id = index
+      .checked_sub(1)
+      .map(|prev_index| rows[prev_index].id.clone());
+    Some(DatabaseAction::CreateRow {
+      row: rows[index].clone(),
+      prev_row_id,
+      related_cells: vec![],
+    })
+  }
+
+  /// Returns the action that creates the field again after it's deleted, along with its
+  /// settings, filters and sorts in the open views. Returns None if the field is not in the view.
+  async fn create_field_action(&self, view_id: &str, field_id: &str) -> Option<DatabaseAction> {
+    let fields = self.get_fields(view_id, None);
+    let index = fields.iter().position(|field| field.id == field_id)?;
+    let mut view_settings = vec![];
+    for view in self.database_views.editors().await {
+      let field_settings = view
+        .v_get_field_settings(&[field_id.to_string()])
+        .await
+        .remove(field_id);
+      let filters = view
+        .v_get_all_filters()
+        .await
+        .into_iter()
+        .filter(|filter| filter.field_id == field_id)
+        .map(|filter| filter.as_ref().clone())
+        .collect::<Vec<Filter>>();
+      let sorts = view
+        .v_get_all_sorts()
+        .await
+        .into_iter()
+        .filter(|sort| sort.field_id == field_id)
+        .collect::<Vec<Sort>>();
+      view_settings.push(FieldViewSettings {
+        view_id: view.view_id.clone(),
+        field_settings,
+        filters,
+        sorts,
+      });
+    }
+    Some(DatabaseAction::CreateField {
+      field: fields[index].clone(),
+      index,
+      view_settings,
+    })
+  }
+
+  /// Restores the settings, filters and sorts of the field that was created again. The filters
+  /// and sorts that are still in the views are skipped.
+  async fn restore_field_view_settings(&self, view_settings: Vec<FieldViewSettings>) {
+    for settings in view_settings {
+      let view = match self.database_views.get_view_editor(&settings.view_id).await {
+        Ok(view) => view,
+        Err(_) => continue,
+      };
+      if let Some(field_settings) = settings.field_settings {
+        if let Err(err) = view
+          .v_update_field_settings(
+            &settings.view_id,
+            &field_settings.field_id,
+            Some(field_settings.visibility),
+            Some(field_settings.calculation_type),
+          )
+          .await
+        {
+          tracing::warn!("Failed to restore the field settings: {}", err);
+        }
+      }
+
+      let filter_ids = view
+        .v_get_all_filters()
+        .await
+        .into_iter()
+        .map(|filter| filter.id.clone())
+        .collect::<Vec<String>>();
+      for filter in settings.filters {
+        if filter_ids.contains(&filter.id) {
+          continue;
+        }
+        let params = UpdateFilterParams {
+          view_id: settings.view_id.clone(),
+          field_id: filter.field_id,
+          filter_id: None,
+          field_type: filter.field_type,
+          condition: filter.condition,
+          content: filter.content,
+          parent_id: filter.parent_id,
+        };
+        if let Err(err) = view.v_insert_filter(params).await {
+          tracing::warn!("Failed to restore the filter: {}", err);
+        }
+      }
+
+      let sort_ids = view
+        .v_get_all_sorts()
+        .await
+        .into_iter()
+        .map(|sort| sort.id)
+        .collect::<Vec<String>>();
+      for sort in settings.sorts {
+        if sort_ids.contains(&sort.id) {
+          continue;
+        }
+        let params = UpdateSortParams {
+          view_id: settings.view_id.clone(),
+          field_id: sort.field_id,
+          sort_id: None,
+          field_type: sort.field_type,
+          condition: sort.condition,
+          by_option_order: sort.by_option_order,
+          empty_first: sort.empty_first,
+        };
+        if let Err(err) = view.v_insert_sort(params).await {
+          tracing::warn!("Failed to restore the sort: {}", err);
+        }
+      }
+    }
+  }
+
+  /// Applies the action through the same methods that the user's changes go through, so the views
+  /// are notified as usual. The changes are made with [ChangeOrigin::Undo], so they are not
+  /// recorded again. Returns the action that reverts it, or None if the row, field or group that
+  /// the action targets doesn't exist anymore.
+  ///
+  /// The [DatabaseAction::DeleteRow] is applied by [crate::manager::DatabaseManager::undo] and
+  /// [crate::manager::DatabaseManager::redo], because the rows are deleted through the manager.
+  /// The manager restores the related cells of the [DatabaseAction::CreateRow] too, because they
+  /// are in the other databases.
+  pub(crate) async fn apply_action(
+    &self,
+    view_id: &str,
+    action: DatabaseAction,
+  ) -> FlowyResult<Option<DatabaseAction>> {
+    let origin = ChangeOrigin::Undo;
+    match action {
+      DatabaseAction::UpdateCell {
+        row_id,
+        field_id,
+        cell,
+      } => {
+        let (field, row) = match (self.get_field(&field_id), self.get_row(view_id, &row_id)) {
+          (Some(field), Some(row)) => (field, row),
+          _ => return Ok(None),
+        };
+        let old_cell = row.cells.get(&field_id).cloned();
+        let cell =
+          cell.unwrap_or_else(|| new_cell_builder(FieldType::from(field.field_type)).build());
+        self
+          .update_cell_with_origin(view_id, row_id.clone(), &field_id, cell, origin)
+          .await?;
+        Ok(Some(DatabaseAction::UpdateCell {
+          row_id,
+          field_id,
+          cell: old_cell,
+        }))
+      },
+      DatabaseAction::CreateRow {
+        row, prev_row_id, ..
+      } => {
+        let row_id = row.id.clone();
+        let params = CreateRowParams {
+          id: row.id,
+          cells: row.cells,
+          height: row.height,
+          visibility: row.visibility,
+          prev_row_id,
+          timestamp: row.created_at,
+        };
+        let row_detail = self
+          .create_row_with_origin(view_id, None, params, origin)
+          .await?;
+        Ok(row_detail.map(|_| DatabaseAction::DeleteRow { row_id }))
+      },
+      DatabaseAction::DeleteRow { .. } => Err(
+        FlowyError::internal().with_context("The rows must be deleted through the DatabaseManager"),
+      ),
+      DatabaseAction::MoveRow { row_id, to_row_id } => {
+        let (from_index, to_index) = {
+          let database = self.database.lock();
+          (
+            database.index_of_row(view_id, &row_id),
+            database.index_of_row(view_id, &to_row_id),
+          )
+        };
+        let from_index = match (from_index, to_index) {
+          (Some(from_index), Some(_)) => from_index,
+          _ => return Ok(None),
+        };
+        self
+          .move_row_with_origin(view_id, row_id.clone(), to_row_id, origin)
+          .await;
+        let restore_to = self
+          .database
+          .lock()
+          .get_rows_for_view(view_id)
+          .get(from_index)
+          .map(|row| row.id.clone());
+        Ok(restore_to.map(|to_row_id| DatabaseAction::MoveRow { row_id, to_row_id }))
+      },
+      DatabaseAction::CreateField {
+        field,
+        index,
+        view_settings,
+      } => {
+        let field_id = field.id.clone();
+        let (from_index, new_field) = self.database.lock().create_field_with_mut(
+          view_id,
+          field.name.clone(),
+          field.field_type,
+          |new_field| {
+            *new_field = field.clone();
+          },
+          default_field_settings_by_layout_map(),
+        );
+        let _ = self
+          .notify_did_insert_database_field(new_field, from_index)
+          .await;
+        if from_index != index {
+          self
+            .move_field(view_id, &field_id, from_index as i32, index as i32)
+            .await?;
+        }
+        self.restore_field_view_settings(view_settings).await;
+        Ok(Some(DatabaseAction::DeleteField { field_id }))
+      },
+      DatabaseAction::DeleteField { field_id } => {
+        let action = match self.create_field_action(view_id, &field_id).await {
+          None => return Ok(None),
+          Some(action) => action,
+        };
+        self
+          .delete_field_with_origin(view_id, &field_id, origin)
+          .await?;
+        Ok(Some(action))
+      },
+      DatabaseAction::SwitchFieldType {
+        field_id,
+        field_type,
+      } => {
+        let old_field = match self.get_field(&field_id) {
+          None => return Ok(None),
+          Some(field) => field,
+        };
+        let old_field_type = FieldType::from(old_field.field_type);
+        self
+          .switch_to_field_type_with_origin(view_id, &field_id, &field_type, origin)
+          .await?;
+        if let Some(new_type_option) = self
+          .get_field(&field_id)
+          .and_then(|field| field.get_any_type_option(field.field_type))
+        {
+          self
+            .update_field_type_option(view_id, &field_id, new_type_option, old_field)
+            .await?;
+        }
+        Ok(Some(DatabaseAction::SwitchFieldType {
+          field_id,
+          field_type: old_field_type,
+        }))
+      },
+      DatabaseAction::MoveGroup {
+        group_id,
+        to_group_id,
+      } => {
+        let view = self.database_views.get_view_editor(view_id).await?;
+        let from_index = match view
+          .v_get_group_ids()
+          .await
+          .iter()
+          .position(|id| id == &group_id)
+        {
+          None => return Ok(None),
+          Some(index) => index,
+        };
+        self
+          .move_group_with_origin(view_id, &group_id, &to_group_id, origin)
+          .await?;
+        let restore_to = view.v_get_group_ids().await.get(from_index).cloned();
+        Ok(restore_to.map(|to_group_id| DatabaseAction::MoveGroup {
+          group_id,
+          to_group_id,
+        }))
+      },
+    }
+  }
+
+  fn get_file_urls_in_row(&self, row: &Row) -> Vec<String> {
+    self
+      .database
+      .lock()
+      .get_fields(None)
+      .into_iter()
+      .filter(|field| FieldType::from(field.field_type).is_files())
+      .filter_map(|field| row.cells.get(&field.id))
+      .flat_map(|cell| FilesCellData::from(cell).urls())
+      .collect()
+  }
+
+  /// Remembers the urls of the files that were removed from the cells. The files are not deleted
+  /// right away, because other cells, e.g. of a duplicated row, can still reference them and the
+  /// removal can be undone while the database is open.
+  fn did_remove_files(&self, urls: Vec<String>) {
+    if !urls.is_empty() {
+      self.removed_file_urls.lock().extend(urls);
+    }
+  }
+
+  /// Returns the urls of the files that were removed from the cells since the last call.
+  pub fn take_removed_file_urls(&self) -> HashSet<String> {
+    std::mem::take(&mut *self.removed_file_urls.lock())
+  }
+
+  /// Returns the urls of the files that the cells reference or that were removed from the cells
+  /// while the database is open. The removed files can still be restored by undoing the removal.
+  pub fn get_used_file_urls(&self) -> HashSet<String> {
+    let mut urls = get_file_urls(&self.database.lock());
+    urls.extend(self.removed_file_urls.lock().iter().cloned());
+    urls
   }
 
   /// Just create an option for the field's type option. The option is save to the database.
@@ -891,6 +1827,361 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  pub fn get_database_id(&self) -> String {
+    self.database.lock().get_database_id()
+  }
+
+  pub fn get_inline_view_id(&self) -> String {
+    self.database.lock().get_inline_view_id()
+  }
+
+  pub fn get_relation_type_option(&self, field_id: &str) -> FlowyResult<RelationTypeOption> {
+    let field = self.get_field(field_id).ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Field with id:{} not found", field_id))
+    })?;
+    let field_type = FieldType::from(field.field_type);
+    if !field_type.is_relation() {
+      return Err(FlowyError::new(
+        ErrorCode::FieldInvalidOperation,
+        format!("Field with id:{} is not a relation field", field_id),
+      ));
+    }
+    Ok(
+      field
+        .get_type_option::<RelationTypeOption>(field_type)
+        .unwrap_or_default(),
+    )
+  }
+
+  /// Returns the reminder of the date cell. It's scheduled before the first occurrence of the date
+  /// whose reminder time is not earlier than `now`. Returns None if the cell has no reminder or
+  /// the date doesn't occur anymore.
+  pub async fn get_date_reminder(
+    &self,
+    view_id: &str,
+    row_id: &RowId,
+    field_id: &str,
+    now: i64,
+  ) -> Option<DateReminder> {
+    let (field, cell_data, title) = {
+      let database = self.database.lock();
+      let field = database.fields.get_field(field_id)?;
+      let cell_data = DateCellData::from(&database.get_cell(field_id, row_id).cell?);
+      let title = database
+        .fields
+        .get_primary_field()
+        .and_then(|primary_field| {
+          let field_type = FieldType::from(primary_field.field_type);
+          let cell = database.get_cell(&primary_field.id, row_id).cell?;
+          Some(stringify_cell_data(
+            &cell,
+            &field_type,
+            &field_type,
+            &primary_field,
+          ))
+        })
+        .unwrap_or_default();
+      (field, cell_data, title)
+    };
+
+    let reminder_offset = cell_data.reminder_offset?;
+    let type_option = field
+      .get_type_option::<DateTypeOption>(FieldType::DateTime)
+      .unwrap_or_default();
+    let occurrence =
+      type_option.next_occurrence(&cell_data, now.saturating_add(reminder_offset))?;
+    let message =
+      type_option.stringify_cell_data(DateCellData::new(occurrence, cell_data.include_time, false));
+    Some(DateReminder {
+      id: DateReminder::reminder_id(row_id.as_str(), field_id),
+      view_id: view_id.to_string(),
+      row_id: row_id.clone().into_inner(),
+      field_id: field_id.to_string(),
+      scheduled_at: occurrence - reminder_offset,
+      title,
+      message,
+    })
+  }
+
+  /// Returns the ids of the reminders of the date cells in the row.
+  pub fn get_row_reminder_ids(&self, row_id: &RowId) -> Vec<String> {
+    let database = self.database.lock();
+    database
+      .get_fields(None)
+      .into_iter()
+      .filter(|field| FieldType::from(field.field_type).is_date())
+      .filter(|field| {
+        database
+          .get_cell(&field.id, row_id)
+          .cell
+          .map_or(false, |cell| {
+            DateCellData::from(&cell).reminder_offset.is_some()
+          })
+      })
+      .map(|field| DateReminder::reminder_id(row_id.as_str(), &field.id))
+      .collect()
+  }
+
+  /// Returns the content of the primary field of the rows. The rows that don't exist are skipped.
+  pub fn get_related_rows(&self, row_ids: &[RowId]) -> Vec<RelatedRowDataPB> {
+    let database = self.database.lock();
+    let primary_field = database.fields.get_primary_field();
+    row_ids
+      .iter()
+      .filter_map(|row_id| {
+        let row_detail = database.get_row_detail(row_id)?;
+        let name = primary_field
+          .as_ref()
+          .and_then(|field| {
+            let field_type = FieldType::from(field.field_type);
+            let cell = row_detail.row.cells.get(&field.id)?;
+            Some(stringify_cell_data(cell, &field_type, &field_type, field))
+          })
+          .unwrap_or_default();
+        Some(RelatedRowDataPB {
+          row_id: row_id.clone().into_inner(),
+          name,
+        })
+      })
+      .collect()
+  }
+
+  pub fn get_lookup_type_option(&self, field_id: &str) -> FlowyResult<LookupTypeOption> {
+    let field = self.get_field(field_id).ok_or_else(|| {
+      FlowyError::record_not_found().with_context(format!("Field with id:{} not found", field_id))
+    })?;
+    let field_type = FieldType::from(field.field_type);
+    if !field_type.is_lookup() {
+      return Err(FlowyError::new(
+        ErrorCode::FieldInvalidOperation,
+        format!("Field with id:{} is not a lookup field", field_id),
+      ));
+    }
+    Ok(
+      field
+        .get_type_option::<LookupTypeOption>(field_type)
+        .unwrap_or_default(),
+    )
+  }
+
+  /// Returns the lookup fields of the database whose type option is fully configured.
+  pub fn get_lookup_fields(&self) -> Vec<(Field, LookupTypeOption)> {
+    self
+      .database
+      .lock()
+      .get_fields(None)
+      .into_iter()
+      .filter(|field| FieldType::from(field.field_type).is_lookup())
+      .filter_map(|field| {
+        let type_option = field
+          .get_type_option::<LookupTypeOption>(FieldType::Lookup)
+          .filter(|type_option| type_option.is_configured())?;
+        Some((field, type_option))
+      })
+      .collect()
+  }
+
+  /// Returns the relation fields of the database that link to a database.
+  pub fn get_relation_fields(&self) -> Vec<(Field, RelationTypeOption)> {
+    self
+      .database
+      .lock()
+      .get_fields(None)
+      .into_iter()
+      .filter(|field| FieldType::from(field.field_type).is_relation())
+      .filter_map(|field| {
+        let type_option = field
+          .get_type_option::<RelationTypeOption>(FieldType::Relation)
+          .filter(|type_option| !type_option.database_id.is_empty())?;
+        Some((field, type_option))
+      })
+      .collect()
+  }
+
+  /// Returns the rows of `row_ids` that are not in the database anymore. The order of the rows
+  /// is stored with the database, so the rows that are not loaded yet are not treated as deleted.
+  pub fn get_deleted_rows(&self, row_ids: &[RowId]) -> Vec<RowId> {
+    let database = self.database.lock();
+    let inline_view_id = database.get_inline_view_id();
+    row_ids
+      .iter()
+      .filter(|row_id| database.index_of_row(&inline_view_id, row_id).is_none())
+      .cloned()
+      .collect()
+  }
+
+  /// Returns the rows of the database with `database_id` that the relation cells link to.
+  pub async fn get_linked_rows(&self, database_id: &str) -> Vec<RowId> {
+    let view_id = self.get_inline_view_id();
+    let mut row_ids: Vec<RowId> = vec![];
+    for (field, type_option) in self.get_relation_fields() {
+      if type_option.database_id != database_id {
+        continue;
+      }
+      for row_cell in self.get_cells_for_field(&view_id, &field.id).await {
+        if let Some(cell) = row_cell.cell.as_ref() {
+          for row_id in RelationCellData::from(cell).row_ids {
+            if !row_ids.contains(&row_id) {
+              row_ids.push(row_id);
+            }
+          }
+        }
+      }
+    }
+    row_ids
+  }
+
+  /// Saves the members in the type option of the person field, so they can be assigned to its
+  /// cells. The members that are already saved are refreshed, and the others are kept, so the
+  /// names of the members assigned to the other cells can still be resolved.
+  pub async fn add_person_members(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    members: Vec<PersonMember>,
+  ) -> FlowyResult<()> {
+    let field = self
+      .get_field(field_id)
+      .filter(|field| FieldType::from(field.field_type).is_person())
+      .ok_or_else(|| FlowyError::record_not_found().with_context("The person field not found"))?;
+    let mut type_option = field
+      .get_type_option::<PersonTypeOption>(FieldType::Person)
+      .unwrap_or_default();
+
+    let mut is_changed = false;
+    for member in members {
+      match type_option
+        .members
+        .iter_mut()
+        .find(|saved_member| saved_member.uid == member.uid)
+      {
+        Some(saved_member) if *saved_member == member => {},
+        Some(saved_member) => {
+          *saved_member = member;
+          is_changed = true;
+        },
+        None => {
+          type_option.members.push(member);
+          is_changed = true;
+        },
+      }
+    }
+    if !is_changed {
+      return Ok(());
+    }
+    self
+      .update_field_type_option(view_id, field_id, type_option.into(), field)
+      .await
+  }
+
+  /// Unlinks the rows from the cells of the relation fields that link to the database with
+  /// `database_id`. It's called after the rows were deleted from that database.
+  pub async fn remove_related_rows(
+    &self,
+    database_id: &str,
+    row_ids: &[RowId],
+  ) -> Vec<RelatedCell> {
+    let view_id = self.get_inline_view_id();
+    let fields = self
+      .get_relation_fields()
+      .into_iter()
+      .filter(|(_, type_option)| type_option.database_id == database_id)
+      .map(|(field, _)| field)
+      .collect::<Vec<Field>>();
+
+    let removed_row_ids = row_ids
+      .iter()
+      .map(|row_id| row_id.clone().into_inner())
+      .collect::<Vec<String>>();
+    let mut related_cells = vec![];
+    for field in fields {
+      for row_cell in self.get_cells_for_field(&view_id, &field.id).await {
+        let linked_row_ids = row_cell
+          .cell
+          .as_ref()
+          .map(|cell| {
+            RelationCellData::from(cell)
+              .row_ids
+              .into_iter()
+              .filter(|row_id| row_ids.contains(row_id))
+              .map(|row_id| row_id.into_inner())
+              .collect::<Vec<String>>()
+          })
+          .unwrap_or_default();
+        if linked_row_ids.is_empty() {
+          continue;
+        }
+
+        // Unlinking the rows is not a change made by the user, so it's not undoable.
+        let changeset = RelationCellChangeset {
+          removed_row_ids: removed_row_ids.clone(),
+          ..Default::default()
+        };
+        let cell_cache = Some(self.cell_cache.clone());
+        let new_cell = match apply_cell_changeset(changeset, row_cell.cell, &field, cell_cache) {
+          Ok(new_cell) => new_cell,
+          Err(err) => {
+            tracing::error!("Failed to unlink the deleted rows: {}", err);
+            continue;
+          },
+        };
+        let origin = ChangeOrigin::Relation;
+        match self
+          .update_cell_with_origin(
+            &view_id,
+            row_cell.row_id.clone(),
+            &field.id,
+            new_cell,
+            origin,
+          )
+          .await
+        {
+          Ok(_) => related_cells.push(RelatedCell {
+            database_id: self.get_database_id(),
+            row_id: row_cell.row_id,
+            field_id: field.id.clone(),
+            linked_row_ids,
+          }),
+          Err(err) => tracing::error!("Failed to unlink the deleted rows: {}", err),
+        }
+      }
+    }
+    related_cells
+  }
+
+  /// Links the rows again to the relation cell that they were unlinked from when they were
+  /// deleted, see [DatabaseEditor::remove_related_rows].
+  pub async fn restore_related_cell(&self, related_cell: RelatedCell) {
+    let field = match self.get_field(&related_cell.field_id) {
+      None => return,
+      Some(field) => field,
+    };
+    if self.get_row_cells(&related_cell.row_id).is_none() {
+      return;
+    }
+    let view_id = self.get_inline_view_id();
+    let cell = self.get_cell(&field.id, &related_cell.row_id).await;
+    let changeset = RelationCellChangeset {
+      inserted_row_ids: related_cell.linked_row_ids,
+      ..Default::default()
+    };
+    let cell_cache = Some(self.cell_cache.clone());
+    let new_cell = match apply_cell_changeset(changeset, cell, &field, cell_cache) {
+      Ok(new_cell) => new_cell,
+      Err(err) => {
+        tracing::error!("Failed to link the restored rows: {}", err);
+        return;
+      },
+    };
+    let origin = ChangeOrigin::Relation;
+    if let Err(err) = self
+      .update_cell_with_origin(&view_id, related_cell.row_id, &field.id, new_cell, origin)
+      .await
+    {
+      tracing::error!("Failed to link the restored rows: {}", err);
+    }
+  }
+
   #[tracing::instrument(level = "trace", skip_all, err)]
   pub async fn load_groups(&self, view_id: &str) -> FlowyResult<RepeatedGroupPB> {
     let view = self.database_views.get_view_editor(view_id).await?;
@@ -898,6 +2189,13 @@ impl DatabaseEditor {
     Ok(RepeatedGroupPB { items: groups })
   }
 
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn load_sub_groups(&self, view_id: &str) -> FlowyResult<RepeatedGroupPB> {
+    let view = self.database_views.get_view_editor(view_id).await?;
+    let groups = view.v_load_sub_groups().await.unwrap_or_default();
+    Ok(RepeatedGroupPB { items: groups })
+  }
+
   #[tracing::instrument(level = "trace", skip_all, err)]
   pub async fn get_group(&self, view_id: &str, group_id: &str) -> FlowyResult<GroupPB> {
     let view = self.database_views.get_view_editor(view_id).await?;
@@ -911,6 +2209,19 @@ impl DatabaseEditor {
     view_id: &str,
     from_group: &str,
     to_group: &str,
+  ) -> FlowyResult<()> {
+    self
+      .move_group_with_origin(view_id, from_group, to_group, ChangeOrigin::User)
+      .await
+  }
+
+  /// Same as [DatabaseEditor::move_group], but only the changes made by the user are undoable.
+  pub async fn move_group_with_origin(
+    &self,
+    view_id: &str,
+    from_group: &str,
+    to_group: &str,
+    origin: ChangeOrigin,
   ) -> FlowyResult<()> {
     // Do nothing if the group is the same
     if from_group == to_group {
@@ -918,7 +2229,28 @@ impl DatabaseEditor {
     }
 
     let view = self.database_views.get_view_editor(view_id).await?;
+    let from_index = view
+      .v_get_group_ids()
+      .await
+      .iter()
+      .position(|group_id| group_id == from_group);
     view.v_move_group(from_group, to_group).await?;
+
+    // The group that takes the old position of the moved group is used to move it back.
+    let restore_to = match from_index {
+      None => None,
+      Some(from_index) => view.v_get_group_ids().await.get(from_index).cloned(),
+    };
+    if let Some(restore_to) = restore_to {
+      self.undo_manager.record(
+        view_id,
+        origin,
+        DatabaseAction::MoveGroup {
+          group_id: from_group.to_string(),
+          to_group_id: restore_to,
+        },
+      );
+    }
     Ok(())
   }
 
@@ -929,6 +2261,7 @@ impl DatabaseEditor {
     to_group: &str,
     from_row: RowId,
     to_row: Option<RowId>,
+    to_sub_group: Option<&str>,
   ) -> FlowyResult<()> {
     let row_detail = self.get_row_detail(view_id, &from_row);
     match row_detail {
@@ -942,7 +2275,13 @@ impl DatabaseEditor {
         let mut row_changeset = RowChangeset::new(row_detail.row.id.clone());
         let view = self.database_views.get_view_editor(view_id).await?;
         view
-          .v_move_group_row(&row_detail, &mut row_changeset, to_group, to_row)
+          .v_move_group_row(
+            &row_detail,
+            &mut row_changeset,
+            to_group,
+            to_row,
+            to_sub_group,
+          )
           .await;
 
         tracing::trace!("Row data changed: {:?}", row_changeset);
@@ -986,9 +2325,16 @@ impl DatabaseEditor {
   }
 
   #[tracing::instrument(level = "trace", skip_all)]
-  pub async fn get_all_calendar_events(&self, view_id: &str) -> Vec<CalendarEventPB> {
+  pub async fn get_all_calendar_events(
+    &self,
+    view_id: &str,
+    window: Option<CalendarWindow>,
+  ) -> Vec<CalendarEventPB> {
     match self.database_views.get_view_editor(view_id).await {
-      Ok(view) => view.v_get_all_calendar_events().await.unwrap_or_default(),
+      Ok(view) => view
+        .v_get_all_calendar_events(window)
+        .await
+        .unwrap_or_default(),
       Err(_) => {
         tracing::warn!("Can not find the view: {}", view_id);
         vec![]
@@ -1112,6 +2458,45 @@ impl DatabaseEditor {
     Ok(csv)
   }
 
+  pub async fn export_json(&self, view_id: &str) -> FlowyResult<String> {
+    let database = self.database.clone();
+    let view_id = view_id.to_string();
+    let json = tokio::task::spawn_blocking(move || {
+      let database_guard = database.lock();
+      JSONExport.export_database(&database_guard, &view_id)
+    })
+    .await
+    .map_err(internal_error)??;
+    Ok(json)
+  }
+
+  pub fn get_view_name(&self, view_id: &str) -> Option<String> {
+    self.database.lock().get_view(view_id).map(|view| view.name)
+  }
+
+  /// Returns the fields that are not hidden in the view, and the rows of the view after its
+  /// filters and sorts are applied.
+  pub async fn get_visible_fields_and_rows(
+    &self,
+    view_id: &str,
+  ) -> FlowyResult<(Vec<Field>, Vec<Arc<RowDetail>>)> {
+    let layout_ty = self.get_layout_type(view_id).await;
+    let hidden_field_ids = self
+      .get_all_field_settings(view_id, layout_ty)
+      .await?
+      .into_iter()
+      .filter(|field_settings| field_settings.visibility == FieldVisibility::AlwaysHidden)
+      .map(|field_settings| field_settings.field_id)
+      .collect::<Vec<String>>();
+    let fields = self
+      .get_fields(view_id, None)
+      .into_iter()
+      .filter(|field| !hidden_field_ids.contains(&field.id))
+      .collect();
+    let rows = self.get_rows(view_id).await?;
+    Ok((fields, rows))
+  }
+
   pub async fn get_field_settings(
     &self,
     view_id: &str,
@@ -1172,14 +2557,152 @@ impl DatabaseEditor {
     &self,
     params: FieldSettingsChangesetParams,
   ) -> FlowyResult<()> {
+    if let Some(calculation_type) = params.calculation_type {
+      let field = self
+        .get_field(&params.field_id)
+        .ok_or_else(|| FlowyError::field_record_not_found().with_context(&params.field_id))?;
+      let field_type = FieldType::from(field.field_type);
+      if !calculation_type.is_supported_by(&field_type) {
+        return Err(FlowyError::new(
+          ErrorCode::FieldInvalidOperation,
+          format!(
+            "The calculation {:?} is not supported by the field type {:?}",
+            calculation_type, field_type
+          ),
+        ));
+      }
+    }
+
     let view = self.database_views.get_view_editor(&params.view_id).await?;
     view
-      .v_update_field_settings(&params.view_id, &params.field_id, params.visibility)
+      .v_update_field_settings(
+        &params.view_id,
+        &params.field_id,
+        params.visibility,
+        params.calculation_type,
+      )
       .await?;
 
     Ok(())
   }
 
+  pub async fn get_calculations(&self, view_id: &str) -> FlowyResult<RepeatedCalculationsPB> {
+    let view = self.database_views.get_view_editor(view_id).await?;
+    let items = view.v_get_calculations().await;
+    Ok(RepeatedCalculationsPB {
+      view_id: view_id.to_string(),
+      items,
+    })
+  }
+
+  /// Re-evaluates the formula cells of the row and saves the results. Check out
+  /// [FormulaEvaluator::evaluate] for the formulas that are evaluated for `changed_field_ids`.
+  ///
+  /// Returns the ids of the formula fields whose cells were updated.
+  fn update_formula_cells(
+    &self,
+    row_id: &RowId,
+    changed_field_ids: Option<&[String]>,
+  ) -> Vec<String> {
+    let database = self.database.lock();
+    let fields = database.get_fields(None);
+    let evaluator = self.formula_evaluator(&fields);
+    update_row_formula_cells(&database, &evaluator, row_id, changed_field_ids)
+  }
+
+  /// The formulas are evaluated in the timezone of the user.
+  fn formula_evaluator<'a>(&self, fields: &'a [Field]) -> FormulaEvaluator<'a> {
+    FormulaEvaluator::new(fields, &self.user.get_date_time_settings().timezone_id)
+  }
+
+  /// Re-evaluates the cells of the formula field in all the rows. It's called after the formula
+  /// changed.
+  async fn refresh_formula_field(&self, view_id: &str, field_id: &str) {
+    let row_ids = self
+      .database
+      .lock()
+      .get_rows_for_view(view_id)
+      .into_iter()
+      .map(|row| row.id)
+      .collect::<Vec<RowId>>();
+    self
+      .refresh_formula_cells(
+        &[view_id.to_string()],
+        row_ids,
+        Some(&[field_id.to_string()]),
+      )
+      .await;
+  }
+
+  /// Evaluates the formula cells of the rows fetched from the server. The rows might have been
+  /// created or imported by a client that didn't evaluate them.
+  pub async fn did_fetch_rows(&self, row_ids: Vec<RowId>) {
+    let row_ids = {
+      let database = self.database.lock();
+      row_ids
+        .into_iter()
+        .filter(|row_id| database.get_row_detail(row_id).is_some())
+        .collect::<Vec<RowId>>()
+    };
+    if row_ids.is_empty() {
+      return;
+    }
+    let view_ids = self
+      .database_views
+      .editors()
+      .await
+      .iter()
+      .map(|view| view.view_id.clone())
+      .collect::<Vec<String>>();
+    self.refresh_formula_cells(&view_ids, row_ids, None).await;
+  }
+
+  /// Re-evaluates the formula cells of the rows and notifies the views of the updated cells.
+  async fn refresh_formula_cells(
+    &self,
+    view_ids: &[String],
+    row_ids: Vec<RowId>,
+    changed_field_ids: Option<&[String]>,
+  ) {
+    let updated_rows = {
+      let database = self.database.lock();
+      let fields = database.get_fields(None);
+      let evaluator = self.formula_evaluator(&fields);
+      if evaluator.is_empty() {
+        return;
+      }
+      row_ids
+        .into_iter()
+        .map(|row_id| {
+          let updated_field_ids =
+            update_row_formula_cells(&database, &evaluator, &row_id, changed_field_ids);
+          (row_id, updated_field_ids)
+        })
+        .collect::<Vec<_>>()
+    };
+
+    let mut changesets = vec![];
+    for (row_id, updated_field_ids) in updated_rows {
+      if updated_field_ids.is_empty() {
+        continue;
+      }
+      for view_id in view_ids {
+        for updated_field_id in updated_field_ids.iter() {
+          changesets.push(CellChangesetNotifyPB {
+            view_id: view_id.clone(),
+            row_id: row_id.clone().into_inner(),
+            field_id: updated_field_id.clone(),
+          });
+        }
+      }
+      let updated_row = UpdatedRow::new(&row_id).with_field_ids(updated_field_ids);
+      let _ = self
+        .row_event_tx
+        .send(DatabaseRowEvent::UpdateRow(updated_row));
+    }
+    notify_did_update_cell(changesets).await;
+  }
+
   fn get_auto_updated_fields(&self, view_id: &str) -> Vec<Field> {
     self
       .database
@@ -1197,6 +2720,62 @@ impl DatabaseEditor {
   }
 }
 
+/// Re-evaluates the formula cells of the row with the evaluator and saves the results. Returns the
+/// ids of the formula fields whose cells were updated.
+fn update_row_formula_cells(
+  database: &Database,
+  evaluator: &FormulaEvaluator,
+  row_id: &RowId,
+  changed_field_ids: Option<&[String]>,
+) -> Vec<String> {
+  if evaluator.is_empty() {
+    return vec![];
+  }
+
+  let mut row = database.get_row(row_id);
+  let updated_field_ids = evaluator.evaluate(
+    &mut row.cells,
+    row.created_at,
+    row.modified_at,
+    changed_field_ids,
+  );
+  if !updated_field_ids.is_empty() {
+    database.update_row(row_id, |row_update| {
+      row_update.update_cells(|cell_update| {
+        for field_id in updated_field_ids.iter() {
+          if let Some(cell) = row.cells.get(field_id) {
+            cell_update.insert(field_id, cell.clone());
+          }
+        }
+      });
+    });
+  }
+  updated_field_ids
+}
+
+/// Returns the type option of the field for the new field type, transformed from the type option
+/// of its current field type.
+fn switched_type_option(field: &Field, new_field_type: &FieldType) -> TypeOptionData {
+  let old_field_type = FieldType::from(field.field_type);
+  let old_type_option = field.get_any_type_option(old_field_type.clone());
+  let new_type_option = field
+    .get_any_type_option(new_field_type)
+    .unwrap_or_else(|| default_type_option_data_from_type(new_field_type));
+  transform_type_option(
+    &new_type_option,
+    new_field_type,
+    old_type_option,
+    old_field_type,
+  )
+}
+
+/// Returns the cell as a string, as it's shown in a field of the given type. The cell might have
+/// been written with another field type.
+fn stringify_cell_with_field_type(cell: &Cell, field: &Field, field_type: &FieldType) -> String {
+  let cell_field_type = get_field_type_from_cell::<FieldType>(cell).unwrap_or(field_type.clone());
+  stringify_cell_data(cell, &cell_field_type, field_type, field)
+}
+
 pub(crate) async fn notify_did_update_cell(changesets: Vec<CellChangesetNotifyPB>) {
   for changeset in changesets {
     let id = format!("{}:{}", changeset.row_id, changeset.field_id);
@@ -1204,6 +2783,35 @@ pub(crate) async fn notify_did_update_cell(changesets: Vec<CellChangesetNotifyPB
   }
 }
 
+/// The cells of the [FieldType::LastEditedTime] and [FieldType::CreatedTime] are not stored in the
+/// row, they are built from the timestamps of the row.
+fn cells_for_field(database: &Database, view_id: &str, field_id: &str) -> Vec<RowCell> {
+  match database.fields.get_field(field_id) {
+    Some(field) => {
+      let field_type = FieldType::from(field.field_type);
+      match field_type {
+        FieldType::LastEditedTime | FieldType::CreatedTime => database
+          .get_rows_for_view(view_id)
+          .into_iter()
+          .map(|row| {
+            let data = if field_type.is_created_time() {
+              TimestampCellData::new(row.created_at)
+            } else {
+              TimestampCellData::new(row.modified_at)
+            };
+            RowCell {
+              row_id: row.id,
+              cell: Some(Cell::from(data)),
+            }
+          })
+          .collect(),
+        _ => database.get_cells_for_field(view_id, field_id),
+      }
+    },
+    None => vec![],
+  }
+}
+
 fn cell_changesets_from_cell_by_field_id(
   view_id: &str,
   row_id: RowId,
@@ -1224,6 +2832,8 @@ struct DatabaseViewDataImpl {
   database: Arc<MutexDatabase>,
   task_scheduler: Arc<RwLock<TaskDispatcher>>,
   cell_cache: CellCache,
+  uid: i64,
+  user: Arc<dyn DatabaseUser>,
 }
 
 impl DatabaseViewData for DatabaseViewDataImpl {
@@ -1310,7 +2920,7 @@ impl DatabaseViewData for DatabaseViewDataImpl {
   }
 
   fn get_cells_for_field(&self, view_id: &str, field_id: &str) -> Fut<Vec<Arc<RowCell>>> {
-    let cells = self.database.lock().get_cells_for_field(view_id, field_id);
+    let cells = cells_for_field(&self.database.lock(), view_id, field_id);
     to_fut(async move { cells.into_iter().map(Arc::new).collect() })
   }
 
@@ -1328,7 +2938,18 @@ impl DatabaseViewData for DatabaseViewDataImpl {
   }
 
   fn insert_group_setting(&self, view_id: &str, setting: GroupSetting) {
-    self.database.lock().insert_group_setting(view_id, setting);
+    let database = self.database.lock();
+    let mut settings: Vec<GroupSetting> = database.get_all_group_setting(view_id);
+    settings.retain(|other| other.is_sub_group != setting.is_sub_group);
+    // Keep the grouping setting in front of the sub-grouping setting
+    if setting.is_sub_group {
+      settings.push(setting);
+    } else {
+      settings.insert(0, setting);
+    }
+    database.views.update_database_view(view_id, |view| {
+      view.set_groups(settings.into_iter().map(|setting| setting.into()).collect());
+    });
   }
 
   fn get_sort(&self, view_id: &str, sort_id: &str) -> Option<Sort> {
@@ -1376,11 +2997,19 @@ impl DatabaseViewData for DatabaseViewDataImpl {
       .get_filter::<Filter>(view_id, filter_id)
   }
 
-  fn get_filter_by_field_id(&self, view_id: &str, field_id: &str) -> Option<Filter> {
+  fn get_all_filter_groups(&self, view_id: &str) -> Vec<FilterGroup> {
+    self.database.lock().get_all_filters(view_id)
+  }
+
+  fn get_filter_group(&self, view_id: &str, group_id: &str) -> Option<FilterGroup> {
     self
       .database
       .lock()
-      .get_filter_by_field_id::<Filter>(view_id, field_id)
+      .get_filter::<FilterGroup>(view_id, group_id)
+  }
+
+  fn insert_filter_group(&self, view_id: &str, filter_group: FilterGroup) {
+    self.database.lock().insert_filter(view_id, filter_group);
   }
 
   fn get_layout_setting(&self, view_id: &str, layout_ty: &DatabaseLayout) -> Option<LayoutSetting> {
@@ -1410,6 +3039,14 @@ impl DatabaseViewData for DatabaseViewDataImpl {
     self.task_scheduler.clone()
   }
 
+  fn get_user_id(&self) -> i64 {
+    self.uid
+  }
+
+  fn get_date_time_settings(&self) -> UserDateTimeSettings {
+    self.user.get_date_time_settings()
+  }
+
   fn get_type_option_cell_handler(
     &self,
     field: &Field,
@@ -1463,10 +3100,11 @@ impl DatabaseViewData for DatabaseViewDataImpl {
     view_id: &str,
     field_id: &str,
     visibility: Option<FieldVisibility>,
+    calculation_type: Option<CalculationType>,
   ) {
     let field_settings_map = self.get_field_settings(view_id, &[field_id.to_string()]);
 
-    let new_field_settings = if let Some(field_settings) = field_settings_map.get(field_id) {
+    let mut new_field_settings = if let Some(field_settings) = field_settings_map.get(field_id) {
       let mut field_settings = field_settings.to_owned();
       field_settings.visibility = visibility.unwrap_or(field_settings.visibility);
       field_settings
@@ -1483,6 +3121,9 @@ impl DatabaseViewData for DatabaseViewDataImpl {
       field_settings.visibility = visibility.unwrap_or(field_settings.visibility);
       field_settings
     };
+    if let Some(calculation_type) = calculation_type {
+      new_field_settings.calculation_type = calculation_type;
+    }
 
     self.database.lock().update_field_settings(
       view_id,
@@ -19,6 +19,20 @@ pub struct InsertedRow {
   pub is_new: bool,
 }
 
+/// Where a change of the database comes from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChangeOrigin {
+  /// The change is made by the user
+  #[default]
+  User,
+  /// The lookup cells are refreshed from the rows of their source database
+  Lookup,
+  /// A change of the user is undone or redone
+  Undo,
+  /// The links to the deleted rows are removed from the relation cells
+  Relation,
+}
+
 #[derive(Debug, Clone)]
 pub struct UpdatedRow {
   pub row_id: String,
@@ -30,6 +44,8 @@ pub struct UpdatedRow {
 
   /// The meta of row was updated if this is Some.
   pub row_detail: Option<RowDetail>,
+
+  pub origin: ChangeOrigin,
 }
 
 impl UpdatedRow {
@@ -39,6 +55,7 @@ impl UpdatedRow {
       height: None,
       field_ids: vec![],
       row_detail: None,
+      origin: ChangeOrigin::User,
     }
   }
 
@@ -56,6 +73,11 @@ impl UpdatedRow {
     self.row_detail = Some(row_detail);
     self
   }
+
+  pub fn with_origin(mut self, origin: ChangeOrigin) -> Self {
+    self.origin = origin;
+    self
+  }
 }
 
 #[derive(Debug, Clone)]
@@ -1,7 +1,9 @@
 mod database_editor;
 mod entities;
+mod undo;
 mod util;
 
 pub use database_editor::*;
 pub use entities::*;
+pub use undo::*;
 pub(crate) use util::database_view_setting_pb_from_view;
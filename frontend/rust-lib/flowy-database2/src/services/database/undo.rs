@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, Row, RowId};
+use parking_lot::Mutex;
+
+use crate::entities::FieldType;
+use crate::services::database::ChangeOrigin;
+use crate::services::field_settings::FieldSettings;
+use crate::services::filter::Filter;
+use crate::services::sort::Sort;
+
+/// The maximum number of actions that can be undone in a view.
+const MAX_UNDO_ACTIONS: usize = 100;
+
+/// An action that reverts a change of the database. Applying an action returns the action that
+/// reverts it in turn, so the same actions are used to undo and redo.
+#[derive(Debug, Clone)]
+pub enum DatabaseAction {
+  /// Replaces the cell. An empty cell is inserted if the cell is None.
+  UpdateCell {
+    row_id: RowId,
+    field_id: String,
+    cell: Option<Cell>,
+  },
+  /// Creates the row again with the same id, after the `prev_row_id`, and links it again to the
+  /// `related_cells`. The reminders of the row are removed when it's deleted, so they can't be
+  /// restored.
+  CreateRow {
+    row: Row,
+    prev_row_id: Option<RowId>,
+    related_cells: Vec<RelatedCell>,
+  },
+  /// Deletes the row. It's applied by the [crate::manager::DatabaseManager], because the rows are
+  /// deleted through it.
+  DeleteRow {
+    row_id: RowId,
+  },
+  /// Moves the row to the position of the `to_row_id`
+  MoveRow {
+    row_id: RowId,
+    to_row_id: RowId,
+  },
+  /// Creates the field again with the same id at the `index`. The cells of a deleted field are
+  /// kept in the rows, so they come back with the field, and its settings, filters and sorts are
+  /// restored in the views that had them.
+  CreateField {
+    field: Field,
+    index: usize,
+    view_settings: Vec<FieldViewSettings>,
+  },
+  DeleteField {
+    field_id: String,
+  },
+  SwitchFieldType {
+    field_id: String,
+    field_type: FieldType,
+  },
+  /// Moves the group to the position of the `to_group_id`
+  MoveGroup {
+    group_id: String,
+    to_group_id: String,
+  },
+}
+
+/// The settings, filters and sorts of a field in a view
+#[derive(Debug, Clone)]
+pub struct FieldViewSettings {
+  pub view_id: String,
+  pub field_settings: Option<FieldSettings>,
+  pub filters: Vec<Filter>,
+  pub sorts: Vec<Sort>,
+}
+
+/// A relation cell of another database that was unlinked from the rows when they were deleted
+#[derive(Debug, Clone)]
+pub struct RelatedCell {
+  pub database_id: String,
+  pub row_id: RowId,
+  pub field_id: String,
+  /// The ids of the deleted rows that the cell linked to
+  pub linked_row_ids: Vec<String>,
+}
+
+#[derive(Default)]
+struct UndoStack {
+  undo: Vec<DatabaseAction>,
+  redo: Vec<DatabaseAction>,
+}
+
+/// Keeps the undo and redo stacks of each view, so only the changes made in a view are undone
+/// in it. The stacks are kept in memory, and they are lost when the database is closed.
+#[derive(Default)]
+pub struct DatabaseUndoManager {
+  stacks: Mutex<HashMap<String, UndoStack>>,
+}
+
+impl DatabaseUndoManager {
+  /// Records the action that reverts a change. Only the changes made by the user are recorded,
+  /// the changes of an undo or redo are reverted by the action that it returns. The redo stack is
+  /// cleared, because the undone changes can't be reapplied on top of the new change.
+  pub fn record(&self, view_id: &str, origin: ChangeOrigin, action: DatabaseAction) {
+    if origin != ChangeOrigin::User {
+      return;
+    }
+    let mut stacks = self.stacks.lock();
+    let stack = stacks.entry(view_id.to_string()).or_default();
+    stack.undo.push(action);
+    if stack.undo.len() > MAX_UNDO_ACTIONS {
+      stack.undo.remove(0);
+    }
+    stack.redo.clear();
+  }
+
+  pub fn pop_undo(&self, view_id: &str) -> Option<DatabaseAction> {
+    self.stacks.lock().get_mut(view_id)?.undo.pop()
+  }
+
+  pub fn pop_redo(&self, view_id: &str) -> Option<DatabaseAction> {
+    self.stacks.lock().get_mut(view_id)?.redo.pop()
+  }
+
+  pub fn push_undo(&self, view_id: &str, action: DatabaseAction) {
+    let mut stacks = self.stacks.lock();
+    stacks
+      .entry(view_id.to_string())
+      .or_default()
+      .undo
+      .push(action);
+  }
+
+  pub fn push_redo(&self, view_id: &str, action: DatabaseAction) {
+    let mut stacks = self.stacks.lock();
+    stacks
+      .entry(view_id.to_string())
+      .or_default()
+      .redo
+      .push(action);
+  }
+
+  pub fn can_undo(&self, view_id: &str) -> bool {
+    self
+      .stacks
+      .lock()
+      .get(view_id)
+      .map(|stack| !stack.undo.is_empty())
+      .unwrap_or(false)
+  }
+
+  pub fn can_redo(&self, view_id: &str) -> bool {
+    self
+      .stacks
+      .lock()
+      .get(view_id)
+      .map(|stack| !stack.redo.is_empty())
+      .unwrap_or(false)
+  }
+}
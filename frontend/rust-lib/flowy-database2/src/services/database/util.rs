@@ -2,10 +2,10 @@ use collab_database::views::DatabaseView;
 
 use crate::entities::{
   CalendarLayoutSettingPB, DatabaseLayoutPB, DatabaseLayoutSettingPB, DatabaseViewSettingPB,
-  FieldSettingsPB, FilterPB, GroupSettingPB, SortPB,
+  FieldSettingsPB, FilterGroupPB, FilterPB, GroupSettingPB, RepeatedFilterGroupPB, SortPB,
 };
 use crate::services::field_settings::FieldSettings;
-use crate::services::filter::Filter;
+use crate::services::filter::{Filter, FilterGroup};
 use crate::services::group::GroupSetting;
 use crate::services::setting::CalendarLayoutSetting;
 use crate::services::sort::Sort;
@@ -25,13 +25,23 @@ pub(crate) fn database_view_setting_pb_from_view(view: DatabaseView) -> Database
 
   let filters = view
     .filters
-    .into_iter()
-    .flat_map(|value| match Filter::try_from(value) {
+    .iter()
+    .flat_map(|value| match Filter::try_from(value.clone()) {
       Ok(filter) => Some(FilterPB::from(&filter)),
       Err(_) => None,
     })
     .collect::<Vec<FilterPB>>();
 
+  // The filter groups are stored alongside the filters
+  let filter_groups = view
+    .filters
+    .into_iter()
+    .flat_map(|value| match FilterGroup::try_from(value) {
+      Ok(filter_group) => Some(FilterGroupPB::from(&filter_group)),
+      Err(_) => None,
+    })
+    .collect::<Vec<FilterGroupPB>>();
+
   let group_settings = view
     .group_settings
     .into_iter()
@@ -61,6 +71,9 @@ pub(crate) fn database_view_setting_pb_from_view(view: DatabaseView) -> Database
   DatabaseViewSettingPB {
     layout_type,
     filters: filters.into(),
+    filter_groups: RepeatedFilterGroupPB {
+      items: filter_groups,
+    },
     group_settings: group_settings.into(),
     sorts: sorts.into(),
     field_settings: field_settings.into(),
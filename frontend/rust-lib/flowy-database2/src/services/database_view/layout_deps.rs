@@ -37,7 +37,7 @@ impl DatabaseLayoutDepsResolver {
           .lock()
           .get_fields(None)
           .into_iter()
-          .any(|field| FieldType::from(field.field_type).can_be_group())
+          .any(|field| FieldType::from(field.field_type).can_be_default_group())
         {
           let select_field = self.create_select_field();
           (Some(select_field), None)
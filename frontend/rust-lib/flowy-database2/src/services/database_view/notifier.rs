@@ -1,7 +1,8 @@
 #![allow(clippy::while_let_loop)]
 use crate::entities::{
   DatabaseViewSettingPB, FilterChangesetNotificationPB, GroupChangesPB, GroupRowsNotificationPB,
-  ReorderAllRowsPB, ReorderSingleRowPB, RowsVisibilityChangePB, SortChangesetNotificationPB,
+  ReorderAllRowsPB, ReorderSingleRowPB, RepeatedCalculationsPB, RowsVisibilityChangePB,
+  SortChangesetNotificationPB,
 };
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::filter::FilterResultNotification;
@@ -88,6 +89,15 @@ pub async fn notify_did_update_group_rows(payload: GroupRowsNotificationPB) {
     .send();
 }
 
+pub async fn notify_did_update_sub_group_rows(payload: GroupRowsNotificationPB) {
+  send_notification(
+    &payload.group_id,
+    DatabaseNotification::DidUpdateSubGroupRow,
+  )
+  .payload(payload)
+  .send();
+}
+
 pub async fn notify_did_update_filter(notification: FilterChangesetNotificationPB) {
   send_notification(&notification.view_id, DatabaseNotification::DidUpdateFilter)
     .payload(notification)
@@ -102,12 +112,29 @@ pub async fn notify_did_update_sort(notification: SortChangesetNotificationPB) {
   }
 }
 
+pub async fn notify_did_update_calculations(notification: RepeatedCalculationsPB) {
+  if !notification.items.is_empty() {
+    send_notification(
+      &notification.view_id,
+      DatabaseNotification::DidUpdateCalculations,
+    )
+    .payload(notification)
+    .send();
+  }
+}
+
 pub(crate) async fn notify_did_update_num_of_groups(view_id: &str, changeset: GroupChangesPB) {
   send_notification(view_id, DatabaseNotification::DidUpdateNumOfGroups)
     .payload(changeset)
     .send();
 }
 
+pub(crate) async fn notify_did_update_num_of_sub_groups(view_id: &str, changeset: GroupChangesPB) {
+  send_notification(view_id, DatabaseNotification::DidUpdateNumOfSubGroups)
+    .payload(changeset)
+    .send();
+}
+
 pub(crate) async fn notify_did_update_setting(view_id: &str, setting: DatabaseViewSettingPB) {
   send_notification(view_id, DatabaseNotification::DidUpdateSettings)
     .payload(setting)
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use crate::entities::{CalculationPB, CalculationType};
+use crate::services::calculations::calculate;
+use crate::services::database_view::DatabaseViewData;
+use crate::services::filter::FilterController;
+
+/// Evaluates the calculations of the view over the rows that pass the filters of the view.
+/// Passing `None` evaluates the calculations of all the fields. The fields without calculation
+/// are skipped.
+pub async fn get_calculations_for_view(
+  view_id: &str,
+  delegate: &Arc<dyn DatabaseViewData>,
+  filter_controller: &FilterController,
+  field_ids: Option<&[String]>,
+) -> Vec<CalculationPB> {
+  let calculation_types = delegate
+    .get_all_field_settings(view_id)
+    .into_values()
+    .filter(|field_settings| field_settings.calculation_type != CalculationType::None)
+    .filter(|field_settings| {
+      field_ids
+        .map(|field_ids| field_ids.contains(&field_settings.field_id))
+        .unwrap_or(true)
+    })
+    .map(|field_settings| (field_settings.field_id, field_settings.calculation_type))
+    .collect::<Vec<_>>();
+  if calculation_types.is_empty() {
+    return vec![];
+  }
+
+  let mut rows = delegate.get_rows(view_id).await;
+  filter_controller.filter_rows(&mut rows).await;
+
+  let mut calculations = vec![];
+  for (field_id, calculation_type) in calculation_types {
+    if let Some(field) = delegate.get_field(&field_id).await {
+      calculations.push(CalculationPB {
+        value: calculate(calculation_type, &field, &rows),
+        field_id,
+        calculation_type,
+      });
+    }
+  }
+  calculations
+}
@@ -13,28 +13,36 @@ use flowy_task::TaskDispatcher;
 use lib_infra::future::Fut;
 
 use crate::entities::{
-  CalendarEventPB, DatabaseLayoutMetaPB, DatabaseLayoutSettingPB, DeleteFilterParams,
-  DeleteGroupParams, DeleteSortParams, FieldType, FieldVisibility, GroupChangesPB, GroupPB,
-  GroupRowsNotificationPB, InsertedRowPB, LayoutSettingParams, RowMetaPB, RowsChangePB,
-  SortChangesetNotificationPB, SortPB, UpdateFilterParams, UpdateSortParams,
+  CalculationPB, CalculationType, CalendarEventPB, CalendarWindow, DatabaseLayoutMetaPB,
+  DatabaseLayoutSettingPB, DeleteFilterGroupParams, DeleteFilterParams, DeleteGroupParams,
+  DeleteSortParams, FieldType, FieldVisibility, FilterChangesetNotificationPB, FilterGroupPB,
+  FilterPB, GroupChangesPB, GroupPB, GroupRowsNotificationPB, InsertedGroupPB, InsertedRowPB,
+  LayoutSettingParams, NumberFilterPB, RepeatedCalculationsPB, RowMetaPB, RowsChangePB,
+  SortChangesetNotificationPB, SortPB, UpdateFilterGroupParams, UpdateFilterParams,
+  UpdateSortParams,
 };
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::cell::CellCache;
 use crate::services::database::{database_view_setting_pb_from_view, DatabaseRowEvent, UpdatedRow};
+use crate::services::database_view::view_calculations::get_calculations_for_view;
 use crate::services::database_view::view_filter::make_filter_controller;
 use crate::services::database_view::view_group::{
   get_cell_for_row, get_cells_for_field, new_group_controller, new_group_controller_with_field,
+  new_sub_group_controller, new_sub_group_controller_with_field,
 };
 use crate::services::database_view::view_sort::make_sort_controller;
 use crate::services::database_view::{
-  notify_did_update_filter, notify_did_update_group_rows, notify_did_update_num_of_groups,
-  notify_did_update_setting, notify_did_update_sort, DatabaseLayoutDepsResolver,
+  notify_did_update_calculations, notify_did_update_filter, notify_did_update_group_rows,
+  notify_did_update_num_of_groups, notify_did_update_num_of_sub_groups, notify_did_update_setting,
+  notify_did_update_sort, notify_did_update_sub_group_rows, DatabaseLayoutDepsResolver,
   DatabaseViewChangedNotifier, DatabaseViewChangedReceiverRunner,
 };
-use crate::services::field::TypeOptionCellDataHandler;
+use crate::services::field::{
+  DateTypeOption, NumberTypeOption, TypeOptionCellDataHandler, UserDateTimeSettings,
+};
 use crate::services::field_settings::FieldSettings;
 use crate::services::filter::{
-  Filter, FilterChangeset, FilterController, FilterType, UpdatedFilterType,
+  Filter, FilterChangeset, FilterController, FilterGroup, FilterType, UpdatedFilterType,
 };
 use crate::services::group::{
   GroupController, GroupSetting, GroupSettingChangeset, MoveGroupRowContext, RowChangeset,
@@ -81,6 +89,8 @@ pub trait DatabaseViewData: Send + Sync + 'static {
 
   fn get_group_setting(&self, view_id: &str) -> Vec<GroupSetting>;
 
+  /// Inserts the setting or replaces the existing setting of the same kind. A view has at most
+  /// one grouping setting and one sub-grouping setting.
   fn insert_group_setting(&self, view_id: &str, setting: GroupSetting);
 
   fn get_sort(&self, view_id: &str, sort_id: &str) -> Option<Sort>;
@@ -101,7 +111,11 @@ pub trait DatabaseViewData: Send + Sync + 'static {
 
   fn get_filter(&self, view_id: &str, filter_id: &str) -> Option<Filter>;
 
-  fn get_filter_by_field_id(&self, view_id: &str, field_id: &str) -> Option<Filter>;
+  fn get_all_filter_groups(&self, view_id: &str) -> Vec<FilterGroup>;
+
+  fn get_filter_group(&self, view_id: &str, group_id: &str) -> Option<FilterGroup>;
+
+  fn insert_filter_group(&self, view_id: &str, filter_group: FilterGroup);
 
   fn get_layout_setting(&self, view_id: &str, layout_ty: &DatabaseLayout) -> Option<LayoutSetting>;
 
@@ -117,6 +131,12 @@ pub trait DatabaseViewData: Send + Sync + 'static {
   /// Returns a `TaskDispatcher` used to poll a `Task`
   fn get_task_scheduler(&self) -> Arc<RwLock<TaskDispatcher>>;
 
+  /// Returns the id of the user who opened the database
+  fn get_user_id(&self) -> i64;
+
+  /// Returns the date and time settings of the user who opened the database
+  fn get_date_time_settings(&self) -> UserDateTimeSettings;
+
   fn get_type_option_cell_handler(
     &self,
     field: &Field,
@@ -136,6 +156,7 @@ pub trait DatabaseViewData: Send + Sync + 'static {
     view_id: &str,
     field_id: &str,
     visibility: Option<FieldVisibility>,
+    calculation_type: Option<CalculationType>,
   );
 }
 
@@ -143,6 +164,8 @@ pub struct DatabaseViewEditor {
   pub view_id: String,
   delegate: Arc<dyn DatabaseViewData>,
   group_controller: Arc<RwLock<Option<Box<dyn GroupController>>>>,
+  /// Groups the rows by the sub-grouping field. Its groups are the swimlanes of the board.
+  sub_group_controller: Arc<RwLock<Option<Box<dyn GroupController>>>>,
   filter_controller: Arc<FilterController>,
   sort_controller: Arc<RwLock<SortController>>,
   pub notifier: DatabaseViewChangedNotifier,
@@ -164,6 +187,8 @@ impl DatabaseViewEditor {
     tokio::spawn(DatabaseViewChangedReceiverRunner(Some(notifier.subscribe())).run());
     let group_controller = new_group_controller(view_id.clone(), delegate.clone()).await?;
     let group_controller = Arc::new(RwLock::new(group_controller));
+    let sub_group_controller = new_sub_group_controller(view_id.clone(), delegate.clone()).await?;
+    let sub_group_controller = Arc::new(RwLock::new(sub_group_controller));
 
     let filter_controller = make_filter_controller(
       &view_id,
@@ -186,6 +211,7 @@ impl DatabaseViewEditor {
       view_id,
       delegate,
       group_controller,
+      sub_group_controller,
       filter_controller,
       sort_controller,
       notifier,
@@ -254,10 +280,42 @@ impl DatabaseViewEditor {
         changes = RowsChangePB::from_insert(inserted_row);
       },
     }
+    self.sub_group_did_create_row(row_detail).await;
 
     send_notification(&self.view_id, DatabaseNotification::DidUpdateViewRows)
       .payload(changes)
       .send();
+    self.spawn_update_calculations(None);
+  }
+
+  /// Puts the new row into the sub-groups that it belongs to.
+  async fn sub_group_did_create_row(&self, row_detail: &RowDetail) {
+    let changesets = self
+      .mut_sub_group_controller(|sub_group_controller, field| {
+        sub_group_controller.fill_groups(&[row_detail], &field)?;
+        let changesets = sub_group_controller
+          .groups()
+          .into_iter()
+          .filter_map(|group| {
+            let index = group.index_of_row(&row_detail.row.id)?;
+            let inserted_row = InsertedRowPB {
+              row_meta: RowMetaPB::from(row_detail),
+              index: Some(index as i32),
+              is_new: true,
+            };
+            Some(GroupRowsNotificationPB::insert(
+              group.id.clone(),
+              vec![inserted_row],
+            ))
+          })
+          .collect::<Vec<_>>();
+        Ok(changesets)
+      })
+      .await;
+
+    for changeset in changesets.unwrap_or_default() {
+      notify_did_update_sub_group_rows(changeset).await;
+    }
   }
 
   #[tracing::instrument(level = "trace", skip_all)]
@@ -275,10 +333,22 @@ impl DatabaseViewEditor {
         notify_did_update_group_rows(changeset).await;
       }
     }
+
+    let result = self
+      .mut_sub_group_controller(|sub_group_controller, field| {
+        sub_group_controller.did_delete_delete_row(row, &field)
+      })
+      .await;
+    if let Some(result) = result {
+      self
+        .notify_sub_group_changes(None, result.deleted_group, result.row_changesets)
+        .await;
+    }
     let changes = RowsChangePB::from_delete(row.id.clone().into_inner());
     send_notification(&self.view_id, DatabaseNotification::DidUpdateViewRows)
       .payload(changes)
       .send();
+    self.spawn_update_calculations(None);
   }
 
   /// Notify the view that the row has been updated. If the view has groups,
@@ -329,6 +399,21 @@ impl DatabaseViewEditor {
         .send();
     }
 
+    let result = self
+      .mut_sub_group_controller(|sub_group_controller, field| {
+        sub_group_controller.did_update_group_row(old_row, row_detail, &field)
+      })
+      .await;
+    if let Some(result) = result {
+      self
+        .notify_sub_group_changes(
+          result.inserted_group,
+          result.deleted_group,
+          result.row_changesets,
+        )
+        .await;
+    }
+
     // Each row update will trigger a filter and sort operation. We don't want
     // to block the main thread, so we spawn a new task to do the work.
     let row_id = row_detail.row.id.clone();
@@ -348,6 +433,14 @@ impl DatabaseViewEditor {
           .await;
       }
     });
+
+    // The row might be hidden or shown by the filters after the update, which changes the
+    // calculations of all the fields.
+    if self.delegate.get_all_filters(&self.view_id).is_empty() {
+      self.spawn_update_calculations(Some(vec![field_id.to_string()]));
+    } else {
+      self.spawn_update_calculations(None);
+    }
   }
 
   pub async fn v_filter_rows(&self, row_details: &mut Vec<Arc<RowDetail>>) {
@@ -376,12 +469,13 @@ impl DatabaseViewEditor {
     row_changeset: &mut RowChangeset,
     to_group_id: &str,
     to_row_id: Option<RowId>,
+    to_sub_group_id: Option<&str>,
   ) {
     let result = self
       .mut_group_controller(|group_controller, field| {
         let move_row_context = MoveGroupRowContext {
           row_detail,
-          row_changeset,
+          row_changeset: &mut *row_changeset,
           field: field.as_ref(),
           to_group_id,
           to_row_id,
@@ -405,7 +499,58 @@ impl DatabaseViewEditor {
         notify_did_update_group_rows(changeset).await;
       }
     }
+
+    // Moving the row to another swimlane updates the cell of the sub-grouping field too, so
+    // that both cells are changed in one move.
+    if let Some(to_sub_group_id) = to_sub_group_id {
+      let result = self
+        .mut_sub_group_controller(|sub_group_controller, field| {
+          let move_row_context = MoveGroupRowContext {
+            row_detail,
+            row_changeset: &mut *row_changeset,
+            field: field.as_ref(),
+            to_group_id: to_sub_group_id,
+            to_row_id: None,
+          };
+          sub_group_controller.move_group_row(move_row_context)
+        })
+        .await;
+
+      if let Some(result) = result {
+        self
+          .notify_sub_group_changes(None, result.deleted_group, result.row_changesets)
+          .await;
+      }
+    }
+  }
+
+  async fn notify_sub_group_changes(
+    &self,
+    inserted_group: Option<InsertedGroupPB>,
+    deleted_group: Option<GroupPB>,
+    row_changesets: Vec<GroupRowsNotificationPB>,
+  ) {
+    let mut group_changes = GroupChangesPB {
+      view_id: self.view_id.clone(),
+      ..Default::default()
+    };
+    if let Some(inserted_group) = inserted_group {
+      group_changes.inserted_groups.push(inserted_group);
+    }
+    if let Some(deleted_group) = deleted_group {
+      group_changes.deleted_groups.push(deleted_group.group_id);
+    }
+    if !group_changes.is_empty() {
+      notify_did_update_num_of_sub_groups(&self.view_id, group_changes).await;
+    }
+
+    for changeset in row_changesets {
+      if !changeset.is_empty() {
+        notify_did_update_sub_group_rows(changeset).await;
+      }
+    }
   }
+
   /// Only call once after database view editor initialized
   #[tracing::instrument(level = "trace", skip(self))]
   pub async fn v_load_groups(&self) -> Option<Vec<GroupPB>> {
@@ -423,6 +568,21 @@ impl DatabaseViewEditor {
     Some(groups)
   }
 
+  #[tracing::instrument(level = "trace", skip(self))]
+  pub async fn v_load_sub_groups(&self) -> Option<Vec<GroupPB>> {
+    let groups = self
+      .sub_group_controller
+      .read()
+      .await
+      .as_ref()?
+      .groups()
+      .into_iter()
+      .filter(|group| group.is_visible)
+      .map(|group_data| GroupPB::from(group_data.clone()))
+      .collect::<Vec<_>>();
+    Some(groups)
+  }
+
   #[tracing::instrument(level = "trace", skip(self))]
   pub async fn v_get_group(&self, group_id: &str) -> FlowyResult<GroupPB> {
     match self
@@ -437,6 +597,18 @@ impl DatabaseViewEditor {
     }
   }
 
+  /// Returns the ids of all the groups, including the hidden ones, in the order of the groups.
+  pub async fn v_get_group_ids(&self) -> Vec<String> {
+    match self.group_controller.read().await.as_ref() {
+      None => vec![],
+      Some(group_controller) => group_controller
+        .groups()
+        .into_iter()
+        .map(|group| group.id.clone())
+        .collect(),
+    }
+  }
+
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub async fn v_move_group(&self, from_group: &str, to_group: &str) -> FlowyResult<()> {
     self
@@ -452,6 +624,13 @@ impl DatabaseViewEditor {
     }
   }
 
+  pub async fn is_sub_grouping_field(&self, field_id: &str) -> bool {
+    match self.sub_group_controller.read().await.as_ref() {
+      Some(sub_group_controller) => sub_group_controller.field_id() == field_id,
+      None => false,
+    }
+  }
+
   /// Called when the user changes the grouping field
   pub async fn v_initialize_new_group(&self, field_id: &str) -> FlowyResult<()> {
     let is_grouping_field = self.is_grouping_field(field_id).await;
@@ -496,6 +675,8 @@ impl DatabaseViewEditor {
       field_id: params.field_id.clone(),
       field_type: params.field_type,
       condition: params.condition,
+      by_option_order: params.by_option_order,
+      empty_first: params.empty_first,
     };
     let sort_type = SortType::from(&sort);
     let mut sort_controller = self.sort_controller.write().await;
@@ -551,13 +732,24 @@ impl DatabaseViewEditor {
       None => gen_database_filter_id(),
       Some(filter_id) => filter_id,
     };
+    if let Some(parent_id) = &params.parent_id {
+      if self
+        .delegate
+        .get_filter_group(&self.view_id, parent_id)
+        .is_none()
+      {
+        return Err(FlowyError::record_not_found().with_context("Can't find the filter group"));
+      }
+    }
     let filter = Filter {
       id: filter_id.clone(),
       field_id: params.field_id.clone(),
       field_type: params.field_type,
       condition: params.condition,
       content: params.content,
+      parent_id: params.parent_id,
     };
+    self.validate_filter(&filter).await?;
     let filter_type = FilterType::from(&filter);
     let filter_controller = self.filter_controller.clone();
     let changeset = if is_exist {
@@ -584,9 +776,28 @@ impl DatabaseViewEditor {
     if let Some(changeset) = changeset {
       notify_did_update_filter(changeset).await;
     }
+    self.spawn_update_calculations(None);
     Ok(())
   }
 
+  /// The content of the number filters is parsed with the format of the field, so it can only be
+  /// validated here.
+  async fn validate_filter(&self, filter: &Filter) -> FlowyResult<()> {
+    if filter.field_type != FieldType::Number {
+      return Ok(());
+    }
+    let format = self
+      .delegate
+      .get_field(&filter.field_id)
+      .await
+      .and_then(|field| field.get_type_option::<NumberTypeOption>(FieldType::Number))
+      .map(|type_option| type_option.format)
+      .unwrap_or_default();
+    NumberFilterPB::from(filter)
+      .validate(&format)
+      .map_err(|code| FlowyError::from(code).with_context("The number filter is invalid"))
+  }
+
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub async fn v_delete_filter(&self, params: DeleteFilterParams) -> FlowyResult<()> {
     let filter_type = params.filter_type;
@@ -601,6 +812,7 @@ impl DatabaseViewEditor {
     if changeset.is_some() {
       notify_did_update_filter(changeset.unwrap()).await;
     }
+    self.spawn_update_calculations(None);
     Ok(())
   }
 
@@ -608,6 +820,130 @@ impl DatabaseViewEditor {
     self.delegate.get_filter(&self.view_id, filter_id)
   }
 
+  pub async fn v_get_all_filter_groups(&self) -> Vec<FilterGroup> {
+    self.delegate.get_all_filter_groups(&self.view_id)
+  }
+
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn v_insert_filter_group(
+    &self,
+    params: UpdateFilterGroupParams,
+  ) -> FlowyResult<FilterGroup> {
+    let group_id = match params.group_id {
+      None => gen_database_filter_id(),
+      Some(group_id) => group_id,
+    };
+
+    // Walk up from the new parent to make sure the group is not moved into itself or into one of
+    // its descendants.
+    let mut ancestor_id = params.parent_id.clone();
+    while let Some(id) = ancestor_id {
+      if id == group_id {
+        return Err(
+          FlowyError::invalid_data().with_context("Can't move a filter group into itself"),
+        );
+      }
+      ancestor_id = match self.delegate.get_filter_group(&self.view_id, &id) {
+        None => {
+          return Err(FlowyError::record_not_found().with_context("Can't find the filter group"))
+        },
+        Some(group) => group.parent_id,
+      };
+    }
+
+    let filter_group = FilterGroup {
+      id: group_id,
+      parent_id: params.parent_id,
+      operator: params.operator,
+    };
+    self
+      .delegate
+      .insert_filter_group(&self.view_id, filter_group.clone());
+    self
+      .filter_controller
+      .did_receive_filter_group_changes(vec![])
+      .await;
+
+    notify_did_update_filter(FilterChangesetNotificationPB::from_update_groups(
+      &self.view_id,
+      vec![FilterGroupPB::from(&filter_group)],
+    ))
+    .await;
+    self.spawn_update_calculations(None);
+    Ok(filter_group)
+  }
+
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn v_delete_filter_group(&self, params: DeleteFilterGroupParams) -> FlowyResult<()> {
+    let filter_groups = self.delegate.get_all_filter_groups(&self.view_id);
+    if !filter_groups
+      .iter()
+      .any(|group| group.id == params.group_id)
+    {
+      return Ok(());
+    }
+
+    // Collect the group and all of its descendant groups
+    let mut deleted_group_ids = vec![params.group_id];
+    let mut index = 0;
+    while index < deleted_group_ids.len() {
+      let children = filter_groups
+        .iter()
+        .filter(|group| group.parent_id.as_ref() == Some(&deleted_group_ids[index]))
+        .filter(|group| !deleted_group_ids.contains(&group.id))
+        .map(|group| group.id.clone())
+        .collect::<Vec<String>>();
+      deleted_group_ids.extend(children);
+      index += 1;
+    }
+
+    let deleted_filters = self
+      .delegate
+      .get_all_filters(&self.view_id)
+      .into_iter()
+      .filter(|filter| {
+        filter
+          .parent_id
+          .as_ref()
+          .map(|parent_id| deleted_group_ids.contains(parent_id))
+          .unwrap_or(false)
+      })
+      .collect::<Vec<Arc<Filter>>>();
+    let deleted_groups = filter_groups
+      .iter()
+      .filter(|group| deleted_group_ids.contains(&group.id))
+      .map(FilterGroupPB::from)
+      .collect::<Vec<FilterGroupPB>>();
+
+    for filter in deleted_filters.iter() {
+      self.delegate.delete_filter(&self.view_id, &filter.id);
+    }
+    for group_id in deleted_group_ids.iter() {
+      self.delegate.delete_filter(&self.view_id, group_id);
+    }
+    self
+      .filter_controller
+      .did_receive_filter_group_changes(
+        deleted_filters
+          .iter()
+          .map(|filter| FilterType::from(filter.as_ref()))
+          .collect(),
+      )
+      .await;
+
+    notify_did_update_filter(FilterChangesetNotificationPB::from_delete_groups(
+      &self.view_id,
+      deleted_groups,
+      deleted_filters
+        .iter()
+        .map(|filter| FilterPB::from(filter.as_ref()))
+        .collect(),
+    ))
+    .await;
+    self.spawn_update_calculations(None);
+    Ok(())
+  }
+
   /// Returns the current calendar settings
   #[tracing::instrument(level = "trace", skip(self))]
   pub async fn v_get_layout_settings(&self, layout_ty: &DatabaseLayout) -> LayoutSettingParams {
@@ -709,14 +1045,23 @@ impl DatabaseViewEditor {
           Ok(())
         })
         .await;
+      self
+        .mut_sub_group_controller(|sub_group_controller, _| {
+          sub_group_controller.did_update_field_type_option(&field);
+          Ok(())
+        })
+        .await;
 
-      if let Some(filter) = self
+      // A field might have multiple filters, for example, in different filter groups.
+      for filter in self
         .delegate
-        .get_filter_by_field_id(&self.view_id, field_id)
+        .get_all_filters(&self.view_id)
+        .into_iter()
+        .filter(|filter| filter.field_id == field_id)
       {
-        let mut old = FilterType::from(&filter);
+        let mut old = FilterType::from(filter.as_ref());
         old.field_type = FieldType::from(old_field.field_type);
-        let new = FilterType::from(&filter);
+        let new = FilterType::from(filter.as_ref());
         let filter_type = UpdatedFilterType::new(Some(old), new);
         let filter_changeset = FilterChangeset::from_update(filter_type);
         let filter_controller = self.filter_controller.clone();
@@ -729,6 +1074,9 @@ impl DatabaseViewEditor {
           }
         });
       }
+
+      // The calculation of the field might not apply to the new field type.
+      self.spawn_update_calculations(Some(vec![field_id.to_string()]));
     }
     Ok(())
   }
@@ -737,6 +1085,9 @@ impl DatabaseViewEditor {
   #[tracing::instrument(level = "debug", skip_all, err)]
   pub async fn v_grouping_by_field(&self, field_id: &str) -> FlowyResult<()> {
     if let Some(field) = self.delegate.get_field(field_id).await {
+      if !FieldType::from(field.field_type).can_be_group() {
+        return Err(FlowyError::invalid_data().with_context("The field can't be grouped by"));
+      }
       let new_group_controller =
         new_group_controller_with_field(self.view_id.clone(), self.delegate.clone(), field).await?;
 
@@ -763,6 +1114,54 @@ impl DatabaseViewEditor {
     Ok(())
   }
 
+  /// Called when the sub-grouping field is changed. Passing `None` removes the sub-groups.
+  #[tracing::instrument(level = "debug", skip_all, err)]
+  pub async fn v_sub_grouping_by_field(&self, field_id: Option<&str>) -> FlowyResult<()> {
+    let field = match field_id {
+      None => None,
+      Some(field_id) => self.delegate.get_field(field_id).await,
+    };
+    if let Some(field) = &field {
+      if !FieldType::from(field.field_type).can_be_group() {
+        return Err(FlowyError::invalid_data().with_context("The field can't be grouped by"));
+      }
+    }
+    let new_sub_group_controller = match field {
+      None => None,
+      Some(field) => Some(
+        new_sub_group_controller_with_field(self.view_id.clone(), self.delegate.clone(), field)
+          .await?,
+      ),
+    };
+
+    let new_groups = new_sub_group_controller
+      .as_ref()
+      .map(|sub_group_controller| {
+        sub_group_controller
+          .groups()
+          .into_iter()
+          .map(|group| GroupPB::from(group.clone()))
+          .collect()
+      })
+      .unwrap_or_default();
+    *self.sub_group_controller.write().await = new_sub_group_controller;
+
+    let changeset = GroupChangesPB {
+      view_id: self.view_id.clone(),
+      initial_groups: new_groups,
+      ..Default::default()
+    };
+    send_notification(&changeset.view_id, DatabaseNotification::DidSubGroupByField)
+      .payload(changeset)
+      .send();
+
+    if let Some(view) = self.delegate.get_view(&self.view_id).await {
+      let setting = database_view_setting_pb_from_view(view);
+      notify_did_update_setting(&self.view_id, setting).await;
+    }
+    Ok(())
+  }
+
   pub async fn v_get_calendar_event(&self, row_id: RowId) -> Option<CalendarEventPB> {
     let layout_ty = DatabaseLayout::Calendar;
     let calendar_setting = self.v_get_layout_settings(&layout_ty).await.calendar?;
@@ -780,11 +1179,8 @@ impl DatabaseViewEditor {
       .unwrap_or_default()
       .into();
 
-    let timestamp = date_cell
-      .into_date_field_cell_data()
-      .unwrap_or_default()
-      .timestamp
-      .unwrap_or_default();
+    let date_cell_data = date_cell.into_date_field_cell_data().unwrap_or_default();
+    let timestamp = date_cell_data.timestamp.unwrap_or_default();
 
     let (_, row_detail) = self.delegate.get_row(&self.view_id, &row_id).await?;
     Some(CalendarEventPB {
@@ -793,10 +1189,19 @@ impl DatabaseViewEditor {
       title,
       timestamp,
       is_scheduled: timestamp != 0,
+      is_recurring: date_cell_data.recurrence.is_some(),
+      end_timestamp: timestamp + date_cell_data.duration(),
     })
   }
 
-  pub async fn v_get_all_calendar_events(&self) -> Option<Vec<CalendarEventPB>> {
+  /// Returns the events of the calendar. If the `window` is provided, only the events that
+  /// overlap with the window are returned, and the recurring dates are expanded into an event for
+  /// each of their occurrences in the window. The date cells of all the rows are scanned to find
+  /// the events, and the rows that can't be loaded are skipped.
+  pub async fn v_get_all_calendar_events(
+    &self,
+    window: Option<CalendarWindow>,
+  ) -> Option<Vec<CalendarEventPB>> {
     let layout_ty = DatabaseLayout::Calendar;
     let calendar_setting = match self.v_get_layout_settings(&layout_ty).await.calendar {
       None => {
@@ -810,54 +1215,65 @@ impl DatabaseViewEditor {
       Some(calendar_setting) => calendar_setting,
     };
 
-    // Text
     let primary_field = self.delegate.get_primary_field().await?;
-    let text_cells =
-      get_cells_for_field(self.delegate.clone(), &self.view_id, &primary_field.id).await;
+    let date_type_option = self
+      .delegate
+      .get_field(&calendar_setting.field_id)
+      .await
+      .and_then(|field| field.get_type_option::<DateTypeOption>(FieldType::DateTime))
+      .unwrap_or_default();
 
-    // Date
-    let timestamp_by_row_id = get_cells_for_field(
+    // The date cell of every row is read, because the rows aren't indexed by date. Only the rows
+    // of the events are loaded afterwards, so the titles and metas of the rows outside the window
+    // are never read.
+    let date_cells = get_cells_for_field(
       self.delegate.clone(),
       &self.view_id,
       &calendar_setting.field_id,
     )
-    .await
-    .into_iter()
-    .map(|date_cell| {
-      let row_id = date_cell.row_id.clone();
-
-      // timestamp
-      let timestamp = date_cell
-        .into_date_field_cell_data()
-        .map(|date_cell_data| date_cell_data.timestamp.unwrap_or_default())
-        .unwrap_or_default();
-
-      (row_id, timestamp)
-    })
-    .collect::<HashMap<RowId, i64>>();
+    .await;
 
     let mut events: Vec<CalendarEventPB> = vec![];
-    for text_cell in text_cells {
-      let row_id = text_cell.row_id.clone();
-      let timestamp = timestamp_by_row_id
-        .get(&row_id)
-        .cloned()
-        .unwrap_or_default();
-
-      let title = text_cell
-        .into_text_field_cell_data()
+    for date_cell in date_cells {
+      let row_id = date_cell.row_id.clone();
+      let date_cell_data = date_cell.into_date_field_cell_data().unwrap_or_default();
+      let occurrences = match window {
+        Some(window) => {
+          date_type_option.occurrences_overlapping(&date_cell_data, window.start, window.end)
+        },
+        None => {
+          let timestamp = date_cell_data.timestamp.unwrap_or_default();
+          vec![(timestamp, timestamp + date_cell_data.duration())]
+        },
+      };
+      if occurrences.is_empty() {
+        continue;
+      }
+
+      let title: String = get_cell_for_row(self.delegate.clone(), &primary_field.id, &row_id)
+        .await
+        .and_then(|text_cell| text_cell.into_text_field_cell_data())
         .unwrap_or_default()
         .into();
-
-      let (_, row_detail) = self.delegate.get_row(&self.view_id, &row_id).await?;
-      let event = CalendarEventPB {
-        row_meta: RowMetaPB::from(row_detail.as_ref()),
-        date_field_id: calendar_setting.field_id.clone(),
-        title,
-        timestamp,
-        is_scheduled: timestamp != 0,
+      let row_detail = match self.delegate.get_row(&self.view_id, &row_id).await {
+        Some((_, row_detail)) => row_detail,
+        None => {
+          tracing::warn!("Failed to load the row {} of a calendar event", row_id);
+          continue;
+        },
       };
-      events.push(event);
+      let row_meta = RowMetaPB::from(row_detail.as_ref());
+      for (timestamp, end_timestamp) in occurrences {
+        events.push(CalendarEventPB {
+          row_meta: row_meta.clone(),
+          date_field_id: calendar_setting.field_id.clone(),
+          title: title.clone(),
+          timestamp,
+          is_scheduled: timestamp != 0,
+          is_recurring: date_cell_data.recurrence.is_some(),
+          end_timestamp,
+        });
+      }
     }
     Some(events)
   }
@@ -882,6 +1298,8 @@ impl DatabaseViewEditor {
     // initialize the group controller if the current layout support grouping
     *self.group_controller.write().await =
       new_group_controller(self.view_id.clone(), self.delegate.clone()).await?;
+    *self.sub_group_controller.write().await =
+      new_sub_group_controller(self.view_id.clone(), self.delegate.clone()).await?;
 
     let payload = DatabaseLayoutMetaPB {
       view_id: self.view_id.clone(),
@@ -923,27 +1341,93 @@ impl DatabaseViewEditor {
     view_id: &str,
     field_id: &str,
     visibility: Option<FieldVisibility>,
+    calculation_type: Option<CalculationType>,
   ) -> FlowyResult<()> {
     self
       .delegate
-      .update_field_settings(view_id, field_id, visibility);
-
+      .update_field_settings(view_id, field_id, visibility, calculation_type);
+
+    if let Some(calculation_type) = calculation_type {
+      let items = if calculation_type == CalculationType::None {
+        vec![CalculationPB {
+          field_id: field_id.to_string(),
+          calculation_type,
+          value: "".to_string(),
+        }]
+      } else {
+        get_calculations_for_view(
+          &self.view_id,
+          &self.delegate,
+          &self.filter_controller,
+          Some(&[field_id.to_string()]),
+        )
+        .await
+      };
+      notify_did_update_calculations(RepeatedCalculationsPB {
+        view_id: self.view_id.clone(),
+        items,
+      })
+      .await;
+    }
     Ok(())
   }
 
+  /// Returns the calculations of the view, evaluated over the rows that pass the filters.
+  pub async fn v_get_calculations(&self) -> Vec<CalculationPB> {
+    get_calculations_for_view(&self.view_id, &self.delegate, &self.filter_controller, None).await
+  }
+
+  /// Re-evaluates the calculations of the fields and notifies the client. Passing `None`
+  /// re-evaluates the calculations of all the fields, which is required when the rows that pass
+  /// the filters might have changed.
+  fn spawn_update_calculations(&self, field_ids: Option<Vec<String>>) {
+    let view_id = self.view_id.clone();
+    let delegate = self.delegate.clone();
+    let weak_filter_controller = Arc::downgrade(&self.filter_controller);
+    tokio::spawn(async move {
+      if let Some(filter_controller) = weak_filter_controller.upgrade() {
+        let items = get_calculations_for_view(
+          &view_id,
+          &delegate,
+          &filter_controller,
+          field_ids.as_deref(),
+        )
+        .await;
+        notify_did_update_calculations(RepeatedCalculationsPB { view_id, items }).await;
+      }
+    });
+  }
+
   async fn mut_group_controller<F, T>(&self, f: F) -> Option<T>
   where
     F: FnOnce(&mut Box<dyn GroupController>, Arc<Field>) -> FlowyResult<T>,
   {
-    let group_field_id = self
-      .group_controller
+    self.mut_controller(&self.group_controller, f).await
+  }
+
+  async fn mut_sub_group_controller<F, T>(&self, f: F) -> Option<T>
+  where
+    F: FnOnce(&mut Box<dyn GroupController>, Arc<Field>) -> FlowyResult<T>,
+  {
+    self.mut_controller(&self.sub_group_controller, f).await
+  }
+
+  async fn mut_controller<F, T>(
+    &self,
+    controller: &RwLock<Option<Box<dyn GroupController>>>,
+    f: F,
+  ) -> Option<T>
+  where
+    F: FnOnce(&mut Box<dyn GroupController>, Arc<Field>) -> FlowyResult<T>,
+  {
+    let group_field_id = controller
       .read()
       .await
       .as_ref()
       .map(|group| group.field_id().to_owned())?;
     let field = self.delegate.get_field(&group_field_id).await?;
 
-    let mut write_guard = self.group_controller.write().await;
+    let mut write_guard = controller.write().await;
     if let Some(group_controller) = &mut *write_guard {
       f(group_controller, field).ok()
     } else {
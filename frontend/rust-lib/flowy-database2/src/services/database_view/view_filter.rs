@@ -9,7 +9,10 @@ use crate::services::cell::CellCache;
 use crate::services::database_view::{
   gen_handler_id, DatabaseViewChangedNotifier, DatabaseViewData,
 };
-use crate::services::filter::{Filter, FilterController, FilterDelegate, FilterTaskHandler};
+use crate::services::field::UserDateTimeSettings;
+use crate::services::filter::{
+  Filter, FilterController, FilterDelegate, FilterGroup, FilterTaskHandler,
+};
 
 pub async fn make_filter_controller(
   view_id: &str,
@@ -18,6 +21,7 @@ pub async fn make_filter_controller(
   cell_cache: CellCache,
 ) -> Arc<FilterController> {
   let filters = delegate.get_all_filters(view_id);
+  let filter_groups = delegate.get_all_filter_groups(view_id);
   let task_scheduler = delegate.get_task_scheduler();
   let filter_delegate = DatabaseViewFilterDelegateImpl(delegate.clone());
 
@@ -28,11 +32,13 @@ pub async fn make_filter_controller(
     filter_delegate,
     task_scheduler.clone(),
     filters,
+    filter_groups,
     cell_cache,
     notifier,
   )
   .await;
   let filter_controller = Arc::new(filter_controller);
+  filter_controller.watch_day_rollover();
   task_scheduler
     .write()
     .await
@@ -51,6 +57,16 @@ impl FilterDelegate for DatabaseViewFilterDelegateImpl {
     to_fut(async move { filter })
   }
 
+  fn get_all_filters(&self, view_id: &str) -> Fut<Vec<Arc<Filter>>> {
+    let filters = self.0.get_all_filters(view_id);
+    to_fut(async move { filters })
+  }
+
+  fn get_all_filter_groups(&self, view_id: &str) -> Fut<Vec<FilterGroup>> {
+    let filter_groups = self.0.get_all_filter_groups(view_id);
+    to_fut(async move { filter_groups })
+  }
+
   fn get_field(&self, field_id: &str) -> Fut<Option<Arc<Field>>> {
     self.0.get_field(field_id)
   }
@@ -66,4 +82,12 @@ impl FilterDelegate for DatabaseViewFilterDelegateImpl {
   fn get_row(&self, view_id: &str, rows_id: &RowId) -> Fut<Option<(usize, Arc<RowDetail>)>> {
     self.0.get_row(view_id, rows_id)
   }
+
+  fn get_user_id(&self) -> i64 {
+    self.0.get_user_id()
+  }
+
+  fn get_date_time_settings(&self) -> UserDateTimeSettings {
+    self.0.get_date_time_settings()
+  }
 }
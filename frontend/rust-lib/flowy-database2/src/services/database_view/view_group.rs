@@ -10,8 +10,8 @@ use crate::entities::FieldType;
 use crate::services::database_view::DatabaseViewData;
 use crate::services::field::RowSingleCellData;
 use crate::services::group::{
-  find_new_grouping_field, make_group_controller, GroupController, GroupSetting,
-  GroupSettingReader, GroupSettingWriter,
+  find_new_grouping_field, make_default_group_controller, make_group_controller, GroupController,
+  GroupSetting, GroupSettingReader, GroupSettingWriter,
 };
 
 pub async fn new_group_controller_with_field(
@@ -54,6 +54,16 @@ pub async fn new_group_controller(
   // If the view is a board and the grouping field is empty, we need to find a new grouping field
   if layout.is_board() && grouping_field.is_none() {
     grouping_field = find_new_grouping_field(&fields, &layout);
+    if let Some(primary_field) = grouping_field
+      .as_ref()
+      .filter(|field| !FieldType::from(field.field_type).can_be_default_group())
+    {
+      let rows = delegate.get_rows(&view_id).await;
+      return Ok(Some(make_default_group_controller(
+        primary_field.clone(),
+        rows,
+      )?));
+    }
   }
 
   if let Some(grouping_field) = grouping_field {
@@ -74,18 +84,78 @@ pub async fn new_group_controller(
   }
 }
 
+/// Returns the controller of the sub-groups, the swimlanes of the board, if the view has a
+/// sub-grouping field.
+pub async fn new_sub_group_controller(
+  view_id: String,
+  delegate: Arc<dyn DatabaseViewData>,
+) -> FlowyResult<Option<Box<dyn GroupController>>> {
+  if !delegate.get_layout_for_view(&view_id).is_board() {
+    return Ok(None);
+  }
+
+  let setting_reader = SubGroupSettingReaderImpl(delegate.clone());
+  let sub_grouping_field = match setting_reader.get_group_setting(&view_id).await {
+    None => None,
+    Some(setting) => delegate.get_field(&setting.field_id).await,
+  };
+
+  match sub_grouping_field {
+    None => Ok(None),
+    Some(sub_grouping_field) => Ok(Some(
+      new_sub_group_controller_with_field(view_id, delegate, sub_grouping_field).await?,
+    )),
+  }
+}
+
+pub async fn new_sub_group_controller_with_field(
+  view_id: String,
+  delegate: Arc<dyn DatabaseViewData>,
+  sub_grouping_field: Arc<Field>,
+) -> FlowyResult<Box<dyn GroupController>> {
+  let setting_reader = SubGroupSettingReaderImpl(delegate.clone());
+  let rows = delegate.get_rows(&view_id).await;
+  let setting_writer = SubGroupSettingWriterImpl(delegate.clone());
+  make_group_controller(
+    view_id,
+    sub_grouping_field,
+    rows,
+    setting_reader,
+    setting_writer,
+  )
+  .await
+}
+
 pub(crate) struct GroupSettingReaderImpl(pub Arc<dyn DatabaseViewData>);
 
 impl GroupSettingReader for GroupSettingReaderImpl {
   fn get_group_setting(&self, view_id: &str) -> Fut<Option<Arc<GroupSetting>>> {
-    let mut settings = self.0.get_group_setting(view_id);
-    to_fut(async move {
-      if settings.is_empty() {
-        None
-      } else {
-        Some(Arc::new(settings.remove(0)))
-      }
-    })
+    let setting = self
+      .0
+      .get_group_setting(view_id)
+      .into_iter()
+      .find(|setting| !setting.is_sub_group);
+    to_fut(async move { setting.map(Arc::new) })
+  }
+
+  fn get_configuration_cells(&self, view_id: &str, field_id: &str) -> Fut<Vec<RowSingleCellData>> {
+    let field_id = field_id.to_owned();
+    let view_id = view_id.to_owned();
+    let delegate = self.0.clone();
+    to_fut(async move { get_cells_for_field(delegate, &view_id, &field_id).await })
+  }
+}
+
+pub(crate) struct SubGroupSettingReaderImpl(pub Arc<dyn DatabaseViewData>);
+
+impl GroupSettingReader for SubGroupSettingReaderImpl {
+  fn get_group_setting(&self, view_id: &str) -> Fut<Option<Arc<GroupSetting>>> {
+    let setting = self
+      .0
+      .get_group_setting(view_id)
+      .into_iter()
+      .find(|setting| setting.is_sub_group);
+    to_fut(async move { setting.map(Arc::new) })
   }
 
   fn get_configuration_cells(&self, view_id: &str, field_id: &str) -> Fut<Vec<RowSingleCellData>> {
@@ -157,3 +227,17 @@ impl GroupSettingWriter for GroupSettingWriterImpl {
     to_fut(async move { Ok(()) })
   }
 }
+
+struct SubGroupSettingWriterImpl(Arc<dyn DatabaseViewData>);
+
+impl GroupSettingWriter for SubGroupSettingWriterImpl {
+  fn save_configuration(
+    &self,
+    view_id: &str,
+    mut group_setting: GroupSetting,
+  ) -> Fut<FlowyResult<()>> {
+    group_setting.is_sub_group = true;
+    self.0.insert_group_setting(view_id, group_setting);
+    to_fut(async move { Ok(()) })
+  }
+}
@@ -67,7 +67,13 @@ impl DatabaseViews {
     let view_editor = self.get_view_editor(view_id).await?;
     let mut row_changeset = RowChangeset::new(row_detail.row.id.clone());
     view_editor
-      .v_move_group_row(&row_detail, &mut row_changeset, &to_group_id, to_row_id)
+      .v_move_group_row(
+        &row_detail,
+        &mut row_changeset,
+        &to_group_id,
+        to_row_id,
+        None,
+      )
       .await;
 
     if !row_changeset.is_empty() {
@@ -97,6 +103,9 @@ impl DatabaseViews {
     if view_editor.is_grouping_field(field_id).await {
       view_editor.v_grouping_by_field(field_id).await?;
     }
+    if view_editor.is_sub_grouping_field(field_id).await {
+      view_editor.v_sub_grouping_by_field(Some(field_id)).await?;
+    }
     view_editor
       .v_did_update_field_type_option(field_id, old_field)
       .await?;
@@ -1,6 +1,9 @@
-use crate::entities::FieldType;
-use crate::services::cell::{FromCellChangeset, ToCellChangeset};
+use crate::entities::{ChecklistCellDataPB, FieldType};
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangeset, ToCellChangeset,
+};
 use crate::services::field::{SelectOption, TypeOptionCellData, CELL_DATA};
+use bytes::Bytes;
 use collab::core::any_map::AnyMapExtension;
 use collab_database::rows::{new_cell_builder, Cell};
 use flowy_error::{internal_error, FlowyResult};
@@ -23,6 +26,12 @@ impl TypeOptionCellData for ChecklistCellData {
   fn is_cell_empty(&self) -> bool {
     self.selected_option_ids.is_empty()
   }
+
+  /// A checklist without any task is sorted with the empty cells. A checklist whose tasks are
+  /// all unchecked isn't.
+  fn is_sort_empty(&self) -> bool {
+    self.options.is_empty()
+  }
 }
 
 impl ChecklistCellData {
@@ -100,6 +109,23 @@ impl ToCellChangeset for ChecklistCellChangeset {
   }
 }
 
+impl DecodedCellData for ChecklistCellDataPB {
+  type Object = ChecklistCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.options.is_empty()
+  }
+}
+
+pub struct ChecklistCellDataParser();
+impl CellProtobufBlobParser for ChecklistCellDataParser {
+  type Object = ChecklistCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    ChecklistCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
 #[cfg(test)]
 mod tests {
   #[test]
@@ -1,8 +1,81 @@
-use crate::entities::{DateFilterConditionPB, DateFilterPB};
-use chrono::NaiveDateTime;
+use std::str::FromStr;
+
+use chrono::{
+  DateTime, Datelike, Duration, FixedOffset, Local, Months, NaiveDate, NaiveDateTime, Offset,
+  TimeZone, Utc, Weekday,
+};
+use chrono_tz::Tz;
+
+use crate::entities::{DateFilterConditionPB, DateFilterPB, DateFilterRelativeUnitPB};
+use crate::services::filter::{Filter, FromFilterString};
+
+/// The date and time settings of the user that the relative date conditions, e.g. today or this
+/// week, are resolved with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserDateTimeSettings {
+  /// The local timezone is used if the timezone id is empty or invalid.
+  pub timezone_id: String,
+  pub first_day_of_week: Weekday,
+}
+
+impl Default for UserDateTimeSettings {
+  fn default() -> Self {
+    Self {
+      timezone_id: "".to_string(),
+      first_day_of_week: Weekday::Mon,
+    }
+  }
+}
+
+/// The date filter with the settings of the current user. The settings are set when the filter
+/// is cached, see [FilterController].
+///
+/// [FilterController]: crate::services::filter::FilterController
+#[derive(Debug, Clone)]
+pub struct DateFilter {
+  filter: DateFilterPB,
+  settings: UserDateTimeSettings,
+}
+
+impl DateFilter {
+  pub fn new(filter: DateFilterPB, settings: UserDateTimeSettings) -> Self {
+    Self { filter, settings }
+  }
+
+  pub fn is_visible<T: Into<Option<i64>>>(&self, cell_timestamp: T) -> bool {
+    self
+      .filter
+      .is_visible_with_settings(cell_timestamp, &self.settings)
+  }
+}
+
+impl FromFilterString for DateFilter {
+  fn from_filter(filter: &Filter) -> Self
+  where
+    Self: Sized,
+  {
+    Self::new(DateFilterPB::from_filter(filter), Default::default())
+  }
+}
 
 impl DateFilterPB {
+  /// The relative conditions are resolved against the current date in UTC, and the weeks start
+  /// on Monday.
   pub fn is_visible<T: Into<Option<i64>>>(&self, cell_timestamp: T) -> bool {
+    let settings = UserDateTimeSettings {
+      timezone_id: "Etc/UTC".to_string(),
+      first_day_of_week: Weekday::Mon,
+    };
+    self.is_visible_with_settings(cell_timestamp, &settings)
+  }
+
+  /// The relative conditions, e.g. today or this week, are resolved against the current date in
+  /// the timezone of the settings.
+  pub fn is_visible_with_settings<T: Into<Option<i64>>>(
+    &self,
+    cell_timestamp: T,
+    settings: &UserDateTimeSettings,
+  ) -> bool {
     match cell_timestamp.into() {
       None => DateFilterConditionPB::DateIsEmpty == self.condition,
       Some(timestamp) => {
@@ -16,6 +89,17 @@ impl DateFilterPB {
           _ => {},
         }
 
+        if self.condition.is_relative() {
+          let today = date_in_timezone(Utc::now().timestamp(), &settings.timezone_id);
+          let cell_date = date_in_timezone(timestamp, &settings.timezone_id);
+          return match (cell_date, today) {
+            (Some(cell_date), Some(today)) => {
+              self.is_visible_relative_to(cell_date, today, settings.first_day_of_week)
+            },
+            _ => false,
+          };
+        }
+
         let cell_time = NaiveDateTime::from_timestamp_opt(timestamp, 0);
         let cell_date = cell_time.map(|time| time.date());
         match self.timestamp {
@@ -54,12 +138,96 @@ impl DateFilterPB {
       },
     }
   }
+
+  /// Evaluates the relative condition against the given date. The past and next ranges include
+  /// today, e.g. within the past 7 days is from 7 days ago to today.
+  pub fn is_visible_relative_to(
+    &self,
+    cell_date: NaiveDate,
+    today: NaiveDate,
+    first_day_of_week: Weekday,
+  ) -> bool {
+    match self.condition {
+      DateFilterConditionPB::DateIsToday => cell_date == today,
+      DateFilterConditionPB::DateIsYesterday => Some(cell_date) == today.pred_opt(),
+      DateFilterConditionPB::DateIsTomorrow => Some(cell_date) == today.succ_opt(),
+      DateFilterConditionPB::DateIsThisWeek => {
+        let start = start_of_week(today, first_day_of_week);
+        cell_date >= start && cell_date < start + Duration::days(7)
+      },
+      DateFilterConditionPB::DateIsThisMonth => {
+        cell_date.year() == today.year() && cell_date.month() == today.month()
+      },
+      DateFilterConditionPB::DateIsThisYear => cell_date.year() == today.year(),
+      DateFilterConditionPB::DateWithinPast => {
+        let count = self.relative_count.unwrap_or(1);
+        match shift_date(today, -count, &self.relative_unit) {
+          Some(start) => cell_date >= start && cell_date <= today,
+          None => false,
+        }
+      },
+      DateFilterConditionPB::DateWithinNext => {
+        let count = self.relative_count.unwrap_or(1);
+        match shift_date(today, count, &self.relative_unit) {
+          Some(end) => cell_date >= today && cell_date <= end,
+          None => false,
+        }
+      },
+      _ => true,
+    }
+  }
+}
+
+/// Returns the date of the timestamp in the timezone. The local timezone is used if the timezone
+/// id is empty or invalid.
+pub fn date_in_timezone(timestamp: i64, timezone_id: &str) -> Option<NaiveDate> {
+  let naive = NaiveDateTime::from_timestamp_opt(timestamp, 0)?;
+  let offset: FixedOffset = match Tz::from_str(timezone_id) {
+    Ok(timezone) => timezone.offset_from_utc_datetime(&naive).fix(),
+    Err(_) => Local.offset_from_utc_datetime(&naive).fix(),
+  };
+  Some(DateTime::<FixedOffset>::from_naive_utc_and_offset(naive, offset).date_naive())
+}
+
+/// Returns the timestamp of the start of the date in the timezone. The local timezone is used if
+/// the timezone id is empty or invalid.
+pub fn start_of_date_in_timezone(date: NaiveDate, timezone_id: &str) -> Option<i64> {
+  let naive = date.and_hms_opt(0, 0, 0)?;
+  let timestamp = match Tz::from_str(timezone_id) {
+    Ok(timezone) => timezone.from_local_datetime(&naive).earliest()?.timestamp(),
+    Err(_) => Local.from_local_datetime(&naive).earliest()?.timestamp(),
+  };
+  Some(timestamp)
+}
+
+fn start_of_week(date: NaiveDate, first_day_of_week: Weekday) -> NaiveDate {
+  let days_from_start =
+    (date.weekday().num_days_from_monday() + 7 - first_day_of_week.num_days_from_monday()) % 7;
+  date - Duration::days(days_from_start as i64)
+}
+
+fn shift_date(date: NaiveDate, count: i64, unit: &DateFilterRelativeUnitPB) -> Option<NaiveDate> {
+  match unit {
+    DateFilterRelativeUnitPB::Day => date.checked_add_signed(Duration::days(count)),
+    DateFilterRelativeUnitPB::Week => date.checked_add_signed(Duration::weeks(count)),
+    DateFilterRelativeUnitPB::Month => {
+      let months = Months::new(u32::try_from(count.unsigned_abs()).ok()?);
+      if count >= 0 {
+        date.checked_add_months(months)
+      } else {
+        date.checked_sub_months(months)
+      }
+    },
+  }
 }
 
 #[cfg(test)]
 mod tests {
   #![allow(clippy::all)]
-  use crate::entities::{DateFilterConditionPB, DateFilterPB};
+  use chrono::{NaiveDate, Weekday};
+
+  use crate::entities::{DateFilterConditionPB, DateFilterPB, DateFilterRelativeUnitPB};
+  use crate::services::field::date_in_timezone;
 
   #[test]
   fn date_filter_is_test() {
@@ -68,6 +236,7 @@ mod tests {
       timestamp: Some(1668387885),
       end: None,
       start: None,
+      ..Default::default()
     };
 
     for (val, visible) in vec![(1668387885, true), (1647251762, false)] {
@@ -81,6 +250,7 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      ..Default::default()
     };
 
     for (val, visible, msg) in vec![(1668387884, false, "1"), (1647251762, true, "2")] {
@@ -95,6 +265,7 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      ..Default::default()
     };
 
     for (val, visible) in vec![(1668387884, true), (1668387885, true)] {
@@ -108,6 +279,7 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      ..Default::default()
     };
 
     for (val, visible) in vec![(1668387888, false), (1668531885, true), (0, false)] {
@@ -122,6 +294,7 @@ mod tests {
       start: Some(1668272685), // 11/13
       end: Some(1668618285),   // 11/17
       timestamp: None,
+      ..Default::default()
     };
 
     for (val, visible, _msg) in vec![
@@ -140,10 +313,125 @@ mod tests {
       start: None,
       end: None,
       timestamp: None,
+      ..Default::default()
     };
 
     for (val, visible) in vec![(None, true), (Some(123), false)] {
       assert_eq!(filter.is_visible(val), visible);
     }
   }
+
+  #[test]
+  fn date_filter_relative_day_test() {
+    let today = NaiveDate::from_ymd_opt(2023, 11, 15).unwrap();
+    for (condition, date, visible) in vec![
+      (DateFilterConditionPB::DateIsToday, (2023, 11, 15), true),
+      (DateFilterConditionPB::DateIsToday, (2023, 11, 14), false),
+      (DateFilterConditionPB::DateIsYesterday, (2023, 11, 14), true),
+      (DateFilterConditionPB::DateIsTomorrow, (2023, 11, 16), true),
+      (DateFilterConditionPB::DateIsTomorrow, (2023, 11, 17), false),
+      // 2023/11/13 is Monday and 2023/11/19 is Sunday
+      (DateFilterConditionPB::DateIsThisWeek, (2023, 11, 13), true),
+      (DateFilterConditionPB::DateIsThisWeek, (2023, 11, 19), true),
+      (DateFilterConditionPB::DateIsThisWeek, (2023, 11, 20), false),
+      (DateFilterConditionPB::DateIsThisMonth, (2023, 11, 1), true),
+      (
+        DateFilterConditionPB::DateIsThisMonth,
+        (2022, 11, 15),
+        false,
+      ),
+      (DateFilterConditionPB::DateIsThisYear, (2023, 1, 1), true),
+    ] {
+      let filter = DateFilterPB {
+        condition,
+        ..Default::default()
+      };
+      let cell_date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+      assert_eq!(
+        filter.is_visible_relative_to(cell_date, today, Weekday::Mon),
+        visible
+      );
+    }
+  }
+
+  #[test]
+  fn date_filter_this_week_start_test() {
+    // 2023/11/15 is Wednesday
+    let today = NaiveDate::from_ymd_opt(2023, 11, 15).unwrap();
+    let filter = DateFilterPB {
+      condition: DateFilterConditionPB::DateIsThisWeek,
+      ..Default::default()
+    };
+    for (first_day_of_week, date, visible) in vec![
+      (Weekday::Sun, (2023, 11, 12), true),
+      (Weekday::Sun, (2023, 11, 18), true),
+      (Weekday::Sun, (2023, 11, 19), false),
+      (Weekday::Sat, (2023, 11, 11), true),
+      (Weekday::Sat, (2023, 11, 17), true),
+      (Weekday::Sat, (2023, 11, 18), false),
+      (Weekday::Wed, (2023, 11, 15), true),
+      (Weekday::Wed, (2023, 11, 14), false),
+    ] {
+      let cell_date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+      assert_eq!(
+        filter.is_visible_relative_to(cell_date, today, first_day_of_week),
+        visible
+      );
+    }
+  }
+
+  #[test]
+  fn date_filter_within_past_and_next_test() {
+    let today = NaiveDate::from_ymd_opt(2023, 3, 31).unwrap();
+    let filter = DateFilterPB {
+      condition: DateFilterConditionPB::DateWithinPast,
+      relative_count: Some(7),
+      relative_unit: DateFilterRelativeUnitPB::Day,
+      ..Default::default()
+    };
+    for (date, visible) in vec![
+      ((2023, 3, 24), true),
+      ((2023, 3, 31), true),
+      ((2023, 3, 23), false),
+      ((2023, 4, 1), false),
+    ] {
+      let cell_date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+      assert_eq!(
+        filter.is_visible_relative_to(cell_date, today, Weekday::Mon),
+        visible
+      );
+    }
+
+    let filter = DateFilterPB {
+      condition: DateFilterConditionPB::DateWithinNext,
+      relative_count: Some(1),
+      relative_unit: DateFilterRelativeUnitPB::Month,
+      ..Default::default()
+    };
+    for (date, visible) in vec![
+      ((2023, 4, 30), true),
+      ((2023, 5, 1), false),
+      ((2023, 3, 30), false),
+    ] {
+      let cell_date = NaiveDate::from_ymd_opt(date.0, date.1, date.2).unwrap();
+      assert_eq!(
+        filter.is_visible_relative_to(cell_date, today, Weekday::Mon),
+        visible
+      );
+    }
+  }
+
+  #[test]
+  fn date_in_timezone_test() {
+    // 2023/11/14 23:00 UTC is already 2023/11/15 in Shanghai
+    let timestamp = 1700002800;
+    assert_eq!(
+      date_in_timezone(timestamp, "Etc/UTC"),
+      NaiveDate::from_ymd_opt(2023, 11, 14)
+    );
+    assert_eq!(
+      date_in_timezone(timestamp, "Asia/Shanghai"),
+      NaiveDate::from_ymd_opt(2023, 11, 15)
+    );
+  }
 }
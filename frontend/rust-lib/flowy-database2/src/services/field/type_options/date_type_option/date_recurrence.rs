@@ -0,0 +1,181 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum RecurrenceFrequency {
+  #[default]
+  Daily = 0,
+  Weekly = 1,
+  Monthly = 2,
+  Yearly = 3,
+}
+
+/// Defines when a recurring date stops repeating.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum RecurrenceEnd {
+  #[default]
+  Never,
+  /// Stops after the given number of occurrences, including the first one.
+  Count(u32),
+  /// Stops after the given timestamp. The occurrence at the timestamp is included.
+  Until(i64),
+}
+
+/// A recurrence rule modeled after the RRULE of RFC 5545. The date repeats every `interval`
+/// days, weeks, months or years. The occurrences of the monthly and yearly rules keep the day of
+/// the first occurrence, so the months without that day are skipped, e.g. the 31st or Feb 29.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DateRecurrence {
+  pub frequency: RecurrenceFrequency,
+  pub interval: u32,
+  /// The days of the week that a weekly rule repeats on. 0 is Monday and 6 is Sunday. The day
+  /// of the first occurrence is used if it's empty.
+  #[serde(default)]
+  pub weekdays: Vec<u32>,
+  #[serde(default)]
+  pub end: RecurrenceEnd,
+}
+
+impl Default for DateRecurrence {
+  fn default() -> Self {
+    Self {
+      frequency: RecurrenceFrequency::Daily,
+      interval: 1,
+      weekdays: vec![],
+      end: RecurrenceEnd::Never,
+    }
+  }
+}
+
+impl DateRecurrence {
+  pub fn new(frequency: RecurrenceFrequency, interval: u32) -> Self {
+    Self {
+      frequency,
+      interval,
+      ..Default::default()
+    }
+  }
+
+  /// Returns the occurrences of the rule in order, starting with `start`. The occurrences are
+  /// computed in the local time of `start`, so the time of the day is kept. The [RecurrenceEnd::Until]
+  /// is not applied here because it's a timestamp, the caller compares it with the occurrences
+  /// after converting them back.
+  pub fn occurrences(&self, start: NaiveDateTime) -> impl Iterator<Item = NaiveDateTime> + '_ {
+    self.occurrences_from(start, start)
+  }
+
+  /// Returns the occurrences of the rule that are at or after `from`, in order. The periods
+  /// before `from` are skipped without being expanded, unless the rule ends after a number of
+  /// occurrences, which are counted from the start.
+  pub fn occurrences_from(
+    &self,
+    start: NaiveDateTime,
+    from: NaiveDateTime,
+  ) -> impl Iterator<Item = NaiveDateTime> + '_ {
+    let interval = self.interval.max(1) as i64;
+    let (first_period, limit) = match self.end {
+      RecurrenceEnd::Count(count) => (0, count as usize),
+      _ => (self.period_before(start, from), usize::MAX),
+    };
+    (first_period..)
+      .map_while(move |period| self.occurrences_in_period(start, period * interval))
+      .flatten()
+      .filter(move |date_time| *date_time >= start)
+      .take(limit)
+      .filter(move |date_time| *date_time >= from)
+  }
+
+  /// Returns the rule of the series moved by `days` days, or `seconds` seconds. The days of the
+  /// week of a weekly rule and the end of the rule are moved with the series, so every occurrence
+  /// is moved by the same amount.
+  pub fn shifted(&self, days: i64, seconds: i64) -> Self {
+    let weekdays = self
+      .weekdays
+      .iter()
+      .filter(|weekday| **weekday < 7)
+      .map(|weekday| (*weekday as i64 + days).rem_euclid(7) as u32)
+      .collect();
+    let end = match self.end {
+      RecurrenceEnd::Until(until) => RecurrenceEnd::Until(until.saturating_add(seconds)),
+      end => end,
+    };
+    Self {
+      weekdays,
+      end,
+      ..self.clone()
+    }
+  }
+
+  /// Returns the index of a period that ends before `from`, so the periods before it don't have
+  /// any occurrence at or after `from`. One period is kept as a margin for the time of the day.
+  fn period_before(&self, start: NaiveDateTime, from: NaiveDateTime) -> i64 {
+    if from <= start {
+      return 0;
+    }
+    let interval = self.interval.max(1) as i64;
+    let units = match self.frequency {
+      RecurrenceFrequency::Daily => (from.date() - start.date()).num_days(),
+      RecurrenceFrequency::Weekly => {
+        let monday = start.date() - Duration::days(start.weekday().num_days_from_monday() as i64);
+        (from.date() - monday).num_weeks()
+      },
+      RecurrenceFrequency::Monthly => {
+        (from.year() as i64 * 12 + from.month0() as i64)
+          - (start.year() as i64 * 12 + start.month0() as i64)
+      },
+      RecurrenceFrequency::Yearly => from.year() as i64 - start.year() as i64,
+    };
+    (units / interval - 1).max(0)
+  }
+
+  /// Returns the occurrences in the `offset`th day, week, month or year after the start. Returns
+  /// None if the period is out of the range of the supported dates.
+  fn occurrences_in_period(&self, start: NaiveDateTime, offset: i64) -> Option<Vec<NaiveDateTime>> {
+    let time = start.time();
+    let occurrences = match self.frequency {
+      RecurrenceFrequency::Daily => {
+        vec![start.checked_add_signed(Duration::days(offset))?]
+      },
+      RecurrenceFrequency::Weekly => {
+        let monday = start.date() - Duration::days(start.weekday().num_days_from_monday() as i64);
+        let week = monday.checked_add_signed(Duration::weeks(offset))?;
+        let mut weekdays = self
+          .weekdays
+          .iter()
+          .filter(|weekday| **weekday < 7)
+          .cloned()
+          .collect::<Vec<u32>>();
+        if weekdays.is_empty() {
+          weekdays.push(start.weekday().num_days_from_monday());
+        }
+        weekdays.sort_unstable();
+        weekdays.dedup();
+        weekdays
+          .into_iter()
+          .filter_map(|weekday| week.checked_add_signed(Duration::days(weekday as i64)))
+          .map(|date| date.and_time(time))
+          .collect()
+      },
+      RecurrenceFrequency::Monthly => {
+        let month0 = start.year() as i64 * 12 + start.month0() as i64 + offset;
+        let year = i32::try_from(month0.div_euclid(12)).ok()?;
+        let month = month0.rem_euclid(12) as u32 + 1;
+        // Make sure the year is supported before skipping the months without the day.
+        NaiveDate::from_ymd_opt(year, month, 1)?;
+        NaiveDate::from_ymd_opt(year, month, start.day())
+          .map(|date| date.and_time(time))
+          .into_iter()
+          .collect()
+      },
+      RecurrenceFrequency::Yearly => {
+        let year = i32::try_from(start.year() as i64 + offset).ok()?;
+        NaiveDate::from_ymd_opt(year, 1, 1)?;
+        NaiveDate::from_ymd_opt(year, start.month(), start.day())
+          .map(|date| date.and_time(time))
+          .into_iter()
+          .collect()
+      },
+    };
+    Some(occurrences)
+  }
+}
@@ -9,9 +9,12 @@ mod tests {
   use crate::entities::FieldType;
   use crate::services::cell::{CellDataChangeset, CellDataDecoder};
   use crate::services::field::{
-    DateCellChangeset, DateFormat, DateTypeOption, FieldBuilder, TimeFormat,
+    DateCellChangeset, DateCellData, DateFormat, DateRecurrence, DateTypeOption, FieldBuilder,
+    RecurrenceEnd, RecurrenceFrequency, TimeFormat,
   };
 
+  const DAY: i64 = 24 * 60 * 60;
+
   #[test]
   fn date_type_option_date_format_test() {
     let mut type_option = DateTypeOption::test();
@@ -532,6 +535,227 @@ mod tests {
     );
   }
 
+  #[test]
+  fn daily_recurrence_test() {
+    let type_option = DateTypeOption::test();
+    // Nov 15, 2023 00:00
+    let start = 1700006400;
+    let mut cell_data = recurring_date(start, DateRecurrence::new(RecurrenceFrequency::Daily, 2));
+    assert_eq!(
+      type_option.occurrences_between(&cell_data, start, start + 5 * DAY),
+      vec![start, start + 2 * DAY, start + 4 * DAY]
+    );
+    // Only the occurrences in the window are returned.
+    assert_eq!(
+      type_option.occurrences_between(&cell_data, start + 9 * DAY, start + 12 * DAY),
+      vec![start + 10 * DAY, start + 12 * DAY]
+    );
+
+    cell_data.recurrence.as_mut().unwrap().end = RecurrenceEnd::Count(2);
+    assert_eq!(
+      type_option.occurrences_between(&cell_data, start, start + 30 * DAY),
+      vec![start, start + 2 * DAY]
+    );
+
+    cell_data.recurrence.as_mut().unwrap().end = RecurrenceEnd::Until(start + 5 * DAY);
+    assert_eq!(
+      type_option.occurrences_between(&cell_data, start, start + 30 * DAY),
+      vec![start, start + 2 * DAY, start + 4 * DAY]
+    );
+  }
+
+  #[test]
+  fn weekly_recurrence_with_weekdays_test() {
+    let type_option = DateTypeOption::test();
+    // Wednesday, Nov 15, 2023 00:00
+    let start = 1700006400;
+    let recurrence = DateRecurrence {
+      weekdays: vec![0, 2],
+      ..DateRecurrence::new(RecurrenceFrequency::Weekly, 1)
+    };
+    let cell_data = recurring_date(start, recurrence);
+    // Wed 15, Mon 20, Wed 22, Mon 27 and Wed 29
+    assert_eq!(
+      type_option.occurrences_between(&cell_data, start, start + 14 * DAY),
+      vec![
+        start,
+        start + 5 * DAY,
+        start + 7 * DAY,
+        start + 12 * DAY,
+        start + 14 * DAY
+      ]
+    );
+  }
+
+  #[test]
+  fn monthly_recurrence_skips_missing_days_test() {
+    let type_option = DateTypeOption::test();
+    // Jan 31, 2023 00:00
+    let start = 1675123200;
+    let cell_data = recurring_date(start, DateRecurrence::new(RecurrenceFrequency::Monthly, 1));
+    // Dec 31, 2023 00:00
+    let occurrences = type_option.occurrences_between(&cell_data, start, 1703980800);
+    // Jan, Mar, May, Jul, Aug, Oct and Dec
+    assert_eq!(occurrences.len(), 7);
+    // Mar 31, 2023 and May 31, 2023
+    assert_eq!(occurrences[1], 1680220800);
+    assert_eq!(occurrences[2], 1685491200);
+  }
+
+  #[test]
+  fn occurrences_long_after_start_test() {
+    let type_option = DateTypeOption::test();
+    // Wednesday, Nov 15, 2023 00:00
+    let start = 1700006400;
+    let cell_data = recurring_date(start, DateRecurrence::new(RecurrenceFrequency::Daily, 3));
+    assert_eq!(
+      type_option.occurrences_between(&cell_data, start + 3000 * DAY + 1, start + 3007 * DAY),
+      vec![start + 3003 * DAY, start + 3006 * DAY]
+    );
+
+    let recurrence = DateRecurrence {
+      weekdays: vec![0, 2],
+      ..DateRecurrence::new(RecurrenceFrequency::Weekly, 1)
+    };
+    let cell_data = recurring_date(start, recurrence);
+    // Mon, Nov 14, 2033 and Wed, Nov 16, 2033
+    assert_eq!(
+      type_option.occurrences_between(&cell_data, start + 3652 * DAY, start + 3655 * DAY),
+      vec![start + 3652 * DAY, start + 3654 * DAY]
+    );
+
+    // Jan 31, 2023 00:00
+    let cell_data = recurring_date(
+      1675123200,
+      DateRecurrence::new(RecurrenceFrequency::Monthly, 1),
+    );
+    // Between Jan 1, 2033 and Mar 31, 2033, Feb is skipped
+    assert_eq!(
+      type_option.occurrences_between(&cell_data, 1988150400, 1995840000),
+      vec![1990742400, 1995840000]
+    );
+  }
+
+  #[test]
+  fn move_recurring_date_test() {
+    let type_option = DateTypeOption::test();
+    // Wednesday, Nov 15, 2023 00:00, every Monday and Wednesday until Dec 31, 2023
+    let start = 1700006400;
+    let recurrence = DateRecurrence {
+      weekdays: vec![0, 2],
+      end: RecurrenceEnd::Until(1703980800),
+      ..DateRecurrence::new(RecurrenceFrequency::Weekly, 1)
+    };
+    let cell_data = recurring_date(start, recurrence);
+
+    // Moving the series back by two days moves the weekdays and the end with it.
+    let changeset = type_option.moved_date_changeset(&cell_data, -2 * DAY);
+    assert_eq!(changeset.date, Some(start - 2 * DAY));
+    assert_eq!(changeset.end_date, None);
+    let recurrence = changeset.recurrence.unwrap();
+    assert_eq!(recurrence.weekdays, vec![5, 0]);
+    assert_eq!(recurrence.end, RecurrenceEnd::Until(1703980800 - 2 * DAY));
+
+    // Moving it within the same day keeps the weekdays.
+    let changeset = type_option.moved_date_changeset(&cell_data, 3600);
+    assert_eq!(changeset.recurrence.unwrap().weekdays, vec![0, 2]);
+  }
+
+  #[test]
+  fn date_range_overlapping_window_test() {
+    let type_option = DateTypeOption::test();
+    // Nov 15, 2023 00:00 → Nov 17, 2023 00:00
+    let start = 1700006400;
+    let cell_data = DateCellData {
+      end_timestamp: Some(start + 2 * DAY),
+      ..DateCellData::new(start, false, true)
+    };
+    assert_eq!(cell_data.duration(), 2 * DAY);
+    // The range started before the window but ends inside it.
+    assert_eq!(
+      type_option.occurrences_overlapping(&cell_data, start + DAY, start + 5 * DAY),
+      vec![(start, start + 2 * DAY)]
+    );
+    assert!(type_option
+      .occurrences_overlapping(&cell_data, start + 3 * DAY, start + 5 * DAY)
+      .is_empty());
+
+    let cell_data = DateCellData {
+      recurrence: Some(DateRecurrence::new(RecurrenceFrequency::Weekly, 1)),
+      ..cell_data
+    };
+    assert_eq!(
+      type_option.occurrences_overlapping(&cell_data, start + 8 * DAY, start + 10 * DAY),
+      vec![(start + 7 * DAY, start + 9 * DAY)]
+    );
+  }
+
+  #[test]
+  fn next_occurrence_of_recurring_date_test() {
+    let type_option = DateTypeOption::test();
+    let start = 1700006400;
+    let mut cell_data = recurring_date(start, DateRecurrence::new(RecurrenceFrequency::Weekly, 1));
+    assert_eq!(
+      type_option.next_occurrence(&cell_data, start + DAY),
+      Some(start + 7 * DAY)
+    );
+
+    cell_data.recurrence = None;
+    assert_eq!(type_option.next_occurrence(&cell_data, start + DAY), None);
+  }
+
+  #[test]
+  fn update_date_keeps_recurrence_and_reminder_test() {
+    let type_option = DateTypeOption::test();
+    let recurrence = DateRecurrence::new(RecurrenceFrequency::Yearly, 1);
+    let cell = initialize_date_cell(
+      &type_option,
+      DateCellChangeset {
+        date: Some(1700006400),
+        recurrence: Some(recurrence.clone()),
+        reminder_offset: Some(3600),
+        ..Default::default()
+      },
+    );
+
+    let (cell, cell_data) = type_option
+      .apply_changeset(
+        DateCellChangeset {
+          date: Some(1701302400),
+          ..Default::default()
+        },
+        Some(cell),
+      )
+      .unwrap();
+    assert_eq!(cell_data.timestamp, Some(1701302400));
+    assert_eq!(cell_data.recurrence, Some(recurrence));
+    assert_eq!(cell_data.reminder_offset, Some(3600));
+
+    let (cell, cell_data) = type_option
+      .apply_changeset(
+        DateCellChangeset {
+          clear_recurrence: Some(true),
+          ..Default::default()
+        },
+        Some(cell),
+      )
+      .unwrap();
+    assert_eq!(cell_data.recurrence, None);
+    assert_eq!(cell_data.reminder_offset, Some(3600));
+
+    let (_, cell_data) = type_option
+      .apply_changeset(
+        DateCellChangeset {
+          clear_flag: Some(true),
+          ..Default::default()
+        },
+        Some(cell),
+      )
+      .unwrap();
+    assert_eq!(cell_data.timestamp, None);
+    assert_eq!(cell_data.reminder_offset, None);
+  }
+
   fn assert_date(
     type_option: &DateTypeOption,
     field: &Field,
@@ -557,4 +781,11 @@ mod tests {
     let (cell, _) = type_option.apply_changeset(changeset, None).unwrap();
     cell
   }
+
+  fn recurring_date(timestamp: i64, recurrence: DateRecurrence) -> DateCellData {
+    DateCellData {
+      recurrence: Some(recurrence),
+      ..DateCellData::new(timestamp, false, false)
+    }
+  }
 }
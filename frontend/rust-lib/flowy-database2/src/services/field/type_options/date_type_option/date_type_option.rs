@@ -10,15 +10,19 @@ use serde::{Deserialize, Serialize};
 
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 
-use crate::entities::{DateCellDataPB, DateFilterPB, FieldType};
+use crate::entities::{DateCellDataPB, DateRecurrencePB, FieldType};
 use crate::services::cell::{CellDataChangeset, CellDataDecoder};
 use crate::services::field::{
-  default_order, DateCellChangeset, DateCellData, DateFormat, TimeFormat, TypeOption,
-  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionCellDataSerde,
-  TypeOptionTransform,
+  default_order, DateCellChangeset, DateCellData, DateFilter, DateFormat, RecurrenceEnd,
+  TimeFormat, TypeOption, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionCellDataSerde, TypeOptionTransform,
 };
 use crate::services::sort::SortCondition;
 
+/// The maximum number of occurrences of a recurring date that are returned for a time window.
+const MAX_OCCURRENCES: usize = 500;
+const DAY_IN_SECONDS: i64 = 24 * 60 * 60;
+
 /// The [DateTypeOption] is used by [FieldType::Date], [FieldType::LastEditedTime], and [FieldType::CreatedTime].
 /// So, storing the field type is necessary to distinguish the field type.
 /// Most of the cases, each [FieldType] has its own [TypeOption] implementation.
@@ -33,7 +37,7 @@ impl TypeOption for DateTypeOption {
   type CellData = DateCellData;
   type CellChangeset = DateCellChangeset;
   type CellProtobufType = DateCellDataPB;
-  type CellFilter = DateFilterPB;
+  type CellFilter = DateFilter;
 }
 
 impl From<TypeOptionData> for DateTypeOption {
@@ -88,6 +92,8 @@ impl TypeOptionCellDataSerde for DateTypeOption {
       end_timestamp: end_timestamp.unwrap_or_default(),
       include_time,
       is_range,
+      recurrence: cell_data.recurrence.map(DateRecurrencePB::from),
+      reminder_offset: cell_data.reminder_offset,
     }
   }
 
@@ -108,6 +114,130 @@ impl DateTypeOption {
     }
   }
 
+  /// Returns the timestamps of the occurrences of the date that start between `start` and `end`.
+  /// A date without recurrence has only one occurrence. The recurrence is expanded in the time
+  /// zone of the type option, so the occurrences keep the local time of the day.
+  pub fn occurrences_between(&self, cell_data: &DateCellData, start: i64, end: i64) -> Vec<i64> {
+    self
+      .occurrence_timestamps(cell_data, start)
+      .take_while(|timestamp| *timestamp <= end)
+      .filter(|timestamp| *timestamp >= start)
+      .take(MAX_OCCURRENCES)
+      .collect()
+  }
+
+  /// Returns the start and the end of the occurrences of the date that overlap with the time
+  /// window between `start` and `end`. Each occurrence of a date range lasts as long as the range,
+  /// so an occurrence that starts before the window is included if it ends inside the window.
+  pub fn occurrences_overlapping(
+    &self,
+    cell_data: &DateCellData,
+    start: i64,
+    end: i64,
+  ) -> Vec<(i64, i64)> {
+    let duration = cell_data.duration();
+    self
+      .occurrences_between(cell_data, start.saturating_sub(duration), end)
+      .into_iter()
+      .map(|timestamp| (timestamp, timestamp + duration))
+      .collect()
+  }
+
+  /// Returns the changeset that moves the date by `delta` seconds. The end of a date range and the
+  /// recurrence are moved by the same amount, so the occurrences of a recurring date keep their
+  /// distance to each other.
+  pub fn moved_date_changeset(&self, cell_data: &DateCellData, delta: i64) -> DateCellChangeset {
+    let timestamp = cell_data.timestamp.unwrap_or_default();
+    let moved_timestamp = timestamp.saturating_add(delta);
+    let recurrence = cell_data.recurrence.as_ref().map(|recurrence| {
+      // The days are counted in the local time, so the weekdays follow the local date.
+      let days = match (
+        self.local_date_time(timestamp),
+        self.local_date_time(moved_timestamp),
+      ) {
+        (Some(from), Some(to)) => (to.date() - from.date()).num_days(),
+        _ => 0,
+      };
+      recurrence.shifted(days, delta)
+    });
+    DateCellChangeset {
+      date: Some(moved_timestamp),
+      end_date: cell_data
+        .end_timestamp
+        .filter(|_| cell_data.is_range)
+        .map(|_| moved_timestamp + cell_data.duration()),
+      recurrence,
+      ..Default::default()
+    }
+  }
+
+  /// Returns the timestamp of the first occurrence of the date that starts at or after `after`.
+  pub fn next_occurrence(&self, cell_data: &DateCellData, after: i64) -> Option<i64> {
+    self
+      .occurrence_timestamps(cell_data, after)
+      .find(|timestamp| *timestamp >= after)
+  }
+
+  /// Returns the timestamps of the occurrences of the date. The recurrence is expanded from the
+  /// local date of `from`, so the occurrences long before it are skipped. Some occurrences before
+  /// `from` can still be returned, the callers filter them out.
+  fn occurrence_timestamps<'a>(
+    &'a self,
+    cell_data: &'a DateCellData,
+    from: i64,
+  ) -> Box<dyn Iterator<Item = i64> + 'a> {
+    let timestamp = match cell_data.timestamp {
+      None => return Box::new(std::iter::empty()),
+      Some(timestamp) => timestamp,
+    };
+    let (recurrence, naive) = match (
+      &cell_data.recurrence,
+      NaiveDateTime::from_timestamp_opt(timestamp, 0),
+    ) {
+      (Some(recurrence), Some(naive)) => (recurrence, naive),
+      _ => return Box::new(std::iter::once(timestamp)),
+    };
+
+    let until = match recurrence.end {
+      RecurrenceEnd::Until(until) => until,
+      _ => i64::MAX,
+    };
+    let local_start = self
+      .get_timezone_offset(naive)
+      .from_utc_datetime(&naive)
+      .naive_local();
+    // The occurrences are compared with the local date of `from` one day earlier, so a change
+    // of the offset can't skip an occurrence.
+    let local_from = self
+      .local_date_time(from.saturating_sub(DAY_IN_SECONDS))
+      .unwrap_or(local_start);
+    Box::new(
+      recurrence
+        .occurrences_from(local_start, local_from)
+        .map(move |local| {
+          // The offset of the occurrence is looked up with its local time, which is off by the
+          // offset itself. It only matters within a few hours of a daylight saving time change.
+          self
+            .get_timezone_offset(local)
+            .from_local_datetime(&local)
+            .unwrap()
+            .timestamp()
+        })
+        .take_while(move |timestamp| *timestamp <= until),
+    )
+  }
+
+  /// Returns the date and time of the timestamp in the time zone of the type option.
+  pub fn local_date_time(&self, timestamp: i64) -> Option<NaiveDateTime> {
+    let naive = NaiveDateTime::from_timestamp_opt(timestamp, 0)?;
+    Some(
+      self
+        .get_timezone_offset(naive)
+        .from_utc_datetime(&naive)
+        .naive_local(),
+    )
+  }
+
   fn formatted_date_time_from_timestamp(&self, timestamp: &Option<i64>) -> (String, String) {
     if let Some(timestamp) = timestamp {
       let naive = chrono::NaiveDateTime::from_timestamp_opt(*timestamp, 0).unwrap();
@@ -257,18 +387,17 @@ impl CellDataChangeset for DateTypeOption {
     cell: Option<Cell>,
   ) -> FlowyResult<(Cell, <Self as TypeOption>::CellData)> {
     // old date cell data
-    let (previous_timestamp, previous_end_timestamp, include_time, is_range) = match cell {
-      Some(cell) => {
-        let cell_data = DateCellData::from(&cell);
-        (
-          cell_data.timestamp,
-          cell_data.end_timestamp,
-          cell_data.include_time,
-          cell_data.is_range,
-        )
-      },
-      None => (None, None, false, false),
-    };
+    let previous_cell_data = cell
+      .map(|cell| DateCellData::from(&cell))
+      .unwrap_or_default();
+    let DateCellData {
+      timestamp: previous_timestamp,
+      end_timestamp: previous_end_timestamp,
+      include_time,
+      is_range,
+      recurrence,
+      reminder_offset,
+    } = previous_cell_data;
 
     if changeset.clear_flag == Some(true) {
       let cell_data = DateCellData {
@@ -276,6 +405,8 @@ impl CellDataChangeset for DateTypeOption {
         end_timestamp: None,
         include_time,
         is_range,
+        recurrence: None,
+        reminder_offset: None,
       };
 
       return Ok((Cell::from(&cell_data), cell_data));
@@ -285,6 +416,18 @@ impl CellDataChangeset for DateTypeOption {
     let include_time = changeset.include_time.unwrap_or(include_time);
     let is_range = changeset.is_range.unwrap_or(is_range);
 
+    // update the recurrence and the reminder if necessary
+    let recurrence = if changeset.clear_recurrence == Some(true) {
+      None
+    } else {
+      changeset.recurrence.or(recurrence)
+    };
+    let reminder_offset = if changeset.clear_reminder == Some(true) {
+      None
+    } else {
+      changeset.reminder_offset.or(reminder_offset)
+    };
+
     // Calculate the timestamp in the time zone specified in type option. If
     // a new timestamp is included in the changeset without an accompanying
     // time string, the old timestamp will simply be overwritten. Meaning, in
@@ -323,6 +466,8 @@ impl CellDataChangeset for DateTypeOption {
       end_timestamp,
       include_time,
       is_range,
+      recurrence,
+      reminder_offset,
     };
 
     Ok((Cell::from(&cell_data), cell_data))
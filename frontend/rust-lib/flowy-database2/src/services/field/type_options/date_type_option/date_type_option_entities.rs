@@ -14,7 +14,7 @@ use crate::entities::{DateCellDataPB, FieldType};
 use crate::services::cell::{
   CellProtobufBlobParser, DecodedCellData, FromCellChangeset, FromCellString, ToCellChangeset,
 };
-use crate::services::field::{TypeOptionCellData, CELL_DATA};
+use crate::services::field::{DateRecurrence, TypeOptionCellData, CELL_DATA};
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct DateCellChangeset {
@@ -25,6 +25,10 @@ pub struct DateCellChangeset {
   pub include_time: Option<bool>,
   pub is_range: Option<bool>,
   pub clear_flag: Option<bool>,
+  pub recurrence: Option<DateRecurrence>,
+  pub clear_recurrence: Option<bool>,
+  pub reminder_offset: Option<i64>,
+  pub clear_reminder: Option<bool>,
 }
 
 impl FromCellChangeset for DateCellChangeset {
@@ -50,6 +54,11 @@ pub struct DateCellData {
   pub include_time: bool,
   #[serde(default)]
   pub is_range: bool,
+  #[serde(default)]
+  pub recurrence: Option<DateRecurrence>,
+  /// The reminder of each occurrence is scheduled the given number of seconds before it.
+  #[serde(default)]
+  pub reminder_offset: Option<i64>,
 }
 
 impl DateCellData {
@@ -59,6 +68,17 @@ impl DateCellData {
       end_timestamp: None,
       include_time,
       is_range,
+      recurrence: None,
+      reminder_offset: None,
+    }
+  }
+
+  /// Returns the number of seconds between the start and the end of a date range. A date that is
+  /// not a range has no duration.
+  pub fn duration(&self) -> i64 {
+    match (self.is_range, self.timestamp, self.end_timestamp) {
+      (true, Some(start), Some(end)) if end > start => end - start,
+      _ => 0,
     }
   }
 }
@@ -79,11 +99,19 @@ impl From<&Cell> for DateCellData {
       .and_then(|data| data.parse::<i64>().ok());
     let include_time = cell.get_bool_value("include_time").unwrap_or_default();
     let is_range = cell.get_bool_value("is_range").unwrap_or_default();
+    let recurrence = cell
+      .get_str_value("recurrence")
+      .and_then(|data| serde_json::from_str::<DateRecurrence>(&data).ok());
+    let reminder_offset = cell
+      .get_str_value("reminder_offset")
+      .and_then(|data| data.parse::<i64>().ok());
     Self {
       timestamp,
       end_timestamp,
       include_time,
       is_range,
+      recurrence,
+      reminder_offset,
     }
   }
 }
@@ -95,6 +123,8 @@ impl From<&DateCellDataPB> for DateCellData {
       end_timestamp: Some(data.end_timestamp),
       include_time: data.include_time,
       is_range: data.is_range,
+      recurrence: data.recurrence.clone().map(DateRecurrence::from),
+      reminder_offset: data.reminder_offset,
     }
   }
 }
@@ -109,6 +139,14 @@ impl From<&DateCellData> for Cell {
       Some(timestamp) => timestamp.to_string(),
       None => "".to_owned(),
     };
+    let recurrence_string = match &cell_data.recurrence {
+      Some(recurrence) => serde_json::to_string(recurrence).unwrap_or_default(),
+      None => "".to_owned(),
+    };
+    let reminder_offset_string = match cell_data.reminder_offset {
+      Some(offset) => offset.to_string(),
+      None => "".to_owned(),
+    };
     // Most of the case, don't use these keys in other places. Otherwise, we should define
     // constants for them.
     new_cell_builder(FieldType::DateTime)
@@ -116,6 +154,8 @@ impl From<&DateCellData> for Cell {
       .insert_str_value("end_timestamp", end_timestamp_string)
       .insert_bool_value("include_time", cell_data.include_time)
       .insert_bool_value("is_range", cell_data.is_range)
+      .insert_str_value("recurrence", recurrence_string)
+      .insert_str_value("reminder_offset", reminder_offset_string)
       .build()
   }
 }
@@ -145,6 +185,8 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
           end_timestamp: None,
           include_time: false,
           is_range: false,
+          recurrence: None,
+          reminder_offset: None,
         })
       }
 
@@ -163,6 +205,8 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
         let mut end_timestamp: Option<i64> = None;
         let mut include_time: Option<bool> = None;
         let mut is_range: Option<bool> = None;
+        let mut recurrence: Option<DateRecurrence> = None;
+        let mut reminder_offset: Option<i64> = None;
 
         while let Some(key) = map.next_key()? {
           match key {
@@ -178,6 +222,12 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
             "is_range" => {
               is_range = map.next_value()?;
             },
+            "recurrence" => {
+              recurrence = map.next_value()?;
+            },
+            "reminder_offset" => {
+              reminder_offset = map.next_value()?;
+            },
             _ => {},
           }
         }
@@ -190,6 +240,8 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
           end_timestamp,
           include_time,
           is_range,
+          recurrence,
+          reminder_offset,
         })
       }
     }
@@ -214,6 +266,25 @@ impl ToString for DateCellData {
   }
 }
 
+/// The reminder of a date cell. It's registered in the reminder store of the user, and the id is
+/// derived from the cell, so each date cell has at most one reminder.
+#[derive(Clone, Debug, Default)]
+pub struct DateReminder {
+  pub id: String,
+  pub view_id: String,
+  pub row_id: String,
+  pub field_id: String,
+  pub scheduled_at: i64,
+  pub title: String,
+  pub message: String,
+}
+
+impl DateReminder {
+  pub fn reminder_id(row_id: &str, field_id: &str) -> String {
+    format!("{}-{}", row_id, field_id)
+  }
+}
+
 #[derive(Clone, Debug, Copy, EnumIter, Serialize, Deserialize, Default)]
 pub enum DateFormat {
   Local = 0,
@@ -0,0 +1,131 @@
+use bytes::Bytes;
+use collab::core::any_map::AnyMapExtension;
+use collab_database::rows::{new_cell_builder, Cell};
+use nanoid::nanoid;
+use serde::{Deserialize, Serialize};
+
+use flowy_error::{internal_error, FlowyResult};
+
+use crate::entities::{FieldType, FilesCellDataPB};
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangeset, ToCellChangeset,
+};
+use crate::services::field::{TypeOptionCellData, CELL_DATA};
+
+/// The record of a file that was uploaded by the [FileStorageService](flowy_storage::FileStorageService).
+/// The content of the file is not stored in the database, only its url.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileAttachment {
+  pub id: String,
+  pub name: String,
+  pub mime: String,
+  pub size: i64,
+  pub url: String,
+  pub uploaded_at: i64,
+}
+
+impl FileAttachment {
+  pub fn new(name: String, mime: String, size: i64, url: String) -> Self {
+    Self {
+      id: nanoid!(6),
+      name,
+      mime,
+      size,
+      url,
+      uploaded_at: chrono::Utc::now().timestamp(),
+    }
+  }
+}
+
+/// The files attached to the cell. They are saved in the cell as a json array.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FilesCellData {
+  pub files: Vec<FileAttachment>,
+}
+
+impl FilesCellData {
+  pub fn urls(&self) -> Vec<String> {
+    self.files.iter().map(|file| file.url.clone()).collect()
+  }
+}
+
+impl From<&Cell> for FilesCellData {
+  fn from(cell: &Cell) -> Self {
+    let value = cell.get_str_value(CELL_DATA).unwrap_or_default();
+    Self::from(value)
+  }
+}
+
+impl From<String> for FilesCellData {
+  fn from(s: String) -> Self {
+    if s.is_empty() {
+      return Self::default();
+    }
+    serde_json::from_str::<Vec<FileAttachment>>(&s)
+      .map(|files| Self { files })
+      .unwrap_or_default()
+  }
+}
+
+impl From<FilesCellData> for Cell {
+  fn from(data: FilesCellData) -> Self {
+    new_cell_builder(FieldType::Files)
+      .insert_str_value(CELL_DATA, data.to_string())
+      .build()
+  }
+}
+
+impl ToString for FilesCellData {
+  fn to_string(&self) -> String {
+    if self.files.is_empty() {
+      return "".to_string();
+    }
+    serde_json::to_string(&self.files).unwrap_or_default()
+  }
+}
+
+impl TypeOptionCellData for FilesCellData {
+  fn is_cell_empty(&self) -> bool {
+    self.files.is_empty()
+  }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FilesCellChangeset {
+  /// The files that were uploaded and will be attached to the cell
+  pub inserted_files: Vec<FileAttachment>,
+  /// The ids of the files that will be removed from the cell
+  pub removed_file_ids: Vec<String>,
+}
+
+impl FromCellChangeset for FilesCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    serde_json::from_str::<FilesCellChangeset>(&changeset).map_err(internal_error)
+  }
+}
+
+impl ToCellChangeset for FilesCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+impl DecodedCellData for FilesCellDataPB {
+  type Object = FilesCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.files.is_empty()
+  }
+}
+
+pub struct FilesCellDataParser();
+impl CellProtobufBlobParser for FilesCellDataParser {
+  type Object = FilesCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    FilesCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
@@ -0,0 +1,190 @@
+use std::cmp::Ordering;
+
+use collab::core::any_map::AnyMapExtension;
+use collab_database::fields::{Field, TypeOptionData, TypeOptionDataBuilder};
+use collab_database::rows::Cell;
+use serde::{Deserialize, Serialize};
+
+use flowy_error::FlowyResult;
+
+use crate::entities::{FieldType, FilesCellDataPB};
+use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+use crate::services::field::{
+  FilesCellChangeset, FilesCellData, TextFilter, TypeOption, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionCellDataSerde,
+  TypeOptionTransform, SELECTION_IDS_SEPARATOR,
+};
+use crate::services::sort::SortCondition;
+
+/// The [FilesTypeOption] attaches files to the cells. The files are uploaded to the file storage
+/// service before they are inserted, and the cells only keep the records of the files.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FilesTypeOption {
+  pub show_thumbnail: bool,
+}
+
+impl TypeOption for FilesTypeOption {
+  type CellData = FilesCellData;
+  type CellChangeset = FilesCellChangeset;
+  type CellProtobufType = FilesCellDataPB;
+  type CellFilter = TextFilter;
+}
+
+impl From<TypeOptionData> for FilesTypeOption {
+  fn from(data: TypeOptionData) -> Self {
+    let show_thumbnail = data.get_bool_value("show_thumbnail").unwrap_or(false);
+    Self { show_thumbnail }
+  }
+}
+
+impl From<FilesTypeOption> for TypeOptionData {
+  fn from(data: FilesTypeOption) -> Self {
+    TypeOptionDataBuilder::new()
+      .insert_bool_value("show_thumbnail", data.show_thumbnail)
+      .build()
+  }
+}
+
+impl TypeOptionCellDataSerde for FilesTypeOption {
+  fn protobuf_encode(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    FilesCellDataPB::from(cell_data)
+  }
+
+  fn parse_cell(&self, cell: &Cell) -> FlowyResult<<Self as TypeOption>::CellData> {
+    Ok(FilesCellData::from(cell))
+  }
+}
+
+impl CellDataChangeset for FilesTypeOption {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    cell: Option<Cell>,
+  ) -> FlowyResult<(Cell, <Self as TypeOption>::CellData)> {
+    let mut cell_data = match cell {
+      None => FilesCellData::default(),
+      Some(cell) => self.parse_cell(&cell)?,
+    };
+
+    cell_data
+      .files
+      .retain(|file| !changeset.removed_file_ids.contains(&file.id));
+    for file in changeset.inserted_files {
+      if !cell_data.files.iter().any(|f| f.id == file.id) {
+        cell_data.files.push(file);
+      }
+    }
+
+    Ok((Cell::from(cell_data.clone()), cell_data))
+  }
+}
+
+impl CellDataDecoder for FilesTypeOption {
+  fn decode_cell(
+    &self,
+    cell: &Cell,
+    decoded_field_type: &FieldType,
+    _field: &Field,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_files() {
+      return Ok(Default::default());
+    }
+
+    self.parse_cell(cell)
+  }
+
+  fn stringify_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data
+      .files
+      .iter()
+      .map(|file| file.name.as_str())
+      .collect::<Vec<_>>()
+      .join(SELECTION_IDS_SEPARATOR)
+  }
+
+  fn stringify_cell(&self, cell: &Cell) -> String {
+    self.stringify_cell_data(FilesCellData::from(cell))
+  }
+}
+
+impl TypeOptionCellDataFilter for FilesTypeOption {
+  /// The files are filtered by their names.
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_files() {
+      return true;
+    }
+
+    filter.is_visible(self.stringify_cell_data(cell_data.clone()))
+  }
+}
+
+impl TypeOptionCellDataCompare for FilesTypeOption {
+  /// Orders the cells by the number of their files. The empty cells are moved to the back.
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+    sort_condition: SortCondition,
+  ) -> Ordering {
+    match (cell_data.is_cell_empty(), other_cell_data.is_cell_empty()) {
+      (true, true) => Ordering::Equal,
+      (true, false) => Ordering::Greater,
+      (false, true) => Ordering::Less,
+      (false, false) => {
+        let order = cell_data.files.len().cmp(&other_cell_data.files.len());
+        sort_condition.evaluate_order(order)
+      },
+    }
+  }
+}
+
+impl TypeOptionTransform for FilesTypeOption {}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+  use crate::services::field::{FileAttachment, FilesCellChangeset, FilesTypeOption};
+
+  fn file(name: &str) -> FileAttachment {
+    FileAttachment::new(
+      name.to_string(),
+      "image/png".to_string(),
+      1024,
+      format!("file:///files/{}", name),
+    )
+  }
+
+  #[test]
+  fn files_cell_insert_and_remove_test() {
+    let type_option = FilesTypeOption::default();
+    let invoice = file("invoice.pdf");
+    let screenshot = file("screenshot.png");
+    let changeset = FilesCellChangeset {
+      inserted_files: vec![invoice.clone(), screenshot.clone(), invoice.clone()],
+      ..Default::default()
+    };
+    let (cell, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_data.files, vec![invoice.clone(), screenshot.clone()]);
+    assert_eq!(
+      type_option.stringify_cell(&cell),
+      "invoice.pdf,screenshot.png"
+    );
+
+    let changeset = FilesCellChangeset {
+      removed_file_ids: vec![invoice.id.clone()],
+      ..Default::default()
+    };
+    let (cell, cell_data) = type_option.apply_changeset(changeset, Some(cell)).unwrap();
+    assert_eq!(cell_data.files, vec![screenshot]);
+    assert_eq!(cell_data.urls(), vec!["file:///files/screenshot.png"]);
+    assert_eq!(type_option.stringify_cell(&cell), "screenshot.png");
+  }
+}
@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod files_entities;
+mod files_type_option;
+
+pub use files_entities::*;
+pub use files_type_option::*;
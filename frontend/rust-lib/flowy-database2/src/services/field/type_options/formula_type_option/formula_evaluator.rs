@@ -0,0 +1,130 @@
+use collab_database::fields::Field;
+use collab_database::rows::{get_field_type_from_cell, Cell, Cells};
+
+use crate::entities::FieldType;
+use crate::services::cell::carry_original_cells;
+use crate::services::field::{
+  evaluate_formula, FormulaCellData, FormulaExpression, FormulaTypeOption, FormulaValue,
+};
+
+/// Evaluates the formula fields of a database against the cells of its rows. The expressions are
+/// parsed once, so the same evaluator can be used for all the rows.
+pub struct FormulaEvaluator<'a> {
+  fields: &'a [Field],
+  /// The formula fields with the ids of the fields they reference and their parsed expression,
+  /// or the message of the error if it can't be parsed.
+  formula_fields: Vec<(&'a Field, Vec<String>, Result<FormulaExpression, String>)>,
+  timezone_id: String,
+}
+
+impl<'a> FormulaEvaluator<'a> {
+  /// The dates of the formulas, e.g. `today()`, are evaluated in the timezone of `timezone_id`,
+  /// usually the one of the user.
+  pub fn new(fields: &'a [Field], timezone_id: &str) -> Self {
+    let formula_fields = fields
+      .iter()
+      .filter(|field| FieldType::from(field.field_type).is_formula())
+      .filter_map(|field| {
+        let type_option = field.get_type_option::<FormulaTypeOption>(FieldType::Formula)?;
+        let expr = type_option.parse_expression().map_err(|err| err.msg);
+        let referenced_field_ids = expr
+          .as_ref()
+          .map(|expr| expr.referenced_field_ids())
+          .unwrap_or_default();
+        Some((field, referenced_field_ids, expr))
+      })
+      .collect();
+    Self {
+      fields,
+      formula_fields,
+      timezone_id: timezone_id.to_string(),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.formula_fields.is_empty()
+  }
+
+  /// Re-evaluates the formula cells and saves the results in `cells`. A formula is evaluated if
+  /// the formula field itself or one of the fields it references is in `changed_field_ids`.
+  /// Formulas that reference other formulas are evaluated in later passes. Passing `None`
+  /// evaluates all the formulas.
+  ///
+  /// Returns the ids of the formula fields whose cells were changed.
+  pub fn evaluate(
+    &self,
+    cells: &mut Cells,
+    created_at: i64,
+    modified_at: i64,
+    changed_field_ids: Option<&[String]>,
+  ) -> Vec<String> {
+    let mut changed_field_ids = changed_field_ids.map(|field_ids| field_ids.to_vec());
+    let mut updated_field_ids: Vec<String> = vec![];
+    // The number of passes is bounded by the number of formula fields, so circular references
+    // can't loop forever.
+    for pass in 0..self.formula_fields.len() {
+      let mut evaluated_field_ids = vec![];
+      for (field, referenced_field_ids, expr) in self.formula_fields.iter() {
+        if let Some(changed_field_ids) = &changed_field_ids {
+          let is_affected = (pass == 0 && changed_field_ids.contains(&field.id))
+            || referenced_field_ids
+              .iter()
+              .any(|field_id| changed_field_ids.contains(field_id));
+          if !is_affected {
+            continue;
+          }
+        }
+
+        let value = match expr {
+          Ok(expr) => evaluate_formula(
+            expr,
+            cells,
+            created_at,
+            modified_at,
+            self.fields,
+            &self.timezone_id,
+          ),
+          Err(msg) => FormulaValue::Error(msg.clone()),
+        };
+        let new_cell = Cell::from(FormulaCellData::from(value));
+        let old_cell = cells.get(&field.id);
+        if old_cell.map_or(false, |old_cell| is_same_formula_cell(old_cell, &new_cell)) {
+          continue;
+        }
+        let cell = carry_original_cells(old_cell, new_cell);
+        cells.insert(field.id.clone(), cell);
+        evaluated_field_ids.push(field.id.clone());
+      }
+
+      if evaluated_field_ids.is_empty() {
+        break;
+      }
+      for field_id in evaluated_field_ids.iter() {
+        if !updated_field_ids.contains(field_id) {
+          updated_field_ids.push(field_id.clone());
+        }
+      }
+      // Only the formulas that depend on the formulas changed in this pass need to be evaluated
+      // again.
+      changed_field_ids = Some(
+        evaluated_field_ids
+          .into_iter()
+          .filter(|field_id| {
+            self
+              .formula_fields
+              .iter()
+              .any(|(_, referenced_field_ids, _)| referenced_field_ids.contains(field_id))
+          })
+          .collect(),
+      );
+    }
+    updated_field_ids
+  }
+}
+
+/// Unchanged cells are not saved again, so evaluating the formulas of a synced row doesn't
+/// produce a new update.
+fn is_same_formula_cell(old_cell: &Cell, new_cell: &Cell) -> bool {
+  get_field_type_from_cell::<FieldType>(old_cell) == Some(FieldType::Formula)
+    && FormulaCellData::from(old_cell) == FormulaCellData::from(new_cell)
+}
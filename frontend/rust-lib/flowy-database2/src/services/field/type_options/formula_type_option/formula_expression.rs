@@ -0,0 +1,794 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use rust_decimal::prelude::{ToPrimitive, Zero};
+use rust_decimal::Decimal;
+
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+
+use crate::services::field::{date_in_timezone, start_of_date_in_timezone, FormulaValue};
+
+const SECONDS_PER_DAY: i64 = 86400;
+/// The maximum nesting of the parentheses, the negations, the function calls and the chained
+/// operators. The expressions are parsed and evaluated recursively, so a deeper expression could
+/// overflow the stack.
+const MAX_FORMULA_DEPTH: usize = 256;
+
+/// A parsed formula expression.
+///
+/// The grammar supports:
+/// * number, string (`"..."`) and boolean (`true`/`false`) literals
+/// * references to other fields of the same row by id: `{field_id}`
+/// * arithmetic `+ - * / %`, string concatenation `&`, comparisons `= != <> < <= > >=`
+/// * function calls, e.g. `if({done}, "Yes", "No")`, `concat({first}, " ", {last})`
+///
+/// Date values are represented as unix timestamps in seconds. Adding or subtracting a number to a
+/// date shifts it by that many days, and subtracting two dates returns the difference in days.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormulaExpression {
+  Literal(FormulaValue),
+  FieldRef(String),
+  Negate(Box<FormulaExpression>),
+  Binary {
+    op: BinaryOp,
+    left: Box<FormulaExpression>,
+    right: Box<FormulaExpression>,
+  },
+  Call {
+    name: String,
+    args: Vec<FormulaExpression>,
+  },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+  Add,
+  Sub,
+  Mul,
+  Div,
+  Rem,
+  Concat,
+  Eq,
+  NotEq,
+  Lt,
+  LtEq,
+  Gt,
+  GtEq,
+}
+
+impl FromStr for FormulaExpression {
+  type Err = FlowyError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let tokens = tokenize(s)?;
+    let mut parser = Parser {
+      tokens,
+      pos: 0,
+      depth: 0,
+    };
+    let expr = parser.parse_expr()?;
+    if parser.pos != parser.tokens.len() {
+      return Err(invalid_formula(format!(
+        "Unexpected token: {:?}",
+        parser.tokens[parser.pos]
+      )));
+    }
+    Ok(expr)
+  }
+}
+
+impl FormulaExpression {
+  /// Returns the ids of the fields referenced by this expression, without duplicates.
+  pub fn referenced_field_ids(&self) -> Vec<String> {
+    let mut field_ids = vec![];
+    self.collect_field_ids(&mut field_ids);
+    field_ids
+  }
+
+  fn collect_field_ids(&self, field_ids: &mut Vec<String>) {
+    match self {
+      FormulaExpression::Literal(_) => {},
+      FormulaExpression::FieldRef(field_id) => {
+        if !field_ids.contains(field_id) {
+          field_ids.push(field_id.clone());
+        }
+      },
+      FormulaExpression::Negate(expr) => expr.collect_field_ids(field_ids),
+      FormulaExpression::Binary { left, right, .. } => {
+        left.collect_field_ids(field_ids);
+        right.collect_field_ids(field_ids);
+      },
+      FormulaExpression::Call { args, .. } => {
+        args.iter().for_each(|arg| arg.collect_field_ids(field_ids));
+      },
+    }
+  }
+
+  /// Evaluates the expression. `values` holds the value of each referenced field keyed by field
+  /// id; missing references evaluate to [FormulaValue::Empty]. The current date, e.g. `today()`,
+  /// is the date in the timezone of `timezone_id`, or in the local timezone if it's empty.
+  pub fn evaluate(
+    &self,
+    values: &HashMap<String, FormulaValue>,
+    timezone_id: &str,
+  ) -> FormulaValue {
+    match self.eval(values, timezone_id) {
+      Ok(value) => value,
+      Err(err) => FormulaValue::Error(err.msg),
+    }
+  }
+
+  fn eval(
+    &self,
+    values: &HashMap<String, FormulaValue>,
+    timezone_id: &str,
+  ) -> FlowyResult<FormulaValue> {
+    match self {
+      FormulaExpression::Literal(value) => Ok(value.clone()),
+      FormulaExpression::FieldRef(field_id) => {
+        Ok(values.get(field_id).cloned().unwrap_or_default())
+      },
+      FormulaExpression::Negate(expr) => {
+        let value = expr.eval(values, timezone_id)?;
+        Ok(FormulaValue::Number(-as_number(&value)?))
+      },
+      FormulaExpression::Binary { op, left, right } => {
+        let left = left.eval(values, timezone_id)?;
+        let right = right.eval(values, timezone_id)?;
+        eval_binary(*op, left, right)
+      },
+      FormulaExpression::Call { name, args } => eval_call(name, args, values, timezone_id),
+    }
+  }
+}
+
+fn eval_binary(op: BinaryOp, left: FormulaValue, right: FormulaValue) -> FlowyResult<FormulaValue> {
+  if let FormulaValue::Error(msg) = &left {
+    return Err(invalid_formula(msg.clone()));
+  }
+  if let FormulaValue::Error(msg) = &right {
+    return Err(invalid_formula(msg.clone()));
+  }
+
+  match op {
+    BinaryOp::Concat => Ok(FormulaValue::Text(format!(
+      "{}{}",
+      left.to_text(),
+      right.to_text()
+    ))),
+    BinaryOp::Add => match (&left, &right) {
+      (FormulaValue::Date(timestamp), other) | (other, FormulaValue::Date(timestamp)) => Ok(
+        FormulaValue::Date(shift_days(*timestamp, as_number(other)?)?),
+      ),
+      (FormulaValue::Text(_), _) | (_, FormulaValue::Text(_)) => Ok(FormulaValue::Text(format!(
+        "{}{}",
+        left.to_text(),
+        right.to_text()
+      ))),
+      _ => checked(as_number(&left)?.checked_add(as_number(&right)?)),
+    },
+    BinaryOp::Sub => match (&left, &right) {
+      (FormulaValue::Date(left), FormulaValue::Date(right)) => Ok(FormulaValue::Number(
+        Decimal::from((left - right) / SECONDS_PER_DAY),
+      )),
+      (FormulaValue::Date(timestamp), other) => Ok(FormulaValue::Date(shift_days(
+        *timestamp,
+        -as_number(other)?,
+      )?)),
+      _ => checked(as_number(&left)?.checked_sub(as_number(&right)?)),
+    },
+    BinaryOp::Mul => checked(as_number(&left)?.checked_mul(as_number(&right)?)),
+    BinaryOp::Div => {
+      let divisor = as_number(&right)?;
+      if divisor.is_zero() {
+        return Err(invalid_formula("Division by zero"));
+      }
+      checked(as_number(&left)?.checked_div(divisor))
+    },
+    BinaryOp::Rem => {
+      let divisor = as_number(&right)?;
+      if divisor.is_zero() {
+        return Err(invalid_formula("Division by zero"));
+      }
+      checked(as_number(&left)?.checked_rem(divisor))
+    },
+    BinaryOp::Eq => Ok(FormulaValue::Bool(left.cmp_value(&right).is_eq())),
+    BinaryOp::NotEq => Ok(FormulaValue::Bool(!left.cmp_value(&right).is_eq())),
+    BinaryOp::Lt => Ok(FormulaValue::Bool(left.cmp_value(&right).is_lt())),
+    BinaryOp::LtEq => Ok(FormulaValue::Bool(left.cmp_value(&right).is_le())),
+    BinaryOp::Gt => Ok(FormulaValue::Bool(left.cmp_value(&right).is_gt())),
+    BinaryOp::GtEq => Ok(FormulaValue::Bool(left.cmp_value(&right).is_ge())),
+  }
+}
+
+fn eval_call(
+  name: &str,
+  args: &[FormulaExpression],
+  values: &HashMap<String, FormulaValue>,
+  timezone_id: &str,
+) -> FlowyResult<FormulaValue> {
+  // `if` only evaluates the branch that is taken.
+  if name == "if" {
+    if args.len() != 2 && args.len() != 3 {
+      return Err(invalid_formula("if expects 2 or 3 arguments"));
+    }
+    let condition = args[0].eval(values, timezone_id)?;
+    return if condition.is_truthy() {
+      args[1].eval(values, timezone_id)
+    } else {
+      args
+        .get(2)
+        .map(|arg| arg.eval(values, timezone_id))
+        .unwrap_or(Ok(FormulaValue::Empty))
+    };
+  }
+
+  let args = args
+    .iter()
+    .map(|arg| arg.eval(values, timezone_id))
+    .collect::<FlowyResult<Vec<FormulaValue>>>()?;
+  let expect_args = |count: usize| {
+    if args.len() != count {
+      Err(invalid_formula(format!(
+        "{} expects {} argument(s)",
+        name, count
+      )))
+    } else {
+      Ok(())
+    }
+  };
+
+  match name {
+    "and" => Ok(FormulaValue::Bool(args.iter().all(|arg| arg.is_truthy()))),
+    "or" => Ok(FormulaValue::Bool(args.iter().any(|arg| arg.is_truthy()))),
+    "not" => {
+      expect_args(1)?;
+      Ok(FormulaValue::Bool(!args[0].is_truthy()))
+    },
+    "concat" => Ok(FormulaValue::Text(
+      args.iter().map(|arg| arg.to_text()).collect::<String>(),
+    )),
+    "len" => {
+      expect_args(1)?;
+      Ok(FormulaValue::Number(Decimal::from(
+        args[0].to_text().chars().count(),
+      )))
+    },
+    "upper" => {
+      expect_args(1)?;
+      Ok(FormulaValue::Text(args[0].to_text().to_uppercase()))
+    },
+    "lower" => {
+      expect_args(1)?;
+      Ok(FormulaValue::Text(args[0].to_text().to_lowercase()))
+    },
+    "abs" => {
+      expect_args(1)?;
+      Ok(FormulaValue::Number(as_number(&args[0])?.abs()))
+    },
+    "round" => {
+      if args.is_empty() || args.len() > 2 {
+        return Err(invalid_formula("round expects 1 or 2 arguments"));
+      }
+      let scale = match args.get(1) {
+        None => 0,
+        Some(arg) => as_number(arg)?.to_u32().unwrap_or_default(),
+      };
+      Ok(FormulaValue::Number(as_number(&args[0])?.round_dp(scale)))
+    },
+    "sum" => {
+      let mut total = Decimal::zero();
+      for arg in args.iter().filter(|arg| !arg.is_empty()) {
+        total = total
+          .checked_add(as_number(arg)?)
+          .ok_or_else(|| invalid_formula("Number overflow"))?;
+      }
+      Ok(FormulaValue::Number(total))
+    },
+    "min" | "max" => {
+      let mut numbers = vec![];
+      for arg in args.iter().filter(|arg| !arg.is_empty()) {
+        numbers.push(as_number(arg)?);
+      }
+      let number = if name == "min" {
+        numbers.into_iter().min()
+      } else {
+        numbers.into_iter().max()
+      };
+      Ok(number.map(FormulaValue::Number).unwrap_or_default())
+    },
+    "now" => {
+      expect_args(0)?;
+      Ok(FormulaValue::Date(chrono::Utc::now().timestamp()))
+    },
+    "today" => {
+      expect_args(0)?;
+      date_in_timezone(chrono::Utc::now().timestamp(), timezone_id)
+        .and_then(|today| start_of_date_in_timezone(today, timezone_id))
+        .map(FormulaValue::Date)
+        .ok_or_else(|| invalid_formula("Can't get the current date"))
+    },
+    "date_add" => {
+      expect_args(2)?;
+      match &args[0] {
+        FormulaValue::Date(timestamp) => Ok(FormulaValue::Date(shift_days(
+          *timestamp,
+          as_number(&args[1])?,
+        )?)),
+        FormulaValue::Empty => Ok(FormulaValue::Empty),
+        _ => Err(invalid_formula("date_add expects a date")),
+      }
+    },
+    "days_between" => {
+      expect_args(2)?;
+      match (&args[0], &args[1]) {
+        (FormulaValue::Date(start), FormulaValue::Date(end)) => Ok(FormulaValue::Number(
+          Decimal::from((end - start) / SECONDS_PER_DAY),
+        )),
+        (FormulaValue::Empty, _) | (_, FormulaValue::Empty) => Ok(FormulaValue::Empty),
+        _ => Err(invalid_formula("days_between expects two dates")),
+      }
+    },
+    _ => Err(invalid_formula(format!("Unknown function: {}", name))),
+  }
+}
+
+fn as_number(value: &FormulaValue) -> FlowyResult<Decimal> {
+  match value {
+    FormulaValue::Empty => Ok(Decimal::zero()),
+    FormulaValue::Number(number) => Ok(*number),
+    FormulaValue::Bool(value) => Ok(if *value {
+      Decimal::from(1)
+    } else {
+      Decimal::zero()
+    }),
+    FormulaValue::Date(timestamp) => Ok(Decimal::from(*timestamp)),
+    FormulaValue::Text(s) => Decimal::from_str(s.trim())
+      .map_err(|_| invalid_formula(format!("Can't convert {} to a number", s))),
+    FormulaValue::Error(msg) => Err(invalid_formula(msg.clone())),
+  }
+}
+
+fn shift_days(timestamp: i64, days: Decimal) -> FlowyResult<i64> {
+  let seconds = days
+    .checked_mul(Decimal::from(SECONDS_PER_DAY))
+    .and_then(|seconds| seconds.round().to_i64())
+    .ok_or_else(|| invalid_formula("Date overflow"))?;
+  timestamp
+    .checked_add(seconds)
+    .ok_or_else(|| invalid_formula("Date overflow"))
+}
+
+fn checked(value: Option<Decimal>) -> FlowyResult<FormulaValue> {
+  value
+    .map(FormulaValue::Number)
+    .ok_or_else(|| invalid_formula("Number overflow"))
+}
+
+fn invalid_formula<T: ToString>(msg: T) -> FlowyError {
+  FlowyError::new(ErrorCode::InvalidFormula, msg)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+  Number(Decimal),
+  Str(String),
+  Ident(String),
+  FieldRef(String),
+  Op(&'static str),
+  LParen,
+  RParen,
+  Comma,
+}
+
+fn tokenize(s: &str) -> FlowyResult<Vec<Token>> {
+  let chars = s.chars().collect::<Vec<char>>();
+  let mut tokens = vec![];
+  let mut i = 0;
+  while i < chars.len() {
+    let c = chars[i];
+    match c {
+      c if c.is_whitespace() => i += 1,
+      '(' => {
+        tokens.push(Token::LParen);
+        i += 1;
+      },
+      ')' => {
+        tokens.push(Token::RParen);
+        i += 1;
+      },
+      ',' => {
+        tokens.push(Token::Comma);
+        i += 1;
+      },
+      '{' => {
+        let end = chars[i..]
+          .iter()
+          .position(|c| *c == '}')
+          .ok_or_else(|| invalid_formula("Unclosed field reference"))?;
+        let field_id = chars[i + 1..i + end].iter().collect::<String>();
+        tokens.push(Token::FieldRef(field_id.trim().to_string()));
+        i += end + 1;
+      },
+      '"' => {
+        let mut value = String::new();
+        i += 1;
+        loop {
+          match chars.get(i) {
+            None => return Err(invalid_formula("Unclosed string literal")),
+            Some('"') => {
+              i += 1;
+              break;
+            },
+            Some('\\') if chars.get(i + 1).is_some() => {
+              value.push(chars[i + 1]);
+              i += 2;
+            },
+            Some(c) => {
+              value.push(*c);
+              i += 1;
+            },
+          }
+        }
+        tokens.push(Token::Str(value));
+      },
+      c if c.is_ascii_digit() || c == '.' => {
+        let start = i;
+        while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+          i += 1;
+        }
+        let s = chars[start..i].iter().collect::<String>();
+        let number =
+          Decimal::from_str(&s).map_err(|_| invalid_formula(format!("Invalid number: {}", s)))?;
+        tokens.push(Token::Number(number));
+      },
+      c if c.is_alphabetic() || c == '_' => {
+        let start = i;
+        while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+          i += 1;
+        }
+        tokens.push(Token::Ident(
+          chars[start..i].iter().collect::<String>().to_lowercase(),
+        ));
+      },
+      _ => {
+        let next = chars.get(i + 1).copied();
+        let op = match (c, next) {
+          ('<', Some('=')) => "<=",
+          ('>', Some('=')) => ">=",
+          ('!', Some('=')) | ('<', Some('>')) => "!=",
+          ('=', Some('=')) => "=",
+          ('+', _) => "+",
+          ('-', _) => "-",
+          ('*', _) => "*",
+          ('/', _) => "/",
+          ('%', _) => "%",
+          ('&', _) => "&",
+          ('=', _) => "=",
+          ('<', _) => "<",
+          ('>', _) => ">",
+          _ => return Err(invalid_formula(format!("Unexpected character: {}", c))),
+        };
+        i += if matches!(
+          (c, next),
+          ('<', Some('='))
+            | ('>', Some('='))
+            | ('!', Some('='))
+            | ('<', Some('>'))
+            | ('=', Some('='))
+        ) {
+          2
+        } else {
+          1
+        };
+        tokens.push(Token::Op(op));
+      },
+    }
+  }
+  Ok(tokens)
+}
+
+struct Parser {
+  tokens: Vec<Token>,
+  pos: usize,
+  /// The depth of the expression that is being parsed, see [MAX_FORMULA_DEPTH]
+  depth: usize,
+}
+
+impl Parser {
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.pos)
+  }
+
+  fn next(&mut self) -> Option<Token> {
+    let token = self.tokens.get(self.pos).cloned();
+    self.pos += 1;
+    token
+  }
+
+  fn next_op(&mut self, ops: &[&'static str]) -> Option<&'static str> {
+    match self.peek() {
+      Some(Token::Op(op)) if ops.contains(op) => {
+        let op = *op;
+        self.pos += 1;
+        Some(op)
+      },
+      _ => None,
+    }
+  }
+
+  /// Enters a nested expression. The depth is restored by the caller when the expression is
+  /// parsed.
+  fn enter(&mut self) -> FlowyResult<()> {
+    if self.depth >= MAX_FORMULA_DEPTH {
+      return Err(invalid_formula("The formula is nested too deeply"));
+    }
+    self.depth += 1;
+    Ok(())
+  }
+
+  fn parse_expr(&mut self) -> FlowyResult<FormulaExpression> {
+    let left = self.parse_concat()?;
+    match self.next_op(&["=", "!=", "<", "<=", ">", ">="]) {
+      None => Ok(left),
+      Some(op) => {
+        let right = self.parse_concat()?;
+        Ok(binary(op, left, right))
+      },
+    }
+  }
+
+  fn parse_concat(&mut self) -> FlowyResult<FormulaExpression> {
+    let depth = self.depth;
+    let mut left = self.parse_additive()?;
+    while let Some(op) = self.next_op(&["&"]) {
+      // Each operator nests the expression on its left one level deeper
+      self.enter()?;
+      let right = self.parse_additive()?;
+      left = binary(op, left, right);
+    }
+    self.depth = depth;
+    Ok(left)
+  }
+
+  fn parse_additive(&mut self) -> FlowyResult<FormulaExpression> {
+    let depth = self.depth;
+    let mut left = self.parse_term()?;
+    while let Some(op) = self.next_op(&["+", "-"]) {
+      // Each operator nests the expression on its left one level deeper
+      self.enter()?;
+      let right = self.parse_term()?;
+      left = binary(op, left, right);
+    }
+    self.depth = depth;
+    Ok(left)
+  }
+
+  fn parse_term(&mut self) -> FlowyResult<FormulaExpression> {
+    let depth = self.depth;
+    let mut left = self.parse_unary()?;
+    while let Some(op) = self.next_op(&["*", "/", "%"]) {
+      // Each operator nests the expression on its left one level deeper
+      self.enter()?;
+      let right = self.parse_unary()?;
+      left = binary(op, left, right);
+    }
+    self.depth = depth;
+    Ok(left)
+  }
+
+  fn parse_unary(&mut self) -> FlowyResult<FormulaExpression> {
+    if self.next_op(&["-"]).is_some() {
+      self.enter()?;
+      let expr = self.parse_unary()?;
+      self.depth -= 1;
+      return Ok(FormulaExpression::Negate(Box::new(expr)));
+    }
+    self.parse_primary()
+  }
+
+  fn parse_primary(&mut self) -> FlowyResult<FormulaExpression> {
+    match self.next() {
+      Some(Token::Number(number)) => Ok(FormulaExpression::Literal(FormulaValue::Number(number))),
+      Some(Token::Str(s)) => Ok(FormulaExpression::Literal(FormulaValue::Text(s))),
+      Some(Token::FieldRef(field_id)) => Ok(FormulaExpression::FieldRef(field_id)),
+      Some(Token::LParen) => {
+        self.enter()?;
+        let expr = self.parse_expr()?;
+        self.depth -= 1;
+        match self.next() {
+          Some(Token::RParen) => Ok(expr),
+          _ => Err(invalid_formula("Expected )")),
+        }
+      },
+      Some(Token::Ident(name)) => match name.as_str() {
+        "true" => Ok(FormulaExpression::Literal(FormulaValue::Bool(true))),
+        "false" => Ok(FormulaExpression::Literal(FormulaValue::Bool(false))),
+        _ => {
+          match self.next() {
+            Some(Token::LParen) => {},
+            _ => return Err(invalid_formula(format!("Expected ( after {}", name))),
+          }
+          let mut args = vec![];
+          if self.peek() == Some(&Token::RParen) {
+            self.pos += 1;
+            return Ok(FormulaExpression::Call { name, args });
+          }
+          self.enter()?;
+          loop {
+            args.push(self.parse_expr()?);
+            match self.next() {
+              Some(Token::Comma) => continue,
+              Some(Token::RParen) => break,
+              _ => return Err(invalid_formula("Expected , or )")),
+            }
+          }
+          self.depth -= 1;
+          Ok(FormulaExpression::Call { name, args })
+        },
+      },
+      Some(token) => Err(invalid_formula(format!("Unexpected token: {:?}", token))),
+      None => Err(invalid_formula("Unexpected end of formula")),
+    }
+  }
+}
+
+fn binary(op: &str, left: FormulaExpression, right: FormulaExpression) -> FormulaExpression {
+  let op = match op {
+    "+" => BinaryOp::Add,
+    "-" => BinaryOp::Sub,
+    "*" => BinaryOp::Mul,
+    "/" => BinaryOp::Div,
+    "%" => BinaryOp::Rem,
+    "&" => BinaryOp::Concat,
+    "=" => BinaryOp::Eq,
+    "!=" => BinaryOp::NotEq,
+    "<" => BinaryOp::Lt,
+    "<=" => BinaryOp::LtEq,
+    ">" => BinaryOp::Gt,
+    _ => BinaryOp::GtEq,
+  };
+  FormulaExpression::Binary {
+    op,
+    left: Box::new(left),
+    right: Box::new(right),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+  use std::str::FromStr;
+
+  use rust_decimal::Decimal;
+
+  use crate::services::field::{date_in_timezone, FormulaExpression, FormulaValue};
+
+  fn eval(s: &str, values: &HashMap<String, FormulaValue>) -> FormulaValue {
+    FormulaExpression::from_str(s)
+      .unwrap()
+      .evaluate(values, "Etc/UTC")
+  }
+
+  fn number(s: &str) -> FormulaValue {
+    FormulaValue::Number(Decimal::from_str(s).unwrap())
+  }
+
+  #[test]
+  fn formula_arithmetic_test() {
+    let values = HashMap::new();
+    assert_eq!(eval("1 + 2 * 3", &values), number("7"));
+    assert_eq!(eval("(1 + 2) * 3", &values), number("9"));
+    assert_eq!(eval("-4 / 8", &values), number("-0.5"));
+    assert_eq!(eval("0.1 + 0.2", &values), number("0.3"));
+    assert_eq!(eval("7 % 4", &values), number("3"));
+    assert!(matches!(eval("1 / 0", &values), FormulaValue::Error(_)));
+  }
+
+  #[test]
+  fn formula_field_ref_test() {
+    let values = HashMap::from([
+      ("price".to_string(), number("12.5")),
+      ("quantity".to_string(), number("4")),
+      ("name".to_string(), FormulaValue::Text("Pen".to_string())),
+    ]);
+    let expr = FormulaExpression::from_str("{price} * {quantity} + {missing}").unwrap();
+    assert_eq!(
+      expr.referenced_field_ids(),
+      vec![
+        "price".to_string(),
+        "quantity".to_string(),
+        "missing".to_string()
+      ]
+    );
+    assert_eq!(expr.evaluate(&values, "Etc/UTC"), number("50"));
+    assert_eq!(
+      eval("{name} & \" x\" & {quantity}", &values),
+      FormulaValue::Text("Pen x4".to_string())
+    );
+    assert_eq!(
+      eval("concat(upper({name}), \"!\")", &values),
+      FormulaValue::Text("PEN!".to_string())
+    );
+  }
+
+  #[test]
+  fn formula_if_test() {
+    let values = HashMap::from([
+      ("done".to_string(), FormulaValue::Bool(true)),
+      ("score".to_string(), number("42")),
+    ]);
+    assert_eq!(
+      eval("if({done}, \"Yes\", \"No\")", &values),
+      FormulaValue::Text("Yes".to_string())
+    );
+    assert_eq!(
+      eval("if({score} >= 50, \"Pass\", \"Fail\")", &values),
+      FormulaValue::Text("Fail".to_string())
+    );
+    // The branch that is not taken is never evaluated
+    assert_eq!(eval("if(true, 1, 1 / 0)", &values), number("1"));
+  }
+
+  #[test]
+  fn formula_date_math_test() {
+    let values = HashMap::from([
+      ("start".to_string(), FormulaValue::Date(1_688_169_600)),
+      ("end".to_string(), FormulaValue::Date(1_688_774_400)),
+    ]);
+    assert_eq!(eval("{end} - {start}", &values), number("7"));
+    assert_eq!(eval("days_between({start}, {end})", &values), number("7"));
+    assert_eq!(
+      eval("{start} + 7", &values),
+      FormulaValue::Date(1_688_774_400)
+    );
+    assert_eq!(
+      eval("date_add({end}, -7)", &values),
+      FormulaValue::Date(1_688_169_600)
+    );
+  }
+
+  #[test]
+  fn formula_parse_error_test() {
+    assert!(FormulaExpression::from_str("1 +").is_err());
+    assert!(FormulaExpression::from_str("{price").is_err());
+    assert!(FormulaExpression::from_str("\"abc").is_err());
+    assert!(FormulaExpression::from_str("sum(1, 2").is_err());
+    assert!(FormulaExpression::from_str("1 2").is_err());
+    assert!(matches!(
+      eval("unknown(1)", &HashMap::new()),
+      FormulaValue::Error(_)
+    ));
+  }
+
+  #[test]
+  fn formula_nested_too_deeply_test() {
+    let nested = |open: &str, close: &str, count: usize| {
+      format!("{}1{}", open.repeat(count), close.repeat(count))
+    };
+    assert_eq!(eval(&nested("(", ")", 50), &HashMap::new()), number("1"));
+    assert!(FormulaExpression::from_str(&nested("(", ")", 100_000)).is_err());
+    assert!(FormulaExpression::from_str(&nested("-", "", 100_000)).is_err());
+    assert!(FormulaExpression::from_str(&nested("abs(", ")", 100_000)).is_err());
+    assert!(FormulaExpression::from_str(&nested("", " + 1", 100_000)).is_err());
+  }
+
+  #[test]
+  fn formula_today_in_timezone_test() {
+    for timezone_id in ["Etc/UTC", "Asia/Shanghai", "America/Los_Angeles"] {
+      let today = match FormulaExpression::from_str("today()")
+        .unwrap()
+        .evaluate(&HashMap::new(), timezone_id)
+      {
+        FormulaValue::Date(timestamp) => timestamp,
+        value => panic!("Expected a date, got {:?}", value),
+      };
+      let now = chrono::Utc::now().timestamp();
+      assert_eq!(
+        date_in_timezone(today, timezone_id),
+        date_in_timezone(now, timezone_id)
+      );
+      assert!(today <= now && now - today < 86400);
+    }
+  }
+}
@@ -0,0 +1,91 @@
+use crate::entities::{FormulaFilterConditionPB, FormulaFilterPB};
+use crate::services::field::FormulaValue;
+
+impl FormulaFilterPB {
+  pub fn is_visible(&self, value: &FormulaValue) -> bool {
+    let content = FormulaValue::Text(self.content.clone());
+    let text = value.to_text().to_lowercase();
+    let content_text = self.content.to_lowercase();
+    match self.condition {
+      FormulaFilterConditionPB::IsEmpty => value.is_empty(),
+      FormulaFilterConditionPB::IsNotEmpty => !value.is_empty(),
+      FormulaFilterConditionPB::Is => value.cmp_value(&content).is_eq(),
+      FormulaFilterConditionPB::IsNot => !value.cmp_value(&content).is_eq(),
+      FormulaFilterConditionPB::Contains => text.contains(&content_text),
+      FormulaFilterConditionPB::DoesNotContain => !text.contains(&content_text),
+      FormulaFilterConditionPB::StartsWith => text.starts_with(&content_text),
+      FormulaFilterConditionPB::EndsWith => text.ends_with(&content_text),
+      FormulaFilterConditionPB::GreaterThan => {
+        !value.is_empty() && value.cmp_value(&content).is_gt()
+      },
+      FormulaFilterConditionPB::LessThan => !value.is_empty() && value.cmp_value(&content).is_lt(),
+      FormulaFilterConditionPB::GreaterThanOrEqualTo => {
+        !value.is_empty() && value.cmp_value(&content).is_ge()
+      },
+      FormulaFilterConditionPB::LessThanOrEqualTo => {
+        !value.is_empty() && value.cmp_value(&content).is_le()
+      },
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::str::FromStr;
+
+  use rust_decimal::Decimal;
+
+  use crate::entities::{FormulaFilterConditionPB, FormulaFilterPB};
+  use crate::services::field::FormulaValue;
+
+  fn number(s: &str) -> FormulaValue {
+    FormulaValue::Number(Decimal::from_str(s).unwrap())
+  }
+
+  #[test]
+  fn formula_filter_number_test() {
+    let filter = FormulaFilterPB {
+      condition: FormulaFilterConditionPB::GreaterThan,
+      content: "12".to_owned(),
+    };
+    for (value, visible) in [
+      (number("123"), true),
+      (number("9"), false),
+      (number("12"), false),
+      (FormulaValue::Empty, false),
+    ] {
+      assert_eq!(filter.is_visible(&value), visible);
+    }
+
+    let filter = FormulaFilterPB {
+      condition: FormulaFilterConditionPB::Is,
+      content: "12.50".to_owned(),
+    };
+    assert!(filter.is_visible(&number("12.5")));
+    assert!(!filter.is_visible(&number("12")));
+  }
+
+  #[test]
+  fn formula_filter_text_test() {
+    let filter = FormulaFilterPB {
+      condition: FormulaFilterConditionPB::Contains,
+      content: "flowy".to_owned(),
+    };
+    assert!(filter.is_visible(&FormulaValue::Text("AppFlowy".to_owned())));
+    assert!(!filter.is_visible(&FormulaValue::Text("App".to_owned())));
+
+    let filter = FormulaFilterPB {
+      condition: FormulaFilterConditionPB::Is,
+      content: "appflowy".to_owned(),
+    };
+    assert!(filter.is_visible(&FormulaValue::Text("AppFlowy".to_owned())));
+
+    let filter = FormulaFilterPB {
+      condition: FormulaFilterConditionPB::IsEmpty,
+      content: "".to_owned(),
+    };
+    assert!(filter.is_visible(&FormulaValue::Empty));
+    assert!(filter.is_visible(&FormulaValue::Text("".to_owned())));
+    assert!(!filter.is_visible(&number("0")));
+  }
+}
@@ -0,0 +1,255 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use chrono::{DateTime, Local, Offset};
+use collab::core::any_map::AnyMapExtension;
+use collab_database::fields::{Field, TypeOptionData, TypeOptionDataBuilder};
+use collab_database::rows::{Cell, Cells};
+use serde::{Deserialize, Serialize};
+
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+
+use crate::entities::{FieldType, FormulaCellDataPB, FormulaFilterPB};
+use crate::services::cell::{stringify_cell_data, CellDataChangeset, CellDataDecoder};
+use crate::services::field::{
+  CheckboxCellData, DateCellData, DateFormat, FormulaCellData, FormulaExpression, FormulaValue,
+  NumberCellData, NumberTypeOption, TypeOption, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionCellDataSerde, TypeOptionTransform,
+};
+use crate::services::sort::SortCondition;
+
+/// The [FormulaTypeOption] stores an expression that is evaluated against the other cells of
+/// the row. The result is saved in the formula cell, so the cells can't be edited directly.
+/// Check out the [FormulaExpression] for the supported syntax.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FormulaTypeOption {
+  pub expression: String,
+  pub date_format: DateFormat,
+}
+
+impl TypeOption for FormulaTypeOption {
+  type CellData = FormulaCellData;
+  type CellChangeset = String;
+  type CellProtobufType = FormulaCellDataPB;
+  type CellFilter = FormulaFilterPB;
+}
+
+impl From<TypeOptionData> for FormulaTypeOption {
+  fn from(data: TypeOptionData) -> Self {
+    let expression = data.get_str_value("expression").unwrap_or_default();
+    let date_format = data
+      .get_i64_value("date_format")
+      .map(DateFormat::from)
+      .unwrap_or_default();
+    Self {
+      expression,
+      date_format,
+    }
+  }
+}
+
+impl From<FormulaTypeOption> for TypeOptionData {
+  fn from(option: FormulaTypeOption) -> Self {
+    TypeOptionDataBuilder::new()
+      .insert_str_value("expression", option.expression)
+      .insert_i64_value("date_format", option.date_format.value())
+      .build()
+  }
+}
+
+impl FormulaTypeOption {
+  /// Parses the expression. An empty expression evaluates to an empty value.
+  pub fn parse_expression(&self) -> FlowyResult<FormulaExpression> {
+    if self.expression.trim().is_empty() {
+      return Ok(FormulaExpression::Literal(FormulaValue::Empty));
+    }
+    FormulaExpression::from_str(&self.expression)
+  }
+
+  /// Returns the ids of the fields that the expression depends on.
+  pub fn referenced_field_ids(&self) -> Vec<String> {
+    self
+      .parse_expression()
+      .map(|expr| expr.referenced_field_ids())
+      .unwrap_or_default()
+  }
+
+  fn format_date(&self, timestamp: i64) -> String {
+    match chrono::NaiveDateTime::from_timestamp_opt(timestamp, 0) {
+      None => "".to_string(),
+      Some(naive) => {
+        let offset = Local::now().offset().fix();
+        let date_time = DateTime::<Local>::from_naive_utc_and_offset(naive, offset);
+        format!("{}", date_time.format(self.date_format.format_str()))
+      },
+    }
+  }
+}
+
+/// Evaluates the parsed expression of a formula field using the cells of a row that might not be
+/// created yet. Check out [FormulaExpression::evaluate] for the `timezone_id`.
+pub fn evaluate_formula(
+  expr: &FormulaExpression,
+  cells: &Cells,
+  created_at: i64,
+  modified_at: i64,
+  fields: &[Field],
+  timezone_id: &str,
+) -> FormulaValue {
+  let values = expr
+    .referenced_field_ids()
+    .into_iter()
+    .filter_map(|field_id| {
+      let field = fields.iter().find(|field| field.id == field_id)?;
+      Some((
+        field_id,
+        formula_value_from_cells(cells, created_at, modified_at, field),
+      ))
+    })
+    .collect::<HashMap<String, FormulaValue>>();
+  expr.evaluate(&values, timezone_id)
+}
+
+/// Reads the value of the field in the row that can be used in a formula.
+fn formula_value_from_cells(
+  cells: &Cells,
+  created_at: i64,
+  modified_at: i64,
+  field: &Field,
+) -> FormulaValue {
+  let field_type = FieldType::from(field.field_type);
+  match field_type {
+    FieldType::CreatedTime => return FormulaValue::Date(created_at),
+    FieldType::LastEditedTime => return FormulaValue::Date(modified_at),
+    _ => {},
+  }
+
+  let cell = match cells.get(&field.id) {
+    None => return FormulaValue::Empty,
+    Some(cell) => cell,
+  };
+  match field_type {
+    FieldType::Number => field
+      .get_type_option::<NumberTypeOption>(&field_type)
+      .and_then(|type_option| {
+        type_option
+          .format_cell_data(&NumberCellData::from(cell))
+          .ok()
+      })
+      .and_then(|cell_format| *cell_format.decimal())
+      .map(FormulaValue::Number)
+      .unwrap_or_default(),
+    FieldType::DateTime => DateCellData::from(cell)
+      .timestamp
+      .map(FormulaValue::Date)
+      .unwrap_or_default(),
+    FieldType::Checkbox => FormulaValue::Bool(CheckboxCellData::from(cell).is_check()),
+    FieldType::Formula => FormulaCellData::from(cell).0,
+    _ => {
+      let s = stringify_cell_data(cell, &field_type, &field_type, field);
+      if s.is_empty() {
+        FormulaValue::Empty
+      } else {
+        FormulaValue::Text(s)
+      }
+    },
+  }
+}
+
+impl TypeOptionCellDataSerde for FormulaTypeOption {
+  fn protobuf_encode(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    let error = match &cell_data.0 {
+      FormulaValue::Error(msg) => Some(msg.clone()),
+      _ => None,
+    };
+    FormulaCellDataPB {
+      data: self.stringify_cell_data(cell_data),
+      error,
+    }
+  }
+
+  fn parse_cell(&self, cell: &Cell) -> FlowyResult<<Self as TypeOption>::CellData> {
+    Ok(FormulaCellData::from(cell))
+  }
+}
+
+impl TypeOptionTransform for FormulaTypeOption {}
+
+impl CellDataDecoder for FormulaTypeOption {
+  fn decode_cell(
+    &self,
+    cell: &Cell,
+    decoded_field_type: &FieldType,
+    _field: &Field,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_formula() {
+      return Ok(Default::default());
+    }
+
+    self.parse_cell(cell)
+  }
+
+  fn stringify_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    match cell_data.0 {
+      FormulaValue::Date(timestamp) => self.format_date(timestamp),
+      FormulaValue::Error(_) => "#ERROR".to_string(),
+      value => value.to_text(),
+    }
+  }
+
+  fn stringify_cell(&self, cell: &Cell) -> String {
+    let cell_data = Self::CellData::from(cell);
+    self.stringify_cell_data(cell_data)
+  }
+}
+
+impl CellDataChangeset for FormulaTypeOption {
+  fn apply_changeset(
+    &self,
+    _changeset: <Self as TypeOption>::CellChangeset,
+    _cell: Option<Cell>,
+  ) -> FlowyResult<(Cell, <Self as TypeOption>::CellData)> {
+    Err(FlowyError::new(
+      ErrorCode::FieldInvalidOperation,
+      "Cells of this field type cannot be edited",
+    ))
+  }
+}
+
+impl TypeOptionCellDataFilter for FormulaTypeOption {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_formula() {
+      return true;
+    }
+
+    filter.is_visible(cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for FormulaTypeOption {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+    sort_condition: SortCondition,
+  ) -> Ordering {
+    match (cell_data.is_cell_empty(), other_cell_data.is_cell_empty()) {
+      (true, true) => Ordering::Equal,
+      (true, false) => Ordering::Greater,
+      (false, true) => Ordering::Less,
+      (false, false) => {
+        let order = cell_data.cmp_value(other_cell_data);
+        sort_condition.evaluate_order(order)
+      },
+    }
+  }
+}
@@ -0,0 +1,148 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use collab::core::any_map::AnyMapExtension;
+use collab_database::rows::{new_cell_builder, Cell};
+use rust_decimal::prelude::Zero;
+use rust_decimal::Decimal;
+
+use crate::entities::FieldType;
+use crate::services::field::{TypeOptionCellData, CELL_DATA};
+
+const VALUE_TYPE: &str = "value_type";
+
+/// The value produced by evaluating a formula, or read from a cell that is referenced by a formula.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum FormulaValue {
+  #[default]
+  Empty,
+  Number(Decimal),
+  Text(String),
+  Bool(bool),
+  /// Unix timestamp in seconds
+  Date(i64),
+  Error(String),
+}
+
+impl FormulaValue {
+  pub fn value_type(&self) -> i64 {
+    match self {
+      FormulaValue::Empty => 0,
+      FormulaValue::Number(_) => 1,
+      FormulaValue::Text(_) => 2,
+      FormulaValue::Bool(_) => 3,
+      FormulaValue::Date(_) => 4,
+      FormulaValue::Error(_) => 5,
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    match self {
+      FormulaValue::Empty => true,
+      FormulaValue::Text(s) => s.is_empty(),
+      _ => false,
+    }
+  }
+
+  pub fn is_truthy(&self) -> bool {
+    match self {
+      FormulaValue::Empty | FormulaValue::Error(_) => false,
+      FormulaValue::Number(number) => !number.is_zero(),
+      FormulaValue::Text(s) => !s.is_empty(),
+      FormulaValue::Bool(value) => *value,
+      FormulaValue::Date(_) => true,
+    }
+  }
+
+  pub fn as_decimal(&self) -> Option<Decimal> {
+    match self {
+      FormulaValue::Number(number) => Some(*number),
+      FormulaValue::Date(timestamp) => Some(Decimal::from(*timestamp)),
+      FormulaValue::Text(s) => Decimal::from_str(s.trim()).ok(),
+      _ => None,
+    }
+  }
+
+  /// Returns the raw text of the value. Dates are returned as timestamps, use the
+  /// [FormulaTypeOption](crate::services::field::FormulaTypeOption) to format them.
+  pub fn to_text(&self) -> String {
+    match self {
+      FormulaValue::Empty => "".to_string(),
+      FormulaValue::Number(number) => number.normalize().to_string(),
+      FormulaValue::Text(s) => s.clone(),
+      FormulaValue::Bool(value) => value.to_string(),
+      FormulaValue::Date(timestamp) => timestamp.to_string(),
+      FormulaValue::Error(msg) => msg.clone(),
+    }
+  }
+
+  /// Compares two values. Numbers, dates and numeric strings are compared by value, everything
+  /// else is compared as case-insensitive text.
+  pub fn cmp_value(&self, other: &FormulaValue) -> Ordering {
+    match (self.as_decimal(), other.as_decimal()) {
+      (Some(left), Some(right)) => left.cmp(&right),
+      _ => self
+        .to_text()
+        .to_lowercase()
+        .cmp(&other.to_text().to_lowercase()),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FormulaCellData(pub FormulaValue);
+
+impl std::ops::Deref for FormulaCellData {
+  type Target = FormulaValue;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl TypeOptionCellData for FormulaCellData {
+  fn is_cell_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
+impl From<&Cell> for FormulaCellData {
+  fn from(cell: &Cell) -> Self {
+    let data = cell.get_str_value(CELL_DATA).unwrap_or_default();
+    let value = match cell.get_i64_value(VALUE_TYPE).unwrap_or_default() {
+      1 => Decimal::from_str(&data)
+        .map(FormulaValue::Number)
+        .unwrap_or_default(),
+      2 => FormulaValue::Text(data),
+      3 => FormulaValue::Bool(data == "true"),
+      4 => data
+        .parse::<i64>()
+        .map(FormulaValue::Date)
+        .unwrap_or_default(),
+      5 => FormulaValue::Error(data),
+      _ => FormulaValue::Empty,
+    };
+    Self(value)
+  }
+}
+
+impl From<FormulaCellData> for Cell {
+  fn from(data: FormulaCellData) -> Self {
+    new_cell_builder(FieldType::Formula)
+      .insert_str_value(CELL_DATA, data.0.to_text())
+      .insert_i64_value(VALUE_TYPE, data.0.value_type())
+      .build()
+  }
+}
+
+impl From<FormulaValue> for FormulaCellData {
+  fn from(value: FormulaValue) -> Self {
+    Self(value)
+  }
+}
+
+impl ToString for FormulaCellData {
+  fn to_string(&self) -> String {
+    self.0.to_text()
+  }
+}
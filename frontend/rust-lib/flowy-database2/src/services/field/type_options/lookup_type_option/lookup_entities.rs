@@ -0,0 +1,103 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use collab::core::any_map::AnyMapExtension;
+use collab_database::rows::{new_cell_builder, Cell};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::FieldType;
+use crate::services::field::{TypeOptionCellData, CELL_DATA};
+
+/// Describes how the value of the lookup cell is computed when several rows of the source
+/// database match the key.
+#[derive(Clone, Debug, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum LookupAggregation {
+  /// The value of the first matching row
+  #[default]
+  First = 0,
+  /// The number of matching rows
+  Count = 1,
+  /// The sum of the numeric values of the matching rows
+  Sum = 2,
+  /// The non-empty values of the matching rows separated by commas
+  Concatenate = 3,
+}
+
+impl std::convert::From<i64> for LookupAggregation {
+  fn from(value: i64) -> Self {
+    match value {
+      0 => LookupAggregation::First,
+      1 => LookupAggregation::Count,
+      2 => LookupAggregation::Sum,
+      3 => LookupAggregation::Concatenate,
+      _ => {
+        tracing::error!("Unsupported lookup aggregation, fallback to first");
+        LookupAggregation::First
+      },
+    }
+  }
+}
+
+impl LookupAggregation {
+  pub fn value(&self) -> i64 {
+    *self as i64
+  }
+}
+
+/// The value pulled from the source database. It's computed by the `DatabaseManager` and saved
+/// in the cell, so the cell can be read without opening the source database.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LookupCellData(pub String);
+
+impl LookupCellData {
+  /// Compares the values numerically if both of them are numbers, otherwise compares them as
+  /// case-insensitive text.
+  pub fn cmp_value(&self, other: &Self) -> Ordering {
+    match (
+      Decimal::from_str(self.0.trim()),
+      Decimal::from_str(other.0.trim()),
+    ) {
+      (Ok(left), Ok(right)) => left.cmp(&right),
+      _ => self.0.to_lowercase().cmp(&other.0.to_lowercase()),
+    }
+  }
+}
+
+impl TypeOptionCellData for LookupCellData {
+  fn is_cell_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
+impl From<&Cell> for LookupCellData {
+  fn from(cell: &Cell) -> Self {
+    Self(cell.get_str_value(CELL_DATA).unwrap_or_default())
+  }
+}
+
+impl From<String> for LookupCellData {
+  fn from(s: String) -> Self {
+    Self(s)
+  }
+}
+
+impl From<LookupCellData> for Cell {
+  fn from(data: LookupCellData) -> Self {
+    new_cell_builder(FieldType::Lookup)
+      .insert_str_value(CELL_DATA, data.0)
+      .build()
+  }
+}
+
+impl ToString for LookupCellData {
+  fn to_string(&self) -> String {
+    self.0.clone()
+  }
+}
+
+impl AsRef<str> for LookupCellData {
+  fn as_ref(&self) -> &str {
+    self.0.as_str()
+  }
+}
@@ -0,0 +1,304 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use collab::core::any_map::AnyMapExtension;
+use collab_database::fields::{Field, TypeOptionData, TypeOptionDataBuilder};
+use collab_database::rows::Cell;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+
+use crate::entities::FieldType;
+use crate::services::cell::{stringify_cell_data, CellDataChangeset, CellDataDecoder};
+use crate::services::field::type_options::util::ProtobufStr;
+use crate::services::field::{
+  FormulaCellData, LookupAggregation, LookupCellData, NumberCellData, NumberTypeOption, TextFilter,
+  TypeOption, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionCellDataSerde, TypeOptionTransform,
+};
+use crate::services::sort::SortCondition;
+
+/// The [LookupTypeOption] pulls a value from the rows of another database whose key matches the
+/// key of the row. The value is computed by the `DatabaseManager` and saved in the cell, so the
+/// cells can't be edited directly.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct LookupTypeOption {
+  pub database_id: String,
+  /// The id of the field in this database whose content is used as the key
+  pub key_field_id: String,
+  /// The id of the field in the source database that is matched against the key
+  pub source_key_field_id: String,
+  /// The id of the field in the source database whose content is pulled
+  pub value_field_id: String,
+  pub aggregation: LookupAggregation,
+}
+
+impl TypeOption for LookupTypeOption {
+  type CellData = LookupCellData;
+  type CellChangeset = String;
+  type CellProtobufType = ProtobufStr;
+  type CellFilter = TextFilter;
+}
+
+impl From<TypeOptionData> for LookupTypeOption {
+  fn from(data: TypeOptionData) -> Self {
+    let database_id = data.get_str_value("database_id").unwrap_or_default();
+    let key_field_id = data.get_str_value("key_field_id").unwrap_or_default();
+    let source_key_field_id = data
+      .get_str_value("source_key_field_id")
+      .unwrap_or_default();
+    let value_field_id = data.get_str_value("value_field_id").unwrap_or_default();
+    let aggregation = data
+      .get_i64_value("aggregation")
+      .map(LookupAggregation::from)
+      .unwrap_or_default();
+    Self {
+      database_id,
+      key_field_id,
+      source_key_field_id,
+      value_field_id,
+      aggregation,
+    }
+  }
+}
+
+impl From<LookupTypeOption> for TypeOptionData {
+  fn from(data: LookupTypeOption) -> Self {
+    TypeOptionDataBuilder::new()
+      .insert_str_value("database_id", data.database_id)
+      .insert_str_value("key_field_id", data.key_field_id)
+      .insert_str_value("source_key_field_id", data.source_key_field_id)
+      .insert_str_value("value_field_id", data.value_field_id)
+      .insert_i64_value("aggregation", data.aggregation.value())
+      .build()
+  }
+}
+
+impl LookupTypeOption {
+  /// Returns true if the source database and all the fields that are required to pull the value
+  /// are set.
+  pub fn is_configured(&self) -> bool {
+    !self.database_id.is_empty()
+      && !self.key_field_id.is_empty()
+      && !self.source_key_field_id.is_empty()
+      && !self.value_field_id.is_empty()
+  }
+
+  /// Computes the content of the lookup cell. The `cells` are the cells of the value field in
+  /// the source rows that match the key, `None` if the source row doesn't have the cell.
+  pub fn aggregate(&self, value_field: Option<&Field>, cells: &[Option<Cell>]) -> LookupCellData {
+    let value_field = match (self.aggregation, value_field) {
+      (LookupAggregation::Count, _) => return LookupCellData(cells.len().to_string()),
+      (_, None) => return LookupCellData::default(),
+      (_, Some(field)) => field,
+    };
+
+    let cells = cells.iter().flatten();
+    let s = match self.aggregation {
+      LookupAggregation::First => cells
+        .take(1)
+        .map(|cell| lookup_text_from_cell(cell, value_field))
+        .collect::<String>(),
+      LookupAggregation::Sum => cells
+        .filter_map(|cell| lookup_decimal_from_cell(cell, value_field))
+        .reduce(|sum, value| sum + value)
+        .map(|sum| sum.normalize().to_string())
+        .unwrap_or_default(),
+      LookupAggregation::Concatenate | LookupAggregation::Count => cells
+        .map(|cell| lookup_text_from_cell(cell, value_field))
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<String>>()
+        .join(", "),
+    };
+    LookupCellData(s)
+  }
+}
+
+/// Returns the text that is used to match the key of the row against the key of the source rows.
+pub fn lookup_key_from_cell(cell: &Cell, field: &Field) -> String {
+  lookup_text_from_cell(cell, field).trim().to_string()
+}
+
+fn lookup_text_from_cell(cell: &Cell, field: &Field) -> String {
+  let field_type = FieldType::from(field.field_type);
+  stringify_cell_data(cell, &field_type, &field_type, field)
+}
+
+fn lookup_decimal_from_cell(cell: &Cell, field: &Field) -> Option<Decimal> {
+  let field_type = FieldType::from(field.field_type);
+  match field_type {
+    FieldType::Number => field
+      .get_type_option::<NumberTypeOption>(&field_type)
+      .and_then(|type_option| {
+        type_option
+          .format_cell_data(&NumberCellData::from(cell))
+          .ok()
+      })
+      .and_then(|cell_format| *cell_format.decimal()),
+    FieldType::Formula => FormulaCellData::from(cell).0.as_decimal(),
+    _ => Decimal::from_str(lookup_text_from_cell(cell, field).trim()).ok(),
+  }
+}
+
+impl TypeOptionCellDataSerde for LookupTypeOption {
+  fn protobuf_encode(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    ProtobufStr::from(cell_data.0)
+  }
+
+  fn parse_cell(&self, cell: &Cell) -> FlowyResult<<Self as TypeOption>::CellData> {
+    Ok(LookupCellData::from(cell))
+  }
+}
+
+impl TypeOptionTransform for LookupTypeOption {}
+
+impl CellDataDecoder for LookupTypeOption {
+  fn decode_cell(
+    &self,
+    cell: &Cell,
+    decoded_field_type: &FieldType,
+    _field: &Field,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_lookup() {
+      return Ok(Default::default());
+    }
+
+    self.parse_cell(cell)
+  }
+
+  fn stringify_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.to_string()
+  }
+
+  fn stringify_cell(&self, cell: &Cell) -> String {
+    LookupCellData::from(cell).to_string()
+  }
+}
+
+impl CellDataChangeset for LookupTypeOption {
+  fn apply_changeset(
+    &self,
+    _changeset: <Self as TypeOption>::CellChangeset,
+    _cell: Option<Cell>,
+  ) -> FlowyResult<(Cell, <Self as TypeOption>::CellData)> {
+    Err(FlowyError::new(
+      ErrorCode::FieldInvalidOperation,
+      "Cells of this field type cannot be edited",
+    ))
+  }
+}
+
+impl TypeOptionCellDataFilter for LookupTypeOption {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_lookup() {
+      return true;
+    }
+
+    filter.is_visible(cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for LookupTypeOption {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+    sort_condition: SortCondition,
+  ) -> Ordering {
+    match (cell_data.is_cell_empty(), other_cell_data.is_cell_empty()) {
+      (true, true) => Ordering::Equal,
+      (true, false) => Ordering::Greater,
+      (false, true) => Ordering::Less,
+      (false, false) => {
+        let order = cell_data.cmp_value(other_cell_data);
+        sort_condition.evaluate_order(order)
+      },
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::entities::FieldType;
+  use crate::services::cell::{insert_number_cell, insert_text_cell};
+  use crate::services::field::{
+    FieldBuilder, LookupAggregation, LookupCellData, LookupTypeOption, NumberTypeOption,
+    RichTextTypeOption,
+  };
+
+  fn type_option(aggregation: LookupAggregation) -> LookupTypeOption {
+    LookupTypeOption {
+      aggregation,
+      ..Default::default()
+    }
+  }
+
+  #[test]
+  fn lookup_aggregate_number_test() {
+    let field = FieldBuilder::new(FieldType::Number, NumberTypeOption::default()).build();
+    let cells = vec![
+      Some(insert_number_cell(3, &field)),
+      None,
+      Some(insert_number_cell(4, &field)),
+    ];
+
+    for (aggregation, expected) in [
+      (LookupAggregation::First, "3"),
+      (LookupAggregation::Count, "3"),
+      (LookupAggregation::Sum, "7"),
+      (LookupAggregation::Concatenate, "3, 4"),
+    ] {
+      assert_eq!(
+        type_option(aggregation).aggregate(Some(&field), &cells),
+        LookupCellData(expected.to_string())
+      );
+    }
+  }
+
+  #[test]
+  fn lookup_aggregate_text_test() {
+    let field = FieldBuilder::new(FieldType::RichText, RichTextTypeOption::default()).build();
+    let cells = vec![
+      Some(insert_text_cell("apple".to_string(), &field)),
+      Some(insert_text_cell("".to_string(), &field)),
+      Some(insert_text_cell("12.5".to_string(), &field)),
+    ];
+
+    for (aggregation, expected) in [
+      (LookupAggregation::First, "apple"),
+      (LookupAggregation::Count, "3"),
+      (LookupAggregation::Sum, "12.5"),
+      (LookupAggregation::Concatenate, "apple, 12.5"),
+    ] {
+      assert_eq!(
+        type_option(aggregation).aggregate(Some(&field), &cells),
+        LookupCellData(expected.to_string())
+      );
+    }
+  }
+
+  #[test]
+  fn lookup_aggregate_no_match_test() {
+    let field = FieldBuilder::new(FieldType::Number, NumberTypeOption::default()).build();
+    for (aggregation, expected) in [
+      (LookupAggregation::First, ""),
+      (LookupAggregation::Count, "0"),
+      (LookupAggregation::Sum, ""),
+      (LookupAggregation::Concatenate, ""),
+    ] {
+      assert_eq!(
+        type_option(aggregation).aggregate(Some(&field), &[]),
+        LookupCellData(expected.to_string())
+      );
+    }
+  }
+}
@@ -0,0 +1,6 @@
+#![allow(clippy::module_inception)]
+mod lookup_entities;
+mod lookup_type_option;
+
+pub use lookup_entities::*;
+pub use lookup_type_option::*;
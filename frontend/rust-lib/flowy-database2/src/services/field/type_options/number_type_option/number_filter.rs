@@ -1,11 +1,28 @@
-use crate::entities::{NumberFilterConditionPB, NumberFilterPB};
-use crate::services::field::NumberCellFormat;
-use rust_decimal::prelude::Zero;
-use rust_decimal::Decimal;
 use std::str::FromStr;
 
+use rust_decimal::Decimal;
+
+use flowy_error::ErrorCode;
+
+use crate::entities::{NumberFilterConditionPB, NumberFilterPB, NumberFilterRangePB};
+use crate::services::field::{NumberCellFormat, NumberFormat};
+
 impl NumberFilterPB {
   pub fn is_visible(&self, num_cell_data: &NumberCellFormat) -> bool {
+    self.is_visible_with_format(num_cell_data, &NumberFormat::Num)
+  }
+
+  /// The values of the filter are parsed with the `format` of the field, so the values entered by
+  /// the user like "$1,200" or "15%" are compared with the cells as numbers.
+  ///
+  /// The content is validated when the filter is saved, but it can become invalid when the format
+  /// of the field changes. A filter whose content can't be parsed, or is empty, shows all the
+  /// rows.
+  pub fn is_visible_with_format(
+    &self,
+    num_cell_data: &NumberCellFormat,
+    format: &NumberFormat,
+  ) -> bool {
     if self.content.is_empty() {
       match self.condition {
         NumberFilterConditionPB::NumberIsEmpty => {
@@ -17,11 +34,32 @@ impl NumberFilterPB {
         _ => {},
       }
     }
-    match num_cell_data.decimal().as_ref() {
-      None => false,
-      Some(cell_decimal) => {
-        let decimal = Decimal::from_str(&self.content).unwrap_or_else(|_| Decimal::zero());
-        match self.condition {
+
+    match self.condition {
+      NumberFilterConditionPB::NumberIsBetween => match self.range(format) {
+        None => true,
+        Some(range) => num_cell_data
+          .decimal()
+          .as_ref()
+          .map(|cell_decimal| range.contains(cell_decimal))
+          .unwrap_or(false),
+      },
+      NumberFilterConditionPB::NumberIsOneOf => match self.values(format) {
+        None => true,
+        Some(values) if values.is_empty() => true,
+        Some(values) => num_cell_data
+          .decimal()
+          .as_ref()
+          .map(|cell_decimal| values.contains(cell_decimal))
+          .unwrap_or(false),
+      },
+      _ => match (
+        parse_number(&self.content, format),
+        num_cell_data.decimal().as_ref(),
+      ) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(decimal), Some(cell_decimal)) => match self.condition {
           NumberFilterConditionPB::Equal => cell_decimal == &decimal,
           NumberFilterConditionPB::NotEqual => cell_decimal != &decimal,
           NumberFilterConditionPB::GreaterThan => cell_decimal > &decimal,
@@ -29,15 +67,83 @@ impl NumberFilterPB {
           NumberFilterConditionPB::GreaterThanOrEqualTo => cell_decimal >= &decimal,
           NumberFilterConditionPB::LessThanOrEqualTo => cell_decimal <= &decimal,
           _ => true,
-        }
+        },
       },
     }
   }
+
+  /// Returns an error if the content of the filter can't be parsed with the `format` of the
+  /// field for its condition. An empty content is valid for the comparisons, which is how the
+  /// filters are created.
+  pub fn validate(&self, format: &NumberFormat) -> Result<(), ErrorCode> {
+    let is_valid = match self.condition {
+      NumberFilterConditionPB::NumberIsBetween => self.range(format).is_some(),
+      NumberFilterConditionPB::NumberIsOneOf => self.values(format).is_some(),
+      NumberFilterConditionPB::NumberIsEmpty | NumberFilterConditionPB::NumberIsNotEmpty => true,
+      _ => self.content.trim().is_empty() || parse_number(&self.content, format).is_some(),
+    };
+    if is_valid {
+      Ok(())
+    } else {
+      Err(ErrorCode::InvalidParams)
+    }
+  }
+
+  fn range(&self, format: &NumberFormat) -> Option<NumberRange> {
+    let range = NumberFilterRangePB::from_str(&self.content).ok()?;
+    Some(NumberRange {
+      start: parse_number(&range.start, format)?,
+      end: parse_number(&range.end, format)?,
+      start_inclusive: range.start_inclusive,
+      end_inclusive: range.end_inclusive,
+    })
+  }
+
+  fn values(&self, format: &NumberFormat) -> Option<Vec<Decimal>> {
+    serde_json::from_str::<Vec<String>>(&self.content)
+      .ok()?
+      .iter()
+      .map(|value| parse_number(value, format))
+      .collect()
+  }
+}
+
+struct NumberRange {
+  start: Decimal,
+  end: Decimal,
+  start_inclusive: bool,
+  end_inclusive: bool,
+}
+
+impl NumberRange {
+  fn contains(&self, decimal: &Decimal) -> bool {
+    let after_start = if self.start_inclusive {
+      decimal >= &self.start
+    } else {
+      decimal > &self.start
+    };
+    let before_end = if self.end_inclusive {
+      decimal <= &self.end
+    } else {
+      decimal < &self.end
+    };
+    after_start && before_end
+  }
+}
+
+fn parse_number(value: &str, format: &NumberFormat) -> Option<Decimal> {
+  let value = value.trim();
+  if value.is_empty() {
+    return None;
+  }
+  NumberCellFormat::from_format_str(value, format)
+    .ok()
+    .and_then(|cell_format| *cell_format.decimal())
 }
 
 #[cfg(test)]
 mod tests {
-  use crate::entities::{NumberFilterConditionPB, NumberFilterPB};
+  use crate::entities::{NumberFilterConditionPB, NumberFilterPB, NumberFilterRangePB};
   use crate::services::field::{NumberCellFormat, NumberFormat};
   #[test]
   fn number_filter_equal_test() {
@@ -80,4 +186,116 @@ mod tests {
       assert_eq!(number_filter.is_visible(&data), visible);
     }
   }
+
+  #[test]
+  fn number_filter_between_test() {
+    let range = |start_inclusive: bool, end_inclusive: bool| NumberFilterRangePB {
+      start: "$1,200".to_owned(),
+      end: "$1,500".to_owned(),
+      start_inclusive,
+      end_inclusive,
+    };
+    let format = NumberFormat::USD;
+    for (range, expected) in [
+      (range(true, true), [true, true, true, false]),
+      (range(false, true), [false, true, true, false]),
+      (range(true, false), [true, true, false, false]),
+    ] {
+      let number_filter = NumberFilterPB {
+        condition: NumberFilterConditionPB::NumberIsBetween,
+        content: range.to_string(),
+      };
+      for (num_str, visible) in ["1200", "1300.5", "1500", ""].into_iter().zip(expected) {
+        let data = NumberCellFormat::from_format_str(num_str, &format).unwrap();
+        assert_eq!(
+          number_filter.is_visible_with_format(&data, &format),
+          visible
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn number_filter_is_one_of_test() {
+    let number_filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::NumberIsOneOf,
+      content: r#"["15%", "30%"]"#.to_owned(),
+    };
+    let format = NumberFormat::Percent;
+    for (num_str, visible) in [("15", true), ("30", true), ("20", false), ("", false)] {
+      let data = NumberCellFormat::from_format_str(num_str, &format).unwrap();
+      assert_eq!(
+        number_filter.is_visible_with_format(&data, &format),
+        visible
+      );
+    }
+  }
+
+  #[test]
+  fn number_filter_invalid_content_test() {
+    for condition in [
+      NumberFilterConditionPB::NumberIsBetween,
+      NumberFilterConditionPB::NumberIsOneOf,
+      NumberFilterConditionPB::Equal,
+    ] {
+      let number_filter = NumberFilterPB {
+        condition,
+        content: "1, 2".to_owned(),
+      };
+      assert!(number_filter.validate(&NumberFormat::Num).is_err());
+    }
+
+    let number_filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::NumberIsBetween,
+      content: r#"{"start": "1", "end": "2"}"#.to_owned(),
+    };
+    assert!(number_filter.validate(&NumberFormat::Num).is_ok());
+
+    let number_filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::Equal,
+      content: "".to_owned(),
+    };
+    assert!(number_filter.validate(&NumberFormat::Num).is_ok());
+  }
+
+  #[test]
+  fn number_filter_unparsable_content_shows_all_rows_test() {
+    for (condition, content) in [
+      (NumberFilterConditionPB::Equal, "abc"),
+      (NumberFilterConditionPB::GreaterThan, ""),
+      (NumberFilterConditionPB::NumberIsBetween, "1, 2"),
+      (NumberFilterConditionPB::NumberIsOneOf, "[\"abc\"]"),
+    ] {
+      let number_filter = NumberFilterPB {
+        condition,
+        content: content.to_owned(),
+      };
+      for num_str in ["0", "12", ""] {
+        let data =
+          NumberCellFormat::from_format_str(num_str, &NumberFormat::Num).unwrap_or_default();
+        assert!(number_filter.is_visible(&data));
+      }
+    }
+  }
+
+  #[test]
+  fn number_filter_validate_with_format_test() {
+    let number_filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::NumberIsOneOf,
+      content: r#"["$1,200", "$1,500"]"#.to_owned(),
+    };
+    assert!(number_filter.validate(&NumberFormat::USD).is_ok());
+
+    let number_filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::NumberIsBetween,
+      content: NumberFilterRangePB {
+        start: "15%".to_owned(),
+        end: "abc".to_owned(),
+        start_inclusive: true,
+        end_inclusive: true,
+      }
+      .to_string(),
+    };
+    assert!(number_filter.validate(&NumberFormat::Percent).is_err());
+  }
 }
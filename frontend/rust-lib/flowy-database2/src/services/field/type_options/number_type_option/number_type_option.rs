@@ -247,7 +247,7 @@ impl TypeOptionCellDataFilter for NumberTypeOption {
       return true;
     }
     match self.format_cell_data(cell_data) {
-      Ok(cell_data) => filter.is_visible(&cell_data),
+      Ok(cell_data) => filter.is_visible_with_format(&cell_data, &self.format),
       Err(_) => true,
     }
   }
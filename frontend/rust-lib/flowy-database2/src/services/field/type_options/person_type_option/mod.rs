@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod person_entities;
+mod person_filter;
+mod person_type_option;
+
+pub use person_entities::*;
+pub use person_type_option::*;
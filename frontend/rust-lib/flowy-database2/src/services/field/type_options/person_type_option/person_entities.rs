@@ -0,0 +1,110 @@
+use bytes::Bytes;
+use collab::core::any_map::AnyMapExtension;
+use collab_database::rows::{new_cell_builder, Cell};
+use serde::{Deserialize, Serialize};
+
+use flowy_error::{internal_error, FlowyResult};
+
+use crate::entities::{FieldType, PersonCellDataPB};
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangeset, ToCellChangeset,
+};
+use crate::services::field::{TypeOptionCellData, CELL_DATA, SELECTION_IDS_SEPARATOR};
+
+/// A member of the workspace that can be assigned to the person cells
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonMember {
+  pub uid: i64,
+  pub name: String,
+  pub email: String,
+  pub icon_url: String,
+}
+
+/// The ids of the users assigned to the cell. They are saved in the cell as a string,
+/// placing a commas separator between each.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PersonCellData {
+  pub user_ids: Vec<i64>,
+}
+
+impl From<&Cell> for PersonCellData {
+  fn from(cell: &Cell) -> Self {
+    let value = cell.get_str_value(CELL_DATA).unwrap_or_default();
+    Self::from(value)
+  }
+}
+
+impl From<String> for PersonCellData {
+  fn from(s: String) -> Self {
+    let user_ids = s
+      .split(SELECTION_IDS_SEPARATOR)
+      .flat_map(|id| id.trim().parse::<i64>().ok())
+      .collect();
+    Self { user_ids }
+  }
+}
+
+impl From<PersonCellData> for Cell {
+  fn from(data: PersonCellData) -> Self {
+    new_cell_builder(FieldType::Person)
+      .insert_str_value(CELL_DATA, data.to_string())
+      .build()
+  }
+}
+
+impl ToString for PersonCellData {
+  fn to_string(&self) -> String {
+    self
+      .user_ids
+      .iter()
+      .map(|user_id| user_id.to_string())
+      .collect::<Vec<_>>()
+      .join(SELECTION_IDS_SEPARATOR)
+  }
+}
+
+impl TypeOptionCellData for PersonCellData {
+  fn is_cell_empty(&self) -> bool {
+    self.user_ids.is_empty()
+  }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PersonCellChangeset {
+  /// The ids of the users that will be assigned to the cell
+  pub inserted_user_ids: Vec<i64>,
+  /// The ids of the users that will be removed from the cell
+  pub removed_user_ids: Vec<i64>,
+}
+
+impl FromCellChangeset for PersonCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    serde_json::from_str::<PersonCellChangeset>(&changeset).map_err(internal_error)
+  }
+}
+
+impl ToCellChangeset for PersonCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+impl DecodedCellData for PersonCellDataPB {
+  type Object = PersonCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.members.is_empty()
+  }
+}
+
+pub struct PersonCellDataParser();
+impl CellProtobufBlobParser for PersonCellDataParser {
+  type Object = PersonCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    PersonCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
@@ -0,0 +1,52 @@
+use crate::entities::{PersonFilterConditionPB, PersonFilterPB};
+use crate::services::field::PersonCellData;
+
+impl PersonFilterPB {
+  /// The [PersonFilterConditionPB::IsMe] condition is visible if the cell contains the current
+  /// user, whose id is put into the `user_ids` when the filter is loaded.
+  pub fn is_visible(&self, cell_data: &PersonCellData) -> bool {
+    let contains_any = || {
+      cell_data
+        .user_ids
+        .iter()
+        .any(|uid| self.user_ids.contains(uid))
+    };
+
+    match self.condition {
+      PersonFilterConditionPB::IsMe => contains_any(),
+      PersonFilterConditionPB::ContainsAnyOf => self.user_ids.is_empty() || contains_any(),
+      PersonFilterConditionPB::IsEmpty => cell_data.user_ids.is_empty(),
+      PersonFilterConditionPB::IsNotEmpty => !cell_data.user_ids.is_empty(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::entities::{PersonFilterConditionPB, PersonFilterPB};
+  use crate::services::field::PersonCellData;
+
+  #[test]
+  fn person_filter_is_me_test() {
+    let filter = PersonFilterPB {
+      condition: PersonFilterConditionPB::IsMe,
+      user_ids: vec![1],
+    };
+    for (value, visible) in [("", false), ("1", true), ("2,1", true), ("2", false)] {
+      let data = PersonCellData::from(value.to_string());
+      assert_eq!(filter.is_visible(&data), visible);
+    }
+  }
+
+  #[test]
+  fn person_filter_contains_any_of_test() {
+    let filter = PersonFilterPB {
+      condition: PersonFilterConditionPB::ContainsAnyOf,
+      user_ids: vec![1, 3],
+    };
+    for (value, visible) in [("", false), ("1", true), ("2,3", true), ("2", false)] {
+      let data = PersonCellData::from(value.to_string());
+      assert_eq!(filter.is_visible(&data), visible);
+    }
+  }
+}
@@ -0,0 +1,251 @@
+use std::cmp::Ordering;
+
+use collab::core::any_map::AnyMapExtension;
+use collab_database::fields::{Field, TypeOptionData, TypeOptionDataBuilder};
+use collab_database::rows::Cell;
+use serde::{Deserialize, Serialize};
+
+use flowy_error::FlowyResult;
+
+use crate::entities::{FieldType, PersonCellDataPB, PersonFilterPB, PersonMemberPB};
+use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+use crate::services::field::{
+  PersonCellChangeset, PersonCellData, PersonMember, TypeOption, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionCellDataSerde,
+  TypeOptionTransform, SELECTION_IDS_SEPARATOR,
+};
+use crate::services::sort::SortCondition;
+
+/// The [PersonTypeOption] assigns the members of the workspace to the cells. The cells store the
+/// ids of the users, and the type option keeps a copy of the members that were assigned to them,
+/// so the names can be resolved without being online.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PersonTypeOption {
+  #[serde(default)]
+  pub members: Vec<PersonMember>,
+}
+
+impl TypeOption for PersonTypeOption {
+  type CellData = PersonCellData;
+  type CellChangeset = PersonCellChangeset;
+  type CellProtobufType = PersonCellDataPB;
+  type CellFilter = PersonFilterPB;
+}
+
+impl From<TypeOptionData> for PersonTypeOption {
+  fn from(data: TypeOptionData) -> Self {
+    data
+      .get_str_value("content")
+      .map(|s| serde_json::from_str::<PersonTypeOption>(&s).unwrap_or_default())
+      .unwrap_or_default()
+  }
+}
+
+impl From<PersonTypeOption> for TypeOptionData {
+  fn from(data: PersonTypeOption) -> Self {
+    let content = serde_json::to_string(&data).unwrap_or_default();
+    TypeOptionDataBuilder::new()
+      .insert_str_value("content", content)
+      .build()
+  }
+}
+
+impl PersonTypeOption {
+  pub fn get_member(&self, uid: i64) -> Option<&PersonMember> {
+    self.members.iter().find(|member| member.uid == uid)
+  }
+
+  /// Returns the members assigned to the cell. The users that are no longer the members of the
+  /// workspace are skipped.
+  pub fn get_assigned_members(&self, cell_data: &PersonCellData) -> Vec<&PersonMember> {
+    cell_data
+      .user_ids
+      .iter()
+      .flat_map(|uid| self.get_member(*uid))
+      .collect()
+  }
+}
+
+impl TypeOptionCellDataSerde for PersonTypeOption {
+  fn protobuf_encode(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    let members = self
+      .get_assigned_members(&cell_data)
+      .into_iter()
+      .map(|member| PersonMemberPB::from(member.clone()))
+      .collect();
+    PersonCellDataPB { members }
+  }
+
+  fn parse_cell(&self, cell: &Cell) -> FlowyResult<<Self as TypeOption>::CellData> {
+    Ok(PersonCellData::from(cell))
+  }
+}
+
+impl CellDataChangeset for PersonTypeOption {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    cell: Option<Cell>,
+  ) -> FlowyResult<(Cell, <Self as TypeOption>::CellData)> {
+    let mut cell_data = match cell {
+      None => PersonCellData::default(),
+      Some(cell) => self.parse_cell(&cell)?,
+    };
+
+    cell_data
+      .user_ids
+      .retain(|uid| !changeset.removed_user_ids.contains(uid));
+    for uid in changeset.inserted_user_ids {
+      // Only the members of the workspace can be assigned
+      if self.get_member(uid).is_none() {
+        tracing::warn!("The user:{} is not a member of the workspace", uid);
+        continue;
+      }
+      if !cell_data.user_ids.contains(&uid) {
+        cell_data.user_ids.push(uid);
+      }
+    }
+
+    Ok((Cell::from(cell_data.clone()), cell_data))
+  }
+}
+
+impl CellDataDecoder for PersonTypeOption {
+  fn decode_cell(
+    &self,
+    cell: &Cell,
+    decoded_field_type: &FieldType,
+    _field: &Field,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_person() {
+      return Ok(Default::default());
+    }
+
+    self.parse_cell(cell)
+  }
+
+  fn stringify_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    self
+      .get_assigned_members(&cell_data)
+      .into_iter()
+      .map(|member| member.name.as_str())
+      .collect::<Vec<_>>()
+      .join(SELECTION_IDS_SEPARATOR)
+  }
+
+  fn stringify_cell(&self, cell: &Cell) -> String {
+    let cell_data = PersonCellData::from(cell);
+    self.stringify_cell_data(cell_data)
+  }
+}
+
+impl TypeOptionCellDataFilter for PersonTypeOption {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_person() {
+      return true;
+    }
+
+    filter.is_visible(cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for PersonTypeOption {
+  /// Orders the cells by the name of their first assigned member. The empty cells are moved to
+  /// the back.
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+    sort_condition: SortCondition,
+  ) -> Ordering {
+    let name = self
+      .get_assigned_members(cell_data)
+      .first()
+      .map(|member| member.name.clone());
+    let other_name = self
+      .get_assigned_members(other_cell_data)
+      .first()
+      .map(|member| member.name.clone());
+    match (name, other_name) {
+      (None, None) => Ordering::Equal,
+      (None, Some(_)) => Ordering::Greater,
+      (Some(_), None) => Ordering::Less,
+      (Some(name), Some(other_name)) => sort_condition.evaluate_order(name.cmp(&other_name)),
+    }
+  }
+}
+
+impl TypeOptionTransform for PersonTypeOption {}
+
+#[cfg(test)]
+mod tests {
+  use collab_database::fields::Field;
+
+  use crate::entities::FieldType;
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+  use crate::services::field::{FieldBuilder, PersonCellChangeset, PersonMember, PersonTypeOption};
+
+  fn member(uid: i64, name: &str) -> PersonMember {
+    PersonMember {
+      uid,
+      name: name.to_string(),
+      ..Default::default()
+    }
+  }
+
+  fn person_field(type_option: &PersonTypeOption) -> Field {
+    FieldBuilder::new(FieldType::Person, type_option.clone()).build()
+  }
+
+  #[test]
+  fn person_cell_only_accepts_members_test() {
+    let type_option = PersonTypeOption {
+      members: vec![member(1, "Lucas"), member(2, "Nathan")],
+    };
+    let changeset = PersonCellChangeset {
+      inserted_user_ids: vec![2, 3, 1, 2],
+      ..Default::default()
+    };
+    let (cell, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_data.user_ids, vec![2, 1]);
+
+    let changeset = PersonCellChangeset {
+      removed_user_ids: vec![2],
+      ..Default::default()
+    };
+    let (_, cell_data) = type_option.apply_changeset(changeset, Some(cell)).unwrap();
+    assert_eq!(cell_data.user_ids, vec![1]);
+  }
+
+  #[test]
+  fn person_cell_stringify_test() {
+    let type_option = PersonTypeOption {
+      members: vec![member(1, "Lucas"), member(2, "Nathan")],
+    };
+    let field = person_field(&type_option);
+    let changeset = PersonCellChangeset {
+      inserted_user_ids: vec![2, 1],
+      ..Default::default()
+    };
+    let (cell, _) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(type_option.stringify_cell(&cell), "Nathan,Lucas");
+
+    // The users who left the workspace are not displayed
+    let type_option = PersonTypeOption {
+      members: vec![member(1, "Lucas")],
+    };
+    assert_eq!(type_option.stringify_cell(&cell), "Lucas");
+    let cell_data = type_option
+      .decode_cell(&cell, &FieldType::Person, &field)
+      .unwrap();
+    assert_eq!(cell_data.user_ids, vec![2, 1]);
+  }
+}
@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod relation_entities;
+mod relation_filter;
+mod relation_type_option;
+
+pub use relation_entities::*;
+pub use relation_type_option::*;
@@ -0,0 +1,83 @@
+use collab::core::any_map::AnyMapExtension;
+use collab_database::rows::{new_cell_builder, Cell, RowId};
+use serde::{Deserialize, Serialize};
+
+use flowy_error::{internal_error, FlowyResult};
+
+use crate::entities::FieldType;
+use crate::services::cell::{FromCellChangeset, ToCellChangeset};
+use crate::services::field::{TypeOptionCellData, CELL_DATA, SELECTION_IDS_SEPARATOR};
+
+/// The ids of the rows in the related database. They are saved in the cell as a string,
+/// placing a commas separator between each.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RelationCellData {
+  pub row_ids: Vec<RowId>,
+}
+
+impl From<&Cell> for RelationCellData {
+  fn from(cell: &Cell) -> Self {
+    let value = cell.get_str_value(CELL_DATA).unwrap_or_default();
+    Self::from(value)
+  }
+}
+
+impl From<String> for RelationCellData {
+  fn from(s: String) -> Self {
+    let row_ids = s
+      .split(SELECTION_IDS_SEPARATOR)
+      .map(|id| id.trim())
+      .filter(|id| !id.is_empty())
+      .map(|id| RowId::from(id.to_string()))
+      .collect();
+    Self { row_ids }
+  }
+}
+
+impl From<RelationCellData> for Cell {
+  fn from(data: RelationCellData) -> Self {
+    new_cell_builder(FieldType::Relation)
+      .insert_str_value(CELL_DATA, data.to_string())
+      .build()
+  }
+}
+
+impl ToString for RelationCellData {
+  fn to_string(&self) -> String {
+    self
+      .row_ids
+      .iter()
+      .map(|row_id| row_id.as_str())
+      .collect::<Vec<_>>()
+      .join(SELECTION_IDS_SEPARATOR)
+  }
+}
+
+impl TypeOptionCellData for RelationCellData {
+  fn is_cell_empty(&self) -> bool {
+    self.row_ids.is_empty()
+  }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RelationCellChangeset {
+  /// The ids of the related rows that will be linked
+  pub inserted_row_ids: Vec<String>,
+  /// The ids of the related rows that will be unlinked
+  pub removed_row_ids: Vec<String>,
+}
+
+impl FromCellChangeset for RelationCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    serde_json::from_str::<RelationCellChangeset>(&changeset).map_err(internal_error)
+  }
+}
+
+impl ToCellChangeset for RelationCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
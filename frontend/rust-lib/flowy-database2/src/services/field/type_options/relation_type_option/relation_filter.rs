@@ -0,0 +1,72 @@
+use crate::entities::{RelationFilterConditionPB, RelationFilterPB};
+use crate::services::field::RelationCellData;
+
+impl RelationFilterPB {
+  pub fn is_visible(&self, cell_data: &RelationCellData) -> bool {
+    let contains_any = || {
+      cell_data
+        .row_ids
+        .iter()
+        .any(|row_id| self.row_ids.iter().any(|id| id == row_id.as_str()))
+    };
+
+    match self.condition {
+      RelationFilterConditionPB::IsEmpty => cell_data.row_ids.is_empty(),
+      RelationFilterConditionPB::IsNotEmpty => !cell_data.row_ids.is_empty(),
+      RelationFilterConditionPB::Contains => self.row_ids.is_empty() || contains_any(),
+      RelationFilterConditionPB::DoesNotContain => self.row_ids.is_empty() || !contains_any(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::entities::{RelationFilterConditionPB, RelationFilterPB};
+  use crate::services::field::RelationCellData;
+
+  #[test]
+  fn relation_filter_is_empty_test() {
+    let filter = RelationFilterPB {
+      condition: RelationFilterConditionPB::IsEmpty,
+      row_ids: vec![],
+    };
+    for (value, visible) in [("", true), ("row_1", false), ("row_1,row_2", false)] {
+      let data = RelationCellData::from(value.to_string());
+      assert_eq!(filter.is_visible(&data), visible);
+    }
+  }
+
+  #[test]
+  fn relation_filter_contains_test() {
+    let filter = RelationFilterPB {
+      condition: RelationFilterConditionPB::Contains,
+      row_ids: vec!["row_1".to_string()],
+    };
+    for (value, visible) in [
+      ("", false),
+      ("row_1", true),
+      ("row_2,row_1", true),
+      ("row_2", false),
+    ] {
+      let data = RelationCellData::from(value.to_string());
+      assert_eq!(filter.is_visible(&data), visible);
+    }
+  }
+
+  #[test]
+  fn relation_filter_does_not_contain_test() {
+    let filter = RelationFilterPB {
+      condition: RelationFilterConditionPB::DoesNotContain,
+      row_ids: vec!["row_1".to_string()],
+    };
+    for (value, visible) in [
+      ("", true),
+      ("row_1", false),
+      ("row_2,row_1", false),
+      ("row_2", true),
+    ] {
+      let data = RelationCellData::from(value.to_string());
+      assert_eq!(filter.is_visible(&data), visible);
+    }
+  }
+}
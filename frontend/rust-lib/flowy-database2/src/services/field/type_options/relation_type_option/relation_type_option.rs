@@ -0,0 +1,160 @@
+use std::cmp::Ordering;
+
+use collab::core::any_map::AnyMapExtension;
+use collab_database::fields::{Field, TypeOptionData, TypeOptionDataBuilder};
+use collab_database::rows::{Cell, RowId};
+
+use flowy_error::FlowyResult;
+
+use crate::entities::{FieldType, RelationCellDataPB, RelationFilterPB};
+use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+use crate::services::field::{
+  RelationCellChangeset, RelationCellData, TypeOption, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionCellDataSerde,
+  TypeOptionTransform,
+};
+use crate::services::sort::SortCondition;
+
+/// The [RelationTypeOption] links the rows of the database to the rows of another database.
+/// The cells store the ids of the linked rows. Use the `DatabaseManager` to resolve the
+/// content of the linked rows, because it requires access to the related database.
+#[derive(Debug, Clone, Default)]
+pub struct RelationTypeOption {
+  pub database_id: String,
+  /// The id of the relation field in the related database that links back to this field. The
+  /// cells of both fields are kept in sync if it's not None.
+  pub reciprocal_field_id: Option<String>,
+}
+
+impl TypeOption for RelationTypeOption {
+  type CellData = RelationCellData;
+  type CellChangeset = RelationCellChangeset;
+  type CellProtobufType = RelationCellDataPB;
+  type CellFilter = RelationFilterPB;
+}
+
+impl From<TypeOptionData> for RelationTypeOption {
+  fn from(data: TypeOptionData) -> Self {
+    let database_id = data.get_str_value("database_id").unwrap_or_default();
+    let reciprocal_field_id = data
+      .get_str_value("reciprocal_field_id")
+      .filter(|field_id| !field_id.is_empty());
+    Self {
+      database_id,
+      reciprocal_field_id,
+    }
+  }
+}
+
+impl From<RelationTypeOption> for TypeOptionData {
+  fn from(data: RelationTypeOption) -> Self {
+    TypeOptionDataBuilder::new()
+      .insert_str_value("database_id", data.database_id)
+      .insert_str_value(
+        "reciprocal_field_id",
+        data.reciprocal_field_id.unwrap_or_default(),
+      )
+      .build()
+  }
+}
+
+impl TypeOptionCellDataSerde for RelationTypeOption {
+  fn protobuf_encode(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    RelationCellDataPB::from(cell_data)
+  }
+
+  fn parse_cell(&self, cell: &Cell) -> FlowyResult<<Self as TypeOption>::CellData> {
+    Ok(RelationCellData::from(cell))
+  }
+}
+
+impl CellDataChangeset for RelationTypeOption {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    cell: Option<Cell>,
+  ) -> FlowyResult<(Cell, <Self as TypeOption>::CellData)> {
+    let mut cell_data = match cell {
+      None => RelationCellData::default(),
+      Some(cell) => self.parse_cell(&cell)?,
+    };
+
+    cell_data.row_ids.retain(|row_id| {
+      !changeset
+        .removed_row_ids
+        .iter()
+        .any(|removed_row_id| removed_row_id == row_id.as_str())
+    });
+    for row_id in changeset.inserted_row_ids {
+      let row_id = RowId::from(row_id);
+      if !cell_data.row_ids.contains(&row_id) {
+        cell_data.row_ids.push(row_id);
+      }
+    }
+
+    Ok((Cell::from(cell_data.clone()), cell_data))
+  }
+}
+
+impl CellDataDecoder for RelationTypeOption {
+  fn decode_cell(
+    &self,
+    cell: &Cell,
+    decoded_field_type: &FieldType,
+    _field: &Field,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_relation() {
+      return Ok(Default::default());
+    }
+
+    self.parse_cell(cell)
+  }
+
+  fn stringify_cell_data(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.to_string()
+  }
+
+  fn stringify_cell(&self, cell: &Cell) -> String {
+    let cell_data = RelationCellData::from(cell);
+    self.stringify_cell_data(cell_data)
+  }
+}
+
+impl TypeOptionCellDataFilter for RelationTypeOption {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_relation() {
+      return true;
+    }
+
+    filter.is_visible(cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for RelationTypeOption {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+    sort_condition: SortCondition,
+  ) -> Ordering {
+    match (cell_data.is_cell_empty(), other_cell_data.is_cell_empty()) {
+      (true, true) => Ordering::Equal,
+      (true, false) => Ordering::Greater,
+      (false, true) => Ordering::Less,
+      (false, false) => {
+        let order = cell_data.row_ids.len().cmp(&other_cell_data.row_ids.len());
+        sort_condition.evaluate_order(order)
+      },
+    }
+  }
+}
+
+impl TypeOptionTransform for RelationTypeOption {}
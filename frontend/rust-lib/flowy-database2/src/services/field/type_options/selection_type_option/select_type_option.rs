@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use bytes::Bytes;
 use collab_database::fields::{Field, TypeOptionData};
 use collab_database::rows::Cell;
@@ -174,6 +176,30 @@ pub fn select_type_option_from_field(
   }
 }
 
+/// Compares the selected options by the positions of the options in the type option, which are
+/// the order that the user arranged them, e.g. Low, Medium, High. The options that don't exist
+/// anymore are placed after the others.
+pub fn cmp_select_option_position(
+  options: &[SelectOption],
+  left: &SelectOptionIds,
+  right: &SelectOptionIds,
+) -> Ordering {
+  let positions = |ids: &SelectOptionIds| {
+    let mut positions = ids
+      .iter()
+      .map(|id| {
+        options
+          .iter()
+          .position(|option| &option.id == id)
+          .unwrap_or(usize::MAX)
+      })
+      .collect::<Vec<usize>>();
+    positions.sort();
+    positions
+  };
+  positions(left).cmp(&positions(right))
+}
+
 pub fn new_select_option_color(options: &[SelectOption]) -> SelectOptionColor {
   let mut freq: Vec<usize> = vec![0; 9];
 
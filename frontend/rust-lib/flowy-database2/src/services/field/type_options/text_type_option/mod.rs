@@ -3,4 +3,5 @@ mod text_filter;
 mod text_tests;
 mod text_type_option;
 
+pub use text_filter::*;
 pub use text_type_option::*;
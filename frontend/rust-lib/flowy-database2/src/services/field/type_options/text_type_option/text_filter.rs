@@ -1,32 +1,150 @@
+use std::borrow::Cow;
+
+use fancy_regex::{escape, Regex};
+
 use crate::entities::{TextFilterConditionPB, TextFilterPB};
+use crate::services::filter::{Filter, FromFilterString};
+
+/// The [TextFilterPB] that is cached by the filter controller. The regular expression of the
+/// filter is compiled once here instead of for every cell.
+#[derive(Debug)]
+pub struct TextFilter {
+  filter: TextFilterPB,
+  regex: Option<Regex>,
+}
+
+impl TextFilter {
+  pub fn is_visible<T: AsRef<str>>(&self, cell_data: T) -> bool {
+    self
+      .filter
+      .is_visible_with_regex(cell_data.as_ref(), self.regex.as_ref())
+  }
+}
+
+impl From<TextFilterPB> for TextFilter {
+  fn from(filter: TextFilterPB) -> Self {
+    let regex = if filter.is_regex_required() {
+      filter.regex().ok()
+    } else {
+      None
+    };
+    Self { filter, regex }
+  }
+}
+
+impl FromFilterString for TextFilter {
+  fn from_filter(filter: &Filter) -> Self
+  where
+    Self: Sized,
+  {
+    TextFilter::from(TextFilterPB::from(filter))
+  }
+}
 
 impl TextFilterPB {
   pub fn is_visible<T: AsRef<str>>(&self, cell_data: T) -> bool {
-    let cell_data = cell_data.as_ref().to_lowercase();
-    let content = &self.content.to_lowercase();
+    let regex = if self.is_regex_required() {
+      self.regex().ok()
+    } else {
+      None
+    };
+    self.is_visible_with_regex(cell_data.as_ref(), regex.as_ref())
+  }
+
+  /// `regex` is the compiled [TextFilterPB::regex], it's only used if the condition requires it.
+  fn is_visible_with_regex(&self, cell_data: &str, regex: Option<&Regex>) -> bool {
+    if self.is_regex_required() {
+      // The invalid pattern is rejected when creating the filter, so the filter that can't be
+      // compiled doesn't match anything.
+      let is_match = regex
+        .and_then(|regex| regex.is_match(cell_data).ok())
+        .unwrap_or(false);
+      return match self.condition {
+        TextFilterConditionPB::DoesNotContain => !is_match,
+        _ => is_match,
+      };
+    }
+
+    let (cell_data, content) = if self.case_sensitive {
+      (Cow::from(cell_data), Cow::from(self.content.as_str()))
+    } else {
+      (
+        Cow::from(cell_data.to_lowercase()),
+        Cow::from(self.content.to_lowercase()),
+      )
+    };
     match self.condition {
-      TextFilterConditionPB::Is => &cell_data == content,
-      TextFilterConditionPB::IsNot => &cell_data != content,
-      TextFilterConditionPB::Contains => cell_data.contains(content),
-      TextFilterConditionPB::DoesNotContain => !cell_data.contains(content),
-      TextFilterConditionPB::StartsWith => cell_data.starts_with(content),
-      TextFilterConditionPB::EndsWith => cell_data.ends_with(content),
+      TextFilterConditionPB::Is => cell_data == content,
+      TextFilterConditionPB::IsNot => cell_data != content,
+      TextFilterConditionPB::Contains => cell_data.contains(content.as_ref()),
+      TextFilterConditionPB::DoesNotContain => !cell_data.contains(content.as_ref()),
+      TextFilterConditionPB::StartsWith => cell_data.starts_with(content.as_ref()),
+      TextFilterConditionPB::EndsWith => cell_data.ends_with(content.as_ref()),
       TextFilterConditionPB::TextIsEmpty => cell_data.is_empty(),
       TextFilterConditionPB::TextIsNotEmpty => !cell_data.is_empty(),
+      TextFilterConditionPB::MatchesRegex => false,
     }
   }
+
+  /// Returns true if the condition is evaluated with a regular expression. The word boundary
+  /// option doesn't apply to the exact and the empty conditions.
+  fn is_regex_required(&self) -> bool {
+    match self.condition {
+      TextFilterConditionPB::MatchesRegex => true,
+      TextFilterConditionPB::Contains
+      | TextFilterConditionPB::DoesNotContain
+      | TextFilterConditionPB::StartsWith
+      | TextFilterConditionPB::EndsWith => self.word_boundary,
+      _ => false,
+    }
+  }
+
+  /// Builds the regular expression that is used to evaluate the filter. The content is the
+  /// pattern for the [TextFilterConditionPB::MatchesRegex] condition, otherwise it's matched
+  /// literally.
+  pub fn regex(&self) -> Result<Regex, fancy_regex::Error> {
+    let pattern = match self.condition {
+      TextFilterConditionPB::MatchesRegex => self.content.clone(),
+      TextFilterConditionPB::StartsWith => format!("^{}", escape(&self.content)),
+      TextFilterConditionPB::EndsWith => format!("{}$", escape(&self.content)),
+      _ => escape(&self.content).to_string(),
+    };
+    // Lookarounds are used instead of \b, so the content can start or end with a non-word
+    // character, e.g. "c++".
+    let pattern = if self.word_boundary {
+      format!(r"(?<!\w)(?:{})(?!\w)", pattern)
+    } else {
+      pattern
+    };
+    let pattern = if self.case_sensitive {
+      pattern
+    } else {
+      format!("(?i){}", pattern)
+    };
+    Regex::new(&pattern)
+  }
+
+  /// Returns an error if the regular expression of the filter is invalid.
+  pub fn validate(&self) -> Result<(), fancy_regex::Error> {
+    if self.is_regex_required() {
+      self.regex()?;
+    }
+    Ok(())
+  }
 }
 
 #[cfg(test)]
 mod tests {
   #![allow(clippy::all)]
   use crate::entities::{TextFilterConditionPB, TextFilterPB};
+  use crate::services::field::TextFilter;
 
   #[test]
   fn text_filter_equal_test() {
     let text_filter = TextFilterPB {
       condition: TextFilterConditionPB::Is,
       content: "appflowy".to_owned(),
+      ..Default::default()
     };
 
     assert!(text_filter.is_visible("AppFlowy"));
@@ -39,6 +157,7 @@ mod tests {
     let text_filter = TextFilterPB {
       condition: TextFilterConditionPB::StartsWith,
       content: "appflowy".to_owned(),
+      ..Default::default()
     };
 
     assert_eq!(text_filter.is_visible("AppFlowy.io"), true);
@@ -51,6 +170,7 @@ mod tests {
     let text_filter = TextFilterPB {
       condition: TextFilterConditionPB::EndsWith,
       content: "appflowy".to_owned(),
+      ..Default::default()
     };
 
     assert_eq!(text_filter.is_visible("https://github.com/appflowy"), true);
@@ -62,6 +182,7 @@ mod tests {
     let text_filter = TextFilterPB {
       condition: TextFilterConditionPB::TextIsEmpty,
       content: "appflowy".to_owned(),
+      ..Default::default()
     };
 
     assert_eq!(text_filter.is_visible(""), true);
@@ -72,6 +193,7 @@ mod tests {
     let text_filter = TextFilterPB {
       condition: TextFilterConditionPB::Contains,
       content: "appflowy".to_owned(),
+      ..Default::default()
     };
 
     assert_eq!(text_filter.is_visible("https://github.com/appflowy"), true);
@@ -80,4 +202,79 @@ mod tests {
     assert_eq!(text_filter.is_visible(""), false);
     assert_eq!(text_filter.is_visible("github"), false);
   }
+
+  #[test]
+  fn text_filter_case_sensitive_test() {
+    let text_filter = TextFilterPB {
+      condition: TextFilterConditionPB::Contains,
+      content: "Flowy".to_owned(),
+      case_sensitive: true,
+      ..Default::default()
+    };
+
+    assert_eq!(text_filter.is_visible("AppFlowy"), true);
+    assert_eq!(text_filter.is_visible("appflowy"), false);
+  }
+
+  #[test]
+  fn text_filter_word_boundary_test() {
+    let text_filter = TextFilterPB {
+      condition: TextFilterConditionPB::Contains,
+      content: "app".to_owned(),
+      word_boundary: true,
+      ..Default::default()
+    };
+
+    assert_eq!(text_filter.is_visible("An App for notes"), true);
+    assert_eq!(text_filter.is_visible("app"), true);
+    assert_eq!(text_filter.is_visible("AppFlowy"), false);
+
+    let text_filter = TextFilterPB {
+      condition: TextFilterConditionPB::EndsWith,
+      content: "c++".to_owned(),
+      word_boundary: true,
+      ..Default::default()
+    };
+    assert_eq!(text_filter.is_visible("I like C++"), true);
+    assert_eq!(text_filter.is_visible("I like objc++"), false);
+  }
+
+  #[test]
+  fn text_filter_regex_test() {
+    let text_filter = TextFilterPB {
+      condition: TextFilterConditionPB::MatchesRegex,
+      content: r"^\d{3}-\d{4}$".to_owned(),
+      ..Default::default()
+    };
+
+    assert_eq!(text_filter.is_visible("555-1234"), true);
+    assert_eq!(text_filter.is_visible("555-12345"), false);
+    assert_eq!(text_filter.is_visible(""), false);
+  }
+
+  #[test]
+  fn cached_text_filter_regex_test() {
+    let text_filter = TextFilter::from(TextFilterPB {
+      condition: TextFilterConditionPB::Contains,
+      content: "c++".to_owned(),
+      word_boundary: true,
+      ..Default::default()
+    });
+
+    assert_eq!(text_filter.is_visible("learn C++ today"), true);
+    assert_eq!(text_filter.is_visible("learn c++"), true);
+    assert_eq!(text_filter.is_visible("abc++"), false);
+  }
+
+  #[test]
+  fn text_filter_invalid_regex_test() {
+    let text_filter = TextFilterPB {
+      condition: TextFilterConditionPB::MatchesRegex,
+      content: "(unclosed".to_owned(),
+      ..Default::default()
+    };
+
+    assert!(text_filter.validate().is_err());
+    assert_eq!(text_filter.is_visible("(unclosed"), false);
+  }
 }
@@ -29,6 +29,7 @@ mod tests {
       end_timestamp: None,
       include_time: true,
       is_range: false,
+      ..Default::default()
     };
 
     assert_eq!(
@@ -41,6 +42,7 @@ mod tests {
       end_timestamp: Some(1648533809),
       include_time: true,
       is_range: false,
+      ..Default::default()
     };
 
     assert_eq!(
@@ -53,6 +55,7 @@ mod tests {
       end_timestamp: Some(1648533809),
       include_time: true,
       is_range: true,
+      ..Default::default()
     };
 
     assert_eq!(
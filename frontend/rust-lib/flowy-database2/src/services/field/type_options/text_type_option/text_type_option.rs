@@ -8,14 +8,14 @@ use serde::{Deserialize, Serialize};
 
 use flowy_error::{FlowyError, FlowyResult};
 
-use crate::entities::{FieldType, TextFilterPB};
+use crate::entities::FieldType;
 use crate::services::cell::{
   stringify_cell_data, CellDataChangeset, CellDataDecoder, CellProtobufBlobParser, DecodedCellData,
   FromCellString,
 };
 use crate::services::field::type_options::util::ProtobufStr;
 use crate::services::field::{
-  TypeOption, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TextFilter, TypeOption, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
   TypeOptionCellDataSerde, TypeOptionTransform, CELL_DATA,
 };
 use crate::services::sort::SortCondition;
@@ -32,7 +32,7 @@ impl TypeOption for RichTextTypeOption {
   type CellData = StrCellData;
   type CellChangeset = String;
   type CellProtobufType = ProtobufStr;
-  type CellFilter = TextFilterPB;
+  type CellFilter = TextFilter;
 }
 
 impl From<TypeOptionData> for RichTextTypeOption {
@@ -73,6 +73,10 @@ impl TypeOptionTransform for RichTextTypeOption {
       || transformed_field_type.is_multi_select()
       || transformed_field_type.is_number()
       || transformed_field_type.is_url()
+      || transformed_field_type.is_formula()
+      || transformed_field_type.is_lookup()
+      || transformed_field_type.is_person()
+      || transformed_field_type.is_files()
     {
       Some(StrCellData::from(stringify_cell_data(
         cell,
@@ -7,11 +7,12 @@ use collab_database::rows::Cell;
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 use serde::{Deserialize, Serialize};
 
-use crate::entities::{DateFilterPB, FieldType, TimestampCellDataPB};
+use crate::entities::{FieldType, TimestampCellDataPB};
 use crate::services::cell::{CellDataChangeset, CellDataDecoder};
 use crate::services::field::{
-  default_order, DateFormat, TimeFormat, TimestampCellData, TypeOption, TypeOptionCellDataCompare,
-  TypeOptionCellDataFilter, TypeOptionCellDataSerde, TypeOptionTransform,
+  default_order, DateFilter, DateFormat, TimeFormat, TimestampCellData, TypeOption,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionCellDataSerde,
+  TypeOptionTransform,
 };
 use crate::services::sort::SortCondition;
 
@@ -38,7 +39,7 @@ impl TypeOption for TimestampTypeOption {
   type CellData = TimestampCellData;
   type CellChangeset = String;
   type CellProtobufType = TimestampCellDataPB;
-  type CellFilter = DateFilterPB;
+  type CellFilter = DateFilter;
 }
 
 impl From<TypeOptionData> for TimestampTypeOption {
@@ -1,9 +1,13 @@
+use bytes::Bytes;
 use collab::core::any_map::AnyMapExtension;
 use collab_database::rows::{new_cell_builder, Cell};
 use serde::Serialize;
 
+use flowy_error::{internal_error, FlowyResult};
+
 use crate::{
-  entities::FieldType,
+  entities::{FieldType, TimestampCellDataPB},
+  services::cell::{CellProtobufBlobParser, DecodedCellData},
   services::field::{TypeOptionCellData, CELL_DATA},
 };
 
@@ -67,3 +71,20 @@ impl ToString for TimestampCellData {
     serde_json::to_string(self).unwrap()
   }
 }
+
+impl DecodedCellData for TimestampCellDataPB {
+  type Object = TimestampCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.timestamp.is_none()
+  }
+}
+
+pub struct TimestampCellDataParser();
+impl CellProtobufBlobParser for TimestampCellDataParser {
+  type Object = TimestampCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    TimestampCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
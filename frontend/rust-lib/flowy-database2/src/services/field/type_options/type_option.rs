@@ -9,14 +9,16 @@ use protobuf::ProtobufError;
 use flowy_error::FlowyResult;
 
 use crate::entities::{
-  CheckboxTypeOptionPB, ChecklistTypeOptionPB, DateTypeOptionPB, FieldType,
-  MultiSelectTypeOptionPB, NumberTypeOptionPB, RichTextTypeOptionPB, SingleSelectTypeOptionPB,
+  CheckboxTypeOptionPB, ChecklistTypeOptionPB, DateTypeOptionPB, FieldType, FilesTypeOptionPB,
+  FormulaTypeOptionPB, LookupTypeOptionPB, MultiSelectTypeOptionPB, NumberTypeOptionPB,
+  PersonTypeOptionPB, RelationTypeOptionPB, RichTextTypeOptionPB, SingleSelectTypeOptionPB,
   TimestampTypeOptionPB, URLTypeOptionPB,
 };
 use crate::services::cell::{CellDataDecoder, FromCellChangeset, ToCellChangeset};
 use crate::services::field::checklist_type_option::ChecklistTypeOption;
 use crate::services::field::{
-  CheckboxTypeOption, DateFormat, DateTypeOption, MultiSelectTypeOption, NumberTypeOption,
+  CheckboxTypeOption, DateFormat, DateTypeOption, FilesTypeOption, FormulaTypeOption,
+  LookupTypeOption, MultiSelectTypeOption, NumberTypeOption, PersonTypeOption, RelationTypeOption,
   RichTextTypeOption, SingleSelectTypeOption, TimeFormat, TimestampTypeOption, URLTypeOption,
 };
 use crate::services::filter::FromFilterString;
@@ -83,6 +85,12 @@ pub trait TypeOptionCellData {
   fn is_cell_empty(&self) -> bool {
     false
   }
+
+  /// Checks if the cell is placed with the empty cells when the rows are sorted. It's the same
+  /// as [TypeOptionCellData::is_cell_empty] unless the sort treats the cell differently.
+  fn is_sort_empty(&self) -> bool {
+    self.is_cell_empty()
+  }
 }
 
 pub trait TypeOptionTransform: TypeOption {
@@ -198,6 +206,21 @@ pub fn type_option_data_from_pb_or_default<T: Into<Bytes>>(
     FieldType::Checklist => {
       ChecklistTypeOptionPB::try_from(bytes).map(|pb| ChecklistTypeOption::from(pb).into())
     },
+    FieldType::Formula => {
+      FormulaTypeOptionPB::try_from(bytes).map(|pb| FormulaTypeOption::from(pb).into())
+    },
+    FieldType::Relation => {
+      RelationTypeOptionPB::try_from(bytes).map(|pb| RelationTypeOption::from(pb).into())
+    },
+    FieldType::Lookup => {
+      LookupTypeOptionPB::try_from(bytes).map(|pb| LookupTypeOption::from(pb).into())
+    },
+    FieldType::Person => {
+      PersonTypeOptionPB::try_from(bytes).map(|pb| PersonTypeOption::from(pb).into())
+    },
+    FieldType::Files => {
+      FilesTypeOptionPB::try_from(bytes).map(|pb| FilesTypeOption::from(pb).into())
+    },
   };
 
   result.unwrap_or_else(|_| default_type_option_data_from_type(field_type))
@@ -255,6 +278,36 @@ pub fn type_option_to_pb(type_option: TypeOptionData, field_type: &FieldType) ->
         .try_into()
         .unwrap()
     },
+    FieldType::Formula => {
+      let formula_type_option: FormulaTypeOption = type_option.into();
+      FormulaTypeOptionPB::from(formula_type_option)
+        .try_into()
+        .unwrap()
+    },
+    FieldType::Relation => {
+      let relation_type_option: RelationTypeOption = type_option.into();
+      RelationTypeOptionPB::from(relation_type_option)
+        .try_into()
+        .unwrap()
+    },
+    FieldType::Lookup => {
+      let lookup_type_option: LookupTypeOption = type_option.into();
+      LookupTypeOptionPB::from(lookup_type_option)
+        .try_into()
+        .unwrap()
+    },
+    FieldType::Person => {
+      let person_type_option: PersonTypeOption = type_option.into();
+      PersonTypeOptionPB::from(person_type_option)
+        .try_into()
+        .unwrap()
+    },
+    FieldType::Files => {
+      let files_type_option: FilesTypeOption = type_option.into();
+      FilesTypeOptionPB::from(files_type_option)
+        .try_into()
+        .unwrap()
+    },
   }
 }
 
@@ -275,5 +328,10 @@ pub fn default_type_option_data_from_type(field_type: &FieldType) -> TypeOptionD
     FieldType::Checkbox => CheckboxTypeOption::default().into(),
     FieldType::URL => URLTypeOption::default().into(),
     FieldType::Checklist => ChecklistTypeOption::default().into(),
+    FieldType::Formula => FormulaTypeOption::default().into(),
+    FieldType::Relation => RelationTypeOption::default().into(),
+    FieldType::Lookup => LookupTypeOption::default().into(),
+    FieldType::Person => PersonTypeOption::default().into(),
+    FieldType::Files => FilesTypeOption::default().into(),
   }
 }
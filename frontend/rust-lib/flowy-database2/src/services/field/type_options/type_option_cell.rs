@@ -15,9 +15,11 @@ use crate::services::cell::{
 };
 use crate::services::field::checklist_type_option::ChecklistTypeOption;
 use crate::services::field::{
-  CheckboxTypeOption, DateTypeOption, MultiSelectTypeOption, NumberTypeOption, RichTextTypeOption,
-  SingleSelectTypeOption, TimestampTypeOption, TypeOption, TypeOptionCellDataCompare,
-  TypeOptionCellDataFilter, TypeOptionCellDataSerde, TypeOptionTransform, URLTypeOption,
+  CheckboxTypeOption, DateTypeOption, FilesTypeOption, FormulaTypeOption, LookupTypeOption,
+  MultiSelectTypeOption, NumberTypeOption, PersonTypeOption, RelationTypeOption,
+  RichTextTypeOption, SingleSelectTypeOption, TimestampTypeOption, TypeOption, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionCellDataSerde,
+  TypeOptionTransform, URLTypeOption,
 };
 use crate::services::sort::SortCondition;
 
@@ -54,7 +56,19 @@ pub trait TypeOptionCellDataHandler: Send + Sync + 'static {
     sort_condition: SortCondition,
   ) -> Ordering;
 
-  fn handle_cell_filter(&self, field_type: &FieldType, field: &Field, cell: &Cell) -> bool;
+  /// Returns true if the cell is placed with the empty cells when the rows are sorted. A cell
+  /// that doesn't exist is empty unless the default cell data isn't, e.g. an unchecked checkbox.
+  fn handle_is_sort_empty(&self, cell: Option<&Cell>, field: &Field) -> bool;
+
+  /// Returns true if the cell passes the filter with the given id. The filters are cached by
+  /// their ids, so a field can have multiple filters.
+  fn handle_cell_filter(
+    &self,
+    filter_id: &str,
+    field_type: &FieldType,
+    field: &Field,
+    cell: &Cell,
+  ) -> bool;
 
   /// Format the cell to string using the passed-in [FieldType] and [Field].
   /// The [Cell] is generic, so we need to know the [FieldType] and [Field] to format the cell.
@@ -300,10 +314,26 @@ where
     }
   }
 
-  fn handle_cell_filter(&self, field_type: &FieldType, field: &Field, cell: &Cell) -> bool {
+  fn handle_is_sort_empty(&self, cell: Option<&Cell>, field: &Field) -> bool {
+    let cell_data = match cell {
+      None => <Self as TypeOption>::CellData::default(),
+      Some(cell) => self
+        .get_decoded_cell_data(cell, &FieldType::from(field.field_type), field)
+        .unwrap_or_default(),
+    };
+    cell_data.is_sort_empty()
+  }
+
+  fn handle_cell_filter(
+    &self,
+    filter_id: &str,
+    field_type: &FieldType,
+    field: &Field,
+    cell: &Cell,
+  ) -> bool {
     let perform_filter = || {
       let filter_cache = self.cell_filter_cache.as_ref()?.read();
-      let cell_filter = filter_cache.get::<<Self as TypeOption>::CellFilter>(&field.id)?;
+      let cell_filter = filter_cache.get::<<Self as TypeOption>::CellFilter>(filter_id)?;
       let cell_data = self.get_decoded_cell_data(cell, field_type, field).ok()?;
       Some(self.apply_filter(cell_filter, field_type, &cell_data))
     };
@@ -479,6 +509,56 @@ impl<'a> TypeOptionCellExt<'a> {
             self.cell_data_cache.clone(),
           )
         }),
+      FieldType::Formula => self
+        .field
+        .get_type_option::<FormulaTypeOption>(field_type)
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Relation => self
+        .field
+        .get_type_option::<RelationTypeOption>(field_type)
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Lookup => self
+        .field
+        .get_type_option::<LookupTypeOption>(field_type)
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Person => self
+        .field
+        .get_type_option::<PersonTypeOption>(field_type)
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
+      FieldType::Files => self
+        .field
+        .get_type_option::<FilesTypeOption>(field_type)
+        .map(|type_option| {
+          TypeOptionCellDataHandlerImpl::new_with_boxed(
+            type_option,
+            self.cell_filter_cache.clone(),
+            self.cell_data_cache.clone(),
+          )
+        }),
     }
   }
 }
@@ -557,6 +637,21 @@ fn get_type_option_transform_handler(
     FieldType::Checklist => {
       Box::new(ChecklistTypeOption::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
     },
+    FieldType::Formula => {
+      Box::new(FormulaTypeOption::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+    },
+    FieldType::Relation => {
+      Box::new(RelationTypeOption::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+    },
+    FieldType::Lookup => {
+      Box::new(LookupTypeOption::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+    },
+    FieldType::Person => {
+      Box::new(PersonTypeOption::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+    },
+    FieldType::Files => {
+      Box::new(FilesTypeOption::from(type_option_data)) as Box<dyn TypeOptionTransformHandler>
+    },
   }
 }
 
@@ -607,8 +702,28 @@ impl RowSingleCellData {
     into_timestamp_field_cell_data,
     <TimestampTypeOption as TypeOption>::CellData
   );
+  into_cell_data!(
+    into_formula_field_cell_data,
+    <FormulaTypeOption as TypeOption>::CellData
+  );
+  into_cell_data!(
+    into_relation_field_cell_data,
+    <RelationTypeOption as TypeOption>::CellData
+  );
+  into_cell_data!(
+    into_lookup_field_cell_data,
+    <LookupTypeOption as TypeOption>::CellData
+  );
+  into_cell_data!(
+    into_person_field_cell_data,
+    <PersonTypeOption as TypeOption>::CellData
+  );
+  into_cell_data!(
+    into_files_field_cell_data,
+    <FilesTypeOption as TypeOption>::CellData
+  );
   into_cell_data!(
     into_check_list_field_cell_data,
-    <CheckboxTypeOption as TypeOption>::CellData
+    <ChecklistTypeOption as TypeOption>::CellData
   );
 }
@@ -1,8 +1,8 @@
-use crate::entities::{FieldType, TextFilterPB, URLCellDataPB};
+use crate::entities::{FieldType, URLCellDataPB};
 use crate::services::cell::{CellDataChangeset, CellDataDecoder};
 use crate::services::field::{
-  TypeOption, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionCellDataSerde,
-  TypeOptionTransform, URLCellData,
+  TextFilter, TypeOption, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionCellDataSerde, TypeOptionTransform, URLCellData,
 };
 use crate::services::sort::SortCondition;
 
@@ -25,7 +25,7 @@ impl TypeOption for URLTypeOption {
   type CellData = URLCellData;
   type CellChangeset = URLCellChangeset;
   type CellProtobufType = URLCellDataPB;
-  type CellFilter = TextFilterPB;
+  type CellFilter = TextFilter;
 }
 
 impl From<TypeOptionData> for URLTypeOption {
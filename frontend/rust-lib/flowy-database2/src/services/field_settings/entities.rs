@@ -2,16 +2,18 @@ use anyhow::bail;
 use collab::core::any_map::AnyMapExtension;
 use collab_database::views::{FieldSettingsMap, FieldSettingsMapBuilder};
 
-use crate::entities::FieldVisibility;
+use crate::entities::{CalculationType, FieldVisibility};
 
 /// Stores the field settings for a single field
 #[derive(Debug, Clone)]
 pub struct FieldSettings {
   pub field_id: String,
   pub visibility: FieldVisibility,
+  pub calculation_type: CalculationType,
 }
 
 pub const VISIBILITY: &str = "visibility";
+pub const CALCULATION_TYPE: &str = "calculation_type";
 
 impl FieldSettings {
   pub fn try_from_anymap(
@@ -22,10 +24,17 @@ impl FieldSettings {
       Some(visbility) => visbility.into(),
       _ => bail!("Invalid field settings data"),
     };
+    // The field settings that were created before the calculations were introduced don't have
+    // the calculation type.
+    let calculation_type = field_settings
+      .get_i64_value(CALCULATION_TYPE)
+      .map(CalculationType::from)
+      .unwrap_or_default();
 
     Ok(Self {
       field_id,
       visibility,
+      calculation_type,
     })
   }
 }
@@ -34,6 +43,7 @@ impl From<FieldSettings> for FieldSettingsMap {
   fn from(field_settings: FieldSettings) -> Self {
     FieldSettingsMapBuilder::new()
       .insert_i64_value(VISIBILITY, field_settings.visibility.into())
+      .insert_i64_value(CALCULATION_TYPE, field_settings.calculation_type.into())
       .build()
   }
 }
@@ -44,4 +54,5 @@ pub struct FieldSettingsChangesetParams {
   pub view_id: String,
   pub field_id: String,
   pub visibility: Option<FieldVisibility>,
+  pub calculation_type: Option<CalculationType>,
 }
@@ -8,9 +8,9 @@ use collab_database::views::{
 };
 use strum::IntoEnumIterator;
 
-use crate::entities::FieldVisibility;
+use crate::entities::{CalculationType, FieldVisibility};
 
-use crate::services::field_settings::{FieldSettings, VISIBILITY};
+use crate::services::field_settings::{FieldSettings, CALCULATION_TYPE, VISIBILITY};
 
 /// Helper struct to create a new field setting
 pub struct FieldSettingsBuilder {
@@ -22,6 +22,7 @@ impl FieldSettingsBuilder {
     let field_settings = FieldSettings {
       field_id: field_id.to_string(),
       visibility: FieldVisibility::AlwaysShown,
+      calculation_type: CalculationType::None,
     };
     Self { field_settings }
   }
@@ -36,6 +37,11 @@ impl FieldSettingsBuilder {
     self
   }
 
+  pub fn calculation_type(mut self, calculation_type: CalculationType) -> Self {
+    self.field_settings.calculation_type = calculation_type;
+    self
+  }
+
   pub fn build(self) -> FieldSettings {
     self.field_settings
   }
@@ -105,6 +111,7 @@ pub fn default_field_settings_by_layout_map() -> HashMap<DatabaseLayout, FieldSe
     };
     let field_settings = FieldSettingsMapBuilder::new()
       .insert_i64_value(VISIBILITY, visibility.into())
+      .insert_i64_value(CALCULATION_TYPE, CalculationType::None.into())
       .build();
     map.insert(layout_ty, field_settings);
   }
@@ -0,0 +1,131 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Weak};
+
+use collab_database::database::Database;
+use collab_database::user::WorkspaceDatabase;
+use parking_lot::Mutex;
+use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
+
+use flowy_storage::FileStorageService;
+
+use crate::entities::FieldType;
+use crate::services::database::DatabaseEditor;
+use crate::services::field::FilesCellData;
+
+/// Deletes the stored files that are no longer referenced by any files cell. Removing a file from
+/// a cell or deleting its row doesn't delete the file, because the same file can be attached to
+/// the cells of the duplicated rows or databases, and the removal can be undone while the database
+/// is open. The removed files are deleted after their database is closed, if none of the databases
+/// in the workspace references them.
+pub struct FileController {
+  editors: Arc<RwLock<HashMap<String, Arc<DatabaseEditor>>>>,
+  storage_service: Weak<dyn FileStorageService>,
+  deletions: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl FileController {
+  pub fn new(
+    editors: Arc<RwLock<HashMap<String, Arc<DatabaseEditor>>>>,
+    storage_service: Weak<dyn FileStorageService>,
+  ) -> Self {
+    Self {
+      editors,
+      storage_service,
+      deletions: Default::default(),
+    }
+  }
+
+  /// Runs [FileController::delete_unused_files] in the background. Use [FileController::flush]
+  /// to wait for it.
+  pub fn spawn_delete_unused_files(
+    self: &Arc<Self>,
+    workspace_database: Arc<WorkspaceDatabase>,
+    database_id: String,
+    file_urls: HashSet<String>,
+  ) {
+    let controller = self.clone();
+    let deletion = tokio::spawn(async move {
+      controller
+        .delete_unused_files(&workspace_database, &database_id, file_urls)
+        .await;
+    });
+
+    let mut deletions = self.deletions.lock();
+    deletions.retain(|deletion| !deletion.is_finished());
+    deletions.push(deletion);
+  }
+
+  /// Waits until the deletions started before the call are done.
+  pub async fn flush(&self) {
+    let deletions = std::mem::take(&mut *self.deletions.lock());
+    for deletion in deletions {
+      let _ = deletion.await;
+    }
+  }
+
+  /// Deletes the files that were removed from the cells of the closed database with
+  /// `database_id` and that are not referenced by the other databases. The open databases are
+  /// read through their editors, and the closed ones are opened and closed again.
+  pub async fn delete_unused_files(
+    &self,
+    workspace_database: &WorkspaceDatabase,
+    database_id: &str,
+    mut file_urls: HashSet<String>,
+  ) {
+    for database in workspace_database.get_all_databases() {
+      if file_urls.is_empty() {
+        return;
+      }
+      if database.database_id == database_id {
+        continue;
+      }
+
+      // Hold the editors, so the database can't be opened by an editor while it's read here
+      let editors = self.editors.read().await;
+      let used_file_urls = match editors.get(&database.database_id) {
+        Some(editor) => editor.get_used_file_urls(),
+        None => match workspace_database.get_database(&database.database_id).await {
+          Some(closed_database) => {
+            let used_file_urls = get_file_urls(&closed_database.lock());
+            workspace_database.close_database(&database.database_id);
+            used_file_urls
+          },
+          None => {
+            // The files might be referenced by the database that can't be read, so they are kept
+            tracing::warn!(
+              "Failed to read the database {}, the removed files are kept",
+              database.database_id
+            );
+            return;
+          },
+        },
+      };
+      file_urls.retain(|url| !used_file_urls.contains(url));
+    }
+
+    let file_storage = match self.storage_service.upgrade() {
+      None => return,
+      Some(file_storage) => file_storage,
+    };
+    for url in file_urls {
+      // The failures are only logged, because the cells no longer reference the files
+      if let Err(err) = file_storage.delete_object_by_url(url.clone()).await {
+        tracing::error!("Failed to delete the file: {}, error: {:?}", url, err);
+      }
+    }
+  }
+}
+
+/// Returns the urls of the files that the files cells of the database reference.
+pub fn get_file_urls(database: &Database) -> HashSet<String> {
+  let inline_view_id = database.get_inline_view_id();
+  database
+    .get_fields(None)
+    .into_iter()
+    .filter(|field| FieldType::from(field.field_type).is_files())
+    .flat_map(|field| database.get_cells_for_field(&inline_view_id, &field.id))
+    .filter_map(|row_cell| row_cell.cell)
+    .flat_map(|cell| FilesCellData::from(&cell).urls())
+    .collect()
+}
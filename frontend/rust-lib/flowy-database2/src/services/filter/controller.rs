@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
 
+use chrono::NaiveDate;
 use collab_database::fields::Field;
 use collab_database::rows::{Cell, Row, RowDetail, RowId};
 use dashmap::DashMap;
@@ -11,20 +13,31 @@ use tokio::sync::RwLock;
 use flowy_error::FlowyResult;
 use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
 use lib_infra::future::Fut;
+use lib_infra::util::timestamp;
 
 use crate::entities::filter_entities::*;
 use crate::entities::{FieldType, InsertedRowPB, RowMetaPB};
 use crate::services::cell::{AnyTypeCache, CellCache, CellFilterCache};
 use crate::services::database_view::{DatabaseViewChanged, DatabaseViewChangedNotifier};
 use crate::services::field::*;
-use crate::services::filter::{Filter, FilterChangeset, FilterResult, FilterResultNotification};
+use crate::services::filter::{
+  Filter, FilterChangeset, FilterGroup, FilterResult, FilterResultNotification, FilterTree,
+  FilterType,
+};
 
 pub trait FilterDelegate: Send + Sync + 'static {
   fn get_filter(&self, view_id: &str, filter_id: &str) -> Fut<Option<Arc<Filter>>>;
+  fn get_all_filters(&self, view_id: &str) -> Fut<Vec<Arc<Filter>>>;
+  fn get_all_filter_groups(&self, view_id: &str) -> Fut<Vec<FilterGroup>>;
   fn get_field(&self, field_id: &str) -> Fut<Option<Arc<Field>>>;
   fn get_fields(&self, view_id: &str, field_ids: Option<Vec<String>>) -> Fut<Vec<Arc<Field>>>;
   fn get_rows(&self, view_id: &str) -> Fut<Vec<Arc<RowDetail>>>;
   fn get_row(&self, view_id: &str, rows_id: &RowId) -> Fut<Option<(usize, Arc<RowDetail>)>>;
+  /// Returns the id of the current user, used by the filters that depend on who is viewing the
+  /// database.
+  fn get_user_id(&self) -> i64;
+  /// Returns the date and time settings of the current user, used by the relative date filters.
+  fn get_date_time_settings(&self) -> UserDateTimeSettings;
 }
 
 pub trait FromFilterString {
@@ -40,6 +53,11 @@ pub struct FilterController {
   result_by_row_id: DashMap<RowId, FilterResult>,
   cell_cache: CellCache,
   cell_filter_cache: CellFilterCache,
+  filter_tree: parking_lot::RwLock<FilterTree>,
+  /// The settings that the date filters are cached with
+  date_time_settings: parking_lot::RwLock<UserDateTimeSettings>,
+  /// The current date in the timezone of the user. It's used to detect the day rollover.
+  today: parking_lot::Mutex<Option<NaiveDate>>,
   task_scheduler: Arc<RwLock<TaskDispatcher>>,
   notifier: DatabaseViewChangedNotifier,
 }
@@ -57,6 +75,7 @@ impl FilterController {
     delegate: T,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
     filters: Vec<Arc<Filter>>,
+    filter_groups: Vec<FilterGroup>,
     cell_cache: CellCache,
     notifier: DatabaseViewChangedNotifier,
   ) -> Self
@@ -69,15 +88,67 @@ impl FilterController {
       delegate: Box::new(delegate),
       result_by_row_id: DashMap::default(),
       cell_cache,
-      // Cache by field_id
+      // Cache by filter_id
       cell_filter_cache: AnyTypeCache::<String>::new(),
+      filter_tree: parking_lot::RwLock::new(FilterTree::new(&filters, &filter_groups)),
+      date_time_settings: Default::default(),
+      today: Default::default(),
       task_scheduler,
       notifier,
     };
+    let date_time_settings = this.delegate.get_date_time_settings();
+    *this.today.lock() = date_in_timezone(timestamp(), &date_time_settings.timezone_id);
+    *this.date_time_settings.write() = date_time_settings;
     this.refresh_filters(filters).await;
     this
   }
 
+  /// Re-runs the filters with relative date conditions, e.g. today or this week, when the day
+  /// rolls over or the user changes the date and time settings. The timezones' offsets are
+  /// multiples of 15 minutes, so checking at every quarter hour catches the midnight of any
+  /// timezone.
+  pub fn watch_day_rollover(self: &Arc<Self>) {
+    let weak_controller: Weak<Self> = Arc::downgrade(self);
+    tokio::spawn(async move {
+      loop {
+        let secs_to_next_quarter = 900 - timestamp().rem_euclid(900);
+        tokio::time::sleep(Duration::from_secs(secs_to_next_quarter as u64)).await;
+        match weak_controller.upgrade() {
+          None => break,
+          Some(controller) => controller.did_receive_time_changed().await,
+        }
+      }
+    });
+  }
+
+  async fn did_receive_time_changed(&self) {
+    let date_time_settings = self.delegate.get_date_time_settings();
+    let today = date_in_timezone(timestamp(), &date_time_settings.timezone_id);
+    let is_settings_changed = *self.date_time_settings.read() != date_time_settings;
+    let is_day_changed = std::mem::replace(&mut *self.today.lock(), today) != today;
+    if !is_settings_changed && !is_day_changed {
+      return;
+    }
+
+    let filters = self.delegate.get_all_filters(&self.view_id).await;
+    let has_relative_date_filter = filters.iter().any(|filter| {
+      let is_date_filter = matches!(
+        filter.field_type,
+        FieldType::DateTime | FieldType::LastEditedTime | FieldType::CreatedTime
+      );
+      is_date_filter && DateFilterPB::from(filter.as_ref()).condition.is_relative()
+    });
+    if is_settings_changed {
+      *self.date_time_settings.write() = date_time_settings;
+      self.refresh_filters(filters).await;
+    }
+    if has_relative_date_filter {
+      self
+        .gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
+        .await;
+    }
+  }
+
   pub async fn close(&self) {
     if let Ok(mut task_scheduler) = self.task_scheduler.try_write() {
       task_scheduler.unregister_handler(&self.handler_id).await;
@@ -103,11 +174,13 @@ impl FilterController {
       return;
     }
     let field_by_field_id = self.get_field_map().await;
+    let filter_tree = self.filter_tree.read();
     rows.iter().for_each(|row_detail| {
       let _ = filter_row(
         &row_detail.row,
         &self.result_by_row_id,
         &field_by_field_id,
+        &filter_tree,
         &self.cell_cache,
         &self.cell_filter_cache,
       );
@@ -117,7 +190,7 @@ impl FilterController {
       self
         .result_by_row_id
         .get(&row_detail.row.id)
-        .map(|result| result.is_visible())
+        .map(|result| result.is_visible)
         .unwrap_or(false)
     });
   }
@@ -152,13 +225,15 @@ impl FilterController {
     if let Some((_, row_detail)) = self.delegate.get_row(&self.view_id, &row_id).await {
       let field_by_field_id = self.get_field_map().await;
       let mut notification = FilterResultNotification::new(self.view_id.clone());
-      if let Some((row_id, is_visible)) = filter_row(
+      let result = filter_row(
         &row_detail.row,
         &self.result_by_row_id,
         &field_by_field_id,
+        &self.filter_tree.read(),
         &self.cell_cache,
         &self.cell_filter_cache,
-      ) {
+      );
+      if let Some((row_id, is_visible)) = result {
         if is_visible {
           if let Some((index, _row)) = self.delegate.get_row(&self.view_id, &row_id).await {
             notification.visible_rows.push(
@@ -182,17 +257,14 @@ impl FilterController {
     let mut visible_rows = vec![];
     let mut invisible_rows = vec![];
 
-    for (index, row_detail) in self
-      .delegate
-      .get_rows(&self.view_id)
-      .await
-      .into_iter()
-      .enumerate()
-    {
+    let rows = self.delegate.get_rows(&self.view_id).await;
+    let filter_tree = self.filter_tree.read();
+    for (index, row_detail) in rows.into_iter().enumerate() {
       if let Some((row_id, is_visible)) = filter_row(
         &row_detail.row,
         &self.result_by_row_id,
         &field_by_field_id,
+        &filter_tree,
         &self.cell_cache,
         &self.cell_filter_cache,
       ) {
@@ -288,9 +360,18 @@ impl FilterController {
           vec![filter],
         ));
       }
-      self.cell_filter_cache.write().remove(&filter_type.field_id);
+      self
+        .cell_filter_cache
+        .write()
+        .remove(&filter_type.filter_id);
     }
 
+    // The deleted filter is removed from the database after the changeset is handled
+    let deleted_filter_id = changeset
+      .delete_filter
+      .as_ref()
+      .map(|filter_type| filter_type.filter_id.as_str());
+    self.reload_filter_tree(deleted_filter_id).await;
     self
       .gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
       .await;
@@ -298,6 +379,34 @@ impl FilterController {
     notification
   }
 
+  /// Called after the filter groups of the view were changed. The filters that were deleted along
+  /// with their groups are removed from the cache.
+  #[tracing::instrument(level = "trace", skip(self))]
+  pub async fn did_receive_filter_group_changes(&self, deleted_filters: Vec<FilterType>) {
+    for filter_type in deleted_filters {
+      self
+        .cell_filter_cache
+        .write()
+        .remove(&filter_type.filter_id);
+    }
+    self.reload_filter_tree(None).await;
+    self
+      .gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
+      .await;
+  }
+
+  async fn reload_filter_tree(&self, deleted_filter_id: Option<&str>) {
+    let filters = self
+      .delegate
+      .get_all_filters(&self.view_id)
+      .await
+      .into_iter()
+      .filter(|filter| Some(filter.id.as_str()) != deleted_filter_id)
+      .collect::<Vec<Arc<Filter>>>();
+    let filter_groups = self.delegate.get_all_filter_groups(&self.view_id).await;
+    *self.filter_tree.write() = FilterTree::new(&filters, &filter_groups);
+  }
+
   async fn filter_from_filter_id(&self, filter_id: &str) -> Option<FilterPB> {
     self
       .delegate
@@ -309,50 +418,75 @@ impl FilterController {
   #[tracing::instrument(level = "trace", skip_all)]
   async fn refresh_filters(&self, filters: Vec<Arc<Filter>>) {
     for filter in filters {
-      let field_id = &filter.field_id;
+      let filter_id = &filter.id;
       tracing::trace!("Create filter with type: {:?}", filter.field_type);
       match &filter.field_type {
         FieldType::RichText => {
           self
             .cell_filter_cache
             .write()
-            .insert(field_id, TextFilterPB::from_filter(filter.as_ref()));
+            .insert(filter_id, TextFilter::from_filter(filter.as_ref()));
         },
         FieldType::Number => {
           self
             .cell_filter_cache
             .write()
-            .insert(field_id, NumberFilterPB::from_filter(filter.as_ref()));
+            .insert(filter_id, NumberFilterPB::from_filter(filter.as_ref()));
         },
         FieldType::DateTime | FieldType::LastEditedTime | FieldType::CreatedTime => {
+          self.cell_filter_cache.write().insert(
+            filter_id,
+            DateFilter::new(
+              DateFilterPB::from_filter(filter.as_ref()),
+              self.date_time_settings.read().clone(),
+            ),
+          );
+        },
+        FieldType::SingleSelect | FieldType::MultiSelect => {
+          self.cell_filter_cache.write().insert(
+            filter_id,
+            SelectOptionFilterPB::from_filter(filter.as_ref()),
+          );
+        },
+        FieldType::Checkbox => {
           self
             .cell_filter_cache
             .write()
-            .insert(field_id, DateFilterPB::from_filter(filter.as_ref()));
+            .insert(filter_id, CheckboxFilterPB::from_filter(filter.as_ref()));
         },
-        FieldType::SingleSelect | FieldType::MultiSelect => {
+        FieldType::URL | FieldType::Lookup | FieldType::Files => {
           self
             .cell_filter_cache
             .write()
-            .insert(field_id, SelectOptionFilterPB::from_filter(filter.as_ref()));
+            .insert(filter_id, TextFilter::from_filter(filter.as_ref()));
         },
-        FieldType::Checkbox => {
+        FieldType::Checklist => {
           self
             .cell_filter_cache
             .write()
-            .insert(field_id, CheckboxFilterPB::from_filter(filter.as_ref()));
+            .insert(filter_id, ChecklistFilterPB::from_filter(filter.as_ref()));
         },
-        FieldType::URL => {
+        FieldType::Formula => {
           self
             .cell_filter_cache
             .write()
-            .insert(field_id, TextFilterPB::from_filter(filter.as_ref()));
+            .insert(filter_id, FormulaFilterPB::from_filter(filter.as_ref()));
         },
-        FieldType::Checklist => {
+        FieldType::Relation => {
           self
             .cell_filter_cache
             .write()
-            .insert(field_id, ChecklistFilterPB::from_filter(filter.as_ref()));
+            .insert(filter_id, RelationFilterPB::from_filter(filter.as_ref()));
+        },
+        FieldType::Person => {
+          let mut person_filter = PersonFilterPB::from_filter(filter.as_ref());
+          if person_filter.condition == PersonFilterConditionPB::IsMe {
+            person_filter.user_ids = vec![self.delegate.get_user_id()];
+          }
+          self
+            .cell_filter_cache
+            .write()
+            .insert(filter_id, person_filter);
         },
       }
     }
@@ -365,6 +499,7 @@ fn filter_row(
   row: &Row,
   result_by_row_id: &DashMap<RowId, FilterResult>,
   field_by_field_id: &HashMap<String, Arc<Field>>,
+  filter_tree: &FilterTree,
   cell_data_cache: &CellCache,
   cell_filter_cache: &CellFilterCache,
 ) -> Option<(RowId, bool)> {
@@ -372,29 +507,47 @@ fn filter_row(
   let mut filter_result = result_by_row_id
     .entry(row.id.clone())
     .or_insert_with(FilterResult::default);
-  let old_is_visible = filter_result.is_visible();
-
-  // Iterate each cell of the row to check its visibility
-  for (field_id, field) in field_by_field_id {
-    if !cell_filter_cache.read().contains(field_id) {
-      filter_result.visible_by_field_id.remove(field_id);
-      continue;
-    }
+  let old_is_visible = filter_result.is_visible;
+
+  // Remove the results of the filters that were deleted
+  filter_result
+    .visible_by_filter_id
+    .retain(|filter_id, _| filter_tree.contains_filter(filter_id));
+
+  // Iterate each filter of the view to check the visibility of its cell
+  for (filter_id, field_id) in filter_tree.filters() {
+    let field = match field_by_field_id.get(field_id) {
+      Some(field) if cell_filter_cache.read().contains(filter_id) => field,
+      _ => {
+        filter_result.visible_by_filter_id.remove(filter_id);
+        continue;
+      },
+    };
 
-    let cell = row.cells.get(field_id).cloned();
     let field_type = FieldType::from(field.field_type);
-    // if the visibility of the cell_rew is changed, which means the visibility of the
-    // row is changed too.
-    if let Some(is_visible) =
-      filter_cell(&field_type, field, cell, cell_data_cache, cell_filter_cache)
-    {
+    // The cells of the LastEditedTime and CreatedTime are built from the timestamps of the row
+    let cell = match field_type {
+      FieldType::LastEditedTime => Some(Cell::from(TimestampCellData::new(row.modified_at))),
+      FieldType::CreatedTime => Some(Cell::from(TimestampCellData::new(row.created_at))),
+      _ => row.cells.get(field_id).cloned(),
+    };
+    if let Some(is_visible) = filter_cell(
+      filter_id,
+      &field_type,
+      field,
+      cell,
+      cell_data_cache,
+      cell_filter_cache,
+    ) {
       filter_result
-        .visible_by_field_id
-        .insert(field_id.to_string(), is_visible);
+        .visible_by_filter_id
+        .insert(filter_id.to_string(), is_visible);
     }
   }
 
-  let is_visible = filter_result.is_visible();
+  // The results of the filters are combined according to the filter groups
+  let is_visible = filter_tree.is_visible(&filter_result.visible_by_filter_id);
+  filter_result.is_visible = is_visible;
   if old_is_visible != is_visible {
     Some((row.id.clone(), is_visible))
   } else {
@@ -407,6 +560,7 @@ fn filter_row(
 
 #[tracing::instrument(level = "trace", skip_all, fields(cell_content))]
 fn filter_cell(
+  filter_id: &str,
   field_type: &FieldType,
   field: &Arc<Field>,
   cell: Option<Cell>,
@@ -419,8 +573,12 @@ fn filter_cell(
     Some(cell_filter_cache.clone()),
   )
   .get_type_option_cell_data_handler(field_type)?;
-  let is_visible =
-    handler.handle_cell_filter(field_type, field.as_ref(), &cell.unwrap_or_default());
+  let is_visible = handler.handle_cell_filter(
+    filter_id,
+    field_type,
+    field.as_ref(),
+    &cell.unwrap_or_default(),
+  );
   Some(is_visible)
 }
 
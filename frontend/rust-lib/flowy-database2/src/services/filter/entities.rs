@@ -12,6 +12,9 @@ pub struct Filter {
   pub field_type: FieldType,
   pub condition: i64,
   pub content: String,
+  /// The id of the [FilterGroup] that contains the filter. The filter is at the root of the view
+  /// if it's None.
+  pub parent_id: Option<String>,
 }
 
 const FILTER_ID: &str = "id";
@@ -19,6 +22,8 @@ const FIELD_ID: &str = "field_id";
 const FIELD_TYPE: &str = "ty";
 const FILTER_CONDITION: &str = "condition";
 const FILTER_CONTENT: &str = "content";
+const FILTER_PARENT_ID: &str = "parent_id";
+const FILTER_OPERATOR: &str = "operator";
 
 impl From<Filter> for FilterMap {
   fn from(data: Filter) -> Self {
@@ -28,6 +33,7 @@ impl From<Filter> for FilterMap {
       .insert_str_value(FILTER_CONTENT, data.content)
       .insert_i64_value(FIELD_TYPE, data.field_type.into())
       .insert_i64_value(FILTER_CONDITION, data.condition)
+      .insert_str_value(FILTER_PARENT_ID, data.parent_id.unwrap_or_default())
       .build()
   }
 }
@@ -47,12 +53,14 @@ impl TryFrom<FilterMap> for Filter {
           .get_i64_value(FIELD_TYPE)
           .map(FieldType::from)
           .unwrap_or_default();
+        let parent_id = parent_id_from_filter_map(&filter);
         Ok(Filter {
           id,
           field_id,
           field_type,
           condition,
           content,
+          parent_id,
         })
       },
       _ => {
@@ -61,6 +69,72 @@ impl TryFrom<FilterMap> for Filter {
     }
   }
 }
+
+/// The empty string is stored when the filter or the group is at the root of the view.
+fn parent_id_from_filter_map(filter: &FilterMap) -> Option<String> {
+  filter
+    .get_str_value(FILTER_PARENT_ID)
+    .filter(|parent_id| !parent_id.is_empty())
+}
+
+/// Defines how the children of a [FilterGroup] are combined.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum FilterOperator {
+  #[default]
+  And = 0,
+  Or = 1,
+}
+
+impl From<i64> for FilterOperator {
+  fn from(value: i64) -> Self {
+    match value {
+      1 => FilterOperator::Or,
+      _ => FilterOperator::And,
+    }
+  }
+}
+
+/// A group of filters and nested groups. The filters and the groups that are at the root of the
+/// view are combined with [FilterOperator::And].
+///
+/// The group is stored alongside the filters of the view, but it doesn't have a field id.
+#[derive(Debug, Clone)]
+pub struct FilterGroup {
+  pub id: String,
+  pub parent_id: Option<String>,
+  pub operator: FilterOperator,
+}
+
+impl From<FilterGroup> for FilterMap {
+  fn from(data: FilterGroup) -> Self {
+    FilterMapBuilder::new()
+      .insert_str_value(FILTER_ID, data.id)
+      .insert_str_value(FILTER_PARENT_ID, data.parent_id.unwrap_or_default())
+      .insert_i64_value(FILTER_OPERATOR, data.operator as i64)
+      .build()
+  }
+}
+
+impl TryFrom<FilterMap> for FilterGroup {
+  type Error = anyhow::Error;
+
+  fn try_from(filter: FilterMap) -> Result<Self, Self::Error> {
+    match (
+      filter.get_str_value(FILTER_ID),
+      filter.get_i64_value(FILTER_OPERATOR),
+      filter.get_str_value(FIELD_ID),
+    ) {
+      (Some(id), Some(operator), None) => Ok(FilterGroup {
+        id,
+        parent_id: parent_id_from_filter_map(&filter),
+        operator: FilterOperator::from(operator),
+      }),
+      _ => {
+        bail!("Invalid filter group data")
+      },
+    }
+  }
+}
 #[derive(Debug)]
 pub struct FilterChangeset {
   pub(crate) insert_filter: Option<FilterType>,
@@ -1,7 +1,9 @@
 mod controller;
 mod entities;
 mod task;
+mod tree;
 
 pub use controller::*;
 pub use entities::*;
 pub(crate) use task::*;
+pub use tree::*;
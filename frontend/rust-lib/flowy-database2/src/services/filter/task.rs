@@ -40,21 +40,19 @@ impl TaskHandler for FilterTaskHandler {
     })
   }
 }
-/// Refresh the filter according to the field id.
-#[derive(Default)]
+/// The result of applying the filters of the view to a row.
 pub(crate) struct FilterResult {
-  pub(crate) visible_by_field_id: HashMap<String, bool>,
+  /// The visibility of the cell of the row under each filter.
+  pub(crate) visible_by_filter_id: HashMap<String, bool>,
+  /// The visibility of the row after combining the results of the filters with the filter groups.
+  pub(crate) is_visible: bool,
 }
 
-impl FilterResult {
-  pub(crate) fn is_visible(&self) -> bool {
-    let mut is_visible = true;
-    for visible in self.visible_by_field_id.values() {
-      if !is_visible {
-        break;
-      }
-      is_visible = *visible;
+impl Default for FilterResult {
+  fn default() -> Self {
+    Self {
+      visible_by_filter_id: HashMap::new(),
+      is_visible: true,
     }
-    is_visible
   }
 }
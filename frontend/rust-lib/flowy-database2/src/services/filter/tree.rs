@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::services::filter::{Filter, FilterGroup, FilterOperator};
+
+enum FilterNode {
+  Filter(String),
+  Group(String),
+}
+
+/// The filters of a view arranged by their [FilterGroup]s. It's used to combine the results of
+/// the filters of a row into the visibility of the row.
+///
+/// The filters and the groups whose parent group doesn't exist are treated as if they were at the
+/// root of the view, so they are still applied.
+#[derive(Default)]
+pub struct FilterTree {
+  children_by_parent_id: HashMap<Option<String>, Vec<FilterNode>>,
+  operator_by_group_id: HashMap<String, FilterOperator>,
+  field_id_by_filter_id: HashMap<String, String>,
+}
+
+impl FilterTree {
+  pub fn new(filters: &[Arc<Filter>], filter_groups: &[FilterGroup]) -> Self {
+    let operator_by_group_id = filter_groups
+      .iter()
+      .map(|group| (group.id.clone(), group.operator))
+      .collect::<HashMap<String, FilterOperator>>();
+    let parent_of = |parent_id: &Option<String>| {
+      parent_id
+        .clone()
+        .filter(|parent_id| operator_by_group_id.contains_key(parent_id))
+    };
+
+    let mut children_by_parent_id: HashMap<Option<String>, Vec<FilterNode>> = HashMap::new();
+    for filter in filters {
+      children_by_parent_id
+        .entry(parent_of(&filter.parent_id))
+        .or_default()
+        .push(FilterNode::Filter(filter.id.clone()));
+    }
+    for group in filter_groups {
+      children_by_parent_id
+        .entry(parent_of(&group.parent_id))
+        .or_default()
+        .push(FilterNode::Group(group.id.clone()));
+    }
+
+    let field_id_by_filter_id = filters
+      .iter()
+      .map(|filter| (filter.id.clone(), filter.field_id.clone()))
+      .collect();
+    Self {
+      children_by_parent_id,
+      operator_by_group_id,
+      field_id_by_filter_id,
+    }
+  }
+
+  /// Returns the (filter_id, field_id) pairs of all the filters.
+  pub fn filters(&self) -> impl Iterator<Item = (&String, &String)> {
+    self.field_id_by_filter_id.iter()
+  }
+
+  pub fn contains_filter(&self, filter_id: &str) -> bool {
+    self.field_id_by_filter_id.contains_key(filter_id)
+  }
+
+  /// Combines the results of the filters according to the operators of the groups. The filters
+  /// without result are skipped, and a group without any result is considered passed.
+  pub fn is_visible(&self, visible_by_filter_id: &HashMap<String, bool>) -> bool {
+    self
+      .evaluate(&None, FilterOperator::And, visible_by_filter_id)
+      .unwrap_or(true)
+  }
+
+  fn evaluate(
+    &self,
+    parent_id: &Option<String>,
+    operator: FilterOperator,
+    visible_by_filter_id: &HashMap<String, bool>,
+  ) -> Option<bool> {
+    let results = self
+      .children_by_parent_id
+      .get(parent_id)?
+      .iter()
+      .filter_map(|node| match node {
+        FilterNode::Filter(filter_id) => visible_by_filter_id.get(filter_id).cloned(),
+        FilterNode::Group(group_id) => {
+          let operator = self
+            .operator_by_group_id
+            .get(group_id)
+            .cloned()
+            .unwrap_or_default();
+          self.evaluate(&Some(group_id.clone()), operator, visible_by_filter_id)
+        },
+      })
+      .collect::<Vec<bool>>();
+
+    if results.is_empty() {
+      return None;
+    }
+    match operator {
+      FilterOperator::And => Some(results.iter().all(|is_visible| *is_visible)),
+      FilterOperator::Or => Some(results.iter().any(|is_visible| *is_visible)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+  use std::sync::Arc;
+
+  use crate::entities::FieldType;
+  use crate::services::filter::{Filter, FilterGroup, FilterOperator, FilterTree};
+
+  fn filter(id: &str, parent_id: Option<&str>) -> Arc<Filter> {
+    Arc::new(Filter {
+      id: id.to_string(),
+      field_id: format!("{}_field", id),
+      field_type: FieldType::RichText,
+      condition: 0,
+      content: "".to_string(),
+      parent_id: parent_id.map(|parent_id| parent_id.to_string()),
+    })
+  }
+
+  fn results(results: &[(&str, bool)]) -> HashMap<String, bool> {
+    results
+      .iter()
+      .map(|(filter_id, is_visible)| (filter_id.to_string(), *is_visible))
+      .collect()
+  }
+
+  #[test]
+  fn filter_tree_or_group_and_root_filter_test() {
+    // (a OR b) AND c
+    let tree = FilterTree::new(
+      &[
+        filter("a", Some("g")),
+        filter("b", Some("g")),
+        filter("c", None),
+      ],
+      &[FilterGroup {
+        id: "g".to_string(),
+        parent_id: None,
+        operator: FilterOperator::Or,
+      }],
+    );
+
+    assert!(tree.is_visible(&results(&[("a", false), ("b", true), ("c", true)])));
+    assert!(!tree.is_visible(&results(&[("a", false), ("b", false), ("c", true)])));
+    assert!(!tree.is_visible(&results(&[("a", true), ("b", true), ("c", false)])));
+  }
+
+  #[test]
+  fn filter_tree_nested_group_test() {
+    // a OR (b AND c)
+    let tree = FilterTree::new(
+      &[
+        filter("a", Some("or")),
+        filter("b", Some("and")),
+        filter("c", Some("and")),
+      ],
+      &[
+        FilterGroup {
+          id: "or".to_string(),
+          parent_id: None,
+          operator: FilterOperator::Or,
+        },
+        FilterGroup {
+          id: "and".to_string(),
+          parent_id: Some("or".to_string()),
+          operator: FilterOperator::And,
+        },
+      ],
+    );
+
+    assert!(tree.is_visible(&results(&[("a", false), ("b", true), ("c", true)])));
+    assert!(!tree.is_visible(&results(&[("a", false), ("b", true), ("c", false)])));
+    assert!(tree.is_visible(&results(&[("a", true), ("b", false), ("c", false)])));
+  }
+
+  #[test]
+  fn filter_tree_missing_parent_and_results_test() {
+    let tree = FilterTree::new(&[filter("a", Some("deleted")), filter("b", None)], &[]);
+    assert!(!tree.is_visible(&results(&[("a", false), ("b", true)])));
+    // The filters without result are skipped
+    assert!(tree.is_visible(&results(&[("b", true)])));
+    assert!(tree.is_visible(&HashMap::new()));
+  }
+}
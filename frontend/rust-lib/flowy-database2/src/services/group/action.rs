@@ -24,6 +24,13 @@ pub trait GroupCustomize: Send + Sync {
     None
   }
 
+  /// Returns the cell of the grouping field in the row. The cells of some field types, for
+  /// example, the [FieldType::CreatedTime](crate::entities::FieldType::CreatedTime), are not
+  /// stored in the row but built from the row.
+  fn cell_from_row(&self, row: &Row, field_id: &str) -> Option<Cell> {
+    row.cells.get(field_id).cloned()
+  }
+
   /// Returns a bool value to determine whether the group should contain this cell or not.
   fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool;
 
@@ -1,6 +1,5 @@
 use std::collections::HashMap;
 use std::fmt::Formatter;
-use std::marker::PhantomData;
 use std::sync::Arc;
 
 use collab_database::fields::Field;
@@ -52,7 +51,9 @@ pub struct GroupContext<C> {
   /// Uses the [GroupSettingReader] to read the configuration data from disk
   setting: Arc<GroupSetting>,
 
-  configuration_phantom: PhantomData<C>,
+  /// The configuration parsed from the content of the [GroupSetting]. The content doesn't change
+  /// while the context is alive, so it's parsed once when the context is created.
+  configuration: C,
 
   /// The grouping field
   field: Arc<Field>,
@@ -74,7 +75,7 @@ pub struct GroupContext<C> {
 
 impl<C> GroupContext<C>
 where
-  C: Serialize + DeserializeOwned,
+  C: Serialize + DeserializeOwned + Default,
 {
   #[tracing::instrument(level = "trace", skip_all, err)]
   pub async fn new(
@@ -93,6 +94,7 @@ where
       },
       Some(setting) => setting,
     };
+    let configuration = serde_json::from_str(&setting.content).unwrap_or_default();
 
     Ok(Self {
       view_id,
@@ -101,7 +103,7 @@ where
       reader,
       writer,
       setting,
-      configuration_phantom: PhantomData,
+      configuration,
     })
   }
 
@@ -385,6 +387,10 @@ where
     self.setting.content.clone()
   }
 
+  pub fn get_configuration(&self) -> &C {
+    &self.configuration
+  }
+
   /// # Arguments
   ///
   /// * `mut_configuration_fn`: mutate the [GroupSetting] and return whether the [GroupSetting] is
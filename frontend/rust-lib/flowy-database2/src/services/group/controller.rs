@@ -110,7 +110,7 @@ pub struct BaseGroupController<C, T, G, P> {
 
 impl<C, T, G, P> BaseGroupController<C, T, G, P>
 where
-  C: Serialize + DeserializeOwned,
+  C: Serialize + DeserializeOwned + Default,
   T: From<TypeOptionData>,
   G: GroupsBuilder<Context = GroupContext<C>, TypeOptionType = T>,
 {
@@ -209,7 +209,7 @@ where
 impl<C, T, G, P> GroupControllerOperation for BaseGroupController<C, T, G, P>
 where
   P: CellProtobufBlobParser,
-  C: Serialize + DeserializeOwned,
+  C: Serialize + DeserializeOwned + Default,
   T: From<TypeOptionData>,
   G: GroupsBuilder<Context = GroupContext<C>, TypeOptionType = T>,
 
@@ -231,9 +231,9 @@ where
   #[tracing::instrument(level = "trace", skip_all, fields(row_count=%rows.len(), group_result))]
   fn fill_groups(&mut self, rows: &[&RowDetail], field: &Field) -> FlowyResult<()> {
     for row_detail in rows {
-      let cell = match row_detail.row.cells.get(&self.grouping_field_id) {
+      let cell = match self.cell_from_row(&row_detail.row, &self.grouping_field_id) {
         None => self.placeholder_cell(),
-        Some(cell) => Some(cell.clone()),
+        Some(cell) => Some(cell),
       };
 
       if let Some(cell) = cell {
@@ -289,10 +289,10 @@ where
       row_changesets: vec![],
     };
 
-    if let Some(cell_data) = get_cell_data_from_row::<P>(Some(&row_detail.row), field) {
+    if let Some(cell_data) = self.get_cell_data_from_row(Some(&row_detail.row), field) {
       let _old_row = old_row_detail.as_ref();
       let old_cell_data =
-        get_cell_data_from_row::<P>(old_row_detail.as_ref().map(|detail| &detail.row), field);
+        self.get_cell_data_from_row(old_row_detail.as_ref().map(|detail| &detail.row), field);
       if let Ok((insert, delete)) = self.create_or_delete_group_when_cell_changed(
         row_detail,
         old_cell_data.as_ref(),
@@ -324,8 +324,8 @@ where
       deleted_group: None,
       row_changesets: vec![],
     };
-    if let Some(cell) = row.cells.get(&self.grouping_field_id) {
-      let cell_bytes = get_cell_protobuf(cell, field, None);
+    if let Some(cell) = self.cell_from_row(row, &self.grouping_field_id) {
+      let cell_bytes = get_cell_protobuf(&cell, field, None);
       let cell_data = cell_bytes.parser::<P>()?;
       if !cell_data.is_empty() {
         tracing::error!("did_delete_delete_row {:?}", cell);
@@ -357,8 +357,8 @@ where
       deleted_group: None,
       row_changesets: vec![],
     };
-    let cell = match context.row_detail.row.cells.get(&self.grouping_field_id) {
-      Some(cell) => Some(cell.clone()),
+    let cell = match self.cell_from_row(&context.row_detail.row, &self.grouping_field_id) {
+      Some(cell) => Some(cell),
       None => self.placeholder_cell(),
     };
 
@@ -392,11 +392,14 @@ struct GroupedRow {
   group_id: String,
 }
 
-fn get_cell_data_from_row<P: CellProtobufBlobParser>(
-  row: Option<&Row>,
-  field: &Field,
-) -> Option<P::Object> {
-  let cell = row.and_then(|row| row.cells.get(&field.id))?;
-  let cell_bytes = get_cell_protobuf(cell, field, None);
-  cell_bytes.parser::<P>().ok()
+impl<C, T, G, P> BaseGroupController<C, T, G, P>
+where
+  P: CellProtobufBlobParser,
+  Self: GroupCustomize<CellData = P::Object>,
+{
+  fn get_cell_data_from_row(&self, row: Option<&Row>, field: &Field) -> Option<P::Object> {
+    let cell = self.cell_from_row(row?, &field.id)?;
+    let cell_bytes = get_cell_protobuf(&cell, field, None);
+    cell_bytes.parser::<P>().ok()
+  }
 }
@@ -0,0 +1,207 @@
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, Cells, Row, RowDetail};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{ChecklistCellDataPB, GroupRowsNotificationPB, InsertedRowPB, RowMetaPB};
+use crate::services::field::checklist_type_option::{
+  ChecklistCellData, ChecklistCellDataParser, ChecklistTypeOption,
+};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+  BaseGroupController, GroupController, GroupsBuilder, MoveGroupRowContext,
+};
+use crate::services::group::{
+  make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroups, Group,
+};
+
+pub const CHECKLIST_NOT_STARTED: &str = "not_started";
+pub const CHECKLIST_IN_PROGRESS: &str = "in_progress";
+pub const CHECKLIST_COMPLETE: &str = "complete";
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct ChecklistGroupConfiguration {
+  pub hide_empty: bool,
+}
+
+pub type ChecklistGroupController = BaseGroupController<
+  ChecklistGroupConfiguration,
+  ChecklistTypeOption,
+  ChecklistGroupGenerator,
+  ChecklistCellDataParser,
+>;
+
+pub type ChecklistGroupContext = GroupContext<ChecklistGroupConfiguration>;
+
+impl GroupCustomize for ChecklistGroupController {
+  type CellData = ChecklistCellDataPB;
+
+  fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+    group_id(cell_data) == Some(content)
+  }
+
+  fn add_or_remove_row_when_cell_changed(
+    &mut self,
+    row_detail: &RowDetail,
+    cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    let group_id = group_id(cell_data);
+    self.context.iter_mut_status_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if Some(group.id.as_str()) == group_id {
+        if !group.contains_row(&row_detail.row.id) {
+          changeset
+            .inserted_rows
+            .push(InsertedRowPB::new(RowMetaPB::from(row_detail)));
+          group.add_row(row_detail.clone());
+        }
+      } else if group.contains_row(&row_detail.row.id) {
+        group.remove_row(&row_detail.row.id);
+        changeset
+          .deleted_rows
+          .push(row_detail.row.id.clone().into_inner());
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn delete_row(&mut self, row: &Row, _cell_data: &Self::CellData) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.context.iter_mut_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if group.contains_row(&row.id) {
+        group.remove_row(&row.id);
+        changeset.deleted_rows.push(row.id.clone().into_inner());
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn move_row(
+    &mut self,
+    _cell_data: &Self::CellData,
+    mut context: MoveGroupRowContext,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let is_moved_to_other_group = self
+      .context
+      .get_group(context.to_group_id)
+      .map(|(_, group)| !group.contains_row(&context.row_detail.row.id))
+      .unwrap_or(false);
+    if is_moved_to_other_group {
+      // Only checking or unchecking all the tasks has an unambiguous result
+      match make_checklist_cell(context.to_group_id, &context.row_detail.row, context.field) {
+        None => {
+          tracing::warn!(
+            "Can not move the row:{} to the checklist group:{}",
+            context.row_detail.row.id,
+            context.to_group_id
+          );
+          return vec![];
+        },
+        Some(cell) => {
+          context
+            .row_changeset
+            .cell_by_field_id
+            .insert(context.field.id.clone(), cell);
+        },
+      }
+    }
+
+    let mut group_changeset = vec![];
+    self.context.iter_mut_groups(|group| {
+      if let Some(changeset) = move_group_row(group, &mut context) {
+        group_changeset.push(changeset);
+      }
+    });
+    group_changeset
+  }
+}
+
+impl GroupController for ChecklistGroupController {
+  fn did_update_field_type_option(&mut self, _field: &Arc<Field>) {}
+
+  /// A new row has no task, so there is no cell to fill.
+  fn will_create_row(&mut self, _cells: &mut Cells, _field: &Field, _group_id: &str) {}
+
+  fn did_create_row(&mut self, row_detail: &RowDetail, group_id: &str) {
+    if let Some(group) = self.context.get_mut_group(group_id) {
+      group.add_row(row_detail.clone())
+    }
+  }
+}
+
+pub struct ChecklistGroupGenerator();
+impl GroupsBuilder for ChecklistGroupGenerator {
+  type Context = ChecklistGroupContext;
+  type TypeOptionType = ChecklistTypeOption;
+
+  fn build(
+    field: &Field,
+    _context: &Self::Context,
+    _type_option: &Option<Self::TypeOptionType>,
+  ) -> GeneratedGroups {
+    let group_configs = [
+      (CHECKLIST_NOT_STARTED, "Not started"),
+      (CHECKLIST_IN_PROGRESS, "In progress"),
+      (CHECKLIST_COMPLETE, "Complete"),
+    ]
+    .into_iter()
+    .map(|(group_id, group_name)| GeneratedGroupConfig {
+      group: Group::new(group_id.to_string(), group_name.to_string()),
+      filter_content: group_id.to_string(),
+    })
+    .collect();
+
+    // The checklists without any task are put into the `No status` group
+    let no_status_group = Some(make_no_status_group(field));
+    GeneratedGroups {
+      no_status_group,
+      group_configs,
+    }
+  }
+}
+
+fn group_id(cell_data: &ChecklistCellDataPB) -> Option<&'static str> {
+  if cell_data.options.is_empty() {
+    None
+  } else if cell_data.selected_options.is_empty() {
+    Some(CHECKLIST_NOT_STARTED)
+  } else if cell_data.selected_options.len() >= cell_data.options.len() {
+    Some(CHECKLIST_COMPLETE)
+  } else {
+    Some(CHECKLIST_IN_PROGRESS)
+  }
+}
+
+/// Returns the cell with all the tasks checked or unchecked. Returns `None` if the row can't be
+/// moved to the group.
+fn make_checklist_cell(group_id: &str, row: &Row, field: &Field) -> Option<Cell> {
+  let mut cell_data = ChecklistCellData::from(row.cells.get(&field.id)?);
+  if cell_data.options.is_empty() {
+    return None;
+  }
+
+  match group_id {
+    CHECKLIST_NOT_STARTED => cell_data.selected_option_ids.clear(),
+    CHECKLIST_COMPLETE => {
+      cell_data.selected_option_ids = cell_data
+        .options
+        .iter()
+        .map(|option| option.id.clone())
+        .collect()
+    },
+    _ => return None,
+  }
+  Some(Cell::from(cell_data))
+}
@@ -299,10 +299,26 @@ fn make_group_from_date_cell(
   type_option: Option<&DateTypeOption>,
   setting_content: &str,
 ) -> Group {
-  let group_id = group_id(cell_data, type_option, setting_content);
+  let binding = DateTypeOption::default();
+  let type_option = type_option.unwrap_or(&binding);
+  make_date_group(
+    cell_data.timestamp,
+    &type_option.timezone_id,
+    setting_content,
+  )
+}
+
+/// Returns the group of the timestamp according to the [DateCondition] of the setting content.
+/// It's shared by the groups of the [FieldType::DateTime] and the timestamp fields.
+pub(crate) fn make_date_group(
+  cell_timestamp: Option<i64>,
+  timezone_id: &str,
+  setting_content: &str,
+) -> Group {
+  let group_id = date_group_id(cell_timestamp, timezone_id, setting_content);
   Group::new(
     group_id.clone(),
-    group_name_from_id(&group_id, type_option, setting_content),
+    date_group_name_from_id(&group_id, timezone_id, setting_content),
   )
 }
 
@@ -315,8 +331,20 @@ fn group_id(
 ) -> String {
   let binding = DateTypeOption::default();
   let type_option = type_option.unwrap_or(&binding);
+  date_group_id(
+    cell_data.timestamp,
+    &type_option.timezone_id,
+    setting_content,
+  )
+}
+
+pub(crate) fn date_group_id(
+  cell_timestamp: Option<i64>,
+  timezone_id: &str,
+  setting_content: &str,
+) -> String {
   let config = DateGroupConfiguration::from_json(setting_content).unwrap_or_default();
-  let date_time = date_time_from_timestamp(cell_data.timestamp, &type_option.timezone_id);
+  let date_time = date_time_from_timestamp(cell_timestamp, timezone_id);
 
   let date_format = GROUP_ID_DATE_FORMAT;
   let month_format = &date_format.replace("%d", "01");
@@ -331,7 +359,7 @@ fn group_id(
       .unwrap()
       .format(date_format),
     DateCondition::Relative => {
-      let now = date_time_from_timestamp(Some(timestamp()), &type_option.timezone_id).date_naive();
+      let now = date_time_from_timestamp(Some(timestamp()), timezone_id).date_naive();
       let date_time = date_time.date_naive();
 
       let diff = date_time.signed_duration_since(now).num_days();
@@ -378,6 +406,14 @@ fn group_name_from_id(
 ) -> String {
   let binding = DateTypeOption::default();
   let type_option = type_option.unwrap_or(&binding);
+  date_group_name_from_id(group_id, &type_option.timezone_id, setting_content)
+}
+
+pub(crate) fn date_group_name_from_id(
+  group_id: &str,
+  timezone_id: &str,
+  setting_content: &str,
+) -> String {
   let config = DateGroupConfiguration::from_json(setting_content).unwrap_or_default();
   let date = NaiveDate::parse_from_str(group_id, GROUP_ID_DATE_FORMAT).unwrap();
 
@@ -412,7 +448,7 @@ fn group_name_from_id(
     },
     DateCondition::Year => date.year().to_string(),
     DateCondition::Relative => {
-      let now = date_time_from_timestamp(Some(timestamp()), &type_option.timezone_id);
+      let now = date_time_from_timestamp(Some(timestamp()), timezone_id);
 
       let diff = date.signed_duration_since(now.date_naive());
       let result = match diff.num_days() {
@@ -0,0 +1,434 @@
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, Cells, Row, RowDetail};
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+use flowy_error::FlowyResult;
+
+use crate::entities::{
+  GroupPB, GroupRowsNotificationPB, InsertedGroupPB, InsertedRowPB, RowMetaPB,
+};
+use crate::services::cell::apply_cell_changeset;
+use crate::services::field::{
+  NumberCellData, NumberCellDataParser, NumberCellFormat, NumberFormat, NumberTypeOption,
+};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+  BaseGroupController, GroupController, GroupsBuilder, MoveGroupRowContext,
+};
+use crate::services::group::{
+  make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroups, Group,
+  GroupConfigurationContentSerde,
+};
+
+const DEFAULT_BUCKET_WIDTH: f64 = 10.0;
+
+/// The numbers are grouped into the buckets of `bucket_width` if the `ranges` is empty. The
+/// buckets are created and deleted with the cells, while the explicit ranges are always shown.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct NumberGroupConfiguration {
+  pub hide_empty: bool,
+  pub bucket_width: f64,
+  pub ranges: Vec<NumberGroupRange>,
+}
+
+impl Default for NumberGroupConfiguration {
+  fn default() -> Self {
+    Self {
+      hide_empty: false,
+      bucket_width: DEFAULT_BUCKET_WIDTH,
+      ranges: vec![],
+    }
+  }
+}
+
+impl GroupConfigurationContentSerde for NumberGroupConfiguration {
+  fn from_json(s: &str) -> Result<Self, serde_json::Error> {
+    serde_json::from_str(s)
+  }
+  fn to_json(&self) -> Result<String, serde_json::Error> {
+    serde_json::to_string(self)
+  }
+}
+
+impl NumberGroupConfiguration {
+  /// Returns the range that contains the number. The numbers that are not in any of the explicit
+  /// ranges are put into the `No status` group.
+  fn range_of(&self, number: f64) -> Option<NumberGroupRange> {
+    if !self.ranges.is_empty() {
+      return self
+        .ranges
+        .iter()
+        .find(|range| range.contains(number))
+        .cloned();
+    }
+
+    let bucket_width = if self.bucket_width > 0.0 {
+      self.bucket_width
+    } else {
+      DEFAULT_BUCKET_WIDTH
+    };
+    let start = (number / bucket_width).floor() * bucket_width;
+    Some(NumberGroupRange {
+      start: Some(start),
+      end: Some(start + bucket_width),
+    })
+  }
+}
+
+/// A range of numbers that includes the `start` and excludes the `end`. The range is unbounded
+/// on the side whose value is `None`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumberGroupRange {
+  pub start: Option<f64>,
+  pub end: Option<f64>,
+}
+
+impl NumberGroupRange {
+  fn contains(&self, number: f64) -> bool {
+    self.start.map(|start| number >= start).unwrap_or(true)
+      && self.end.map(|end| number < end).unwrap_or(true)
+  }
+
+  /// The id of the group is `start..end`, the value of the unbounded side is left empty.
+  fn group_id(&self) -> String {
+    format!(
+      "{}..{}",
+      self
+        .start
+        .map(|start| start.to_string())
+        .unwrap_or_default(),
+      self.end.map(|end| end.to_string()).unwrap_or_default()
+    )
+  }
+
+  fn from_group_id(group_id: &str) -> Option<Self> {
+    let (start, end) = group_id.split_once("..")?;
+    let parse = |s: &str| -> Option<Option<f64>> {
+      if s.is_empty() {
+        Some(None)
+      } else {
+        s.parse::<f64>().ok().map(Some)
+      }
+    };
+    Some(Self {
+      start: parse(start)?,
+      end: parse(end)?,
+    })
+  }
+
+  fn group_name(&self) -> String {
+    match (self.start, self.end) {
+      (Some(start), Some(end)) => format!("{} - {}", start, end),
+      (Some(start), None) => format!(">= {}", start),
+      (None, Some(end)) => format!("< {}", end),
+      (None, None) => "All".to_string(),
+    }
+  }
+
+  /// Returns the number that is written to the cell when a row is moved to or created in the
+  /// group of this range.
+  fn representative_number(&self) -> f64 {
+    match (self.start, self.end) {
+      (Some(start), _) => start,
+      (None, Some(end)) => end - 1.0,
+      (None, None) => 0.0,
+    }
+  }
+}
+
+pub type NumberGroupController = BaseGroupController<
+  NumberGroupConfiguration,
+  NumberTypeOption,
+  NumberGroupGenerator,
+  NumberCellDataParser,
+>;
+
+pub type NumberGroupContext = GroupContext<NumberGroupConfiguration>;
+
+impl NumberGroupController {
+  fn group_id_of(&self, cell_data: &NumberCellFormat) -> Option<String> {
+    let number = cell_data.decimal().as_ref()?.to_f64()?;
+    self
+      .context
+      .get_configuration()
+      .range_of(number)
+      .map(|range| range.group_id())
+  }
+}
+
+impl GroupCustomize for NumberGroupController {
+  type CellData = NumberCellFormat;
+
+  fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+    self.group_id_of(cell_data).as_deref() == Some(content)
+  }
+
+  fn create_or_delete_group_when_cell_changed(
+    &mut self,
+    row_detail: &RowDetail,
+    old_cell_data: Option<&Self::CellData>,
+    cell_data: &Self::CellData,
+  ) -> FlowyResult<(Option<InsertedGroupPB>, Option<GroupPB>)> {
+    // The groups of the explicit ranges are fixed
+    if !self.context.get_configuration().ranges.is_empty() {
+      return Ok((None, None));
+    }
+
+    let group_id = self.group_id_of(cell_data);
+    let mut inserted_group = None;
+    if let Some(group_id) = &group_id {
+      if self.context.get_group(group_id).is_none() {
+        if let Some(range) = NumberGroupRange::from_group_id(group_id) {
+          let group = Group::new(group_id.clone(), range.group_name());
+          let mut new_group = self.context.add_new_group(group)?;
+          new_group.group.rows.push(RowMetaPB::from(row_detail));
+          inserted_group = Some(new_group);
+        }
+      }
+    }
+
+    // Delete the old bucket if the row was the only row in that bucket
+    let old_group_id = old_cell_data.and_then(|old_cell_data| self.group_id_of(old_cell_data));
+    let deleted_group = match old_group_id
+      .filter(|old_group_id| Some(old_group_id) != group_id.as_ref())
+      .and_then(|old_group_id| self.context.get_group(&old_group_id))
+    {
+      Some((_, group)) if group.rows.iter().all(|row| row.row.id == row_detail.row.id) => {
+        Some(group.clone())
+      },
+      _ => None,
+    };
+
+    let deleted_group = match deleted_group {
+      None => None,
+      Some(group) => {
+        self.context.delete_group(&group.id)?;
+        Some(GroupPB::from(group))
+      },
+    };
+
+    Ok((inserted_group, deleted_group))
+  }
+
+  fn add_or_remove_row_when_cell_changed(
+    &mut self,
+    row_detail: &RowDetail,
+    cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    let group_id = self.group_id_of(cell_data);
+    self.context.iter_mut_status_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if Some(&group.id) == group_id.as_ref() {
+        if !group.contains_row(&row_detail.row.id) {
+          changeset
+            .inserted_rows
+            .push(InsertedRowPB::new(RowMetaPB::from(row_detail)));
+          group.add_row(row_detail.clone());
+        }
+      } else if group.contains_row(&row_detail.row.id) {
+        group.remove_row(&row_detail.row.id);
+        changeset
+          .deleted_rows
+          .push(row_detail.row.id.clone().into_inner());
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn delete_row(&mut self, row: &Row, _cell_data: &Self::CellData) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.context.iter_mut_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if group.contains_row(&row.id) {
+        group.remove_row(&row.id);
+        changeset.deleted_rows.push(row.id.clone().into_inner());
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn move_row(
+    &mut self,
+    _cell_data: &Self::CellData,
+    mut context: MoveGroupRowContext,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let is_moved_to_other_group = self
+      .context
+      .get_group(context.to_group_id)
+      .map(|(_, group)| !group.contains_row(&context.row_detail.row.id))
+      .unwrap_or(false);
+    if is_moved_to_other_group {
+      if let Some(cell) = make_number_cell(context.to_group_id, context.field) {
+        context
+          .row_changeset
+          .cell_by_field_id
+          .insert(context.field.id.clone(), cell);
+      }
+    }
+
+    let mut group_changeset = vec![];
+    self.context.iter_mut_groups(|group| {
+      if let Some(changeset) = move_group_row(group, &mut context) {
+        group_changeset.push(changeset);
+      }
+    });
+    group_changeset
+  }
+}
+
+impl GroupController for NumberGroupController {
+  fn did_update_field_type_option(&mut self, _field: &Arc<Field>) {}
+
+  fn will_create_row(&mut self, cells: &mut Cells, field: &Field, group_id: &str) {
+    match self.context.get_group(group_id) {
+      None => tracing::warn!("Can not find the group: {}", group_id),
+      Some((_, group)) => {
+        if let Some(cell) = make_number_cell(&group.id, field) {
+          cells.insert(field.id.clone(), cell);
+        }
+      },
+    }
+  }
+
+  fn did_create_row(&mut self, row_detail: &RowDetail, group_id: &str) {
+    if let Some(group) = self.context.get_mut_group(group_id) {
+      group.add_row(row_detail.clone())
+    }
+  }
+}
+
+pub struct NumberGroupGenerator();
+impl GroupsBuilder for NumberGroupGenerator {
+  type Context = NumberGroupContext;
+  type TypeOptionType = NumberTypeOption;
+
+  fn build(
+    field: &Field,
+    context: &Self::Context,
+    _type_option: &Option<Self::TypeOptionType>,
+  ) -> GeneratedGroups {
+    let configuration = context.get_configuration();
+
+    let mut ranges = if configuration.ranges.is_empty() {
+      // Read all the cells for the grouping field
+      let cells = futures::executor::block_on(context.get_all_cells());
+      cells
+        .into_iter()
+        .flat_map(|value| value.into_number_field_cell_data())
+        .flat_map(|cell| number_from_cell_data(&cell))
+        .flat_map(|number| configuration.range_of(number))
+        .collect::<Vec<NumberGroupRange>>()
+    } else {
+      configuration.ranges.clone()
+    };
+    if configuration.ranges.is_empty() {
+      ranges.sort_by(|a, b| {
+        a.start
+          .partial_cmp(&b.start)
+          .unwrap_or(std::cmp::Ordering::Equal)
+      });
+    }
+
+    let group_configs = ranges
+      .into_iter()
+      .map(|range| {
+        let group_id = range.group_id();
+        GeneratedGroupConfig {
+          group: Group::new(group_id.clone(), range.group_name()),
+          filter_content: group_id,
+        }
+      })
+      .collect();
+
+    let no_status_group = Some(make_no_status_group(field));
+    GeneratedGroups {
+      no_status_group,
+      group_configs,
+    }
+  }
+}
+
+fn number_from_cell_data(cell_data: &NumberCellData) -> Option<f64> {
+  NumberCellFormat::from_format_str(&cell_data.0, &NumberFormat::Num)
+    .ok()?
+    .decimal()
+    .as_ref()?
+    .to_f64()
+}
+
+fn make_number_cell(group_id: &str, field: &Field) -> Option<Cell> {
+  let range = NumberGroupRange::from_group_id(group_id)?;
+  apply_cell_changeset(range.representative_number().to_string(), None, field, None).ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::group::controller_impls::number_controller::{
+    NumberGroupConfiguration, NumberGroupRange,
+  };
+
+  #[test]
+  fn number_group_bucket_test() {
+    let configuration = NumberGroupConfiguration::default();
+    let range = configuration.range_of(4.0).unwrap();
+    assert_eq!(range.group_id(), "0..10");
+    assert_eq!(range.group_name(), "0 - 10");
+
+    let range = configuration.range_of(-0.5).unwrap();
+    assert_eq!(range.group_id(), "-10..0");
+
+    let configuration = NumberGroupConfiguration {
+      bucket_width: 2.5,
+      ..Default::default()
+    };
+    assert_eq!(configuration.range_of(6.0).unwrap().group_id(), "5..7.5");
+  }
+
+  #[test]
+  fn number_group_explicit_range_test() {
+    let configuration: NumberGroupConfiguration = serde_json::from_str(
+      r#"{"ranges": [{"start": null, "end": 5}, {"start": 5, "end": 20}, {"start": 20, "end": null}]}"#,
+    )
+    .unwrap();
+
+    let range = configuration.range_of(1.0).unwrap();
+    assert_eq!(range.group_id(), "..5");
+    assert_eq!(range.group_name(), "< 5");
+    assert_eq!(range.representative_number(), 4.0);
+
+    assert_eq!(configuration.range_of(5.0).unwrap().group_id(), "5..20");
+    let range = configuration.range_of(100.0).unwrap();
+    assert_eq!(range.group_name(), ">= 20");
+    assert_eq!(
+      NumberGroupRange::from_group_id(&range.group_id()).unwrap(),
+      range
+    );
+  }
+
+  #[test]
+  fn number_group_number_out_of_ranges_test() {
+    let configuration = NumberGroupConfiguration {
+      ranges: vec![NumberGroupRange {
+        start: Some(0.0),
+        end: Some(10.0),
+      }],
+      ..Default::default()
+    };
+    assert!(configuration.range_of(10.0).is_none());
+    assert!(configuration.range_of(-1.0).is_none());
+  }
+}
@@ -0,0 +1,185 @@
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::{new_cell_builder, Cell, Cells, Row, RowDetail};
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{
+  FieldType, GroupRowsNotificationPB, InsertedRowPB, PersonCellDataPB, RowMetaPB,
+};
+use crate::services::cell::insert_person_cell;
+use crate::services::field::{PersonCellDataParser, PersonMember, PersonTypeOption, CELL_DATA};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::controller::{
+  BaseGroupController, GroupController, GroupsBuilder, MoveGroupRowContext,
+};
+use crate::services::group::{
+  make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroups, Group, GroupContext,
+  GroupData,
+};
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct PersonGroupConfiguration {
+  pub hide_empty: bool,
+}
+
+pub type PersonGroupContext = GroupContext<PersonGroupConfiguration>;
+
+/// Groups the rows by the [FieldType::Person]. Each member saved in the type option has its own
+/// group, and a row that is assigned to multiple members shows up in each of their groups.
+pub type PersonGroupController = BaseGroupController<
+  PersonGroupConfiguration,
+  PersonTypeOption,
+  PersonGroupGenerator,
+  PersonCellDataParser,
+>;
+
+impl GroupCustomize for PersonGroupController {
+  type CellData = PersonCellDataPB;
+
+  fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+    cell_data
+      .members
+      .iter()
+      .any(|member| member.uid.to_string() == content)
+  }
+
+  fn placeholder_cell(&self) -> Option<Cell> {
+    Some(
+      new_cell_builder(FieldType::Person)
+        .insert_str_value(CELL_DATA, "")
+        .build(),
+    )
+  }
+
+  fn add_or_remove_row_when_cell_changed(
+    &mut self,
+    row_detail: &RowDetail,
+    cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.context.iter_mut_status_groups(|group| {
+      if let Some(changeset) = add_or_remove_person_row(group, cell_data, row_detail) {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn delete_row(&mut self, row: &Row, cell_data: &Self::CellData) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.context.iter_mut_status_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      let is_assigned = cell_data
+        .members
+        .iter()
+        .any(|member| member.uid.to_string() == group.id);
+      if is_assigned && group.contains_row(&row.id) {
+        group.remove_row(&row.id);
+        changeset.deleted_rows.push(row.id.clone().into_inner());
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn move_row(
+    &mut self,
+    _cell_data: &Self::CellData,
+    mut context: MoveGroupRowContext,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut group_changeset = vec![];
+    self.context.iter_mut_groups(|group| {
+      if let Some(changeset) = move_group_row(group, &mut context) {
+        group_changeset.push(changeset);
+      }
+    });
+    group_changeset
+  }
+}
+
+impl GroupController for PersonGroupController {
+  fn did_update_field_type_option(&mut self, _field: &Arc<Field>) {}
+
+  fn will_create_row(&mut self, cells: &mut Cells, field: &Field, group_id: &str) {
+    match group_id.parse::<i64>() {
+      Ok(uid) if self.context.get_group(group_id).is_some() => {
+        let cell = insert_person_cell(vec![uid], field);
+        cells.insert(field.id.clone(), cell);
+      },
+      _ => tracing::warn!("Can not find the group: {}", group_id),
+    }
+  }
+
+  fn did_create_row(&mut self, row_detail: &RowDetail, group_id: &str) {
+    if let Some(group) = self.context.get_mut_group(group_id) {
+      group.add_row(row_detail.clone())
+    }
+  }
+}
+
+pub struct PersonGroupGenerator;
+impl GroupsBuilder for PersonGroupGenerator {
+  type Context = PersonGroupContext;
+  type TypeOptionType = PersonTypeOption;
+
+  fn build(
+    field: &Field,
+    _context: &Self::Context,
+    type_option: &Option<Self::TypeOptionType>,
+  ) -> GeneratedGroups {
+    let group_configs = match type_option {
+      None => vec![],
+      Some(type_option) => generate_person_groups(&type_option.members),
+    };
+
+    GeneratedGroups {
+      no_status_group: Some(make_no_status_group(field)),
+      group_configs,
+    }
+  }
+}
+
+fn generate_person_groups(members: &[PersonMember]) -> Vec<GeneratedGroupConfig> {
+  members
+    .iter()
+    .map(|member| GeneratedGroupConfig {
+      group: Group::new(member.uid.to_string(), member.name.clone()),
+      filter_content: member.uid.to_string(),
+    })
+    .collect()
+}
+
+fn add_or_remove_person_row(
+  group: &mut GroupData,
+  cell_data: &PersonCellDataPB,
+  row_detail: &RowDetail,
+) -> Option<GroupRowsNotificationPB> {
+  let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+  let is_assigned = cell_data
+    .members
+    .iter()
+    .any(|member| member.uid.to_string() == group.id);
+  if is_assigned {
+    if !group.contains_row(&row_detail.row.id) {
+      changeset
+        .inserted_rows
+        .push(InsertedRowPB::new(RowMetaPB::from(row_detail)));
+      group.add_row(row_detail.clone());
+    }
+  } else if group.contains_row(&row_detail.row.id) {
+    group.remove_row(&row_detail.row.id);
+    changeset
+      .deleted_rows
+      .push(row_detail.row.id.clone().into_inner());
+  }
+
+  if changeset.is_empty() {
+    None
+  } else {
+    Some(changeset)
+  }
+}
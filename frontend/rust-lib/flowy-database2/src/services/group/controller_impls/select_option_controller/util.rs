@@ -6,7 +6,8 @@ use crate::entities::{
   FieldType, GroupRowsNotificationPB, InsertedRowPB, RowMetaPB, SelectOptionCellDataPB,
 };
 use crate::services::cell::{
-  insert_checkbox_cell, insert_date_cell, insert_select_option_cell, insert_url_cell,
+  insert_checkbox_cell, insert_date_cell, insert_person_cell, insert_select_option_cell,
+  insert_url_cell,
 };
 use crate::services::field::{SelectOption, CHECK};
 use crate::services::group::controller::MoveGroupRowContext;
@@ -180,6 +181,19 @@ pub fn make_inserted_cell(group_id: &str, field: &Field) -> Option<Cell> {
       let cell = insert_date_cell(date.timestamp(), None, field);
       Some(cell)
     },
+    FieldType::Person => match group_id.parse::<i64>() {
+      Ok(uid) => Some(insert_person_cell(vec![uid], field)),
+      Err(_) => {
+        tracing::warn!("Invalid person group id: {}", group_id);
+        None
+      },
+    },
+    // The group controllers of these field types insert the cell by themselves
+    FieldType::Number
+    | FieldType::RichText
+    | FieldType::Checklist
+    | FieldType::CreatedTime
+    | FieldType::LastEditedTime => None,
     _ => {
       tracing::warn!("Unknown field type: {:?}", field_type);
       None
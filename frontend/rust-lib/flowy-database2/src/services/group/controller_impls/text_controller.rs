@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::{Cells, Row, RowDetail};
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use flowy_error::FlowyResult;
+
+use crate::entities::{
+  GroupPB, GroupRowsNotificationPB, InsertedGroupPB, InsertedRowPB, RowMetaPB,
+};
+use crate::services::cell::insert_text_cell;
+use crate::services::field::{RichTextTypeOption, TextCellData, TextCellDataParser};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+  BaseGroupController, GroupController, GroupsBuilder, MoveGroupRowContext,
+};
+use crate::services::group::{
+  make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroups, Group,
+  GroupConfigurationContentSerde,
+};
+
+/// The group of the texts that don't start with a letter when grouping by the first letter.
+const NON_LETTER_GROUP_ID: &str = "#";
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct TextGroupConfiguration {
+  pub hide_empty: bool,
+  pub condition: TextGroupCondition,
+}
+
+impl GroupConfigurationContentSerde for TextGroupConfiguration {
+  fn from_json(s: &str) -> Result<Self, serde_json::Error> {
+    serde_json::from_str(s)
+  }
+  fn to_json(&self) -> Result<String, serde_json::Error> {
+    serde_json::to_string(self)
+  }
+}
+
+#[derive(Default, PartialEq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum TextGroupCondition {
+  /// Each distinct text has its own group
+  #[default]
+  Exact = 0,
+  /// The texts are grouped by their first letter, case insensitive
+  FirstLetter = 1,
+}
+
+pub type TextGroupController = BaseGroupController<
+  TextGroupConfiguration,
+  RichTextTypeOption,
+  TextGroupGenerator,
+  TextCellDataParser,
+>;
+
+pub type TextGroupContext = GroupContext<TextGroupConfiguration>;
+
+impl TextGroupController {
+  fn condition(&self) -> &TextGroupCondition {
+    &self.context.get_configuration().condition
+  }
+}
+
+impl GroupCustomize for TextGroupController {
+  type CellData = TextCellData;
+
+  fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+    group_id(cell_data, self.condition()).as_deref() == Some(content)
+  }
+
+  fn create_or_delete_group_when_cell_changed(
+    &mut self,
+    row_detail: &RowDetail,
+    old_cell_data: Option<&Self::CellData>,
+    cell_data: &Self::CellData,
+  ) -> FlowyResult<(Option<InsertedGroupPB>, Option<GroupPB>)> {
+    let group_id = group_id(cell_data, self.condition());
+    let mut inserted_group = None;
+    if let Some(group_id) = &group_id {
+      if self.context.get_group(group_id).is_none() {
+        let group = Group::new(group_id.clone(), group_id.clone());
+        let mut new_group = self.context.add_new_group(group)?;
+        new_group.group.rows.push(RowMetaPB::from(row_detail));
+        inserted_group = Some(new_group);
+      }
+    }
+
+    // Delete the old group if the row was the only row in that group
+    let old_group_id =
+      old_cell_data.and_then(|old_cell_data| self::group_id(old_cell_data, self.condition()));
+    let deleted_group = match old_group_id
+      .filter(|old_group_id| Some(old_group_id) != group_id.as_ref())
+      .and_then(|old_group_id| self.context.get_group(&old_group_id))
+    {
+      Some((_, group)) if group.rows.iter().all(|row| row.row.id == row_detail.row.id) => {
+        Some(group.clone())
+      },
+      _ => None,
+    };
+
+    let deleted_group = match deleted_group {
+      None => None,
+      Some(group) => {
+        self.context.delete_group(&group.id)?;
+        Some(GroupPB::from(group))
+      },
+    };
+
+    Ok((inserted_group, deleted_group))
+  }
+
+  fn add_or_remove_row_when_cell_changed(
+    &mut self,
+    row_detail: &RowDetail,
+    cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    let group_id = group_id(cell_data, self.condition());
+    self.context.iter_mut_status_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if Some(&group.id) == group_id.as_ref() {
+        if !group.contains_row(&row_detail.row.id) {
+          changeset
+            .inserted_rows
+            .push(InsertedRowPB::new(RowMetaPB::from(row_detail)));
+          group.add_row(row_detail.clone());
+        }
+      } else if group.contains_row(&row_detail.row.id) {
+        group.remove_row(&row_detail.row.id);
+        changeset
+          .deleted_rows
+          .push(row_detail.row.id.clone().into_inner());
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn delete_row(&mut self, row: &Row, _cell_data: &Self::CellData) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.context.iter_mut_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if group.contains_row(&row.id) {
+        group.remove_row(&row.id);
+        changeset.deleted_rows.push(row.id.clone().into_inner());
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn move_row(
+    &mut self,
+    _cell_data: &Self::CellData,
+    mut context: MoveGroupRowContext,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let is_moved_to_other_group = self
+      .context
+      .get_group(context.to_group_id)
+      .map(|(_, group)| !group.contains_row(&context.row_detail.row.id))
+      .unwrap_or(false);
+    if is_moved_to_other_group {
+      // The text can't be derived from its first letter, so the row can only be reordered
+      // within its group.
+      if self.condition() == &TextGroupCondition::FirstLetter {
+        tracing::warn!(
+          "Can not move the row:{} to the group:{} grouped by the first letter",
+          context.row_detail.row.id,
+          context.to_group_id
+        );
+        return vec![];
+      }
+
+      let cell = insert_text_cell(context.to_group_id.to_owned(), context.field);
+      context
+        .row_changeset
+        .cell_by_field_id
+        .insert(context.field.id.clone(), cell);
+    }
+
+    let mut group_changeset = vec![];
+    self.context.iter_mut_groups(|group| {
+      if let Some(changeset) = move_group_row(group, &mut context) {
+        group_changeset.push(changeset);
+      }
+    });
+    group_changeset
+  }
+}
+
+impl GroupController for TextGroupController {
+  fn did_update_field_type_option(&mut self, _field: &Arc<Field>) {}
+
+  fn will_create_row(&mut self, cells: &mut Cells, field: &Field, group_id: &str) {
+    if self.condition() == &TextGroupCondition::FirstLetter {
+      return;
+    }
+
+    match self.context.get_group(group_id) {
+      None => tracing::warn!("Can not find the group: {}", group_id),
+      Some((_, group)) => {
+        let cell = insert_text_cell(group.id.clone(), field);
+        cells.insert(field.id.clone(), cell);
+      },
+    }
+  }
+
+  fn did_create_row(&mut self, row_detail: &RowDetail, group_id: &str) {
+    if let Some(group) = self.context.get_mut_group(group_id) {
+      group.add_row(row_detail.clone())
+    }
+  }
+}
+
+pub struct TextGroupGenerator();
+impl GroupsBuilder for TextGroupGenerator {
+  type Context = TextGroupContext;
+  type TypeOptionType = RichTextTypeOption;
+
+  fn build(
+    field: &Field,
+    context: &Self::Context,
+    _type_option: &Option<Self::TypeOptionType>,
+  ) -> GeneratedGroups {
+    let condition = &context.get_configuration().condition;
+
+    // Read all the cells for the grouping field
+    let cells = futures::executor::block_on(context.get_all_cells());
+
+    // Generate the groups
+    let mut group_configs: Vec<GeneratedGroupConfig> = cells
+      .into_iter()
+      .flat_map(|value| value.into_text_field_cell_data())
+      .flat_map(|cell| group_id(&TextCellData(cell.0), condition))
+      .map(|group_id| GeneratedGroupConfig {
+        group: Group::new(group_id.clone(), group_id.clone()),
+        filter_content: group_id,
+      })
+      .collect();
+    group_configs.sort_by(|a, b| a.filter_content.cmp(&b.filter_content));
+
+    let no_status_group = Some(make_no_status_group(field));
+    GeneratedGroups {
+      no_status_group,
+      group_configs,
+    }
+  }
+}
+
+/// Returns the id of the group that the text belongs to. The empty text belongs to the
+/// `No status` group.
+fn group_id(cell_data: &TextCellData, condition: &TextGroupCondition) -> Option<String> {
+  let text = cell_data.trim();
+  let first_char = text.chars().next()?;
+  match condition {
+    TextGroupCondition::Exact => Some(text.to_string()),
+    TextGroupCondition::FirstLetter => {
+      if first_char.is_alphabetic() {
+        Some(first_char.to_uppercase().to_string())
+      } else {
+        Some(NON_LETTER_GROUP_ID.to_string())
+      }
+    },
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::field::TextCellData;
+  use crate::services::group::controller_impls::text_controller::{group_id, TextGroupCondition};
+
+  #[test]
+  fn text_group_id_test() {
+    let text = |s: &str| TextCellData(s.to_string());
+    let exact = TextGroupCondition::Exact;
+    let first_letter = TextGroupCondition::FirstLetter;
+
+    assert_eq!(group_id(&text("Apple"), &exact).unwrap(), "Apple");
+    assert_eq!(group_id(&text("apple"), &first_letter).unwrap(), "A");
+    assert_eq!(group_id(&text("  éclair"), &first_letter).unwrap(), "É");
+    assert_eq!(group_id(&text("42 apples"), &first_letter).unwrap(), "#");
+    assert!(group_id(&text(""), &exact).is_none());
+    assert!(group_id(&text("   "), &first_letter).is_none());
+  }
+}
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, Cells, Row, RowDetail};
+
+use flowy_error::FlowyResult;
+
+use crate::entities::{
+  FieldType, GroupPB, GroupRowsNotificationPB, InsertedGroupPB, InsertedRowPB, RowMetaPB,
+  TimestampCellDataPB,
+};
+use crate::services::field::{TimestampCellData, TimestampCellDataParser, TimestampTypeOption};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+  BaseGroupController, GroupController, GroupsBuilder, MoveGroupRowContext,
+};
+use crate::services::group::controller_impls::date_controller::{date_group_id, make_date_group};
+use crate::services::group::{
+  make_no_status_group, move_group_row, DateGroupConfiguration, GeneratedGroupConfig,
+  GeneratedGroups,
+};
+
+/// The [TimestampTypeOption] doesn't have a timezone, the timestamps are grouped in the local
+/// timezone.
+const LOCAL_TIMEZONE_ID: &str = "";
+
+/// Groups the rows by the [FieldType::CreatedTime] or the [FieldType::LastEditedTime] with the
+/// same [DateCondition](crate::services::group::DateCondition)s as the [FieldType::DateTime].
+/// The timestamps are maintained by the rows, so the rows can't be moved to other groups.
+pub type TimestampGroupController = BaseGroupController<
+  DateGroupConfiguration,
+  TimestampTypeOption,
+  TimestampGroupGenerator,
+  TimestampCellDataParser,
+>;
+
+pub type TimestampGroupContext = GroupContext<DateGroupConfiguration>;
+
+impl TimestampGroupController {
+  fn group_id_of(&self, cell_data: &TimestampCellDataPB) -> Option<String> {
+    let timestamp = cell_data.timestamp?;
+    Some(date_group_id(
+      Some(timestamp),
+      LOCAL_TIMEZONE_ID,
+      &self.context.get_setting_content(),
+    ))
+  }
+}
+
+impl GroupCustomize for TimestampGroupController {
+  type CellData = TimestampCellDataPB;
+
+  fn cell_from_row(&self, row: &Row, _field_id: &str) -> Option<Cell> {
+    let field_type = self
+      .type_option
+      .as_ref()
+      .map(|type_option| type_option.field_type.clone())
+      .unwrap_or(FieldType::LastEditedTime);
+    let timestamp = if field_type.is_created_time() {
+      row.created_at
+    } else {
+      row.modified_at
+    };
+    Some(Cell::from(TimestampCellData::new(timestamp)))
+  }
+
+  fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+    self.group_id_of(cell_data).as_deref() == Some(content)
+  }
+
+  fn create_or_delete_group_when_cell_changed(
+    &mut self,
+    row_detail: &RowDetail,
+    old_cell_data: Option<&Self::CellData>,
+    cell_data: &Self::CellData,
+  ) -> FlowyResult<(Option<InsertedGroupPB>, Option<GroupPB>)> {
+    let setting_content = self.context.get_setting_content();
+    let group_id = self.group_id_of(cell_data);
+    let mut inserted_group = None;
+    if let Some(group_id) = &group_id {
+      if self.context.get_group(group_id).is_none() {
+        let group = make_date_group(cell_data.timestamp, LOCAL_TIMEZONE_ID, &setting_content);
+        let mut new_group = self.context.add_new_group(group)?;
+        new_group.group.rows.push(RowMetaPB::from(row_detail));
+        inserted_group = Some(new_group);
+      }
+    }
+
+    // Delete the old group if the row was the only row in that group
+    let old_group_id = old_cell_data.and_then(|old_cell_data| self.group_id_of(old_cell_data));
+    let deleted_group = match old_group_id
+      .filter(|old_group_id| Some(old_group_id) != group_id.as_ref())
+      .and_then(|old_group_id| self.context.get_group(&old_group_id))
+    {
+      Some((_, group)) if group.rows.iter().all(|row| row.row.id == row_detail.row.id) => {
+        Some(group.clone())
+      },
+      _ => None,
+    };
+
+    let deleted_group = match deleted_group {
+      None => None,
+      Some(group) => {
+        self.context.delete_group(&group.id)?;
+        Some(GroupPB::from(group))
+      },
+    };
+
+    Ok((inserted_group, deleted_group))
+  }
+
+  fn add_or_remove_row_when_cell_changed(
+    &mut self,
+    row_detail: &RowDetail,
+    cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    let group_id = self.group_id_of(cell_data);
+    self.context.iter_mut_status_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if Some(&group.id) == group_id.as_ref() {
+        if !group.contains_row(&row_detail.row.id) {
+          changeset
+            .inserted_rows
+            .push(InsertedRowPB::new(RowMetaPB::from(row_detail)));
+          group.add_row(row_detail.clone());
+        }
+      } else if group.contains_row(&row_detail.row.id) {
+        group.remove_row(&row_detail.row.id);
+        changeset
+          .deleted_rows
+          .push(row_detail.row.id.clone().into_inner());
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn delete_row(&mut self, row: &Row, _cell_data: &Self::CellData) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.context.iter_mut_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if group.contains_row(&row.id) {
+        group.remove_row(&row.id);
+        changeset.deleted_rows.push(row.id.clone().into_inner());
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn move_row(
+    &mut self,
+    _cell_data: &Self::CellData,
+    mut context: MoveGroupRowContext,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let is_moved_to_other_group = self
+      .context
+      .get_group(context.to_group_id)
+      .map(|(_, group)| !group.contains_row(&context.row_detail.row.id))
+      .unwrap_or(false);
+    if is_moved_to_other_group {
+      tracing::warn!(
+        "Can not move the row:{} to the group:{}, the timestamp is read-only",
+        context.row_detail.row.id,
+        context.to_group_id
+      );
+      return vec![];
+    }
+
+    let mut group_changeset = vec![];
+    self.context.iter_mut_groups(|group| {
+      if let Some(changeset) = move_group_row(group, &mut context) {
+        group_changeset.push(changeset);
+      }
+    });
+    group_changeset
+  }
+}
+
+impl GroupController for TimestampGroupController {
+  fn did_update_field_type_option(&mut self, _field: &Arc<Field>) {}
+
+  /// The timestamp is set by the row, so there is no cell to fill.
+  fn will_create_row(&mut self, _cells: &mut Cells, _field: &Field, _group_id: &str) {}
+
+  fn did_create_row(&mut self, row_detail: &RowDetail, group_id: &str) {
+    if let Some(group) = self.context.get_mut_group(group_id) {
+      group.add_row(row_detail.clone())
+    }
+  }
+}
+
+pub struct TimestampGroupGenerator();
+impl GroupsBuilder for TimestampGroupGenerator {
+  type Context = TimestampGroupContext;
+  type TypeOptionType = TimestampTypeOption;
+
+  fn build(
+    field: &Field,
+    context: &Self::Context,
+    _type_option: &Option<Self::TypeOptionType>,
+  ) -> GeneratedGroups {
+    // Read all the cells for the grouping field
+    let cells = futures::executor::block_on(context.get_all_cells());
+
+    // Generate the groups
+    let setting_content = context.get_setting_content();
+    let mut group_configs: Vec<GeneratedGroupConfig> = cells
+      .into_iter()
+      .flat_map(|value| value.into_timestamp_field_cell_data())
+      .filter(|cell| cell.timestamp.is_some())
+      .map(|cell| {
+        let group = make_date_group(cell.timestamp, LOCAL_TIMEZONE_ID, &setting_content);
+        GeneratedGroupConfig {
+          filter_content: group.id.clone(),
+          group,
+        }
+      })
+      .collect();
+    group_configs.sort_by(|a, b| a.filter_content.cmp(&b.filter_content));
+
+    let no_status_group = Some(make_no_status_group(field));
+    GeneratedGroups {
+      no_status_group,
+      group_configs,
+    }
+  }
+}
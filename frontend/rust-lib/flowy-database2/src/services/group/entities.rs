@@ -12,6 +12,9 @@ pub struct GroupSetting {
   pub field_type: i64,
   pub groups: Vec<Group>,
   pub content: String,
+  /// A board can be grouped by a second field, the sub-group field, whose groups are rendered as
+  /// the swimlanes of the board. A view has at most one setting of each kind.
+  pub is_sub_group: bool,
 }
 
 pub struct GroupSettingChangeset {
@@ -32,6 +35,7 @@ impl GroupSetting {
       field_type,
       groups: vec![],
       content,
+      is_sub_group: false,
     }
   }
 }
@@ -41,6 +45,7 @@ const FIELD_ID: &str = "field_id";
 const FIELD_TYPE: &str = "ty";
 const GROUPS: &str = "groups";
 const CONTENT: &str = "content";
+const IS_SUB_GROUP: &str = "is_sub_group";
 
 impl TryFrom<GroupSettingMap> for GroupSetting {
   type Error = anyhow::Error;
@@ -54,12 +59,14 @@ impl TryFrom<GroupSettingMap> for GroupSetting {
       (Some(id), Some(field_id), Some(field_type)) => {
         let content = value.get_str_value(CONTENT).unwrap_or_default();
         let groups = value.try_get_array(GROUPS);
+        let is_sub_group = value.get_bool_value(IS_SUB_GROUP).unwrap_or_default();
         Ok(Self {
           id,
           field_id,
           field_type,
           groups,
           content,
+          is_sub_group,
         })
       },
       _ => {
@@ -77,6 +84,7 @@ impl From<GroupSetting> for GroupSettingMap {
       .insert_i64_value(FIELD_TYPE, setting.field_type)
       .insert_maps(GROUPS, setting.groups)
       .insert_str_value(CONTENT, setting.content)
+      .insert_bool_value(IS_SUB_GROUP, setting.is_sub_group)
       .build()
   }
 }
@@ -8,10 +8,13 @@ use flowy_error::FlowyResult;
 
 use crate::entities::FieldType;
 use crate::services::group::{
-  CheckboxGroupContext, CheckboxGroupController, DateGroupContext, DateGroupController,
-  DefaultGroupController, Group, GroupController, GroupSetting, GroupSettingReader,
-  GroupSettingWriter, MultiSelectGroupController, MultiSelectOptionGroupContext,
-  SingleSelectGroupController, SingleSelectOptionGroupContext, URLGroupContext, URLGroupController,
+  CheckboxGroupContext, CheckboxGroupController, ChecklistGroupContext, ChecklistGroupController,
+  DateGroupContext, DateGroupController, DefaultGroupController, Group, GroupController,
+  GroupSetting, GroupSettingReader, GroupSettingWriter, MultiSelectGroupController,
+  MultiSelectOptionGroupContext, NumberGroupContext, NumberGroupController, PersonGroupContext,
+  PersonGroupController, SingleSelectGroupController, SingleSelectOptionGroupContext,
+  TextGroupContext, TextGroupController, TimestampGroupContext, TimestampGroupController,
+  URLGroupContext, URLGroupController,
 };
 
 /// Returns a group controller.
@@ -105,6 +108,61 @@ where
       let controller = DateGroupController::new(&grouping_field, configuration).await?;
       group_controller = Box::new(controller);
     },
+    FieldType::Number => {
+      let configuration = NumberGroupContext::new(
+        view_id,
+        grouping_field.clone(),
+        configuration_reader,
+        configuration_writer,
+      )
+      .await?;
+      let controller = NumberGroupController::new(&grouping_field, configuration).await?;
+      group_controller = Box::new(controller);
+    },
+    FieldType::RichText => {
+      let configuration = TextGroupContext::new(
+        view_id,
+        grouping_field.clone(),
+        configuration_reader,
+        configuration_writer,
+      )
+      .await?;
+      let controller = TextGroupController::new(&grouping_field, configuration).await?;
+      group_controller = Box::new(controller);
+    },
+    FieldType::Checklist => {
+      let configuration = ChecklistGroupContext::new(
+        view_id,
+        grouping_field.clone(),
+        configuration_reader,
+        configuration_writer,
+      )
+      .await?;
+      let controller = ChecklistGroupController::new(&grouping_field, configuration).await?;
+      group_controller = Box::new(controller);
+    },
+    FieldType::Person => {
+      let configuration = PersonGroupContext::new(
+        view_id,
+        grouping_field.clone(),
+        configuration_reader,
+        configuration_writer,
+      )
+      .await?;
+      let controller = PersonGroupController::new(&grouping_field, configuration).await?;
+      group_controller = Box::new(controller);
+    },
+    FieldType::CreatedTime | FieldType::LastEditedTime => {
+      let configuration = TimestampGroupContext::new(
+        view_id,
+        grouping_field.clone(),
+        configuration_reader,
+        configuration_writer,
+      )
+      .await?;
+      let controller = TimestampGroupController::new(&grouping_field, configuration).await?;
+      group_controller = Box::new(controller);
+    },
     _ => {
       group_controller = Box::new(DefaultGroupController::new(&grouping_field));
     },
@@ -119,6 +177,22 @@ where
   Ok(group_controller)
 }
 
+/// Returns the controller that puts all the rows into one group. It's used when the board is
+/// grouped by the primary field because none of its fields can be grouped by default.
+pub fn make_default_group_controller(
+  grouping_field: Arc<Field>,
+  row_details: Vec<Arc<RowDetail>>,
+) -> FlowyResult<Box<dyn GroupController>> {
+  let mut group_controller: Box<dyn GroupController> =
+    Box::new(DefaultGroupController::new(&grouping_field));
+  let rows = row_details
+    .iter()
+    .map(|row| row.as_ref())
+    .collect::<Vec<&RowDetail>>();
+  group_controller.fill_groups(rows.as_slice(), &grouping_field)?;
+  Ok(group_controller)
+}
+
 #[tracing::instrument(level = "debug", skip_all)]
 pub fn find_new_grouping_field(
   fields: &[Arc<Field>],
@@ -128,7 +202,7 @@ pub fn find_new_grouping_field(
     .iter()
     .flat_map(|field_rev| {
       let field_type = FieldType::from(field_rev.field_type);
-      match field_type.can_be_group() {
+      match field_type.can_be_default_group() {
         true => Some(field_rev.clone()),
         false => None,
       }
@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::{Cell, RowId};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::RwLock;
+
+use crate::services::database::{ChangeOrigin, DatabaseEditor, DatabaseRowEvent};
+use crate::services::field::{lookup_key_from_cell, LookupCellData, LookupTypeOption};
+
+/// Maps the keys of the rows in the source database to the ids of the rows.
+#[derive(Debug, Default)]
+pub struct LookupKeyIndex {
+  row_ids_by_key: HashMap<String, Vec<RowId>>,
+}
+
+impl LookupKeyIndex {
+  pub fn get(&self, key: &str) -> &[RowId] {
+    self
+      .row_ids_by_key
+      .get(key)
+      .map(|row_ids| row_ids.as_slice())
+      .unwrap_or_default()
+  }
+}
+
+/// Keeps the cells of the lookup fields up to date. It listens to the row events of the opened
+/// databases and refreshes the lookup cells that depend on the changed rows. The refreshed cells
+/// are saved through the [DatabaseEditor], so the cached cell data is invalidated, because the
+/// cache key contains the content of the cell.
+pub struct LookupController {
+  editors: Arc<RwLock<HashMap<String, Arc<DatabaseEditor>>>>,
+  /// The key indexes of the source databases, keyed by the database id and then by the id of the
+  /// key field. An index is built the first time it's needed.
+  key_indexes: parking_lot::RwLock<HashMap<String, HashMap<String, Arc<LookupKeyIndex>>>>,
+}
+
+impl LookupController {
+  pub fn new(editors: Arc<RwLock<HashMap<String, Arc<DatabaseEditor>>>>) -> Self {
+    Self {
+      editors,
+      key_indexes: Default::default(),
+    }
+  }
+
+  /// Removes all the key indexes. They will be rebuilt when they are needed.
+  pub fn clear_key_indexes(&self) {
+    self.key_indexes.write().clear();
+  }
+
+  /// Removes the key indexes of the database. It's called when the database is opened, because
+  /// its rows might have been changed by other clients while it was closed.
+  pub fn remove_key_indexes(&self, database_id: &str) {
+    self.key_indexes.write().remove(database_id);
+  }
+
+  /// Refreshes the lookup cells that depend on the rows of the database. The task ends when the
+  /// editor is dropped.
+  pub fn listen_row_events(self: &Arc<Self>, editor: &DatabaseEditor) {
+    let database_id = editor.get_database_id();
+    let mut row_event_rx = editor.subscribe_row_event();
+    let weak_controller = Arc::downgrade(self);
+    tokio::spawn(async move {
+      loop {
+        let event = match row_event_rx.recv().await {
+          Ok(event) => Some(event),
+          // Some events were missed, so all the dependent cells need to be refreshed.
+          Err(RecvError::Lagged(_)) => None,
+          Err(RecvError::Closed) => break,
+        };
+        match weak_controller.upgrade() {
+          None => break,
+          Some(controller) => controller.did_receive_row_event(&database_id, event).await,
+        }
+      }
+    });
+  }
+
+  /// Refreshes all the cells of the lookup field.
+  pub async fn refresh_lookup_field(&self, editor: &DatabaseEditor, field_id: &str) {
+    match editor.get_lookup_type_option(field_id) {
+      Ok(type_option) if type_option.is_configured() => {
+        self
+          .refresh_lookup_cells(editor, field_id, &type_option, None)
+          .await;
+      },
+      Ok(_) => {},
+      Err(err) => tracing::error!("Failed to refresh the lookup field: {}", err),
+    }
+  }
+
+  async fn did_receive_row_event(&self, database_id: &str, event: Option<DatabaseRowEvent>) {
+    // None means that all the fields of the rows might have changed.
+    let changed_field_ids = match &event {
+      Some(DatabaseRowEvent::UpdateRow(updated_row)) => Some(updated_row.field_ids.clone()),
+      Some(DatabaseRowEvent::Move { .. }) => return,
+      _ => None,
+    };
+    // The lookup field whose refresh produced the event. It's not refreshed again, so a lookup
+    // field can't trigger itself. The first updated field is the refreshed cell, the others are
+    // the formulas that depend on it.
+    let refreshed_field_id = match &event {
+      Some(DatabaseRowEvent::UpdateRow(updated_row))
+        if updated_row.origin == ChangeOrigin::Lookup =>
+      {
+        updated_row.field_ids.first().cloned()
+      },
+      _ => None,
+    };
+    let is_changed = |field_id: &str| {
+      changed_field_ids
+        .as_ref()
+        .map(|field_ids| field_ids.iter().any(|id| id == field_id))
+        .unwrap_or(true)
+    };
+
+    let editors = self
+      .editors
+      .read()
+      .await
+      .values()
+      .cloned()
+      .collect::<Vec<_>>();
+    let mut lookup_fields: Vec<(Arc<DatabaseEditor>, Field, LookupTypeOption)> = vec![];
+    for editor in editors {
+      for (field, type_option) in editor.get_lookup_fields() {
+        lookup_fields.push((editor.clone(), field, type_option));
+      }
+    }
+
+    // The key indexes of the source rows are out of date if the keys changed.
+    for (_, _, type_option) in lookup_fields.iter() {
+      if type_option.database_id == database_id && is_changed(&type_option.source_key_field_id) {
+        if let Some(key_indexes) = self.key_indexes.write().get_mut(database_id) {
+          key_indexes.remove(&type_option.source_key_field_id);
+        }
+      }
+    }
+
+    for (editor, field, type_option) in lookup_fields {
+      if editor.get_database_id() == database_id && refreshed_field_id.as_ref() == Some(&field.id) {
+        continue;
+      }
+      if type_option.database_id == database_id
+        && (is_changed(&type_option.source_key_field_id) || is_changed(&type_option.value_field_id))
+      {
+        self
+          .refresh_lookup_cells(&editor, &field.id, &type_option, None)
+          .await;
+      } else if editor.get_database_id() == database_id {
+        // Only the row itself needs to be refreshed if it was inserted or its key changed.
+        let row_id = match &event {
+          Some(DatabaseRowEvent::InsertRow(inserted_row)) => {
+            Some(inserted_row.row_detail.row.id.clone())
+          },
+          Some(DatabaseRowEvent::UpdateRow(updated_row))
+            if is_changed(&type_option.key_field_id) =>
+          {
+            Some(RowId::from(updated_row.row_id.clone()))
+          },
+          _ => None,
+        };
+        if let Some(row_id) = row_id {
+          self
+            .refresh_lookup_cells(&editor, &field.id, &type_option, Some(&[row_id]))
+            .await;
+        }
+      }
+    }
+  }
+
+  /// Recomputes the lookup cells of the rows. Passing `None` recomputes the cells of all the rows.
+  /// Only the cells whose content changed are saved.
+  async fn refresh_lookup_cells(
+    &self,
+    editor: &DatabaseEditor,
+    field_id: &str,
+    type_option: &LookupTypeOption,
+    row_ids: Option<&[RowId]>,
+  ) {
+    let source = self
+      .editors
+      .read()
+      .await
+      .get(&type_option.database_id)
+      .cloned();
+    let source = match source {
+      None => {
+        tracing::warn!(
+          "The source database:{} of the lookup field:{} is not opened",
+          type_option.database_id,
+          field_id
+        );
+        return;
+      },
+      Some(source) => source,
+    };
+    let key_field = match editor.get_field(&type_option.key_field_id) {
+      None => return,
+      Some(field) => field,
+    };
+    let value_field = source.get_field(&type_option.value_field_id);
+    let key_index = self
+      .get_key_index(&source, &type_option.source_key_field_id)
+      .await;
+
+    let view_id = editor.get_inline_view_id();
+    for row_cell in editor
+      .get_cells_for_field(&view_id, &type_option.key_field_id)
+      .await
+    {
+      if let Some(row_ids) = row_ids {
+        if !row_ids.contains(&row_cell.row_id) {
+          continue;
+        }
+      }
+
+      let key = row_cell
+        .cell
+        .as_ref()
+        .map(|cell| lookup_key_from_cell(cell, &key_field))
+        .unwrap_or_default();
+      let mut cells = vec![];
+      for source_row_id in key_index.get(&key) {
+        cells.push(
+          source
+            .get_cell(&type_option.value_field_id, source_row_id)
+            .await,
+        );
+      }
+      let cell_data = type_option.aggregate(value_field.as_ref(), &cells);
+      let old_cell_data = editor
+        .get_cell(field_id, &row_cell.row_id)
+        .await
+        .map(|cell| LookupCellData::from(&cell))
+        .unwrap_or_default();
+      if old_cell_data == cell_data {
+        continue;
+      }
+
+      if let Err(err) = editor
+        .update_cell_with_origin(
+          &view_id,
+          row_cell.row_id,
+          field_id,
+          Cell::from(cell_data),
+          ChangeOrigin::Lookup,
+        )
+        .await
+      {
+        tracing::error!("Failed to update the lookup cell: {}", err);
+      }
+    }
+  }
+
+  async fn get_key_index(
+    &self,
+    source: &DatabaseEditor,
+    source_key_field_id: &str,
+  ) -> Arc<LookupKeyIndex> {
+    let database_id = source.get_database_id();
+    let key_index = self
+      .key_indexes
+      .read()
+      .get(&database_id)
+      .and_then(|key_indexes| key_indexes.get(source_key_field_id))
+      .cloned();
+    if let Some(key_index) = key_index {
+      return key_index;
+    }
+
+    let mut row_ids_by_key: HashMap<String, Vec<RowId>> = HashMap::new();
+    if let Some(key_field) = source.get_field(source_key_field_id) {
+      let view_id = source.get_inline_view_id();
+      for row_cell in source
+        .get_cells_for_field(&view_id, source_key_field_id)
+        .await
+      {
+        if let Some(cell) = row_cell.cell.as_ref() {
+          let key = lookup_key_from_cell(cell, &key_field);
+          if !key.is_empty() {
+            row_ids_by_key.entry(key).or_default().push(row_cell.row_id);
+          }
+        }
+      }
+    }
+
+    let key_index = Arc::new(LookupKeyIndex { row_ids_by_key });
+    self
+      .key_indexes
+      .write()
+      .entry(database_id)
+      .or_default()
+      .insert(source_key_field_id.to_string(), key_index.clone());
+    key_index
+  }
+}
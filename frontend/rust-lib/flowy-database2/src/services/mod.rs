@@ -1,10 +1,15 @@
+pub mod calculations;
 pub mod cell;
 pub mod database;
 pub mod database_view;
 pub mod field;
 pub mod field_settings;
+pub mod file;
 pub mod filter;
 pub mod group;
+pub mod lookup;
+pub mod relation;
+pub mod row_history;
 pub mod setting;
 pub mod share;
 pub mod snapshot;
@@ -0,0 +1,97 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use collab_database::rows::RowId;
+use tokio::sync::RwLock;
+
+use crate::services::database::{DatabaseEditor, RelatedCell};
+
+/// Removes the links to the deleted rows from the relation cells. It keeps a reverse index that
+/// maps each database to the databases whose relation fields link to it, so deleting a row only
+/// touches the databases that can link to it.
+///
+/// A database is indexed when it's opened and when its relation cells are updated, which covers
+/// the links that can be created while it's open. A database that is closed when the row is
+/// deleted removes the links when it's opened again, see [RelationController::unlink_deleted_rows].
+pub struct RelationController {
+  editors: Arc<RwLock<HashMap<String, Arc<DatabaseEditor>>>>,
+  /// The ids of the databases that link to the database of the key
+  related_database_ids: parking_lot::RwLock<HashMap<String, HashSet<String>>>,
+}
+
+impl RelationController {
+  pub fn new(editors: Arc<RwLock<HashMap<String, Arc<DatabaseEditor>>>>) -> Self {
+    Self {
+      editors,
+      related_database_ids: Default::default(),
+    }
+  }
+
+  /// Adds the databases that the relation fields of the editor link to the index.
+  pub fn index_database(&self, editor: &DatabaseEditor) {
+    let database_id = editor.get_database_id();
+    let mut related_database_ids = self.related_database_ids.write();
+    for (_, type_option) in editor.get_relation_fields() {
+      related_database_ids
+        .entry(type_option.database_id)
+        .or_default()
+        .insert(database_id.clone());
+    }
+  }
+
+  /// Removes the links to the rows from the open databases that link to their database, and
+  /// returns the cells that linked to them.
+  pub async fn did_delete_rows(&self, database_id: &str, row_ids: &[RowId]) -> Vec<RelatedCell> {
+    let mut related_cells = vec![];
+    let related_database_ids = self
+      .related_database_ids
+      .read()
+      .get(database_id)
+      .cloned()
+      .unwrap_or_default();
+    for related_database_id in related_database_ids {
+      let editor = self.editors.read().await.get(&related_database_id).cloned();
+      if let Some(editor) = editor {
+        related_cells.extend(editor.remove_related_rows(database_id, row_ids).await);
+      }
+    }
+    related_cells
+  }
+
+  /// Links the restored rows again to the cells returned by [RelationController::did_delete_rows].
+  /// The cells of the databases that were closed since then are skipped.
+  pub async fn restore_related_cells(&self, related_cells: Vec<RelatedCell>) {
+    for related_cell in related_cells {
+      let editor = self
+        .editors
+        .read()
+        .await
+        .get(&related_cell.database_id)
+        .cloned();
+      match editor {
+        Some(editor) => editor.restore_related_cell(related_cell).await,
+        None => tracing::warn!(
+          "The database {} is closed, the links of the restored rows are not restored",
+          related_cell.database_id
+        ),
+      }
+    }
+  }
+
+  /// Removes the links to the rows that were deleted while the database was closed.
+  /// `related_editor` is an open database that the relation fields of `editor` link to.
+  pub async fn unlink_deleted_rows(
+    &self,
+    editor: &DatabaseEditor,
+    related_editor: &DatabaseEditor,
+  ) {
+    let related_database_id = related_editor.get_database_id();
+    let linked_row_ids = editor.get_linked_rows(&related_database_id).await;
+    let deleted_row_ids = related_editor.get_deleted_rows(&linked_row_ids);
+    if !deleted_row_ids.is_empty() {
+      let _ = editor
+        .remove_related_rows(&related_database_id, &deleted_row_ids)
+        .await;
+    }
+  }
+}
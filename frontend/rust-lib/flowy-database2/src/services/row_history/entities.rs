@@ -0,0 +1,23 @@
+use collab_database::rows::Cell;
+
+/// A change of a cell. The cells before and after the change are kept, so the cell can be
+/// restored to the value it had before the change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellChange {
+  pub id: String,
+  pub database_id: String,
+  pub row_id: String,
+  pub field_id: String,
+  /// The id of the user who made the change. It's 0 if the change was synced without the user.
+  pub uid: i64,
+  /// The cell before the change. None if the cell didn't exist.
+  pub old_cell: Option<Cell>,
+  /// The cell after the change. None if the cell was removed.
+  pub new_cell: Option<Cell>,
+  /// The old and new cells formatted as strings, so the history can be shown even if the field
+  /// was deleted or its type was changed.
+  pub old_content: String,
+  pub new_content: String,
+  /// The time of the change in milliseconds
+  pub timestamp: i64,
+}
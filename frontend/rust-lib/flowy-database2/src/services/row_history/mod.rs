@@ -0,0 +1,7 @@
+mod entities;
+mod persistence;
+mod recorder;
+
+pub use entities::*;
+pub use persistence::*;
+pub use recorder::*;
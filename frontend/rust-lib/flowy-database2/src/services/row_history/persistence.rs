@@ -0,0 +1,17 @@
+use flowy_error::FlowyResult;
+
+use crate::services::row_history::CellChange;
+
+/// Stores the history of the rows on the device of the user. The changes synced from the other
+/// users are stored too, see [crate::services::row_history::RowHistoryRecorder].
+pub trait RowHistoryPersistence: Send + Sync {
+  fn insert_cell_changes(&self, uid: i64, changes: Vec<CellChange>) -> FlowyResult<()>;
+
+  /// Returns the changes of the row, ordered by the time of the change.
+  fn get_row_history(&self, uid: i64, row_id: &str) -> FlowyResult<Vec<CellChange>>;
+
+  fn get_cell_change(&self, uid: i64, change_id: &str) -> FlowyResult<Option<CellChange>>;
+
+  /// Deletes the changes made before the `timestamp` in milliseconds.
+  fn delete_changes_before(&self, uid: i64, timestamp: i64) -> FlowyResult<()>;
+}
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use collab::core::collab::MutexCollab;
+use collab::core::origin::CollabOrigin;
+use collab::preclude::{CollabPlugin, TransactionMut};
+use collab_database::rows::{Cell, Cells, RowId};
+use nanoid::nanoid;
+use tokio::sync::{mpsc, oneshot, RwLock};
+
+use crate::entities::FieldType;
+use crate::services::cell::stringify_cell_data;
+use crate::services::database::DatabaseEditor;
+use crate::services::row_history::{CellChange, RowHistoryPersistence};
+
+/// The changes older than this are deleted when the recorder is created
+const ROW_HISTORY_RETENTION_IN_MILLIS: i64 = 90 * 24 * 60 * 60 * 1000;
+
+enum RowHistoryEvent {
+  /// The row collab was built with the `cells`. It's None if the row has no data yet.
+  DidLoadRow {
+    row_id: String,
+    cells: Option<Cells>,
+  },
+  /// The next changes of the fields are not made by a user, e.g. they are undone
+  SkipChanges {
+    row_id: String,
+    field_ids: Vec<String>,
+  },
+  /// The row collab was updated, either locally or by an update synced from another user
+  DidUpdateRow {
+    row_id: String,
+    uid: Option<i64>,
+    timestamp: i64,
+  },
+  /// The rows were deleted or their database was closed, so their cells are no longer kept
+  ForgetRows {
+    row_ids: Vec<String>,
+  },
+  Flush(oneshot::Sender<()>),
+}
+
+/// Records the changes of the cells in the row history. The changes are observed on the row
+/// collabs, so the changes synced from the other users are recorded along with the local ones.
+/// The cells are compared and written to the disk by a background task, so the calls that change
+/// the cells don't wait for the history.
+pub struct RowHistoryRecorder {
+  tx: mpsc::UnboundedSender<RowHistoryEvent>,
+}
+
+impl RowHistoryRecorder {
+  pub fn new(
+    uid: i64,
+    editors: Arc<RwLock<HashMap<String, Arc<DatabaseEditor>>>>,
+    persistence: Arc<dyn RowHistoryPersistence>,
+  ) -> Self {
+    let (tx, rx) = mpsc::unbounded_channel();
+    let worker = RowHistoryWorker {
+      uid,
+      editors,
+      persistence,
+      row_cells: Default::default(),
+      skipped_field_ids: Default::default(),
+    };
+    tokio::spawn(worker.run(rx));
+    Self { tx }
+  }
+
+  /// Adds the plugin that observes the updates of the row to the row collab.
+  pub fn observe_row(&self, row_id: &str, collab: &MutexCollab) {
+    let cells = collab
+      .to_json_value()
+      .get("data")
+      .and_then(|data| data.get("cells"))
+      .and_then(|cells| serde_json::from_value::<Cells>(cells.clone()).ok());
+    let _ = self.tx.send(RowHistoryEvent::DidLoadRow {
+      row_id: row_id.to_string(),
+      cells,
+    });
+    collab.lock().add_plugin(Arc::new(RowHistoryPlugin {
+      tx: self.tx.clone(),
+    }));
+  }
+
+  /// The next changes of the cells are not recorded. It must be called before the cells are
+  /// changed.
+  pub fn skip_changes(&self, row_id: &RowId, field_ids: &[String]) {
+    let _ = self.tx.send(RowHistoryEvent::SkipChanges {
+      row_id: row_id.clone().into_inner(),
+      field_ids: field_ids.to_vec(),
+    });
+  }
+
+  /// Drops the cells kept to compare the next changes of the rows. It's called when the rows are
+  /// deleted or their database is closed, and the rows are observed again when they are loaded.
+  pub fn forget_rows(&self, row_ids: Vec<RowId>) {
+    let _ = self.tx.send(RowHistoryEvent::ForgetRows {
+      row_ids: row_ids
+        .into_iter()
+        .map(|row_id| row_id.into_inner())
+        .collect(),
+    });
+  }
+
+  /// Waits until the changes made before the call are written to the disk.
+  pub async fn flush(&self) {
+    let (tx, rx) = oneshot::channel();
+    if self.tx.send(RowHistoryEvent::Flush(tx)).is_ok() {
+      let _ = rx.await;
+    }
+  }
+}
+
+/// The updates are applied while the row collab is locked, so the plugin only tells the
+/// recorder which row was updated and by whom.
+struct RowHistoryPlugin {
+  tx: mpsc::UnboundedSender<RowHistoryEvent>,
+}
+
+impl CollabPlugin for RowHistoryPlugin {
+  fn receive_update(&self, object_id: &str, txn: &TransactionMut, _update: &[u8]) {
+    let uid = match CollabOrigin::from(txn) {
+      CollabOrigin::Client(client) => Some(client.uid),
+      _ => None,
+    };
+    let _ = self.tx.send(RowHistoryEvent::DidUpdateRow {
+      row_id: object_id.to_string(),
+      uid,
+      timestamp: chrono::Utc::now().timestamp_millis(),
+    });
+  }
+}
+
+struct RowHistoryWorker {
+  uid: i64,
+  editors: Arc<RwLock<HashMap<String, Arc<DatabaseEditor>>>>,
+  persistence: Arc<dyn RowHistoryPersistence>,
+  /// The cells of each row when they were last compared
+  row_cells: HashMap<String, Cells>,
+  skipped_field_ids: HashMap<String, Vec<String>>,
+}
+
+impl RowHistoryWorker {
+  async fn run(mut self, mut rx: mpsc::UnboundedReceiver<RowHistoryEvent>) {
+    let persistence = self.persistence.clone();
+    let (uid, before) = (
+      self.uid,
+      chrono::Utc::now().timestamp_millis() - ROW_HISTORY_RETENTION_IN_MILLIS,
+    );
+    let _ = tokio::task::spawn_blocking(move || {
+      if let Err(err) = persistence.delete_changes_before(uid, before) {
+        tracing::error!("Failed to delete the old changes of the rows: {:?}", err);
+      }
+    })
+    .await;
+
+    while let Some(event) = rx.recv().await {
+      match event {
+        RowHistoryEvent::DidLoadRow { row_id, cells } => match cells {
+          None => {
+            self.row_cells.remove(&row_id);
+          },
+          Some(cells) => {
+            self.row_cells.insert(row_id, cells);
+          },
+        },
+        RowHistoryEvent::SkipChanges { row_id, field_ids } => {
+          self
+            .skipped_field_ids
+            .entry(row_id)
+            .or_default()
+            .extend(field_ids);
+        },
+        RowHistoryEvent::DidUpdateRow {
+          row_id,
+          uid,
+          timestamp,
+        } => {
+          let changes = self.did_update_row(row_id, uid, timestamp).await;
+          if !changes.is_empty() {
+            let persistence = self.persistence.clone();
+            let uid = self.uid;
+            let _ = tokio::task::spawn_blocking(move || {
+              if let Err(err) = persistence.insert_cell_changes(uid, changes) {
+                tracing::error!("Failed to record the changes of the cells: {:?}", err);
+              }
+            })
+            .await;
+          }
+        },
+        RowHistoryEvent::ForgetRows { row_ids } => {
+          for row_id in row_ids {
+            self.row_cells.remove(&row_id);
+            self.skipped_field_ids.remove(&row_id);
+          }
+        },
+        RowHistoryEvent::Flush(tx) => {
+          let _ = tx.send(());
+        },
+      }
+    }
+  }
+
+  /// Compares the cells of the row with the cells it had before the update. The first update of
+  /// a row that had no data only creates the row, so it's not recorded.
+  async fn did_update_row(
+    &mut self,
+    row_id: String,
+    uid: Option<i64>,
+    timestamp: i64,
+  ) -> Vec<CellChange> {
+    let skipped_field_ids = self.skipped_field_ids.remove(&row_id).unwrap_or_default();
+    let editor_row_id = RowId::from(row_id.clone());
+    let (editor, new_cells) = {
+      let editors = self.editors.read().await;
+      match editors.values().find_map(|editor| {
+        let cells = editor.get_row_cells(&editor_row_id)?;
+        Some((editor.clone(), cells))
+      }) {
+        None => {
+          // The row was deleted or its database isn't open
+          self.row_cells.remove(&row_id);
+          return vec![];
+        },
+        Some(editor_and_cells) => editor_and_cells,
+      }
+    };
+    let old_cells = match self.row_cells.insert(row_id.clone(), new_cells.clone()) {
+      None => return vec![],
+      Some(old_cells) => old_cells,
+    };
+
+    let database_id = editor.get_database_id();
+    let mut field_ids = new_cells.keys().chain(old_cells.keys()).collect::<Vec<_>>();
+    field_ids.sort();
+    field_ids.dedup();
+    field_ids
+      .into_iter()
+      .filter(|field_id| !skipped_field_ids.contains(field_id))
+      .filter_map(|field_id| {
+        let (old_cell, new_cell) = (old_cells.get(field_id), new_cells.get(field_id));
+        if old_cell == new_cell {
+          return None;
+        }
+        // The formulas, lookups and timestamps are computed from the other cells
+        let field = editor.get_field(field_id)?;
+        let field_type = FieldType::from(field.field_type);
+        if field_type.is_formula()
+          || field_type.is_lookup()
+          || field_type.is_created_time()
+          || field_type.is_last_edited_time()
+        {
+          return None;
+        }
+        let stringify = |cell: Option<&Cell>| {
+          cell
+            .map(|cell| stringify_cell_data(cell, &field_type, &field_type, &field))
+            .unwrap_or_default()
+        };
+        Some(CellChange {
+          id: nanoid!(12),
+          database_id: database_id.clone(),
+          row_id: row_id.clone(),
+          field_id: field_id.clone(),
+          uid: uid.unwrap_or_default(),
+          old_cell: old_cell.cloned(),
+          new_cell: new_cell.cloned(),
+          old_content: stringify(old_cell),
+          new_content: stringify(new_cell),
+          timestamp,
+        })
+      })
+      .collect()
+  }
+}
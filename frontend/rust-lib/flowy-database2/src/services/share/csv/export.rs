@@ -1,4 +1,10 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
 use collab_database::database::Database;
+use collab_database::fields::Field;
+use collab_database::rows::RowDetail;
 use indexmap::IndexMap;
 
 use flowy_error::{FlowyError, FlowyResult};
@@ -16,6 +22,15 @@ pub enum CSVFormat {
   META,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct CSVExportOptions {
+  /// Appends the id, the created time, the last edited time and the document text of the rows.
+  pub include_row_meta: bool,
+}
+
+/// The names of the columns appended when the row meta is included.
+const ROW_META_COLUMNS: [&str; 4] = ["Row ID", "Created time", "Last edited time", "Document"];
+
 pub struct CSVExport;
 impl CSVExport {
   pub fn export_database(&self, database: &Database, style: CSVFormat) -> FlowyResult<String> {
@@ -67,4 +82,70 @@ impl CSVExport {
     let csv = String::from_utf8(data).map_err(|e| FlowyError::internal().with_context(e))?;
     Ok(csv)
   }
+
+  /// Exports the given fields and rows with the cell data as it's shown. The rows are written in
+  /// the given order. `row_documents` maps the row ids to the text of their documents.
+  pub fn export_rows(
+    &self,
+    fields: &[Field],
+    rows: &[Arc<RowDetail>],
+    row_documents: &HashMap<String, String>,
+    options: &CSVExportOptions,
+  ) -> FlowyResult<String> {
+    let mut wtr = csv::Writer::from_writer(vec![]);
+    let mut field_records = fields
+      .iter()
+      .map(|field| field.name.clone())
+      .collect::<Vec<String>>();
+    if options.include_row_meta {
+      field_records.extend(ROW_META_COLUMNS.iter().map(|name| name.to_string()));
+    }
+    wtr
+      .write_record(&field_records)
+      .map_err(|e| FlowyError::internal().with_context(e))?;
+
+    for row_detail in rows {
+      let row = &row_detail.row;
+      let mut cells = fields
+        .iter()
+        .map(|field| match row.cells.get(&field.id) {
+          None => "".to_string(),
+          Some(cell) => {
+            let field_type = FieldType::from(field.field_type);
+            stringify_cell_data(cell, &field_type, &field_type, field)
+          },
+        })
+        .collect::<Vec<_>>();
+      if options.include_row_meta {
+        cells.push(row.id.to_string());
+        cells.push(format_timestamp(row.created_at));
+        cells.push(format_timestamp(row.modified_at));
+        cells.push(
+          row_documents
+            .get(row.id.as_str())
+            .cloned()
+            .unwrap_or_default(),
+        );
+      }
+
+      if let Err(e) = wtr.write_record(&cells) {
+        tracing::warn!("CSV failed to write record: {}", e);
+      }
+    }
+
+    let data = wtr
+      .into_inner()
+      .map_err(|e| FlowyError::internal().with_context(e))?;
+    let csv = String::from_utf8(data).map_err(|e| FlowyError::internal().with_context(e))?;
+    Ok(csv)
+  }
+}
+
+/// Formats the timestamp, in seconds, as an RFC 3339 date time in UTC
+fn format_timestamp(timestamp: i64) -> String {
+  Utc
+    .timestamp_opt(timestamp, 0)
+    .single()
+    .map(|date_time| date_time.to_rfc3339())
+    .unwrap_or_default()
 }
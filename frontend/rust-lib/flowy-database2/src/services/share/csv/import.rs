@@ -1,33 +1,90 @@
-use std::{fs::File, io::prelude::*};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::Read;
+use std::str::FromStr;
 
+use chrono::{Local, NaiveDate, NaiveDateTime, TimeZone};
 use collab_database::database::{gen_database_id, gen_field_id, gen_row_id};
-use collab_database::fields::Field;
+use collab_database::fields::{Field, TypeOptionData};
 use collab_database::rows::{new_cell_builder, Cell, CreateRowParams};
 use collab_database::views::{CreateDatabaseParams, DatabaseLayout};
 
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 
 use crate::entities::FieldType;
-use crate::services::field::{default_type_option_data_from_type, CELL_DATA};
+use crate::services::field::{
+  default_type_option_data_from_type, new_select_option_color, CheckboxCellData, DateCellData,
+  DateFormat, DateTypeOption, MultiSelectTypeOption, NumberFormat, NumberTypeOption, SelectOption,
+  SelectOptionIds, SingleSelectTypeOption, URLCellData, CELL_DATA,
+};
 use crate::services::field_settings::DatabaseFieldSettingsMapBuilder;
-use crate::services::share::csv::CSVFormat;
+use crate::services::share::csv::{CSVFormat, CSVImportReport, CSVRecordReader};
+
+/// The number of rows that are read and inserted into the database at a time. The field types are
+/// inferred from the first batch, and a column falls back to text if a later value doesn't fit its
+/// field type.
+pub const CSV_IMPORT_BATCH_SIZE: usize = 1000;
+/// The number of values of a column that are sampled to infer its field type.
+const FIELD_TYPE_SAMPLE_SIZE: usize = 100;
+/// A column is inferred as a select column only if it has at most this number of options.
+const MAX_SELECT_OPTIONS: usize = 20;
+/// The options of a select column are short, longer values are considered to be text.
+const MAX_SELECT_OPTION_LEN: usize = 50;
+const MULTI_SELECT_SEPARATOR: char = ',';
+const CURRENCY_SYMBOLS: [(&str, NumberFormat); 6] = [
+  ("$", NumberFormat::USD),
+  ("€", NumberFormat::EUR),
+  ("£", NumberFormat::Pound),
+  ("¥", NumberFormat::Yen),
+  ("₹", NumberFormat::Rupee),
+  ("₩", NumberFormat::Won),
+];
+/// The date formats that are tried in order when inferring a date column. The month-day-year
+/// format is tried before the day-month-year one, so an ambiguous date like 01/02/2023 is read
+/// as January 2.
+const DATE_FORMATS: [DateFormat; 5] = [
+  DateFormat::ISO,
+  DateFormat::US,
+  DateFormat::Local,
+  DateFormat::DayMonthYear,
+  DateFormat::Friendly,
+];
+/// The time formats that are tried after the date of a date-time value, separated by a space or
+/// a `T`.
+const TIME_FORMATS: [&str; 4] = ["%H:%M:%S", "%H:%M", "%I:%M:%S %p", "%I:%M %p"];
+
+#[derive(Debug, Clone, Default)]
+pub struct CSVImportOptions {
+  /// Overrides the inferred field type of the columns, by the index of the column. The first
+  /// column is the primary field, it's always imported as text. It's only used by the
+  /// [CSVFormat::Original] format.
+  pub field_types: HashMap<usize, FieldType>,
+  /// The label of the encoding of the CSV, like `windows-1252`. It's detected if it's None.
+  pub encoding: Option<String>,
+  /// The delimiter of the CSV, one of `,`, `;` or tab is detected if it's None.
+  pub delimiter: Option<u8>,
+}
+
+/// A column of the CSV with the field type it's imported as.
+#[derive(Debug, Clone)]
+pub struct CSVColumn {
+  pub name: String,
+  pub field_type: FieldType,
+}
 
 #[derive(Default)]
 pub struct CSVImporter;
 
 impl CSVImporter {
+  /// Opens the CSV file to import it in batches.
   pub fn import_csv_from_file(
     &self,
-    view_id: &str,
     path: &str,
-    style: CSVFormat,
-  ) -> FlowyResult<CreateDatabaseParams> {
-    let mut file = File::open(path)?;
-    let mut content = String::new();
-    file.read_to_string(&mut content)?;
-    let fields_with_rows = self.get_fields_and_rows(content)?;
-    let database_data = database_from_fields_and_rows(view_id, fields_with_rows, &style);
-    Ok(database_data)
+    format: CSVFormat,
+    options: &CSVImportOptions,
+  ) -> FlowyResult<CSVImport<File>> {
+    let file = File::open(path)?;
+    CSVImport::new(file, format, options)
   }
 
   pub fn import_csv_from_string(
@@ -35,100 +92,85 @@ impl CSVImporter {
     view_id: String,
     content: String,
     format: CSVFormat,
-  ) -> FlowyResult<CreateDatabaseParams> {
-    let fields_with_rows = self.get_fields_and_rows(content)?;
-    let database_data = database_from_fields_and_rows(&view_id, fields_with_rows, &format);
-    Ok(database_data)
+    options: CSVImportOptions,
+  ) -> FlowyResult<(CreateDatabaseParams, CSVImportReport)> {
+    self.import_csv_from_bytes(view_id, content.as_bytes(), format, options)
   }
 
-  fn get_fields_and_rows(&self, content: String) -> Result<FieldsRows, FlowyError> {
-    let mut fields: Vec<String> = vec![];
-    if content.is_empty() {
-      return Err(FlowyError::invalid_data().with_context("Import content is empty"));
+  /// Imports the CSV content at once. It's decoded like the content of a CSV file, see
+  /// [CSVImportOptions::encoding].
+  pub fn import_csv_from_bytes(
+    &self,
+    view_id: String,
+    bytes: &[u8],
+    format: CSVFormat,
+    options: CSVImportOptions,
+  ) -> FlowyResult<(CreateDatabaseParams, CSVImportReport)> {
+    let mut import = CSVImport::new(bytes, format, &options)?;
+    let mut params = import.database_params(&view_id);
+    while let Some(rows) = import.next_rows()? {
+      params.created_rows.extend(rows);
     }
+    // The select options found in the later rows are added to the fields
+    params.fields = import.fields();
+    Ok((params, import.finish()))
+  }
 
-    let mut reader = csv::Reader::from_reader(content.as_bytes());
-    if let Ok(headers) = reader.headers() {
-      for header in headers {
-        fields.push(header.to_string());
-      }
-    } else {
-      return Err(FlowyError::invalid_data().with_context("Header not found"));
-    }
-
-    let rows = reader
-      .records()
-      .flat_map(|r| r.ok())
-      .map(|record| {
-        record
-          .into_iter()
-          .map(|s| s.to_string())
-          .collect::<Vec<String>>()
+  /// Returns the params to create the database of the records read from another format, like
+  /// the sheet of a workbook. The field types are inferred as the ones of the CSV columns, unless
+  /// they are given in the options.
+  pub(crate) fn import_records(
+    &self,
+    view_id: &str,
+    headers: &[String],
+    rows: Vec<Vec<String>>,
+    options: &CSVImportOptions,
+  ) -> FlowyResult<CreateDatabaseParams> {
+    let mut converter = RowConverter::new(headers, &rows, &CSVFormat::Original, options)?;
+    let created_rows = converter.rows(&rows);
+    Ok(grid_database_params(
+      view_id,
+      converter.fields(),
+      created_rows,
+    ))
+  }
+
+  /// Returns the columns of the CSV with the field types inferred from their values. It's used to
+  /// let the user review the field types before importing the CSV.
+  pub fn infer_field_types(&self, content: String) -> FlowyResult<Vec<CSVColumn>> {
+    let mut reader = CSVRecordReader::new(content.as_bytes(), None, None)?;
+    let rows = reader.next_batch(CSV_IMPORT_BATCH_SIZE)?;
+    let columns = reader
+      .headers()
+      .iter()
+      .enumerate()
+      .map(|(index, name)| {
+        let field_type = if index == 0 {
+          FieldType::RichText
+        } else {
+          infer_field_type(&column_values(&rows, index))
+        };
+        CSVColumn {
+          name: name.clone(),
+          field_type,
+        }
       })
       .collect();
-
-    Ok(FieldsRows { fields, rows })
+    Ok(columns)
   }
 }
 
-fn database_from_fields_and_rows(
+/// Returns the params to create a grid with the fields and the rows.
+fn grid_database_params(
   view_id: &str,
-  fields_and_rows: FieldsRows,
-  format: &CSVFormat,
+  fields: Vec<Field>,
+  created_rows: Vec<CreateRowParams>,
 ) -> CreateDatabaseParams {
-  let (fields, rows) = fields_and_rows.split();
-  let database_id = gen_database_id();
-
-  let fields = fields
-    .into_iter()
-    .enumerate()
-    .map(|(index, field_meta)| match format {
-      CSVFormat::Original => default_field(field_meta, index == 0),
-      CSVFormat::META => {
-        //
-        match serde_json::from_str(&field_meta) {
-          Ok(field) => field,
-          Err(e) => {
-            dbg!(e);
-            default_field(field_meta, index == 0)
-          },
-        }
-      },
-    })
-    .collect::<Vec<Field>>();
-
   let field_settings =
     DatabaseFieldSettingsMapBuilder::new(fields.clone(), DatabaseLayout::Grid).build();
 
-  let created_rows = rows
-    .iter()
-    .map(|cells| {
-      let mut params = CreateRowParams::new(gen_row_id());
-      for (index, cell_content) in cells.iter().enumerate() {
-        if let Some(field) = fields.get(index) {
-          let field_type = FieldType::from(field.field_type);
-
-          // Make the cell based on the style.
-          let cell = match format {
-            CSVFormat::Original => new_cell_builder(field_type)
-              .insert_str_value(CELL_DATA, cell_content.to_string())
-              .build(),
-            CSVFormat::META => match serde_json::from_str::<Cell>(cell_content) {
-              Ok(cell) => cell,
-              Err(_) => new_cell_builder(field_type)
-                .insert_str_value(CELL_DATA, "".to_string())
-                .build(),
-            },
-          };
-          params.cells.insert(field.id.clone(), cell);
-        }
-      }
-      params
-    })
-    .collect::<Vec<CreateRowParams>>();
-
   CreateDatabaseParams {
-    database_id,
+    database_id: gen_database_id(),
     view_id: view_id.to_string(),
     name: "".to_string(),
     layout: DatabaseLayout::Grid,
@@ -142,6 +184,204 @@ fn database_from_fields_and_rows(
   }
 }
 
+/// Imports a CSV in batches of rows, so a large CSV is never loaded in memory at once. The field
+/// types are inferred from the first batch, and the database is created with it by calling
+/// [CSVImport::database_params] first. The other batches are then inserted into the database.
+pub struct CSVImport<R: Read> {
+  records: CSVRecordReader<R>,
+  converter: RowConverter,
+  first_rows: Vec<Vec<String>>,
+  imported_rows: usize,
+}
+
+impl<R: Read> CSVImport<R> {
+  pub fn new(reader: R, format: CSVFormat, options: &CSVImportOptions) -> FlowyResult<Self> {
+    let mut records = CSVRecordReader::new(reader, options.encoding.as_deref(), options.delimiter)?;
+    let first_rows = records.next_batch(CSV_IMPORT_BATCH_SIZE)?;
+    let converter = RowConverter::new(records.headers(), &first_rows, &format, options)?;
+    Ok(Self {
+      records,
+      converter,
+      first_rows,
+      imported_rows: 0,
+    })
+  }
+
+  /// Returns the params to create the database with the first batch of rows.
+  pub fn database_params(&mut self, view_id: &str) -> CreateDatabaseParams {
+    let first_rows = std::mem::take(&mut self.first_rows);
+    let created_rows = self.converter.rows(&first_rows);
+    self.imported_rows += created_rows.len();
+    let fields = self.converter.take_fields();
+    grid_database_params(view_id, fields, created_rows)
+  }
+
+  /// Returns the next batch of rows, or None if all the rows were read.
+  pub fn next_rows(&mut self) -> FlowyResult<Option<Vec<CreateRowParams>>> {
+    let rows = self.records.next_batch(CSV_IMPORT_BATCH_SIZE)?;
+    if rows.is_empty() {
+      return Ok(None);
+    }
+    let created_rows = self.converter.rows(&rows);
+    self.imported_rows += created_rows.len();
+    Ok(Some(created_rows))
+  }
+
+  pub fn fields(&self) -> Vec<Field> {
+    self.converter.fields()
+  }
+
+  /// Returns the fields that were updated since the database was created, or since the last call.
+  /// The options of a select field are extended with the new values found in the rows.
+  pub fn take_updated_fields(&mut self) -> Vec<Field> {
+    self.converter.take_fields()
+  }
+
+  pub fn imported_rows(&self) -> usize {
+    self.imported_rows
+  }
+
+  pub fn read_bytes(&self) -> u64 {
+    self.records.read_bytes()
+  }
+
+  pub fn finish(self) -> CSVImportReport {
+    CSVImportReport {
+      imported_rows: self.imported_rows,
+      skipped_lines: self.records.skipped_lines(),
+    }
+  }
+}
+
+/// Converts the records of the CSV to the rows of the database.
+enum RowConverter {
+  /// Each column is imported with the field type inferred from its values, or the one given in
+  /// the options, converting the values to the cells of the field type.
+  Original(Vec<ImportedColumn>),
+  /// The headers and the values are the serialized fields and cells.
+  Meta { fields: Vec<Field>, is_taken: bool },
+}
+
+impl RowConverter {
+  fn new(
+    headers: &[String],
+    sample_rows: &[Vec<String>],
+    format: &CSVFormat,
+    options: &CSVImportOptions,
+  ) -> FlowyResult<Self> {
+    match format {
+      CSVFormat::Original => {
+        let mut columns = vec![];
+        for (index, name) in headers.iter().enumerate() {
+          let field_type = match (index, options.field_types.get(&index)) {
+            (0, _) => FieldType::RichText,
+            (_, Some(field_type)) => {
+              if !is_importable_field_type(field_type) {
+                return Err(FlowyError::new(
+                  ErrorCode::InvalidParams,
+                  format!("The column: {} can't be imported as {:?}", name, field_type),
+                ));
+              }
+              field_type.clone()
+            },
+            (_, None) => infer_field_type(&column_values(sample_rows, index)),
+          };
+          columns.push(ImportedColumn::new(
+            name.clone(),
+            index == 0,
+            field_type,
+            sample_rows,
+            index,
+          ));
+        }
+        Ok(Self::Original(columns))
+      },
+      CSVFormat::META => {
+        let fields = headers
+          .iter()
+          .enumerate()
+          .map(
+            |(index, field_meta)| match serde_json::from_str(field_meta) {
+              Ok(field) => field,
+              Err(err) => {
+                tracing::warn!("Failed to parse the field of the column {}: {}", index, err);
+                default_field(field_meta.clone(), index == 0)
+              },
+            },
+          )
+          .collect::<Vec<Field>>();
+        Ok(Self::Meta {
+          fields,
+          is_taken: false,
+        })
+      },
+    }
+  }
+
+  fn fields(&self) -> Vec<Field> {
+    match self {
+      Self::Original(columns) => columns.iter().map(|column| column.field.clone()).collect(),
+      Self::Meta { fields, .. } => fields.clone(),
+    }
+  }
+
+  /// Returns the fields that were updated since the last call. All the fields are returned by the
+  /// first call.
+  fn take_fields(&mut self) -> Vec<Field> {
+    match self {
+      Self::Original(columns) => {
+        let mut fields = vec![];
+        for column in columns.iter_mut() {
+          if std::mem::take(&mut column.is_updated) {
+            fields.push(column.field.clone());
+          }
+        }
+        fields
+      },
+      Self::Meta { fields, is_taken } => {
+        if std::mem::replace(is_taken, true) {
+          vec![]
+        } else {
+          fields.clone()
+        }
+      },
+    }
+  }
+
+  fn rows(&mut self, rows: &[Vec<String>]) -> Vec<CreateRowParams> {
+    match self {
+      Self::Original(columns) => rows
+        .iter()
+        .map(|cells| {
+          let mut params = CreateRowParams::new(gen_row_id());
+          for (column, cell_content) in columns.iter_mut().zip(cells.iter()) {
+            let cell = column.cell(cell_content);
+            params.cells.insert(column.field.id.clone(), cell);
+          }
+          params
+        })
+        .collect(),
+      Self::Meta { fields, .. } => rows
+        .iter()
+        .map(|cells| {
+          let mut params = CreateRowParams::new(gen_row_id());
+          for (field, cell_content) in fields.iter().zip(cells.iter()) {
+            let field_type = FieldType::from(field.field_type);
+            let cell = match serde_json::from_str::<Cell>(cell_content) {
+              Ok(cell) => cell,
+              Err(_) => new_cell_builder(field_type)
+                .insert_str_value(CELL_DATA, "".to_string())
+                .build(),
+            };
+            params.cells.insert(field.id.clone(), cell);
+          }
+          params
+        })
+        .collect(),
+    }
+  }
+}
+
 fn default_field(field_str: String, is_primary: bool) -> Field {
   let field_type = FieldType::RichText;
   let type_option_data = default_type_option_data_from_type(&field_type);
@@ -154,26 +394,410 @@ fn default_field(field_str: String, is_primary: bool) -> Field {
   .with_type_option_data(field_type, type_option_data)
 }
 
-struct FieldsRows {
-  fields: Vec<String>,
-  rows: Vec<Vec<String>>,
+/// Returns the non-empty values of the column, trimmed.
+fn column_values(rows: &[Vec<String>], index: usize) -> Vec<&str> {
+  rows
+    .iter()
+    .filter_map(|row| row.get(index))
+    .map(|value| value.trim())
+    .filter(|value| !value.is_empty())
+    .collect()
+}
+
+fn is_importable_field_type(field_type: &FieldType) -> bool {
+  matches!(
+    field_type,
+    FieldType::RichText
+      | FieldType::Number
+      | FieldType::DateTime
+      | FieldType::Checkbox
+      | FieldType::URL
+      | FieldType::SingleSelect
+      | FieldType::MultiSelect
+  )
+}
+
+/// Infers the field type of the column from a sample of its values. A field type is picked only
+/// if all the sampled values fit it, otherwise the column is imported as text.
+fn infer_field_type(values: &[&str]) -> FieldType {
+  let samples = &values[..values.len().min(FIELD_TYPE_SAMPLE_SIZE)];
+  if samples.is_empty() {
+    return FieldType::RichText;
+  }
+
+  if samples.iter().all(|value| is_checkbox_value(value)) {
+    return FieldType::Checkbox;
+  }
+  if samples.iter().all(|value| parse_number(value).is_some()) {
+    return FieldType::Number;
+  }
+  if date_format(samples).is_some() {
+    return FieldType::DateTime;
+  }
+  if samples.iter().all(|value| is_url(value)) {
+    return FieldType::URL;
+  }
+
+  // The values of a select column repeat, otherwise they are just text.
+  let is_multi_select = samples
+    .iter()
+    .any(|value| value.contains(MULTI_SELECT_SEPARATOR));
+  let options = samples
+    .iter()
+    .flat_map(|value| select_option_names(value, is_multi_select))
+    .collect::<Vec<&str>>();
+  let distinct_options = options.iter().collect::<HashSet<_>>().len();
+  if distinct_options <= MAX_SELECT_OPTIONS
+    && distinct_options < options.len()
+    && options
+      .iter()
+      .all(|option| option.chars().count() <= MAX_SELECT_OPTION_LEN)
+  {
+    if is_multi_select {
+      return FieldType::MultiSelect;
+    }
+    return FieldType::SingleSelect;
+  }
+  FieldType::RichText
+}
+
+fn is_checkbox_value(value: &str) -> bool {
+  matches!(
+    value.to_lowercase().as_str(),
+    "yes" | "no" | "true" | "false"
+  )
+}
+
+fn is_url(value: &str) -> bool {
+  if value.chars().any(char::is_whitespace) {
+    return false;
+  }
+  if value.starts_with("http://") || value.starts_with("https://") || value.starts_with("www.") {
+    return true;
+  }
+  // A domain like appflowy.io
+  let host = value.split('/').next().unwrap_or_default();
+  match host.rsplit_once('.') {
+    Some((name, top_level_domain)) => {
+      !name.is_empty()
+        && (2..=24).contains(&top_level_domain.len())
+        && top_level_domain.chars().all(|c| c.is_ascii_alphabetic())
+    },
+    None => false,
+  }
+}
+
+/// Returns the number without the currency symbol, the percent sign and the thousands
+/// separators, with the number format that the symbol stands for.
+fn parse_number(value: &str) -> Option<(String, NumberFormat)> {
+  let (number, format) = match value.strip_suffix('%') {
+    Some(number) => (number, NumberFormat::Percent),
+    None => match CURRENCY_SYMBOLS
+      .iter()
+      .find(|(symbol, _)| value.starts_with(symbol))
+    {
+      Some((symbol, format)) => (&value[symbol.len()..], *format),
+      None => (value, NumberFormat::Num),
+    },
+  };
+  let number = number.trim();
+
+  // The groups after a thousands separator have three digits.
+  let integer_part = number.split('.').next().unwrap_or_default();
+  if integer_part
+    .split(',')
+    .skip(1)
+    .any(|group| group.len() != 3)
+  {
+    return None;
+  }
+  let number = number.replace(',', "");
+  number
+    .parse::<f64>()
+    .ok()
+    .filter(|number| number.is_finite())
+    .map(|_| (number, format))
 }
-impl FieldsRows {
-  fn split(self) -> (Vec<String>, Vec<Vec<String>>) {
-    (self.fields, self.rows)
+
+/// Returns the number format shared by all the values, or [NumberFormat::Num] if the values use
+/// different formats.
+fn number_format(values: &[&str]) -> NumberFormat {
+  let mut formats = values
+    .iter()
+    .filter_map(|value| parse_number(value).map(|(_, format)| format));
+  match formats.next() {
+    None => NumberFormat::Num,
+    Some(format) => {
+      if formats.all(|other| other == format) {
+        format
+      } else {
+        NumberFormat::Num
+      }
+    },
+  }
+}
+
+/// Returns the first date format that all the values can be parsed with.
+fn date_format(values: &[&str]) -> Option<DateFormat> {
+  DATE_FORMATS.into_iter().find(|format| {
+    values
+      .iter()
+      .all(|value| parse_date_time(value, format).is_some())
+  })
+}
+
+/// Parses the date, optionally followed by a time. Returns whether the value has a time, the
+/// dates without a time are at midnight.
+fn parse_date_time(value: &str, format: &DateFormat) -> Option<(NaiveDateTime, bool)> {
+  if let Ok(date) = NaiveDate::parse_from_str(value, format.format_str()) {
+    return Some((date.and_hms_opt(0, 0, 0)?, false));
+  }
+  TIME_FORMATS
+    .iter()
+    .flat_map(|time_format| {
+      [' ', 'T'].map(|separator| format!("{}{}{}", format.format_str(), separator, time_format))
+    })
+    .find_map(|date_time_format| NaiveDateTime::parse_from_str(value, &date_time_format).ok())
+    .map(|date_time| (date_time, true))
+}
+
+/// Returns the timestamp of the date in the local timezone, and whether the value has a time.
+fn parse_date(value: &str, format: Option<DateFormat>) -> Option<(i64, bool)> {
+  let (date_time, include_time) = match format {
+    Some(format) => parse_date_time(value, &format)?,
+    None => DATE_FORMATS
+      .iter()
+      .find_map(|format| parse_date_time(value, format))?,
+  };
+  Local
+    .from_local_datetime(&date_time)
+    .earliest()
+    .map(|date_time| (date_time.timestamp(), include_time))
+}
+
+fn select_option_names(value: &str, is_multi_select: bool) -> Vec<&str> {
+  if is_multi_select {
+    value
+      .split(MULTI_SELECT_SEPARATOR)
+      .map(|name| name.trim())
+      .filter(|name| !name.is_empty())
+      .collect()
+  } else {
+    vec![value]
+  }
+}
+
+/// Converts the values of a column to the cells of its field type.
+enum CellConversion {
+  Text,
+  Number,
+  Date(Option<DateFormat>),
+  Checkbox,
+  Url,
+  Select(Vec<SelectOption>),
+}
+
+struct ImportedColumn {
+  field: Field,
+  conversion: CellConversion,
+  /// Whether the field was updated since it was taken by [RowConverter::take_fields].
+  is_updated: bool,
+}
+
+impl ImportedColumn {
+  fn new(
+    name: String,
+    is_primary: bool,
+    field_type: FieldType,
+    rows: &[Vec<String>],
+    index: usize,
+  ) -> Self {
+    let values = column_values(rows, index);
+    let (type_option_data, conversion): (TypeOptionData, CellConversion) = match field_type {
+      FieldType::Number => {
+        let mut type_option = NumberTypeOption::default();
+        type_option.set_format(number_format(&values));
+        (type_option.into(), CellConversion::Number)
+      },
+      FieldType::DateTime => {
+        let date_format = date_format(&values);
+        let type_option = DateTypeOption {
+          date_format: date_format.unwrap_or_default(),
+          ..Default::default()
+        };
+        (type_option.into(), CellConversion::Date(date_format))
+      },
+      FieldType::Checkbox => (
+        default_type_option_data_from_type(&field_type),
+        CellConversion::Checkbox,
+      ),
+      FieldType::URL => (
+        default_type_option_data_from_type(&field_type),
+        CellConversion::Url,
+      ),
+      FieldType::SingleSelect | FieldType::MultiSelect => {
+        let is_multi_select = field_type == FieldType::MultiSelect;
+        let mut options: Vec<SelectOption> = vec![];
+        for name in values
+          .iter()
+          .flat_map(|value| select_option_names(value, is_multi_select))
+        {
+          if !options.iter().any(|option| option.name == name) {
+            let color = new_select_option_color(&options);
+            options.push(SelectOption::with_color(name, color));
+          }
+        }
+        (
+          select_type_option_data(&field_type, options.clone()),
+          CellConversion::Select(options),
+        )
+      },
+      _ => (
+        default_type_option_data_from_type(&field_type),
+        CellConversion::Text,
+      ),
+    };
+
+    let field = Field::new(gen_field_id(), name, field_type.clone().into(), is_primary)
+      .with_type_option_data(field_type, type_option_data);
+    Self {
+      field,
+      conversion,
+      is_updated: true,
+    }
+  }
+
+  fn cell(&mut self, content: &str) -> Cell {
+    let field_type = FieldType::from(self.field.field_type);
+    let value = content.trim();
+    let empty_cell = || {
+      new_cell_builder(field_type.clone())
+        .insert_str_value(CELL_DATA, "".to_string())
+        .build()
+    };
+    if value.is_empty() {
+      return empty_cell();
+    }
+
+    match &mut self.conversion {
+      CellConversion::Text => new_cell_builder(field_type)
+        .insert_str_value(CELL_DATA, content.to_string())
+        .build(),
+      CellConversion::Number => match parse_number(value) {
+        Some((number, _)) => new_cell_builder(field_type)
+          .insert_str_value(CELL_DATA, number)
+          .build(),
+        None => self.fall_back_to_text(content),
+      },
+      CellConversion::Date(date_format) => match parse_date(value, *date_format) {
+        Some((timestamp, include_time)) => Cell::from(&DateCellData {
+          timestamp: Some(timestamp),
+          include_time,
+          ..Default::default()
+        }),
+        None => self.fall_back_to_text(content),
+      },
+      CellConversion::Checkbox => {
+        let cell_data = CheckboxCellData::from_str(value).unwrap_or_default();
+        if cell_data.0.is_empty() {
+          self.fall_back_to_text(content)
+        } else {
+          Cell::from(cell_data)
+        }
+      },
+      CellConversion::Url => {
+        if is_url(value) {
+          Cell::from(URLCellData {
+            url: value.to_string(),
+            data: value.to_string(),
+          })
+        } else {
+          self.fall_back_to_text(content)
+        }
+      },
+      CellConversion::Select(options) => {
+        // The options are inferred from the first rows, the new values of the later rows are
+        // added to the options of the field.
+        let is_multi_select = field_type == FieldType::MultiSelect;
+        let mut option_ids = vec![];
+        let mut is_options_updated = false;
+        for name in select_option_names(value, is_multi_select) {
+          match options.iter().find(|option| option.name == name) {
+            Some(option) => option_ids.push(option.id.clone()),
+            None => {
+              let option = SelectOption::with_color(name, new_select_option_color(options));
+              option_ids.push(option.id.clone());
+              options.push(option);
+              is_options_updated = true;
+            },
+          }
+        }
+        if is_options_updated {
+          let type_option_data = select_type_option_data(&field_type, options.clone());
+          self
+            .field
+            .type_options
+            .insert(field_type.to_string(), type_option_data);
+          self.is_updated = true;
+        }
+        SelectOptionIds::from(option_ids).to_cell_data(field_type)
+      },
+    }
+  }
+
+  /// Imports the column as text from now on, because the value doesn't fit the field type that
+  /// was inferred from the sampled values. The cells that were already converted are shown as
+  /// text by the text field, so none of the values is lost.
+  fn fall_back_to_text(&mut self, content: &str) -> Cell {
+    let field_type = FieldType::RichText;
+    self.field.field_type = field_type.clone().into();
+    self.field.type_options.insert(
+      field_type.to_string(),
+      default_type_option_data_from_type(&field_type),
+    );
+    self.conversion = CellConversion::Text;
+    self.is_updated = true;
+    new_cell_builder(field_type)
+      .insert_str_value(CELL_DATA, content.to_string())
+      .build()
+  }
+}
+
+fn select_type_option_data(field_type: &FieldType, options: Vec<SelectOption>) -> TypeOptionData {
+  if field_type == &FieldType::MultiSelect {
+    MultiSelectTypeOption {
+      options,
+      disable_color: false,
+    }
+    .into()
+  } else {
+    SingleSelectTypeOption {
+      options,
+      disable_color: false,
+    }
+    .into()
   }
 }
 
 pub struct ImportResult {
   pub database_id: String,
   pub view_id: String,
+  pub report: CSVImportReport,
 }
 
 #[cfg(test)]
 mod tests {
+  use std::collections::HashMap;
+
+  use chrono::{Local, TimeZone, Timelike};
+  use collab::core::any_map::AnyMapExtension;
   use collab_database::database::gen_database_view_id;
 
-  use crate::services::share::csv::{CSVFormat, CSVImporter};
+  use crate::entities::FieldType;
+  use crate::services::field::{DateCellData, SelectOptionIds, CELL_DATA};
+  use crate::services::share::csv::{
+    CSVFormat, CSVImport, CSVImportOptions, CSVImporter, CSV_IMPORT_BATCH_SIZE,
+  };
 
   #[test]
   fn test_import_csv_from_str() {
@@ -182,8 +806,13 @@ mod tests {
 2,tag 2,2,"May 22, 2023",No,
 ,,,,Yes,"#;
     let importer = CSVImporter;
-    let result = importer
-      .import_csv_from_string(gen_database_view_id(), s.to_string(), CSVFormat::Original)
+    let (result, _) = importer
+      .import_csv_from_string(
+        gen_database_view_id(),
+        s.to_string(),
+        CSVFormat::Original,
+        CSVImportOptions::default(),
+      )
       .unwrap();
     assert_eq!(result.created_rows.len(), 3);
     assert_eq!(result.fields.len(), 6);
@@ -202,12 +831,252 @@ mod tests {
     println!("{:?}", result);
   }
 
+  #[test]
+  fn import_csv_infer_field_types_test() {
+    let s = r#"Name,Tags,Price,Date,Done,Website,Status,Labels
+a,tag 1,"$1,000","May 26, 2023",Yes,appflowy.io,Todo,"a,b"
+b,tag 2,$20.5,"May 22, 2023",No,https://github.com,Done,b
+c,tag 3,$3,,Yes,,Todo,"a,c""#;
+    let importer = CSVImporter;
+    let (result, _) = importer
+      .import_csv_from_string(
+        gen_database_view_id(),
+        s.to_string(),
+        CSVFormat::Original,
+        CSVImportOptions::default(),
+      )
+      .unwrap();
+    let field_types = result
+      .fields
+      .iter()
+      .map(|field| FieldType::from(field.field_type))
+      .collect::<Vec<FieldType>>();
+    assert_eq!(
+      field_types,
+      vec![
+        FieldType::RichText,
+        FieldType::RichText,
+        FieldType::Number,
+        FieldType::DateTime,
+        FieldType::Checkbox,
+        FieldType::URL,
+        FieldType::SingleSelect,
+        FieldType::MultiSelect,
+      ]
+    );
+
+    // The empty values are imported as empty cells
+    for row in &result.created_rows {
+      assert_eq!(row.cells.len(), 8);
+    }
+  }
+
+  #[test]
+  fn import_csv_with_field_type_override_test() {
+    let s = r#"Name,Number,Status
+a,1,Todo
+b,2,Todo"#;
+    let importer = CSVImporter;
+    let columns = importer.infer_field_types(s.to_string()).unwrap();
+    assert_eq!(columns[1].field_type, FieldType::Number);
+    assert_eq!(columns[2].field_type, FieldType::SingleSelect);
+
+    let options = CSVImportOptions {
+      field_types: HashMap::from([(1, FieldType::RichText), (2, FieldType::RichText)]),
+      ..Default::default()
+    };
+    let (result, _) = importer
+      .import_csv_from_string(
+        gen_database_view_id(),
+        s.to_string(),
+        CSVFormat::Original,
+        options,
+      )
+      .unwrap();
+    assert_eq!(
+      FieldType::from(result.fields[1].field_type),
+      FieldType::RichText
+    );
+    assert_eq!(
+      FieldType::from(result.fields[2].field_type),
+      FieldType::RichText
+    );
+
+    // The field types that can't be converted from text are rejected
+    let options = CSVImportOptions {
+      field_types: HashMap::from([(1, FieldType::Relation)]),
+      ..Default::default()
+    };
+    let result = importer.import_csv_from_string(
+      gen_database_view_id(),
+      s.to_string(),
+      CSVFormat::Original,
+      options,
+    );
+    assert!(result.is_err());
+  }
+
+  #[test]
+  fn import_csv_with_bom_and_semicolon_test() {
+    let s = "\u{feff}Name;Amount;Note\na;1;\"x;y\"\nb;2;z";
+    let (result, report) = CSVImporter
+      .import_csv_from_string(
+        gen_database_view_id(),
+        s.to_string(),
+        CSVFormat::Original,
+        CSVImportOptions::default(),
+      )
+      .unwrap();
+    assert_eq!(result.fields.len(), 3);
+    assert_eq!(result.fields[0].name, "Name");
+    assert_eq!(
+      FieldType::from(result.fields[1].field_type),
+      FieldType::Number
+    );
+    assert_eq!(report.imported_rows, 2);
+    assert!(report.skipped_lines.is_empty());
+  }
+
+  #[test]
+  fn import_csv_with_tab_delimiter_and_windows_1252_test() {
+    // "Café" encoded with Windows-1252
+    let mut content = b"Name\tCity\n".to_vec();
+    content.extend_from_slice(b"a\tCaf\xe9\n");
+    let (params, _) = CSVImporter
+      .import_csv_from_bytes(
+        gen_database_view_id(),
+        &content,
+        CSVFormat::Original,
+        CSVImportOptions::default(),
+      )
+      .unwrap();
+    assert_eq!(params.fields[1].name, "City");
+    let cell = params.created_rows[0]
+      .cells
+      .get(&params.fields[1].id)
+      .unwrap();
+    assert_eq!(cell.get_str_value(CELL_DATA).unwrap(), "Café");
+  }
+
+  #[test]
+  fn import_csv_report_skipped_lines_test() {
+    let s = "Name,Amount\na,1\nb,2,3\nc\n";
+    let (result, report) = CSVImporter
+      .import_csv_from_string(
+        gen_database_view_id(),
+        s.to_string(),
+        CSVFormat::Original,
+        CSVImportOptions::default(),
+      )
+      .unwrap();
+    // The short record is kept with an empty cell, the long one is skipped
+    assert_eq!(result.created_rows.len(), 2);
+    assert_eq!(result.created_rows[1].cells.len(), 2);
+    assert_eq!(report.imported_rows, 2);
+    assert_eq!(report.skipped_lines.len(), 1);
+    assert_eq!(report.skipped_lines[0].line, 3);
+  }
+
+  #[test]
+  fn import_csv_in_batches_test() {
+    let mut s = "Name,Status\n".to_string();
+    for index in 0..CSV_IMPORT_BATCH_SIZE {
+      s.push_str(&format!(
+        "{},{}\n",
+        index,
+        if index % 2 == 0 { "Todo" } else { "Done" }
+      ));
+    }
+    // A new option found after the first batch
+    s.push_str("last,Doing\n");
+
+    let mut import =
+      CSVImport::new(s.as_bytes(), CSVFormat::Original, &Default::default()).unwrap();
+    let params = import.database_params(&gen_database_view_id());
+    assert_eq!(params.created_rows.len(), CSV_IMPORT_BATCH_SIZE);
+    assert_eq!(
+      FieldType::from(params.fields[1].field_type),
+      FieldType::SingleSelect
+    );
+    assert!(import.take_updated_fields().is_empty());
+
+    let rows = import.next_rows().unwrap().unwrap();
+    assert_eq!(rows.len(), 1);
+    assert!(import.next_rows().unwrap().is_none());
+
+    let updated_fields = import.take_updated_fields();
+    assert_eq!(updated_fields.len(), 1);
+    let option_ids = SelectOptionIds::from(rows[0].cells.get(&updated_fields[0].id).unwrap());
+    assert_eq!(option_ids.len(), 1);
+    assert_eq!(import.finish().imported_rows, CSV_IMPORT_BATCH_SIZE + 1);
+  }
+
+  #[test]
+  fn import_csv_falls_back_to_text_test() {
+    let mut s = "Name,Date,Done,Count,Link\n".to_string();
+    for index in 0..CSV_IMPORT_BATCH_SIZE {
+      s.push_str(&format!(
+        "{},2023-05-26 13:45,Yes,{},appflowy.io\n",
+        index, index
+      ));
+    }
+    // The values after the first batch that don't fit the inferred field types
+    s.push_str("last,someday,maybe,many,not a link\n");
+
+    let mut import =
+      CSVImport::new(s.as_bytes(), CSVFormat::Original, &Default::default()).unwrap();
+    let params = import.database_params(&gen_database_view_id());
+    assert_eq!(
+      FieldType::from(params.fields[1].field_type),
+      FieldType::DateTime
+    );
+    assert_eq!(
+      FieldType::from(params.fields[2].field_type),
+      FieldType::Checkbox
+    );
+    assert_eq!(
+      FieldType::from(params.fields[3].field_type),
+      FieldType::Number
+    );
+    assert_eq!(FieldType::from(params.fields[4].field_type), FieldType::URL);
+
+    // The time of the date is kept
+    let date_cell_data = DateCellData::from(
+      params.created_rows[0]
+        .cells
+        .get(&params.fields[1].id)
+        .unwrap(),
+    );
+    assert!(date_cell_data.include_time);
+    let date_time = Local
+      .timestamp_opt(date_cell_data.timestamp.unwrap(), 0)
+      .unwrap();
+    assert_eq!((date_time.hour(), date_time.minute()), (13, 45));
+
+    // The columns fall back to text and keep the values
+    let rows = import.next_rows().unwrap().unwrap();
+    let updated_fields = import.take_updated_fields();
+    assert_eq!(updated_fields.len(), 4);
+    for (field, content) in updated_fields
+      .iter()
+      .zip(["someday", "maybe", "many", "not a link"])
+    {
+      assert_eq!(FieldType::from(field.field_type), FieldType::RichText);
+      let cell = rows[0].cells.get(&field.id).unwrap();
+      assert_eq!(cell.get_str_value(CELL_DATA).unwrap(), content);
+    }
+  }
+
   #[test]
   fn import_empty_csv_data_test() {
     let s = r#""#;
     let importer = CSVImporter;
-    let result =
-      importer.import_csv_from_string(gen_database_view_id(), s.to_string(), CSVFormat::Original);
+    let result = importer.import_csv_from_string(
+      gen_database_view_id(),
+      s.to_string(),
+      CSVFormat::Original,
+      CSVImportOptions::default(),
+    );
     assert!(result.is_err());
   }
 }
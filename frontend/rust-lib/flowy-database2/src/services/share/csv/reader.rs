@@ -0,0 +1,234 @@
+use std::io::{Chain, Cursor, Read};
+
+use encoding_rs::{Decoder, Encoding, UTF_8, WINDOWS_1252};
+
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+
+/// The number of bytes that are read ahead to detect the encoding and the delimiter of the CSV.
+const SNIFF_SIZE: u64 = 64 * 1024;
+/// The delimiters that are detected. The comma is preferred when the header uses several of them.
+const DELIMITERS: [u8; 3] = [b',', b';', b'\t'];
+
+/// A line of the CSV that was skipped, because it couldn't be parsed.
+#[derive(Debug, Clone)]
+pub struct CSVSkippedLine {
+  pub line: u64,
+  pub error: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CSVImportReport {
+  pub imported_rows: usize,
+  pub skipped_lines: Vec<CSVSkippedLine>,
+}
+
+type SourceReader<R> = DecodeReader<Chain<Cursor<Vec<u8>>, CountingReader<R>>>;
+
+/// Reads the records of a CSV in batches. The content is decoded to UTF-8 while it's read, and the
+/// records that can't be parsed are skipped and reported instead of failing the whole import.
+pub(crate) struct CSVRecordReader<R: Read> {
+  reader: csv::Reader<SourceReader<R>>,
+  headers: Vec<String>,
+  skipped_lines: Vec<CSVSkippedLine>,
+}
+
+impl<R: Read> CSVRecordReader<R> {
+  /// Creates a reader of the CSV. The encoding and the delimiter are detected from the beginning
+  /// of the content, unless they are given.
+  pub fn new(reader: R, encoding: Option<&str>, delimiter: Option<u8>) -> FlowyResult<Self> {
+    let mut reader = CountingReader {
+      inner: reader,
+      count: 0,
+    };
+    let mut sniffed = vec![];
+    (&mut reader).take(SNIFF_SIZE).read_to_end(&mut sniffed)?;
+    if sniffed.is_empty() {
+      return Err(FlowyError::invalid_data().with_context("Import content is empty"));
+    }
+
+    let encoding = detect_encoding(&sniffed, encoding)?;
+    let delimiter = delimiter.unwrap_or_else(|| detect_delimiter(&encoding.decode(&sniffed).0));
+    let source = DecodeReader {
+      inner: Cursor::new(sniffed).chain(reader),
+      // The decoder removes the BOM, and switches to the encoding of the BOM if there is one.
+      decoder: encoding.new_decoder(),
+      input: vec![0; 8 * 1024],
+      output: vec![],
+      output_pos: 0,
+      is_finished: false,
+    };
+    let mut reader = csv::ReaderBuilder::new()
+      .delimiter(delimiter)
+      .flexible(true)
+      .from_reader(source);
+    let headers = reader
+      .headers()
+      .map_err(|err| FlowyError::invalid_data().with_context(format!("Header not found: {}", err)))?
+      .iter()
+      .map(|header| header.to_string())
+      .collect();
+
+    Ok(Self {
+      reader,
+      headers,
+      skipped_lines: vec![],
+    })
+  }
+
+  pub fn headers(&self) -> &[String] {
+    &self.headers
+  }
+
+  /// Returns the next records, at most `batch_size` of them. An empty batch means that all the
+  /// records were read.
+  pub fn next_batch(&mut self, batch_size: usize) -> FlowyResult<Vec<Vec<String>>> {
+    let mut rows = Vec::with_capacity(batch_size);
+    let mut record = csv::StringRecord::new();
+    while rows.len() < batch_size {
+      match self.reader.read_record(&mut record) {
+        Ok(true) => {
+          let line = record.position().map(|pos| pos.line()).unwrap_or_default();
+          if record.len() > self.headers.len() {
+            self.skipped_lines.push(CSVSkippedLine {
+              line,
+              error: format!(
+                "Expected {} fields, found {}",
+                self.headers.len(),
+                record.len()
+              ),
+            });
+            continue;
+          }
+
+          // The missing fields of a short record are imported as empty cells
+          let mut row = record
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>();
+          row.resize(self.headers.len(), "".to_string());
+          rows.push(row);
+        },
+        Ok(false) => break,
+        Err(err) => {
+          if err.is_io_error() {
+            return Err(FlowyError::internal().with_context(err));
+          }
+          self.skipped_lines.push(CSVSkippedLine {
+            line: err.position().map(|pos| pos.line()).unwrap_or_default(),
+            error: err.to_string(),
+          });
+        },
+      }
+    }
+    Ok(rows)
+  }
+
+  /// Returns the number of bytes read from the source, it's used to report the progress.
+  pub fn read_bytes(&self) -> u64 {
+    self.reader.get_ref().inner.get_ref().1.count
+  }
+
+  pub fn skipped_lines(self) -> Vec<CSVSkippedLine> {
+    self.skipped_lines
+  }
+}
+
+fn detect_encoding(bytes: &[u8], label: Option<&str>) -> FlowyResult<&'static Encoding> {
+  if let Some(label) = label {
+    return Encoding::for_label(label.as_bytes()).ok_or_else(|| {
+      FlowyError::new(
+        ErrorCode::InvalidParams,
+        format!("Unsupported encoding: {}", label),
+      )
+    });
+  }
+  if let Some((encoding, _)) = Encoding::for_bom(bytes) {
+    return Ok(encoding);
+  }
+  match std::str::from_utf8(bytes) {
+    Ok(_) => Ok(UTF_8),
+    // The sniffed bytes might end in the middle of a character
+    Err(err) if err.error_len().is_none() => Ok(UTF_8),
+    // The CSVs exported by the spreadsheet apps on Windows are usually encoded with the code page
+    // of the system, which is Windows-1252 for most of the latin languages.
+    Err(_) => Ok(WINDOWS_1252),
+  }
+}
+
+/// Returns the delimiter that is used the most in the header, ignoring the quoted names.
+fn detect_delimiter(content: &str) -> u8 {
+  let header = content.lines().next().unwrap_or_default();
+  let mut counts = [0; DELIMITERS.len()];
+  let mut is_quoted = false;
+  for byte in header.bytes() {
+    if byte == b'"' {
+      is_quoted = !is_quoted;
+    } else if !is_quoted {
+      if let Some(index) = DELIMITERS.iter().position(|delimiter| *delimiter == byte) {
+        counts[index] += 1;
+      }
+    }
+  }
+
+  let delimiter = (1..DELIMITERS.len()).fold(0, |delimiter, index| {
+    if counts[index] > counts[delimiter] {
+      index
+    } else {
+      delimiter
+    }
+  });
+  DELIMITERS[delimiter]
+}
+
+struct CountingReader<R> {
+  inner: R,
+  count: u64,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+  fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+    let len = self.inner.read(buf)?;
+    self.count += len as u64;
+    Ok(len)
+  }
+}
+
+/// Decodes the content of the inner reader to UTF-8.
+struct DecodeReader<R> {
+  inner: R,
+  decoder: Decoder,
+  input: Vec<u8>,
+  output: Vec<u8>,
+  output_pos: usize,
+  is_finished: bool,
+}
+
+impl<R: Read> Read for DecodeReader<R> {
+  fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+    while self.output_pos == self.output.len() {
+      if self.is_finished {
+        return Ok(0);
+      }
+
+      let len = self.inner.read(&mut self.input)?;
+      let is_last = len == 0;
+      let max_len = self.decoder.max_utf8_buffer_length(len).ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, "The content is too long")
+      })?;
+      self.output.resize(max_len, 0);
+      // The output is large enough to decode all the input at once
+      let (_, _, written, _) =
+        self
+          .decoder
+          .decode_to_utf8(&self.input[..len], &mut self.output, is_last);
+      self.output.truncate(written);
+      self.output_pos = 0;
+      self.is_finished = is_last;
+    }
+
+    let len = buf.len().min(self.output.len() - self.output_pos);
+    buf[..len].copy_from_slice(&self.output[self.output_pos..self.output_pos + len]);
+    self.output_pos += len;
+    Ok(len)
+  }
+}
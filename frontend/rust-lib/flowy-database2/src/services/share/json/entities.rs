@@ -0,0 +1,138 @@
+use std::collections::{BTreeMap, HashMap};
+
+use collab_database::fields::Field;
+use collab_database::rows::Cell;
+use collab_database::views::{
+  DatabaseLayout, FieldSettingsMap, FilterMap, GroupSettingMap, SortMap,
+};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::services::setting::CalendarLayoutSetting;
+
+/// The version of the schema written by the export. It's increased whenever the schema changes
+/// in a way that the older versions can't read, and the import rejects the newer versions.
+pub const DATABASE_JSON_VERSION: u32 = 1;
+
+/// The JSON representation of a database view, used to keep the databases as reviewable text.
+///
+/// Version 1 of the schema:
+/// ```json
+/// {
+///   "version": 1,
+///   "name": "Tasks",
+///   "layout": "Grid",
+///   "fields": [{ "id": "...", "name": "Status", "field_type": 3, "type_options": {...}, ... }],
+///   "rows": [{ "id": "...", "height": 60, "visibility": true, "cells": { "<field id>": {...} } }],
+///   "settings": {
+///     "filters": [...],
+///     "sorts": [...],
+///     "groups": [...],
+///     "calendar": null,
+///     "field_settings": { "<field id>": { "visibility": 0, ... } }
+///   }
+/// }
+/// ```
+/// The fields keep their type options, including the select options, and the cells are keyed
+/// by the id of their field. The rows are written in the order of the view. The settings are
+/// stored as they are in the view, so the filters, sorts and groups refer to the ids of the
+/// fields. The keys of the maps are sorted, so exporting the same database twice writes the same
+/// text.
+///
+/// Limits of version 1:
+/// - Only the calendar layout setting is exported, the settings of the other layouts are reset
+///   to their defaults on import.
+/// - The `modified_at` of the rows is exported for reference only. The imported rows are
+///   created at their `created_at`, so they are modified at that time too.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatabaseJSON {
+  pub version: u32,
+  pub name: String,
+  pub layout: DatabaseJSONLayout,
+  #[serde(serialize_with = "serialize_sorted")]
+  pub fields: Vec<Field>,
+  pub rows: Vec<RowJSON>,
+  #[serde(default)]
+  pub settings: DatabaseSettingsJSON,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum DatabaseJSONLayout {
+  Grid,
+  Board,
+  Calendar,
+}
+
+impl From<DatabaseLayout> for DatabaseJSONLayout {
+  fn from(layout: DatabaseLayout) -> Self {
+    match layout {
+      DatabaseLayout::Grid => DatabaseJSONLayout::Grid,
+      DatabaseLayout::Board => DatabaseJSONLayout::Board,
+      DatabaseLayout::Calendar => DatabaseJSONLayout::Calendar,
+    }
+  }
+}
+
+impl From<DatabaseJSONLayout> for DatabaseLayout {
+  fn from(layout: DatabaseJSONLayout) -> Self {
+    match layout {
+      DatabaseJSONLayout::Grid => DatabaseLayout::Grid,
+      DatabaseJSONLayout::Board => DatabaseLayout::Board,
+      DatabaseJSONLayout::Calendar => DatabaseLayout::Calendar,
+    }
+  }
+}
+
+/// A row of the database. The id is informative only, the imported rows get new ids.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RowJSON {
+  pub id: String,
+  pub height: i32,
+  pub visibility: bool,
+  pub created_at: i64,
+  /// Ignored on import
+  pub modified_at: i64,
+  #[serde(serialize_with = "serialize_sorted")]
+  pub cells: HashMap<String, Cell>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DatabaseSettingsJSON {
+  /// The filters and the filter groups of the view
+  #[serde(default, serialize_with = "serialize_sorted")]
+  pub filters: Vec<FilterMap>,
+  #[serde(default, serialize_with = "serialize_sorted")]
+  pub sorts: Vec<SortMap>,
+  #[serde(default, serialize_with = "serialize_sorted")]
+  pub groups: Vec<GroupSettingMap>,
+  /// The layout setting of the calendar layout, it's the only layout that has settings
+  #[serde(default)]
+  pub calendar: Option<CalendarLayoutSetting>,
+  /// The settings of the fields by the id of the field. The fields without settings get the
+  /// default settings of the layout.
+  #[serde(default, serialize_with = "serialize_sorted")]
+  pub field_settings: HashMap<String, FieldSettingsMap>,
+}
+
+/// Serializes the value with the keys of its maps sorted, including the maps nested in the
+/// cells, the type options and the settings, which are hash maps.
+fn serialize_sorted<T: Serialize, S: Serializer>(
+  value: &T,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  let value = serde_json::to_value(value).map_err(serde::ser::Error::custom)?;
+  sort_keys(value).serialize(serializer)
+}
+
+fn sort_keys(value: Value) -> Value {
+  match value {
+    Value::Object(map) => map
+      .into_iter()
+      .map(|(key, value)| (key, sort_keys(value)))
+      .collect::<BTreeMap<_, _>>()
+      .into_iter()
+      .collect(),
+    Value::Array(values) => Value::Array(values.into_iter().map(sort_keys).collect()),
+    value => value,
+  }
+}
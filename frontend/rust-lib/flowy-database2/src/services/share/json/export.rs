@@ -0,0 +1,56 @@
+use collab_database::database::Database;
+use collab_database::views::DatabaseLayout;
+
+use flowy_error::{FlowyError, FlowyResult};
+
+use crate::services::setting::CalendarLayoutSetting;
+use crate::services::share::json::{
+  DatabaseJSON, DatabaseSettingsJSON, RowJSON, DATABASE_JSON_VERSION,
+};
+
+pub struct JSONExport;
+impl JSONExport {
+  /// Exports the view with its fields, rows and settings as pretty printed JSON.
+  pub fn export_database(&self, database: &Database, view_id: &str) -> FlowyResult<String> {
+    let view = database
+      .get_view(view_id)
+      .ok_or_else(|| FlowyError::record_not_found().with_context("Can't find the view"))?;
+    let fields = database.get_fields_in_view(view_id, None);
+    let rows = database
+      .get_rows_for_view(view_id)
+      .into_iter()
+      .map(|row| RowJSON {
+        id: row.id.to_string(),
+        height: row.height,
+        visibility: row.visibility,
+        created_at: row.created_at,
+        modified_at: row.modified_at,
+        cells: row
+          .cells
+          .iter()
+          .map(|(field_id, cell)| (field_id.clone(), cell.clone()))
+          .collect(),
+      })
+      .collect();
+    let calendar = view
+      .layout_settings
+      .get(&DatabaseLayout::Calendar)
+      .map(|setting| CalendarLayoutSetting::from(setting.clone()));
+
+    let database_json = DatabaseJSON {
+      version: DATABASE_JSON_VERSION,
+      name: view.name,
+      layout: view.layout.into(),
+      fields,
+      rows,
+      settings: DatabaseSettingsJSON {
+        filters: view.filters,
+        sorts: view.sorts,
+        groups: view.group_settings,
+        calendar,
+        field_settings: view.field_settings.into_inner(),
+      },
+    };
+    serde_json::to_string_pretty(&database_json).map_err(|e| FlowyError::internal().with_context(e))
+  }
+}
@@ -0,0 +1,74 @@
+use collab_database::database::{gen_database_id, gen_row_id};
+use collab_database::rows::CreateRowParams;
+use collab_database::views::{CreateDatabaseParams, DatabaseLayout, LayoutSettings};
+
+use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+
+use crate::services::field_settings::field_settings_for_field;
+use crate::services::share::json::{DatabaseJSON, DATABASE_JSON_VERSION};
+
+pub struct JSONImporter;
+impl JSONImporter {
+  /// Returns the params to create the database of the JSON exported by
+  /// [JSONExport](crate::services::share::json::JSONExport). The database and its rows get new
+  /// ids, the fields keep their ids because the cells and the settings refer to them.
+  pub fn import_json_from_string(
+    &self,
+    view_id: String,
+    content: &str,
+  ) -> FlowyResult<CreateDatabaseParams> {
+    let database_json = serde_json::from_str::<DatabaseJSON>(content)
+      .map_err(|err| FlowyError::invalid_data().with_context(err))?;
+    if database_json.version > DATABASE_JSON_VERSION {
+      return Err(FlowyError::new(
+        ErrorCode::InvalidParams,
+        format!(
+          "The database JSON version {} is not supported, the latest version is {}",
+          database_json.version, DATABASE_JSON_VERSION
+        ),
+      ));
+    }
+
+    let layout = DatabaseLayout::from(database_json.layout);
+    let mut layout_settings = LayoutSettings::default();
+    if let Some(calendar) = database_json.settings.calendar {
+      layout_settings.insert(DatabaseLayout::Calendar, calendar.into());
+    }
+
+    let mut field_settings = database_json.settings.field_settings;
+    for field in database_json.fields.iter() {
+      field_settings
+        .entry(field.id.clone())
+        .or_insert_with(|| field_settings_for_field(layout, field));
+    }
+
+    let created_rows = database_json
+      .rows
+      .into_iter()
+      .map(|row| {
+        let mut params = CreateRowParams::new(gen_row_id());
+        params.height = row.height;
+        params.visibility = row.visibility;
+        params.timestamp = row.created_at;
+        for (field_id, cell) in row.cells {
+          params.cells.insert(field_id, cell);
+        }
+        params
+      })
+      .collect();
+
+    Ok(CreateDatabaseParams {
+      database_id: gen_database_id(),
+      view_id,
+      name: database_json.name,
+      layout,
+      layout_settings,
+      filters: database_json.settings.filters,
+      groups: database_json.settings.groups,
+      sorts: database_json.settings.sorts,
+      created_rows,
+      fields: database_json.fields,
+      field_settings: field_settings.into(),
+    })
+  }
+}
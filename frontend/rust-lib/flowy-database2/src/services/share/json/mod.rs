@@ -0,0 +1,7 @@
+mod entities;
+mod export;
+mod import;
+
+pub use entities::*;
+pub use export::*;
+pub use import::*;
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use collab_database::fields::Field;
+use collab_database::rows::RowDetail;
+
+use crate::entities::FieldType;
+use crate::services::cell::stringify_cell_data;
+
+pub struct MarkdownExport;
+impl MarkdownExport {
+  /// Exports the fields and the rows as a Markdown table, with the cell data as it's shown.
+  pub fn export_rows(&self, fields: &[Field], rows: &[Arc<RowDetail>]) -> String {
+    let mut markdown = String::new();
+    let header = fields
+      .iter()
+      .map(|field| escape_cell(&field.name))
+      .collect::<Vec<String>>();
+    write_line(&mut markdown, &header);
+    write_line(&mut markdown, &vec!["---".to_string(); fields.len()]);
+
+    for row_detail in rows {
+      let cells = fields
+        .iter()
+        .map(|field| match row_detail.row.cells.get(&field.id) {
+          None => "".to_string(),
+          Some(cell) => {
+            let field_type = FieldType::from(field.field_type);
+            escape_cell(&stringify_cell_data(cell, &field_type, &field_type, field))
+          },
+        })
+        .collect::<Vec<String>>();
+      write_line(&mut markdown, &cells);
+    }
+    markdown
+  }
+}
+
+fn write_line(markdown: &mut String, cells: &[String]) {
+  markdown.push_str("| ");
+  markdown.push_str(&cells.join(" | "));
+  markdown.push_str(" |\n");
+}
+
+/// Escapes the pipes that would end the cell, and replaces the line breaks that would end the
+/// row of the table.
+fn escape_cell(content: &str) -> String {
+  content
+    .replace('|', "\\|")
+    .replace("\r\n", "<br>")
+    .replace('\n', "<br>")
+}
@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use chrono::{Local, NaiveDateTime, NaiveTime, TimeZone};
+use collab_database::fields::Field;
+use collab_database::rows::RowDetail;
+use rust_xlsxwriter::{ExcelDateTime, Format, Workbook, Worksheet, XlsxError};
+
+use flowy_error::{internal_error, FlowyResult};
+
+use crate::entities::FieldType;
+use crate::services::cell::stringify_cell_data;
+use crate::services::field::{
+  CheckboxCellData, DateCellData, DateTypeOption, MultiSelectTypeOption, NumberCellData,
+  SingleSelectTypeOption,
+};
+use crate::services::share::xlsx::validation::{add_list_validations, ListValidation};
+
+/// The characters that are not allowed in the name of a sheet
+const INVALID_SHEET_NAME_CHARS: [char; 7] = ['[', ']', ':', '*', '?', '/', '\\'];
+const MAX_SHEET_NAME_LEN: usize = 31;
+const DATE_FORMAT: &str = "yyyy-mm-dd";
+const DATE_TIME_FORMAT: &str = "yyyy-mm-dd hh:mm";
+
+pub struct XLSXExport;
+impl XLSXExport {
+  /// Exports the fields and the rows as a workbook with a single sheet. The cells are written with
+  /// the types of the spreadsheet: the numbers as numbers, the dates as dates and the checkboxes
+  /// as booleans. The select options are written as text, with a list of the options of the field
+  /// to pick them from.
+  pub fn export_rows(
+    &self,
+    sheet_name: &str,
+    fields: &[Field],
+    rows: &[Arc<RowDetail>],
+  ) -> FlowyResult<Vec<u8>> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet
+      .set_name(valid_sheet_name(sheet_name))
+      .map_err(internal_error)?;
+
+    let header_format = Format::new().set_bold();
+    for (col, field) in fields.iter().enumerate() {
+      worksheet
+        .write_string_with_format(0, col as u16, &field.name, &header_format)
+        .map_err(internal_error)?;
+    }
+
+    let date_format = Format::new().set_num_format(DATE_FORMAT);
+    let date_time_format = Format::new().set_num_format(DATE_TIME_FORMAT);
+    for (index, row_detail) in rows.iter().enumerate() {
+      let row = &row_detail.row;
+      let row_num = index as u32 + 1;
+      for (col, field) in fields.iter().enumerate() {
+        let col = col as u16;
+        let field_type = FieldType::from(field.field_type);
+        if matches!(
+          field_type,
+          FieldType::CreatedTime | FieldType::LastEditedTime
+        ) {
+          let timestamp = if field_type == FieldType::CreatedTime {
+            row.created_at
+          } else {
+            row.modified_at
+          };
+          // The timestamps are shown in the local timezone
+          let date_time = Local
+            .timestamp_opt(timestamp, 0)
+            .single()
+            .map(|date_time| date_time.naive_local());
+          write_date_time(worksheet, row_num, col, date_time, &date_time_format)
+            .map_err(internal_error)?;
+          continue;
+        }
+
+        let cell = match row.cells.get(&field.id) {
+          None => continue,
+          Some(cell) => cell,
+        };
+        match field_type {
+          FieldType::Number => {
+            let number = NumberCellData::from(cell).0;
+            if number.is_empty() {
+              continue;
+            }
+            // The numbers that can't be parsed are written as they are shown
+            match number.parse::<f64>() {
+              Ok(number) => worksheet.write_number(row_num, col, number),
+              Err(_) => worksheet.write_string(
+                row_num,
+                col,
+                stringify_cell_data(cell, &field_type, &field_type, field),
+              ),
+            }
+            .map_err(internal_error)?;
+          },
+          FieldType::Checkbox => {
+            worksheet
+              .write_boolean(row_num, col, CheckboxCellData::from(cell).is_check())
+              .map_err(internal_error)?;
+          },
+          FieldType::DateTime => {
+            let date = DateCellData::from(cell);
+            if let Some(timestamp) = date.timestamp {
+              let format = if date.include_time {
+                &date_time_format
+              } else {
+                &date_format
+              };
+              let date_time = field
+                .get_type_option::<DateTypeOption>(field_type)
+                .unwrap_or_default()
+                .local_date_time(timestamp)
+                // The dates without time are written at midnight, so they are read back as dates
+                .map(|date_time| {
+                  if date.include_time {
+                    date_time
+                  } else {
+                    date_time.date().and_time(NaiveTime::MIN)
+                  }
+                });
+              write_date_time(worksheet, row_num, col, date_time, format)
+                .map_err(internal_error)?;
+            }
+          },
+          _ => {
+            let content = stringify_cell_data(cell, &field_type, &field_type, field);
+            if !content.is_empty() {
+              worksheet
+                .write_string(row_num, col, content)
+                .map_err(internal_error)?;
+            }
+          },
+        }
+      }
+    }
+
+    let validations = if rows.is_empty() {
+      vec![]
+    } else {
+      fields
+        .iter()
+        .enumerate()
+        .filter_map(|(col, field)| select_option_validation(field, col as u16, rows.len() as u32))
+        .collect()
+    };
+    let workbook = workbook.save_to_buffer().map_err(internal_error)?;
+    add_list_validations(workbook, &validations).map_err(internal_error)
+  }
+}
+
+/// Writes the date time as it's shown in the timezone of its field, as the date times of the
+/// spreadsheets have no timezone.
+fn write_date_time(
+  worksheet: &mut Worksheet,
+  row_num: u32,
+  col: u16,
+  date_time: Option<NaiveDateTime>,
+  format: &Format,
+) -> Result<(), XlsxError> {
+  let date_time =
+    date_time.and_then(|date_time| ExcelDateTime::from_timestamp(date_time.timestamp()).ok());
+  if let Some(date_time) = date_time {
+    worksheet.write_datetime_with_format(row_num, col, date_time, format)?;
+  }
+  Ok(())
+}
+
+/// Returns the list of the options of the select field for the rows of the column. The list of a
+/// multi select field doesn't reject the other values, so several options can be entered in a
+/// cell. The list is skipped if it can't be written.
+fn select_option_validation(field: &Field, col: u16, row_count: u32) -> Option<ListValidation> {
+  let field_type = FieldType::from(field.field_type);
+  let options = match field_type {
+    FieldType::SingleSelect => {
+      field
+        .get_type_option::<SingleSelectTypeOption>(&field_type)?
+        .options
+    },
+    FieldType::MultiSelect => {
+      field
+        .get_type_option::<MultiSelectTypeOption>(&field_type)?
+        .options
+    },
+    _ => return None,
+  };
+  let names = options
+    .into_iter()
+    .map(|option| option.name)
+    .collect::<Vec<String>>();
+  ListValidation::new(
+    col,
+    1,
+    row_count,
+    names,
+    field_type == FieldType::SingleSelect,
+  )
+}
+
+fn valid_sheet_name(name: &str) -> String {
+  let name = name
+    .chars()
+    .filter(|c| !INVALID_SHEET_NAME_CHARS.contains(c))
+    .take(MAX_SHEET_NAME_LEN)
+    .collect::<String>();
+  let name = name.trim_matches('\'').trim();
+  if name.is_empty() {
+    "Sheet1".to_string()
+  } else {
+    name.to_string()
+  }
+}
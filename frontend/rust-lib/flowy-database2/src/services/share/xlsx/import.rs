@@ -0,0 +1,120 @@
+use std::io::Cursor;
+
+use calamine::{open_workbook_from_rs, Data, Reader, Xlsx};
+use chrono::{NaiveDateTime, NaiveTime};
+use collab_database::database::gen_database_view_id;
+use collab_database::views::CreateDatabaseParams;
+
+use flowy_error::{FlowyError, FlowyResult};
+
+use crate::entities::FieldType;
+use crate::services::share::csv::{CSVImportOptions, CSVImporter};
+
+/// The database imported from a sheet of the workbook
+pub struct XLSXSheet {
+  pub name: String,
+  pub params: CreateDatabaseParams,
+}
+
+pub struct XLSXImporter;
+impl XLSXImporter {
+  /// Returns the params to create a grid for each sheet of the workbook that has data. The first
+  /// row of a sheet is the names of the fields. The first sheet is imported as the view of the
+  /// `view_id`, the other sheets get new view ids.
+  ///
+  /// The columns whose cells all have the same spreadsheet type are imported as the field type of
+  /// it, like the numbers as a number field. The field types of the other columns are inferred
+  /// from their text as the ones of the CSV columns.
+  pub fn import_xlsx_from_bytes(
+    &self,
+    view_id: String,
+    bytes: Vec<u8>,
+  ) -> FlowyResult<Vec<XLSXSheet>> {
+    let mut workbook: Xlsx<_> = open_workbook_from_rs(Cursor::new(bytes))
+      .map_err(|err| FlowyError::invalid_data().with_context(err))?;
+    let mut sheets = vec![];
+    for name in workbook.sheet_names() {
+      let range = workbook
+        .worksheet_range(&name)
+        .map_err(|err| FlowyError::invalid_data().with_context(err))?;
+      let mut records = range.rows();
+      let headers = match records.next() {
+        None => continue,
+        Some(headers) => headers.iter().map(cell_text).collect::<Vec<String>>(),
+      };
+      let records = records.collect::<Vec<&[Data]>>();
+      let options = CSVImportOptions {
+        field_types: (1..headers.len())
+          .filter_map(|index| {
+            column_field_type(&records, index).map(|field_type| (index, field_type))
+          })
+          .collect(),
+        ..Default::default()
+      };
+      let rows = records
+        .iter()
+        .map(|record| record.iter().map(cell_text).collect())
+        .collect();
+
+      let sheet_view_id = if sheets.is_empty() {
+        view_id.clone()
+      } else {
+        gen_database_view_id()
+      };
+      let mut params = CSVImporter.import_records(&sheet_view_id, &headers, rows, &options)?;
+      params.name = name.clone();
+      sheets.push(XLSXSheet { name, params });
+    }
+
+    if sheets.is_empty() {
+      return Err(FlowyError::invalid_data().with_context("The workbook has no data"));
+    }
+    Ok(sheets)
+  }
+}
+
+/// Returns the field type of the column if all its cells have the same spreadsheet type.
+fn column_field_type(records: &[&[Data]], index: usize) -> Option<FieldType> {
+  let mut column_field_type = None;
+  for value in records.iter().filter_map(|record| record.get(index)) {
+    let field_type = match value {
+      Data::Empty => continue,
+      Data::Bool(_) => FieldType::Checkbox,
+      Data::Int(_) | Data::Float(_) => FieldType::Number,
+      Data::DateTime(_) | Data::DateTimeIso(_) => FieldType::DateTime,
+      _ => return None,
+    };
+    match &column_field_type {
+      None => column_field_type = Some(field_type),
+      Some(column_field_type) if column_field_type == &field_type => {},
+      Some(_) => return None,
+    }
+  }
+  column_field_type
+}
+
+/// Returns the text of the cell that is converted to the cell of the field type, the dates are
+/// written in the ISO format. The time of a date is kept if it's not midnight.
+fn cell_text(data: &Data) -> String {
+  match data {
+    Data::Int(value) => value.to_string(),
+    Data::Float(value) => value.to_string(),
+    Data::String(value) => value.clone(),
+    Data::Bool(value) => if *value { "Yes" } else { "No" }.to_string(),
+    Data::DateTime(value) => value.as_datetime().map(date_time_text).unwrap_or_default(),
+    Data::DateTimeIso(value) => NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+      .map(date_time_text)
+      // The value is a date without time
+      .unwrap_or_else(|_| value.chars().take(10).collect()),
+    Data::DurationIso(value) => value.clone(),
+    Data::Error(_) | Data::Empty => "".to_string(),
+  }
+}
+
+fn date_time_text(date_time: NaiveDateTime) -> String {
+  if date_time.time() == NaiveTime::MIN {
+    date_time.format("%Y-%m-%d").to_string()
+  } else {
+    date_time.format("%Y-%m-%d %H:%M:%S").to_string()
+  }
+}
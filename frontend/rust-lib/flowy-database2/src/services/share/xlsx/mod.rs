@@ -0,0 +1,6 @@
+mod export;
+mod import;
+mod validation;
+
+pub use export::*;
+pub use import::*;
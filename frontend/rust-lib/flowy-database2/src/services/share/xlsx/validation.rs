@@ -0,0 +1,121 @@
+use std::io::{Cursor, Read, Write};
+
+use rust_xlsxwriter::column_number_to_name;
+use zip::result::ZipResult;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// The path of the worksheet in the workbook written by the export, which has a single sheet
+const WORKSHEET_PATH: &str = "xl/worksheets/sheet1.xml";
+/// The spreadsheets limit the list of the values to 255 characters, including the separators
+const MAX_LIST_LEN: usize = 255;
+
+/// A list of the values that the cells of a column can be picked from
+pub(crate) struct ListValidation {
+  col: u16,
+  first_row: u32,
+  last_row: u32,
+  values: Vec<String>,
+  /// Whether the values that are not in the list are rejected
+  strict: bool,
+}
+
+impl ListValidation {
+  /// Returns None if the values can't be written as a list. The values are separated by
+  /// commas, so they can't contain one, and the list can't be longer than 255 characters.
+  pub(crate) fn new(
+    col: u16,
+    first_row: u32,
+    last_row: u32,
+    values: Vec<String>,
+    strict: bool,
+  ) -> Option<Self> {
+    if values.is_empty() || values.iter().any(|value| value.contains(',')) {
+      return None;
+    }
+    let len = values
+      .iter()
+      .map(|value| value.chars().count())
+      .sum::<usize>()
+      + values.len()
+      - 1;
+    if len > MAX_LIST_LEN {
+      return None;
+    }
+    Some(Self {
+      col,
+      first_row,
+      last_row,
+      values,
+      strict,
+    })
+  }
+
+  fn to_xml(&self) -> String {
+    let col = column_number_to_name(self.col);
+    // The quotes of the list are escaped by doubling them
+    let list = escape_xml(&self.values.join(",").replace('"', "\"\""));
+    format!(
+      r#"<dataValidation type="list" allowBlank="1" showErrorMessage="{}" sqref="{}{}:{}{}"><formula1>"{}"</formula1></dataValidation>"#,
+      u8::from(self.strict),
+      col,
+      self.first_row + 1,
+      col,
+      self.last_row + 1,
+      list
+    )
+  }
+}
+
+/// Adds the lists to the worksheet of the workbook. The writer of the workbook doesn't support
+/// the data validations, so they are inserted in the XML of the worksheet.
+pub(crate) fn add_list_validations(
+  workbook: Vec<u8>,
+  validations: &[ListValidation],
+) -> ZipResult<Vec<u8>> {
+  if validations.is_empty() {
+    return Ok(workbook);
+  }
+  let validations_xml = format!(
+    r#"<dataValidations count="{}">{}</dataValidations>"#,
+    validations.len(),
+    validations
+      .iter()
+      .map(|validation| validation.to_xml())
+      .collect::<String>()
+  );
+
+  let mut archive = ZipArchive::new(Cursor::new(workbook))?;
+  let mut writer = ZipWriter::new(Cursor::new(vec![]));
+  for index in 0..archive.len() {
+    let mut file = archive.by_index(index)?;
+    if file.name() != WORKSHEET_PATH {
+      writer.raw_copy_file(file)?;
+      continue;
+    }
+
+    let mut xml = String::new();
+    file.read_to_string(&mut xml)?;
+    // The data validations follow the cells. The worksheet has no auto filter, merged cells or
+    // conditional formats, which would come between them.
+    let xml = xml.replacen(
+      "</sheetData>",
+      &format!("</sheetData>{}", validations_xml),
+      1,
+    );
+    writer.start_file(
+      WORKSHEET_PATH,
+      FileOptions::default().compression_method(CompressionMethod::Deflated),
+    )?;
+    writer.write_all(xml.as_bytes())?;
+  }
+  Ok(writer.finish()?.into_inner())
+}
+
+fn escape_xml(content: &str) -> String {
+  content
+    .replace('&', "&amp;")
+    .replace('<', "&lt;")
+    .replace('>', "&gt;")
+    .replace('"', "&quot;")
+}
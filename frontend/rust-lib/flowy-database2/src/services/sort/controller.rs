@@ -1,10 +1,10 @@
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 use std::sync::Arc;
 
 use collab_database::fields::Field;
-use collab_database::rows::{Cell, Row, RowDetail, RowId};
+use collab_database::rows::{Row, RowDetail, RowId};
 use rayon::prelude::ParallelSliceMut;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
@@ -13,14 +13,14 @@ use flowy_error::FlowyResult;
 use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
 use lib_infra::future::Fut;
 
-use crate::entities::FieldType;
 use crate::entities::SortChangesetNotificationPB;
 use crate::services::cell::CellCache;
 use crate::services::database_view::{DatabaseViewChanged, DatabaseViewChangedNotifier};
-use crate::services::field::{default_order, TypeOptionCellExt};
-use crate::services::sort::{
-  ReorderAllRowsResult, ReorderSingleRowResult, Sort, SortChangeset, SortCondition,
+use crate::services::field::{
+  cmp_select_option_position, default_order, select_type_option_from_field, SelectOption,
+  SelectOptionIds, TypeOptionCellDataHandler, TypeOptionCellExt,
 };
+use crate::services::sort::{ReorderAllRowsResult, ReorderSingleRowResult, Sort, SortChangeset};
 
 pub trait SortDelegate: Send + Sync {
   fn get_sort(&self, view_id: &str, sort_id: &str) -> Fut<Option<Arc<Sort>>>;
@@ -156,10 +156,20 @@ impl SortController {
     }
 
     let fields = self.delegate.get_fields(&self.view_id, None).await;
-    for sort in self.sorts.iter().rev() {
-      rows
-        .par_sort_by(|left, right| cmp_row(&left.row, &right.row, sort, &fields, &self.cell_cache));
-    }
+    let row_sorters = self
+      .sorts
+      .iter()
+      .filter_map(|sort| RowSorter::new(sort, &fields, &self.cell_cache, rows))
+      .collect::<Vec<_>>();
+    // The rows that are equal by all the sorts keep the order of their creation. The sort is
+    // stable, so the rows that are created at the same time keep their current order.
+    rows.par_sort_by(|left, right| {
+      row_sorters
+        .iter()
+        .map(|row_sorter| row_sorter.cmp(&left.row, &right.row))
+        .find(|order| order.is_ne())
+        .unwrap_or_else(|| left.row.created_at.cmp(&right.row.created_at))
+    });
     rows.iter().enumerate().for_each(|(index, row_detail)| {
       self
         .row_index_cache
@@ -233,51 +243,86 @@ impl SortController {
   }
 }
 
-fn cmp_row(
-  left: &Row,
-  right: &Row,
-  sort: &Arc<Sort>,
-  fields: &[Arc<Field>],
-  cell_data_cache: &CellCache,
-) -> Ordering {
-  let field_type = sort.field_type.clone();
-  match fields
-    .iter()
-    .find(|field_rev| field_rev.id == sort.field_id)
-  {
-    None => default_order(),
-    Some(field_rev) => cmp_cell(
-      left.cells.get(&sort.field_id),
-      right.cells.get(&sort.field_id),
-      field_rev,
-      field_type,
-      cell_data_cache,
-      sort.condition,
-    ),
-  }
+/// Compares the rows by a sort. The handler of the field and the rows whose cells are empty are
+/// resolved once for the sorted rows, so the comparisons don't decode the cells again.
+struct RowSorter<'a> {
+  sort: &'a Sort,
+  field: &'a Field,
+  handler: Box<dyn TypeOptionCellDataHandler>,
+  /// The options of the select option field if the rows are sorted by the order of its options
+  options: Option<Vec<SelectOption>>,
+  empty_row_ids: HashSet<RowId>,
 }
 
-fn cmp_cell(
-  left_cell: Option<&Cell>,
-  right_cell: Option<&Cell>,
-  field: &Arc<Field>,
-  field_type: FieldType,
-  cell_data_cache: &CellCache,
-  sort_condition: SortCondition,
-) -> Ordering {
-  match TypeOptionCellExt::new_with_cell_data_cache(field.as_ref(), Some(cell_data_cache.clone()))
-    .get_type_option_cell_data_handler(&field_type)
-  {
-    None => default_order(),
-    Some(handler) => {
-      let cal_order = || {
-        let order =
-          handler.handle_cell_compare(left_cell, right_cell, field.as_ref(), sort_condition);
-        Option::<Ordering>::Some(order)
-      };
+impl<'a> RowSorter<'a> {
+  /// Returns None if the field of the sort doesn't exist, in which case the sort is ignored.
+  fn new(
+    sort: &'a Sort,
+    fields: &'a [Arc<Field>],
+    cell_data_cache: &CellCache,
+    rows: &[Arc<RowDetail>],
+  ) -> Option<Self> {
+    let field = fields.iter().find(|field| field.id == sort.field_id)?;
+    let handler =
+      TypeOptionCellExt::new_with_cell_data_cache(field.as_ref(), Some(cell_data_cache.clone()))
+        .get_type_option_cell_data_handler(&sort.field_type)?;
+    let options = if sort.by_option_order && sort.field_type.is_select_option() {
+      select_type_option_from_field(field)
+        .ok()
+        .map(|type_option| type_option.options().clone())
+    } else {
+      None
+    };
+    let empty_row_ids = rows
+      .iter()
+      .filter(|row_detail| {
+        handler.handle_is_sort_empty(row_detail.row.cells.get(&field.id), field.as_ref())
+      })
+      .map(|row_detail| row_detail.row.id.clone())
+      .collect();
+    Some(Self {
+      sort,
+      field: field.as_ref(),
+      handler,
+      options,
+      empty_row_ids,
+    })
+  }
 
-      cal_order().unwrap_or_else(default_order)
-    },
+  fn cmp(&self, left: &Row, right: &Row) -> Ordering {
+    // The empty cells are placed first or last regardless of the sort condition
+    let empty_order = if self.sort.empty_first {
+      Ordering::Less
+    } else {
+      Ordering::Greater
+    };
+    match (
+      self.empty_row_ids.contains(&left.id),
+      self.empty_row_ids.contains(&right.id),
+    ) {
+      (true, true) => default_order(),
+      (true, false) => empty_order,
+      (false, true) => empty_order.reverse(),
+      (false, false) => {
+        let left_cell = left.cells.get(&self.field.id);
+        let right_cell = right.cells.get(&self.field.id);
+        match (&self.options, left_cell, right_cell) {
+          (Some(options), Some(left_cell), Some(right_cell)) => {
+            let order = cmp_select_option_position(
+              options,
+              &SelectOptionIds::from(left_cell),
+              &SelectOptionIds::from(right_cell),
+            );
+            self.sort.condition.evaluate_order(order)
+          },
+          _ => {
+            self
+              .handler
+              .handle_cell_compare(left_cell, right_cell, self.field, self.sort.condition)
+          },
+        }
+      },
+    }
   }
 }
 
@@ -13,12 +13,19 @@ pub struct Sort {
   pub field_id: String,
   pub field_type: FieldType,
   pub condition: SortCondition,
+  /// Sorts the select option fields by the positions of the options in the type option instead
+  /// of their names. It's ignored by the other field types.
+  pub by_option_order: bool,
+  /// Puts the rows whose cells are empty before the others, regardless of the condition.
+  pub empty_first: bool,
 }
 
 const SORT_ID: &str = "id";
 const FIELD_ID: &str = "field_id";
 const FIELD_TYPE: &str = "ty";
 const SORT_CONDITION: &str = "condition";
+const SORT_BY_OPTION_ORDER: &str = "by_option_order";
+const SORT_EMPTY_FIRST: &str = "empty_first";
 
 impl TryFrom<SortMap> for Sort {
   type Error = anyhow::Error;
@@ -33,11 +40,17 @@ impl TryFrom<SortMap> for Sort {
         let condition =
           SortCondition::try_from(value.get_i64_value(SORT_CONDITION).unwrap_or_default())
             .unwrap_or_default();
+        let by_option_order = value
+          .get_bool_value(SORT_BY_OPTION_ORDER)
+          .unwrap_or_default();
+        let empty_first = value.get_bool_value(SORT_EMPTY_FIRST).unwrap_or_default();
         Ok(Self {
           id,
           field_id,
           field_type,
           condition,
+          by_option_order,
+          empty_first,
         })
       },
       _ => {
@@ -54,6 +67,8 @@ impl From<Sort> for SortMap {
       .insert_str_value(FIELD_ID, data.field_id)
       .insert_i64_value(FIELD_TYPE, data.field_type.into())
       .insert_i64_value(SORT_CONDITION, data.condition.value())
+      .insert_bool_value(SORT_BY_OPTION_ORDER, data.by_option_order)
+      .insert_bool_value(SORT_EMPTY_FIRST, data.empty_first)
       .build()
   }
 }
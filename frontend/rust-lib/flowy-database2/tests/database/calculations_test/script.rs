@@ -0,0 +1,134 @@
+use flowy_database2::entities::{
+  CalculationType, FieldType, NumberFilterConditionPB, NumberFilterPB, UpdateFilterParams,
+  UpdateFilterPayloadPB,
+};
+use flowy_database2::services::field_settings::FieldSettingsChangesetParams;
+
+use crate::database::database_editor::DatabaseEditorTest;
+
+pub enum CalculationScript {
+  UpdateCalculation {
+    field_type: FieldType,
+    calculation_type: CalculationType,
+  },
+  AssertUpdateCalculationFailed {
+    field_type: FieldType,
+    calculation_type: CalculationType,
+  },
+  AssertCalculation {
+    field_type: FieldType,
+    expected: String,
+  },
+  AssertNumberOfCalculations {
+    expected: usize,
+  },
+  UpdateNumberCell {
+    row_index: usize,
+    content: String,
+  },
+  CreateNumberFilter {
+    condition: NumberFilterConditionPB,
+    content: String,
+  },
+}
+
+pub struct DatabaseCalculationTest {
+  inner: DatabaseEditorTest,
+}
+
+impl DatabaseCalculationTest {
+  pub async fn new() -> Self {
+    let inner = DatabaseEditorTest::new_grid().await;
+    Self { inner }
+  }
+
+  pub async fn run_scripts(&mut self, scripts: Vec<CalculationScript>) {
+    for script in scripts {
+      self.run_script(script).await;
+    }
+  }
+
+  pub async fn run_script(&mut self, script: CalculationScript) {
+    match script {
+      CalculationScript::UpdateCalculation {
+        field_type,
+        calculation_type,
+      } => {
+        let params = self.calculation_params(field_type, calculation_type);
+        self
+          .editor
+          .update_field_settings_with_changeset(params)
+          .await
+          .unwrap();
+      },
+      CalculationScript::AssertUpdateCalculationFailed {
+        field_type,
+        calculation_type,
+      } => {
+        let params = self.calculation_params(field_type, calculation_type);
+        let result = self
+          .editor
+          .update_field_settings_with_changeset(params)
+          .await;
+        assert!(result.is_err());
+      },
+      CalculationScript::AssertCalculation {
+        field_type,
+        expected,
+      } => {
+        let field = self.get_first_field(field_type);
+        let calculations = self.editor.get_calculations(&self.view_id).await.unwrap();
+        let calculation = calculations
+          .items
+          .into_iter()
+          .find(|calculation| calculation.field_id == field.id)
+          .unwrap();
+        assert_eq!(calculation.value, expected);
+      },
+      CalculationScript::AssertNumberOfCalculations { expected } => {
+        let calculations = self.editor.get_calculations(&self.view_id).await.unwrap();
+        assert_eq!(calculations.items.len(), expected);
+      },
+      CalculationScript::UpdateNumberCell { row_index, content } => {
+        let field = self.get_first_field(FieldType::Number);
+        let row_id = self.row_details[row_index].row.id.clone();
+        self.update_cell(&field.id, row_id, content).await.unwrap();
+      },
+      CalculationScript::CreateNumberFilter { condition, content } => {
+        let field = self.get_first_field(FieldType::Number);
+        let number_filter = NumberFilterPB { condition, content };
+        let payload = UpdateFilterPayloadPB::new(&self.view_id, &field, number_filter);
+        let params: UpdateFilterParams = payload.try_into().unwrap();
+        self.editor.create_or_update_filter(params).await.unwrap();
+      },
+    }
+  }
+
+  fn calculation_params(
+    &self,
+    field_type: FieldType,
+    calculation_type: CalculationType,
+  ) -> FieldSettingsChangesetParams {
+    let field = self.get_first_field(field_type);
+    FieldSettingsChangesetParams {
+      view_id: self.view_id.clone(),
+      field_id: field.id,
+      visibility: None,
+      calculation_type: Some(calculation_type),
+    }
+  }
+}
+
+impl std::ops::Deref for DatabaseCalculationTest {
+  type Target = DatabaseEditorTest;
+
+  fn deref(&self) -> &Self::Target {
+    &self.inner
+  }
+}
+
+impl std::ops::DerefMut for DatabaseCalculationTest {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.inner
+  }
+}
@@ -0,0 +1,132 @@
+use flowy_database2::entities::{CalculationType, FieldType, NumberFilterConditionPB};
+
+use crate::database::calculations_test::script::CalculationScript::*;
+use crate::database::calculations_test::script::DatabaseCalculationTest;
+
+#[tokio::test]
+async fn calculation_number_field_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+  // The numbers of the rows are 1, 2, 3, 14, empty, 5 and the last row doesn't have the cell.
+  for (calculation_type, expected) in [
+    (CalculationType::Count, "7"),
+    (CalculationType::CountEmpty, "2"),
+    (CalculationType::CountUnique, "5"),
+    (CalculationType::Sum, "$25"),
+    (CalculationType::Average, "$5"),
+    (CalculationType::Median, "$3"),
+    (CalculationType::Min, "$1"),
+    (CalculationType::Max, "$14"),
+  ] {
+    let scripts = vec![
+      UpdateCalculation {
+        field_type: FieldType::Number,
+        calculation_type,
+      },
+      AssertCalculation {
+        field_type: FieldType::Number,
+        expected: expected.to_string(),
+      },
+    ];
+    test.run_scripts(scripts).await;
+  }
+}
+
+#[tokio::test]
+async fn calculation_text_and_checkbox_field_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+  let scripts = vec![
+    UpdateCalculation {
+      field_type: FieldType::RichText,
+      calculation_type: CalculationType::CountUnique,
+    },
+    UpdateCalculation {
+      field_type: FieldType::Checkbox,
+      calculation_type: CalculationType::PercentChecked,
+    },
+    AssertNumberOfCalculations { expected: 2 },
+    AssertCalculation {
+      field_type: FieldType::RichText,
+      expected: "5".to_string(),
+    },
+    AssertCalculation {
+      field_type: FieldType::Checkbox,
+      expected: "42.86%".to_string(),
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calculation_over_filtered_rows_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+  let scripts = vec![
+    UpdateCalculation {
+      field_type: FieldType::Number,
+      calculation_type: CalculationType::Sum,
+    },
+    CreateNumberFilter {
+      condition: NumberFilterConditionPB::LessThan,
+      content: "3".to_string(),
+    },
+    AssertCalculation {
+      field_type: FieldType::Number,
+      expected: "$3".to_string(),
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calculation_after_updating_cell_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+  let scripts = vec![
+    UpdateCalculation {
+      field_type: FieldType::Number,
+      calculation_type: CalculationType::Sum,
+    },
+    UpdateNumberCell {
+      row_index: 0,
+      content: "10".to_string(),
+    },
+    AssertCalculation {
+      field_type: FieldType::Number,
+      expected: "$34".to_string(),
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calculation_remove_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+  let scripts = vec![
+    UpdateCalculation {
+      field_type: FieldType::Number,
+      calculation_type: CalculationType::Sum,
+    },
+    AssertNumberOfCalculations { expected: 1 },
+    UpdateCalculation {
+      field_type: FieldType::Number,
+      calculation_type: CalculationType::None,
+    },
+    AssertNumberOfCalculations { expected: 0 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calculation_not_supported_by_field_type_test() {
+  let mut test = DatabaseCalculationTest::new().await;
+  let scripts = vec![
+    AssertUpdateCalculationFailed {
+      field_type: FieldType::RichText,
+      calculation_type: CalculationType::Sum,
+    },
+    AssertUpdateCalculationFailed {
+      field_type: FieldType::Number,
+      calculation_type: CalculationType::PercentChecked,
+    },
+    AssertNumberOfCalculations { expected: 0 },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -1,16 +1,20 @@
 use std::time::Duration;
 
-use flowy_database2::entities::{CellChangesetPB, FieldType};
+use collab_database::rows::RowId;
+
+use flowy_database2::entities::{CellChangesetPB, CellIdParams, FieldType, UploadFileParams};
 use flowy_database2::services::cell::ToCellChangeset;
 use flowy_database2::services::field::checklist_type_option::ChecklistCellChangeset;
 use flowy_database2::services::field::{
-  DateCellData, MultiSelectTypeOption, SelectOptionCellChangeset, SingleSelectTypeOption,
-  StrCellData, URLCellData,
+  DateCellData, FileAttachment, FilesCellChangeset, FilesCellData, FormulaCellData,
+  MultiSelectTypeOption, PersonCellChangeset, RelationCellChangeset, SelectOptionCellChangeset,
+  SingleSelectTypeOption, StrCellData, URLCellData,
 };
 
 use crate::database::cell_test::script::CellScript::UpdateCell;
 use crate::database::cell_test::script::DatabaseCellTest;
 use crate::database::field_test::util::make_date_cell_string;
+use crate::database::mock_data::LUCAS;
 
 #[tokio::test]
 async fn grid_cell_update() {
@@ -22,7 +26,11 @@ async fn grid_cell_update() {
   for (_, row_detail) in rows.iter().enumerate() {
     for field in &fields {
       let field_type = FieldType::from(field.field_type);
-      if field_type == FieldType::LastEditedTime || field_type == FieldType::CreatedTime {
+      if field_type == FieldType::LastEditedTime
+        || field_type == FieldType::CreatedTime
+        || field_type == FieldType::Formula
+        || field_type == FieldType::Lookup
+      {
         continue;
       }
       let cell_changeset = match field_type {
@@ -50,6 +58,26 @@ async fn grid_cell_update() {
         .to_cell_changeset_str(),
         FieldType::Checkbox => "1".to_string(),
         FieldType::URL => "1".to_string(),
+        FieldType::Relation => RelationCellChangeset {
+          inserted_row_ids: vec!["related_row".to_string()],
+          ..Default::default()
+        }
+        .to_cell_changeset_str(),
+        FieldType::Person => PersonCellChangeset {
+          inserted_user_ids: vec![LUCAS],
+          ..Default::default()
+        }
+        .to_cell_changeset_str(),
+        FieldType::Files => FilesCellChangeset {
+          inserted_files: vec![FileAttachment::new(
+            "invoice.pdf".to_string(),
+            "application/pdf".to_string(),
+            1024,
+            "file:///invoice.pdf".to_string(),
+          )],
+          ..Default::default()
+        }
+        .to_cell_changeset_str(),
         _ => "".to_string(),
       };
 
@@ -194,3 +222,131 @@ async fn update_updated_at_field_on_other_cell_update() {
     }
   }
 }
+
+#[tokio::test]
+async fn update_cell_recomputes_formula_test() {
+  let mut test = DatabaseCellTest::new().await;
+  let price_field = test.get_first_field(FieldType::Number);
+  let formula_field = test.get_first_field(FieldType::Formula);
+  let row_id = test.row_details[0].row.id.clone();
+
+  // The formula cells of the created rows are computed, the total is price * 2.
+  let cell = test
+    .editor
+    .get_cell(&formula_field.id, &row_id)
+    .await
+    .unwrap();
+  assert_eq!(FormulaCellData::from(&cell).to_text(), "2");
+
+  test
+    .run_script(UpdateCell {
+      changeset: CellChangesetPB {
+        view_id: test.view_id.clone(),
+        row_id: row_id.to_string(),
+        field_id: price_field.id.clone(),
+        cell_changeset: "21".to_string(),
+      },
+      is_err: false,
+    })
+    .await;
+  let cell = test
+    .editor
+    .get_cell(&formula_field.id, &row_id)
+    .await
+    .unwrap();
+  assert_eq!(FormulaCellData::from(&cell).to_text(), "42");
+}
+
+#[tokio::test]
+async fn upload_and_delete_file_test() {
+  let test = DatabaseCellTest::new().await;
+  let files_field = test.get_first_field(FieldType::Files);
+  let row_id = test.row_details[0].row.id.clone();
+  let (file, file_path) = upload_test_file(&test, &files_field.id, &row_id).await;
+  assert_eq!(file.mime, "application/pdf");
+  assert_eq!(file.size, 7);
+
+  // The uploaded file is stored by the local server.
+  let stored_path = url::Url::parse(&file.url).unwrap().to_file_path().unwrap();
+  assert!(stored_path.exists());
+  let cell = test
+    .editor
+    .get_cell(&files_field.id, &row_id)
+    .await
+    .unwrap();
+  assert_eq!(FilesCellData::from(&cell).files, vec![file.clone()]);
+
+  // Removing the file from the cell keeps the stored file while the database is open.
+  let cell_id = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: files_field.id.clone(),
+    row_id: row_id.clone(),
+  };
+  test.editor.delete_file(cell_id, file.id).await.unwrap();
+  assert!(stored_path.exists());
+  let cell = test
+    .editor
+    .get_cell(&files_field.id, &row_id)
+    .await
+    .unwrap();
+  assert!(FilesCellData::from(&cell).files.is_empty());
+
+  // The stored file is deleted after the database is closed.
+  test
+    .sdk
+    .database_manager
+    .close_database_view(&test.view_id)
+    .await
+    .unwrap();
+  test.sdk.database_manager.flush_file_deletions().await;
+  assert!(!stored_path.exists());
+  let _ = std::fs::remove_file(file_path);
+}
+
+#[tokio::test]
+async fn delete_row_keeps_file_of_duplicated_row_test() {
+  let test = DatabaseCellTest::new().await;
+  let files_field = test.get_first_field(FieldType::Files);
+  let row_id = test.row_details[0].row.id.clone();
+  let (file, file_path) = upload_test_file(&test, &files_field.id, &row_id).await;
+  let stored_path = url::Url::parse(&file.url).unwrap().to_file_path().unwrap();
+
+  test
+    .editor
+    .duplicate_row(&test.view_id, None, &row_id)
+    .await;
+  test.editor.delete_row(&test.view_id, &row_id).await;
+  test
+    .sdk
+    .database_manager
+    .close_database_view(&test.view_id)
+    .await
+    .unwrap();
+  test.sdk.database_manager.flush_file_deletions().await;
+  assert!(stored_path.exists());
+  let _ = std::fs::remove_file(file_path);
+}
+
+async fn upload_test_file(
+  test: &DatabaseCellTest,
+  field_id: &str,
+  row_id: &RowId,
+) -> (FileAttachment, std::path::PathBuf) {
+  let workspace_id = test.sdk.get_current_workspace().await.workspace.id;
+  let file_path = std::env::temp_dir().join(format!("{}-invoice.pdf", nanoid::nanoid!(6)));
+  std::fs::write(&file_path, b"invoice").unwrap();
+  let params = UploadFileParams {
+    cell_id: CellIdParams {
+      view_id: test.view_id.clone(),
+      field_id: field_id.to_string(),
+      row_id: row_id.clone(),
+    },
+    file_path: file_path.to_str().unwrap().to_string(),
+  };
+  let file = test
+    .editor
+    .upload_file(params, &workspace_id)
+    .await
+    .unwrap();
+  (file, file_path)
+}
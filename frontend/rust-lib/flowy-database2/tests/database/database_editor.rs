@@ -15,10 +15,10 @@ use flowy_database2::services::field::checklist_type_option::{
   ChecklistCellChangeset, ChecklistTypeOption,
 };
 use flowy_database2::services::field::{
-  CheckboxTypeOption, DateCellChangeset, MultiSelectTypeOption, SelectOption,
+  CheckboxTypeOption, DateCellChangeset, MultiSelectTypeOption, PersonCellData, SelectOption,
   SelectOptionCellChangeset, SingleSelectTypeOption,
 };
-use flowy_database2::services::share::csv::{CSVFormat, ImportResult};
+use flowy_database2::services::share::csv::{CSVFormat, CSVImportOptions, ImportResult};
 use flowy_error::FlowyResult;
 
 use crate::database::mock_data::{
@@ -272,7 +272,26 @@ impl DatabaseEditorTest {
     self
       .sdk
       .database_manager
-      .import_csv(gen_database_view_id(), s, format)
+      .import_csv(
+        gen_database_view_id(),
+        s,
+        format,
+        CSVImportOptions::default(),
+      )
+      .await
+      .unwrap()
+  }
+
+  pub async fn import_file(&self, file_path: String, format: CSVFormat) -> ImportResult {
+    self
+      .sdk
+      .database_manager
+      .import_csv_from_file(
+        gen_database_view_id(),
+        file_path,
+        format,
+        CSVImportOptions::default(),
+      )
       .await
       .unwrap()
   }
@@ -400,6 +419,15 @@ impl<'a> TestRowBuilder<'a> {
     checklist_field.id.clone()
   }
 
+  pub fn insert_person_cell(&mut self, user_ids: Vec<i64>) -> String {
+    let person_field = self.field_with_type(&FieldType::Person);
+    let cell_data = PersonCellData { user_ids };
+    self
+      .cell_build
+      .insert_text_cell(&person_field.id, cell_data.to_string());
+    person_field.id.clone()
+  }
+
   pub fn field_with_type(&self, field_type: &FieldType) -> Field {
     self
       .fields
@@ -86,6 +86,7 @@ impl FieldSettingsTest {
           view_id: self.view_id.clone(),
           field_id,
           visibility,
+          calculation_type: None,
         };
         let _ = self
           .editor
@@ -79,7 +79,11 @@ impl DatabaseFieldTest {
           self.field_count -= 1;
         }
 
-        self.editor.delete_field(&field.id).await.unwrap();
+        self
+          .editor
+          .delete_field(&self.view_id, &field.id)
+          .await
+          .unwrap();
         let fields = self.editor.get_fields(&self.view_id, None);
         assert_eq!(self.field_count, fields.len());
       },
@@ -90,7 +94,7 @@ impl DatabaseFieldTest {
         //
         self
           .editor
-          .switch_to_field_type(&field_id, &new_field_type)
+          .switch_to_field_type(&self.view_id, &field_id, &new_field_type)
           .await
           .unwrap();
       },
@@ -107,6 +107,10 @@ pub fn make_date_cell_string(timestamp: i64) -> String {
     include_time: Some(false),
     is_range: Some(false),
     clear_flag: None,
+    recurrence: None,
+    clear_recurrence: None,
+    reminder_offset: None,
+    clear_reminder: None,
   })
   .unwrap()
 }
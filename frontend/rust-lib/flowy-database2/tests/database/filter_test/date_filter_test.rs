@@ -1,4 +1,4 @@
-use flowy_database2::entities::DateFilterConditionPB;
+use flowy_database2::entities::{DateFilterConditionPB, DateFilterRelativeUnitPB, FieldType};
 
 use crate::database::filter_test::script::FilterScript::*;
 use crate::database::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
@@ -107,3 +107,56 @@ async fn grid_filter_date_within_test() {
   ];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_date_is_this_year_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  // The dates of the rows are in 2022
+  let scripts = vec![
+    CreateRelativeDateFilter {
+      field_type: FieldType::DateTime,
+      condition: DateFilterConditionPB::DateIsThisYear,
+      relative_count: None,
+      relative_unit: DateFilterRelativeUnitPB::Day,
+    },
+    AssertNumberOfVisibleRows { expected: 0 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_created_time_is_today_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_details.len();
+  // The rows are created when the test starts
+  let scripts = vec![
+    CreateRelativeDateFilter {
+      field_type: FieldType::CreatedTime,
+      condition: DateFilterConditionPB::DateIsToday,
+      relative_count: None,
+      relative_unit: DateFilterRelativeUnitPB::Day,
+    },
+    AssertNumberOfVisibleRows {
+      expected: row_count,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_last_edited_time_within_past_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_details.len();
+  let scripts = vec![
+    CreateRelativeDateFilter {
+      field_type: FieldType::LastEditedTime,
+      condition: DateFilterConditionPB::DateWithinPast,
+      relative_count: Some(1),
+      relative_unit: DateFilterRelativeUnitPB::Week,
+    },
+    AssertNumberOfVisibleRows {
+      expected: row_count,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -0,0 +1,130 @@
+use flowy_database2::entities::{
+  CheckboxFilterConditionPB, FieldType, FilterOperatorPB, NumberFilterConditionPB, NumberFilterPB,
+  UpdateFilterParams, UpdateFilterPayloadPB,
+};
+
+use crate::database::filter_test::script::DatabaseFilterTest;
+use crate::database::filter_test::script::FilterScript::*;
+
+#[tokio::test]
+async fn grid_filter_or_group_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let group_id = test.create_filter_group(None, FilterOperatorPB::Or).await;
+  let scripts = vec![
+    CreateNumberFilterInGroup {
+      group_id: group_id.clone(),
+      condition: NumberFilterConditionPB::Equal,
+      content: "1".to_string(),
+    },
+    CreateNumberFilterInGroup {
+      group_id,
+      condition: NumberFilterConditionPB::Equal,
+      content: "3".to_string(),
+    },
+    AssertFilterCount { count: 2 },
+    AssertFilterGroupCount { count: 1 },
+    AssertNumberOfVisibleRows { expected: 2 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_or_group_and_root_filter_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let group_id = test.create_filter_group(None, FilterOperatorPB::Or).await;
+  // (number = 1 OR number = 3) AND checked
+  let scripts = vec![
+    CreateNumberFilterInGroup {
+      group_id: group_id.clone(),
+      condition: NumberFilterConditionPB::Equal,
+      content: "1".to_string(),
+    },
+    CreateNumberFilterInGroup {
+      group_id,
+      condition: NumberFilterConditionPB::Equal,
+      content: "3".to_string(),
+    },
+    CreateCheckboxFilter {
+      condition: CheckboxFilterConditionPB::IsChecked,
+      changed: None,
+    },
+    AssertNumberOfVisibleRows { expected: 1 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_nested_group_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let or_group_id = test.create_filter_group(None, FilterOperatorPB::Or).await;
+  let and_group_id = test
+    .create_filter_group(Some(or_group_id.clone()), FilterOperatorPB::And)
+    .await;
+  // number = 1 OR (number > 2 AND number < 5)
+  let scripts = vec![
+    CreateNumberFilterInGroup {
+      group_id: or_group_id,
+      condition: NumberFilterConditionPB::Equal,
+      content: "1".to_string(),
+    },
+    CreateNumberFilterInGroup {
+      group_id: and_group_id.clone(),
+      condition: NumberFilterConditionPB::GreaterThan,
+      content: "2".to_string(),
+    },
+    CreateNumberFilterInGroup {
+      group_id: and_group_id,
+      condition: NumberFilterConditionPB::LessThan,
+      content: "5".to_string(),
+    },
+    AssertNumberOfVisibleRows { expected: 2 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_delete_group_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_details.len();
+  let or_group_id = test.create_filter_group(None, FilterOperatorPB::Or).await;
+  let and_group_id = test
+    .create_filter_group(Some(or_group_id.clone()), FilterOperatorPB::And)
+    .await;
+  let scripts = vec![
+    CreateNumberFilterInGroup {
+      group_id: or_group_id.clone(),
+      condition: NumberFilterConditionPB::Equal,
+      content: "1".to_string(),
+    },
+    CreateNumberFilterInGroup {
+      group_id: and_group_id,
+      condition: NumberFilterConditionPB::Equal,
+      content: "2".to_string(),
+    },
+    AssertNumberOfVisibleRows { expected: 2 },
+    // Deleting the group deletes its nested group and all of their filters
+    DeleteFilterGroup {
+      group_id: or_group_id,
+    },
+    AssertFilterCount { count: 0 },
+    AssertFilterGroupCount { count: 0 },
+    AssertNumberOfVisibleRows {
+      expected: row_count,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_in_not_exist_group_test() {
+  let test = DatabaseFilterTest::new().await;
+  let field = test.get_first_field(FieldType::Number);
+  let number_filter = NumberFilterPB {
+    condition: NumberFilterConditionPB::Equal,
+    content: "1".to_string(),
+  };
+  let payload =
+    UpdateFilterPayloadPB::new(&test.view_id(), &field, number_filter).with_parent_id("unknown");
+  let params: UpdateFilterParams = payload.try_into().unwrap();
+  assert!(test.editor.create_or_update_filter(params).await.is_err());
+}
@@ -1,4 +1,7 @@
-use flowy_database2::entities::NumberFilterConditionPB;
+use flowy_database2::entities::{
+  FieldType, NumberFilterConditionPB, NumberFilterPB, NumberFilterRangePB, UpdateFilterParams,
+  UpdateFilterPayloadPB,
+};
 
 use crate::database::filter_test::script::FilterScript::*;
 use crate::database::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
@@ -42,7 +45,6 @@ async fn grid_filter_number_is_less_than_test() {
 }
 
 #[tokio::test]
-#[should_panic]
 async fn grid_filter_number_is_less_than_test2() {
   let mut test = DatabaseFilterTest::new().await;
   let row_count = test.row_details.len();
@@ -117,3 +119,90 @@ async fn grid_filter_number_is_not_empty_test() {
   ];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_number_is_between_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_details.len();
+  let expected = 3;
+  let range = NumberFilterRangePB {
+    start: "$2".to_string(),
+    end: "$5".to_string(),
+    start_inclusive: true,
+    end_inclusive: true,
+  };
+  let scripts = vec![
+    CreateNumberFilter {
+      condition: NumberFilterConditionPB::NumberIsBetween,
+      content: range.to_string(),
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_is_between_exclusive_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let range = NumberFilterRangePB {
+    start: "1".to_string(),
+    end: "5".to_string(),
+    start_inclusive: false,
+    end_inclusive: false,
+  };
+  let scripts = vec![
+    CreateNumberFilter {
+      condition: NumberFilterConditionPB::NumberIsBetween,
+      content: range.to_string(),
+      changed: None,
+    },
+    AssertNumberOfVisibleRows { expected: 2 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_is_one_of_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_details.len();
+  let expected = 2;
+  let scripts = vec![
+    CreateNumberFilter {
+      condition: NumberFilterConditionPB::NumberIsOneOf,
+      content: r#"["$1", "$14"]"#.to_string(),
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_invalid_content_test() {
+  let test = DatabaseFilterTest::new().await;
+  let field = test.get_first_field(FieldType::Number);
+  // The values are parsed with the USD format of the field
+  for (content, is_valid) in [
+    (r#"["$1,200", "$1,500"]"#, true),
+    (r#"["$1,200", "abc"]"#, false),
+    ("1, 2", false),
+  ] {
+    let number_filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::NumberIsOneOf,
+      content: content.to_string(),
+    };
+    let params: UpdateFilterParams =
+      UpdateFilterPayloadPB::new(&test.view_id(), &field, number_filter)
+        .try_into()
+        .unwrap();
+    let result = test.editor.create_or_update_filter(params).await;
+    assert_eq!(result.is_ok(), is_valid, "{}", content);
+  }
+}
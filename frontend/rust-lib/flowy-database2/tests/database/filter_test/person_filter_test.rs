@@ -0,0 +1,58 @@
+use flowy_database2::entities::PersonFilterConditionPB;
+
+use crate::database::filter_test::script::DatabaseFilterTest;
+use crate::database::filter_test::script::FilterScript::*;
+use crate::database::mock_data::{LUCAS, NATHAN};
+
+#[tokio::test]
+async fn grid_filter_person_is_empty_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreatePersonFilter {
+      condition: PersonFilterConditionPB::IsEmpty,
+      user_ids: vec![],
+    },
+    AssertNumberOfVisibleRows { expected: 5 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_person_contains_any_of_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreatePersonFilter {
+      condition: PersonFilterConditionPB::ContainsAnyOf,
+      user_ids: vec![NATHAN],
+    },
+    AssertNumberOfVisibleRows { expected: 1 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_person_contains_any_of_multiple_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreatePersonFilter {
+      condition: PersonFilterConditionPB::ContainsAnyOf,
+      user_ids: vec![LUCAS, NATHAN],
+    },
+    AssertNumberOfVisibleRows { expected: 2 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_person_is_me_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  // The signed in user isn't assigned to any of the rows
+  let scripts = vec![
+    CreatePersonFilter {
+      condition: PersonFilterConditionPB::IsMe,
+      user_ids: vec![],
+    },
+    AssertNumberOfVisibleRows { expected: 0 },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -10,7 +10,7 @@ use collab_database::rows::{Row, RowId};
 use futures::TryFutureExt;
 use tokio::sync::broadcast::Receiver;
 
-use flowy_database2::entities::{CheckboxFilterConditionPB, CheckboxFilterPB, ChecklistFilterConditionPB, ChecklistFilterPB, DatabaseViewSettingPB, DateFilterConditionPB, DateFilterPB, DeleteFilterParams, FieldType, FilterPB, NumberFilterConditionPB, NumberFilterPB, SelectOptionConditionPB, SelectOptionFilterPB, SelectOptionPB, TextFilterConditionPB, TextFilterPB, UpdateFilterParams, UpdateFilterPayloadPB};
+use flowy_database2::entities::{CheckboxFilterConditionPB, CheckboxFilterPB, ChecklistFilterConditionPB, ChecklistFilterPB, DatabaseViewSettingPB, DateFilterConditionPB, DateFilterPB, DateFilterRelativeUnitPB, DeleteFilterGroupParams, DeleteFilterParams, FieldType, FilterOperatorPB, FilterPB, NumberFilterConditionPB, NumberFilterPB, PersonFilterConditionPB, PersonFilterPB, SelectOptionConditionPB, SelectOptionFilterPB, SelectOptionPB, TextFilterConditionPB, TextFilterPB, UpdateFilterGroupParams, UpdateFilterParams, UpdateFilterPayloadPB};
 use flowy_database2::services::database_view::DatabaseViewChanged;
 use flowy_database2::services::field::SelectOption;
 use flowy_database2::services::filter::FilterType;
@@ -56,6 +56,11 @@ pub enum FilterScript {
         content: String,
         changed: Option<FilterRowChanged>,
     },
+    CreateNumberFilterInGroup {
+        group_id: String,
+        condition: NumberFilterConditionPB,
+        content: String,
+    },
     CreateCheckboxFilter {
         condition: CheckboxFilterConditionPB,
         changed: Option<FilterRowChanged>,
@@ -67,6 +72,12 @@ pub enum FilterScript {
         timestamp: Option<i64>,
         changed: Option<FilterRowChanged>,
     },
+    CreateRelativeDateFilter {
+        field_type: FieldType,
+        condition: DateFilterConditionPB,
+        relative_count: Option<i64>,
+        relative_unit: DateFilterRelativeUnitPB,
+    },
     CreateMultiSelectFilter {
         condition: SelectOptionConditionPB,
         option_ids: Vec<String>,
@@ -80,6 +91,10 @@ pub enum FilterScript {
         condition: ChecklistFilterConditionPB,
         changed: Option<FilterRowChanged>,
     },
+    CreatePersonFilter {
+        condition: PersonFilterConditionPB,
+        user_ids: Vec<i64>,
+    },
     AssertFilterCount {
         count: i32,
     },
@@ -88,6 +103,12 @@ pub enum FilterScript {
         filter_type: FilterType,
         changed: Option<FilterRowChanged>,
     },
+    DeleteFilterGroup {
+        group_id: String,
+    },
+    AssertFilterGroupCount {
+        count: i32,
+    },
     AssertFilterContent {
         filter_id: String,
         condition: i64,
@@ -125,6 +146,17 @@ impl DatabaseFilterTest {
         self.editor.get_all_filters(&self.view_id).await.items
     }
 
+    /// Creates a filter group and returns its id.
+    pub async fn create_filter_group(&self, parent_id: Option<String>, operator: FilterOperatorPB) -> String {
+        let params = UpdateFilterGroupParams {
+            view_id: self.view_id(),
+            group_id: None,
+            parent_id,
+            operator: operator.into(),
+        };
+        self.editor.create_or_update_filter_group(params).await.unwrap().id
+    }
+
     pub async fn run_scripts(&mut self, scripts: Vec<FilterScript>) {
         for script in scripts {
             self.run_script(script).await;
@@ -156,7 +188,8 @@ impl DatabaseFilterTest {
                 let field = self.get_first_field(FieldType::RichText);
                 let text_filter= TextFilterPB {
                     condition,
-                    content
+                    content,
+                    ..Default::default()
                 };
                 let payload =
                     UpdateFilterPayloadPB::new(
@@ -173,7 +206,8 @@ impl DatabaseFilterTest {
                     filter_id: Some(filter.id),
                     field_type: filter.field_type.into(),
                     condition: condition as i64,
-                    content
+                    content,
+                    parent_id: filter.parent_id,
                 };
                 self.editor.create_or_update_filter(params).await.unwrap();
             }
@@ -191,6 +225,16 @@ impl DatabaseFilterTest {
                         &field, number_filter);
                 self.insert_filter(payload).await;
             }
+            FilterScript::CreateNumberFilterInGroup { group_id, condition, content } => {
+                let field = self.get_first_field(FieldType::Number);
+                let number_filter = NumberFilterPB {
+                    condition,
+                    content
+                };
+                let payload =
+                    UpdateFilterPayloadPB::new(&self.view_id(), &field, number_filter).with_parent_id(&group_id);
+                self.insert_filter(payload).await;
+            }
             FilterScript::CreateCheckboxFilter {condition, changed} => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 self.assert_future_changed(changed).await;
@@ -210,13 +254,26 @@ impl DatabaseFilterTest {
                     condition,
                     start,
                     end,
-                    timestamp
+                    timestamp,
+                    ..Default::default()
                 };
 
                 let payload =
                     UpdateFilterPayloadPB::new(&self.view_id(), &field, date_filter);
                 self.insert_filter(payload).await;
             }
+            FilterScript::CreateRelativeDateFilter { field_type, condition, relative_count, relative_unit } => {
+                let field = self.get_first_field(field_type);
+                let date_filter = DateFilterPB {
+                    condition,
+                    relative_count,
+                    relative_unit,
+                    ..Default::default()
+                };
+                let payload =
+                    UpdateFilterPayloadPB::new(&self.view_id(), &field, date_filter);
+                self.insert_filter(payload).await;
+            }
             FilterScript::CreateMultiSelectFilter { condition, option_ids} => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 let field = self.get_first_field(FieldType::MultiSelect);
@@ -244,10 +301,26 @@ impl DatabaseFilterTest {
                     UpdateFilterPayloadPB::new(& self.view_id(), &field, filter);
                 self.insert_filter(payload).await;
             }
+            FilterScript::CreatePersonFilter { condition, user_ids } => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                let field = self.get_first_field(FieldType::Person);
+                let filter = PersonFilterPB { condition, user_ids };
+                let payload =
+                    UpdateFilterPayloadPB::new(&self.view_id(), &field, filter);
+                self.insert_filter(payload).await;
+            }
             FilterScript::AssertFilterCount { count } => {
                 let filters = self.editor.get_all_filters(&self.view_id).await.items;
                 assert_eq!(count as usize, filters.len());
             }
+            FilterScript::DeleteFilterGroup { group_id } => {
+                let params = DeleteFilterGroupParams { view_id: self.view_id(), group_id };
+                self.editor.delete_filter_group(params).await.unwrap();
+            }
+            FilterScript::AssertFilterGroupCount { count } => {
+                let filter_groups = self.editor.get_all_filter_groups(&self.view_id).await.items;
+                assert_eq!(count as usize, filter_groups.len());
+            }
             FilterScript::AssertFilterContent { filter_id, condition, content} => {
                 let filter = self.editor.get_filter(&self.view_id, &filter_id).await.unwrap();
                 assert_eq!(&filter.content, &content);
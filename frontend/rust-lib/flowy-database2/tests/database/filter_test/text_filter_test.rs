@@ -1,7 +1,8 @@
 use flowy_database2::entities::{
-  FieldType, TextFilterConditionPB, TextFilterPB, UpdateFilterPayloadPB,
+  FieldType, TextFilterConditionPB, TextFilterPB, UpdateFilterParams, UpdateFilterPayloadPB,
 };
 use flowy_database2::services::filter::FilterType;
+use flowy_error::ErrorCode;
 
 use crate::database::filter_test::script::FilterScript::*;
 use crate::database::filter_test::script::*;
@@ -195,6 +196,7 @@ async fn grid_filter_delete_test() {
   let text_filter = TextFilterPB {
     condition: TextFilterConditionPB::TextIsEmpty,
     content: "".to_string(),
+    ..Default::default()
   };
   let payload = UpdateFilterPayloadPB::new(&test.view_id(), &field, text_filter);
   let scripts = vec![
@@ -243,3 +245,70 @@ async fn grid_filter_update_empty_text_cell_test() {
   ];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_text_regex_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let field = test.get_first_field(FieldType::RichText).clone();
+  let text_filter = TextFilterPB {
+    condition: TextFilterConditionPB::MatchesRegex,
+    content: "^[A-C]$".to_string(),
+    ..Default::default()
+  };
+  let payload = UpdateFilterPayloadPB::new(&test.view_id(), &field, text_filter);
+  let scripts = vec![
+    InsertFilter { payload },
+    AssertNumberOfVisibleRows { expected: 2 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_text_case_sensitive_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let field = test.get_first_field(FieldType::RichText).clone();
+  let text_filter = TextFilterPB {
+    condition: TextFilterConditionPB::Contains,
+    content: "a".to_string(),
+    case_sensitive: true,
+    ..Default::default()
+  };
+  let payload = UpdateFilterPayloadPB::new(&test.view_id(), &field, text_filter);
+  let scripts = vec![
+    InsertFilter { payload },
+    AssertNumberOfVisibleRows { expected: 0 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_text_word_boundary_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let field = test.get_first_field(FieldType::RichText).clone();
+  let text_filter = TextFilterPB {
+    condition: TextFilterConditionPB::Contains,
+    content: "a".to_string(),
+    word_boundary: true,
+    ..Default::default()
+  };
+  let payload = UpdateFilterPayloadPB::new(&test.view_id(), &field, text_filter);
+  let scripts = vec![
+    InsertFilter { payload },
+    AssertNumberOfVisibleRows { expected: 1 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_text_invalid_regex_test() {
+  let test = DatabaseFilterTest::new().await;
+  let field = test.get_first_field(FieldType::RichText).clone();
+  let text_filter = TextFilterPB {
+    condition: TextFilterConditionPB::MatchesRegex,
+    content: "[A-C".to_string(),
+    ..Default::default()
+  };
+  let payload = UpdateFilterPayloadPB::new(&test.view_id(), &field, text_filter);
+  let result: Result<UpdateFilterParams, ErrorCode> = payload.try_into();
+  assert_eq!(result.unwrap_err(), ErrorCode::InvalidRegex);
+}
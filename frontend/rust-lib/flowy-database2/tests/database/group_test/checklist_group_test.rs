@@ -0,0 +1,86 @@
+use flowy_database2::entities::FieldType;
+
+use crate::database::group_test::script::DatabaseGroupTest;
+use crate::database::group_test::script::GroupScript::*;
+
+#[tokio::test]
+async fn group_by_checklist_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let checklist_field = test.get_field(FieldType::Checklist).await;
+  let scripts = vec![
+    GroupByField {
+      field_id: checklist_field.id.clone(),
+    },
+    // No status, not started, in progress and complete
+    AssertGroupCount(4),
+    // The checklists without any task
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 5,
+    },
+    AssertGroupIDName {
+      group_index: 3,
+      group_id: "complete".to_string(),
+      group_name: "Complete".to_string(),
+    },
+    UpdateGroupedCellWithData {
+      from_group_index: 0,
+      row_index: 0,
+      cell_data: "Write the tests".to_string(),
+    },
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 4,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 1,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_checklist_move_row_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let checklist_field = test.get_field(FieldType::Checklist).await;
+  let scripts = vec![
+    GroupByField {
+      field_id: checklist_field.id.clone(),
+    },
+    UpdateGroupedCellWithData {
+      from_group_index: 0,
+      row_index: 0,
+      cell_data: "Write the tests".to_string(),
+    },
+    // Moving to the complete group checks all the tasks
+    MoveRowToGroup {
+      from_group_index: 1,
+      from_row_index: 0,
+      to_group_index: 3,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 0,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 1,
+    },
+    // Which tasks are checked in progress is ambiguous, so the row stays in its group
+    MoveRowToGroup {
+      from_group_index: 3,
+      from_row_index: 0,
+      to_group_index: 2,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 0,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 1,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
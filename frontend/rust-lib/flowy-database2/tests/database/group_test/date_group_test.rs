@@ -209,3 +209,41 @@ async fn change_date_on_moving_row_to_another_group() {
     NaiveDateTime::parse_from_str("2022/11/01 00:00:00", "%Y/%m/%d %H:%M:%S").unwrap();
   assert_eq!(date_time.timestamp(), date_cell.timestamp.unwrap());
 }
+
+#[tokio::test]
+async fn group_by_created_time_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let created_time_field = test.get_field(FieldType::CreatedTime).await;
+  let this_year = offset::Local::now().format("%Y/01/01").to_string();
+  let scripts = vec![
+    GroupByFieldWithSetting {
+      field_id: created_time_field.id.clone(),
+      setting_content: r#"{"condition": 4, "hide_empty": false}"#.to_string(),
+    },
+    AssertGroupCount(2),
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 0,
+    },
+    AssertGroupIDName {
+      group_index: 1,
+      group_id: this_year,
+      group_name: offset::Local::now().format("%Y").to_string(),
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 5,
+    },
+    // The created time can't be changed by moving the row
+    MoveRowToGroup {
+      from_group_index: 1,
+      from_row_index: 0,
+      to_group_index: 0,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 5,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
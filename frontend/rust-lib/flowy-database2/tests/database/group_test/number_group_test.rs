@@ -0,0 +1,133 @@
+use collab_database::rows::RowId;
+
+use flowy_database2::entities::FieldType;
+
+use crate::database::group_test::script::DatabaseGroupTest;
+use crate::database::group_test::script::GroupScript::*;
+
+#[tokio::test]
+async fn group_by_number_bucket_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let number_field = test.get_field(FieldType::Number).await;
+  let scripts = vec![
+    GroupByFieldWithSetting {
+      field_id: number_field.id.clone(),
+      setting_content: r#"{"bucket_width": 10}"#.to_string(),
+    },
+    AssertGroupCount(2),
+    // The row with the empty number
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 1,
+    },
+    AssertGroupIDName {
+      group_index: 1,
+      group_id: "0..10".to_string(),
+      group_name: "0 - 10".to_string(),
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 4,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_number_update_cell_to_new_bucket_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let number_field = test.get_field(FieldType::Number).await;
+  let scripts = vec![
+    GroupByFieldWithSetting {
+      field_id: number_field.id.clone(),
+      setting_content: r#"{"bucket_width": 10}"#.to_string(),
+    },
+    UpdateGroupedCellWithData {
+      from_group_index: 1,
+      row_index: 0,
+      cell_data: "15".to_string(),
+    },
+    AssertGroupCount(3),
+    AssertGroupIDName {
+      group_index: 2,
+      group_id: "10..20".to_string(),
+      group_name: "10 - 20".to_string(),
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 3,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 1,
+    },
+    // The bucket is removed after its last row leaves
+    UpdateGroupedCellWithData {
+      from_group_index: 2,
+      row_index: 0,
+      cell_data: "5".to_string(),
+    },
+    AssertGroupCount(2),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 4,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_number_range_move_row_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let number_field = test.get_field(FieldType::Number).await;
+  let scripts = vec![
+    GroupByFieldWithSetting {
+      field_id: number_field.id.clone(),
+      setting_content: r#"{"ranges": [{"start": null, "end": 2}, {"start": 2, "end": 4}, {"start": 4, "end": null}]}"#.to_string(),
+    },
+    AssertGroupCount(4),
+    AssertGroupIDName {
+      group_index: 1,
+      group_id: "..2".to_string(),
+      group_name: "< 2".to_string(),
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 1,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 2,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 1,
+    },
+    MoveRowToGroup {
+      from_group_index: 1,
+      from_row_index: 0,
+      to_group_index: 3,
+    },
+    // The groups of the explicit ranges are kept even if they are empty
+    AssertGroupCount(4),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 0,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+
+  // The number of the moved row is set to the start of the range
+  let group = test.group_at_index(3).await;
+  let row_id = group.rows.last().unwrap().id.clone();
+  let cell = test
+    .editor
+    .get_cell_pb(&number_field.id, &RowId::from(row_id))
+    .await
+    .unwrap();
+  assert_eq!(String::from_utf8(cell.data).unwrap(), "4");
+}
@@ -0,0 +1,73 @@
+use flowy_database2::entities::FieldType;
+
+use crate::database::group_test::script::DatabaseGroupTest;
+use crate::database::group_test::script::GroupScript::*;
+use crate::database::mock_data::{LUCAS, NATHAN};
+
+#[tokio::test]
+async fn group_by_person_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let person_field = test.get_field(FieldType::Person).await;
+  let scripts = vec![
+    GroupByField {
+      field_id: person_field.id.clone(),
+    },
+    // No status, Lucas and Nathan
+    AssertGroupCount(3),
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 2,
+    },
+    AssertGroupIDName {
+      group_index: 1,
+      group_id: LUCAS.to_string(),
+      group_name: "Lucas".to_string(),
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+    AssertGroupIDName {
+      group_index: 2,
+      group_id: NATHAN.to_string(),
+      group_name: "Nathan".to_string(),
+    },
+    // The row assigned to both of them shows up in both groups
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_person_move_row_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let person_field = test.get_field(FieldType::Person).await;
+  let scripts = vec![
+    GroupByField {
+      field_id: person_field.id.clone(),
+    },
+    // Moving the row to Nathan's group reassigns it to Nathan
+    MoveRowToGroup {
+      from_group_index: 1,
+      from_row_index: 0,
+      to_group_index: 2,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 1,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 3,
+    },
+    CreateRow { group_index: 1 },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -4,7 +4,8 @@ use collab_database::rows::{CreateRowParams, RowId};
 
 use flowy_database2::entities::{FieldType, GroupPB, RowMetaPB};
 use flowy_database2::services::cell::{
-  delete_select_option_cell, insert_date_cell, insert_select_option_cell, insert_url_cell,
+  delete_select_option_cell, insert_checklist_cell, insert_date_cell, insert_number_cell,
+  insert_select_option_cell, insert_text_cell, insert_url_cell,
 };
 use flowy_database2::services::field::{
   edit_single_select_type_option, SelectOption, SelectTypeOptionSharedAction,
@@ -62,11 +63,34 @@ pub enum GroupScript {
   GroupByField {
     field_id: String,
   },
+  GroupByFieldWithSetting {
+    field_id: String,
+    setting_content: String,
+  },
+  MoveRowToGroup {
+    from_group_index: usize,
+    from_row_index: usize,
+    to_group_index: usize,
+  },
   AssertGroupIDName {
     group_index: usize,
     group_id: String,
     group_name: String,
   },
+  SubGroupByField {
+    field_id: Option<String>,
+  },
+  AssertSubGroupCount(usize),
+  AssertSubGroupRowCount {
+    sub_group_index: usize,
+    row_count: usize,
+  },
+  MoveRowToSubGroup {
+    from_group_index: usize,
+    from_row_index: usize,
+    to_group_index: usize,
+    to_sub_group_index: usize,
+  },
 }
 
 pub struct DatabaseGroupTest {
@@ -117,7 +141,13 @@ impl DatabaseGroupTest {
 
         self
           .editor
-          .move_group_row(&self.view_id, &to_group.group_id, from_row, Some(to_row))
+          .move_group_row(
+            &self.view_id,
+            &to_group.group_id,
+            from_row,
+            Some(to_row),
+            None,
+          )
           .await
           .unwrap();
       },
@@ -150,7 +180,7 @@ impl DatabaseGroupTest {
       } => {
         let row = self.row_at_index(group_index, row_index).await;
         let row_id = RowId::from(row.id);
-        self.editor.delete_row(&row_id).await;
+        self.editor.delete_row(&self.view_id, &row_id).await;
       },
       GroupScript::UpdateGroupedCell {
         from_group_index,
@@ -211,6 +241,9 @@ impl DatabaseGroupTest {
           FieldType::DateTime => {
             insert_date_cell(cell_data.parse::<i64>().unwrap(), Some(true), &field)
           },
+          FieldType::Number => insert_number_cell(cell_data.parse::<i64>().unwrap(), &field),
+          FieldType::RichText => insert_text_cell(cell_data, &field),
+          FieldType::Checklist => insert_checklist_cell(vec![cell_data], &field),
           _ => {
             panic!("Unsupported group field type");
           },
@@ -260,6 +293,35 @@ impl DatabaseGroupTest {
           .await
           .unwrap();
       },
+      GroupScript::GroupByFieldWithSetting {
+        field_id,
+        setting_content,
+      } => {
+        self
+          .editor
+          .set_group_by_field(&self.view_id, &field_id, Some(setting_content))
+          .await
+          .unwrap();
+      },
+      GroupScript::MoveRowToGroup {
+        from_group_index,
+        from_row_index,
+        to_group_index,
+      } => {
+        let from_row = self.row_at_index(from_group_index, from_row_index).await;
+        let to_group = self.group_at_index(to_group_index).await;
+        self
+          .editor
+          .move_group_row(
+            &self.view_id,
+            &to_group.group_id,
+            RowId::from(from_row.id),
+            None,
+            None,
+          )
+          .await
+          .unwrap();
+      },
       GroupScript::AssertGroupIDName {
         group_index,
         group_id,
@@ -269,9 +331,58 @@ impl DatabaseGroupTest {
         assert_eq!(group_id, group.group_id, "group index: {}", group_index);
         assert_eq!(group_name, group.group_name, "group index: {}", group_index);
       },
+      GroupScript::SubGroupByField { field_id } => {
+        self
+          .editor
+          .set_sub_group_by_field(&self.view_id, field_id)
+          .await
+          .unwrap();
+      },
+      GroupScript::AssertSubGroupCount(count) => {
+        let sub_groups = self.editor.load_sub_groups(&self.view_id).await.unwrap();
+        assert_eq!(count, sub_groups.len());
+      },
+      GroupScript::AssertSubGroupRowCount {
+        sub_group_index,
+        row_count,
+      } => {
+        let sub_group = self.sub_group_at_index(sub_group_index).await;
+        assert_eq!(row_count, sub_group.rows.len());
+      },
+      GroupScript::MoveRowToSubGroup {
+        from_group_index,
+        from_row_index,
+        to_group_index,
+        to_sub_group_index,
+      } => {
+        let from_row = self.row_at_index(from_group_index, from_row_index).await;
+        let to_group = self.group_at_index(to_group_index).await;
+        let to_sub_group = self.sub_group_at_index(to_sub_group_index).await;
+        self
+          .editor
+          .move_group_row(
+            &self.view_id,
+            &to_group.group_id,
+            RowId::from(from_row.id),
+            None,
+            Some(&to_sub_group.group_id),
+          )
+          .await
+          .unwrap();
+      },
     }
   }
 
+  pub async fn sub_group_at_index(&self, index: usize) -> GroupPB {
+    let sub_groups = self
+      .editor
+      .load_sub_groups(&self.view_id)
+      .await
+      .unwrap()
+      .items;
+    sub_groups.get(index).unwrap().clone()
+  }
+
   pub async fn group_at_index(&self, index: usize) -> GroupPB {
     let groups = self.editor.load_groups(&self.view_id).await.unwrap().items;
     groups.get(index).unwrap().clone()
@@ -306,6 +417,10 @@ impl DatabaseGroupTest {
     .unwrap();
   }
 
+  pub async fn get_checkbox_field(&self) -> Field {
+    self.get_field(FieldType::Checkbox).await
+  }
+
   pub async fn get_url_field(&self) -> Field {
     self.get_field(FieldType::URL).await
   }
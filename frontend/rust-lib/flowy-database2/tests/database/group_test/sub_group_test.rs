@@ -0,0 +1,113 @@
+use crate::database::group_test::script::DatabaseGroupTest;
+use crate::database::group_test::script::GroupScript::*;
+
+#[tokio::test]
+async fn sub_group_by_checkbox_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let checkbox_field = test.get_checkbox_field().await;
+  let scripts = vec![
+    SubGroupByField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    AssertGroupCount(4),
+    AssertSubGroupCount(2),
+    // checked
+    AssertSubGroupRowCount {
+      sub_group_index: 0,
+      row_count: 2,
+    },
+    // unchecked
+    AssertSubGroupRowCount {
+      sub_group_index: 1,
+      row_count: 3,
+    },
+    SubGroupByField { field_id: None },
+    AssertSubGroupCount(0),
+    AssertGroupCount(4),
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sub_group_move_row_to_other_group_and_sub_group_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let checkbox_field = test.get_checkbox_field().await;
+  let single_select_field = test.get_single_select_field().await;
+  let scripts = vec![
+    SubGroupByField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    MoveRowToSubGroup {
+      from_group_index: 1,
+      from_row_index: 0,
+      to_group_index: 2,
+      to_sub_group_index: 1,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 1,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 3,
+    },
+    AssertSubGroupRowCount {
+      sub_group_index: 0,
+      row_count: 1,
+    },
+    AssertSubGroupRowCount {
+      sub_group_index: 1,
+      row_count: 4,
+    },
+    // Both the cells were changed, so the groups stay the same after regrouping the rows
+    GroupByField {
+      field_id: single_select_field.id.clone(),
+    },
+    SubGroupByField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 3,
+    },
+    AssertSubGroupRowCount {
+      sub_group_index: 1,
+      row_count: 4,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sub_group_create_row_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let checkbox_field = test.get_checkbox_field().await;
+  let scripts = vec![
+    SubGroupByField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    CreateRow { group_index: 1 },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 3,
+    },
+    // The new row is unchecked
+    AssertSubGroupRowCount {
+      sub_group_index: 1,
+      row_count: 4,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sub_group_by_grouping_field_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let single_select_field = test.get_single_select_field().await;
+  test
+    .editor
+    .set_sub_group_by_field(&test.view_id, Some(single_select_field.id.clone()))
+    .await
+    .unwrap_err();
+  test.run_scripts(vec![AssertSubGroupCount(0)]).await;
+}
@@ -0,0 +1,87 @@
+use flowy_database2::entities::FieldType;
+
+use crate::database::group_test::script::DatabaseGroupTest;
+use crate::database::group_test::script::GroupScript::*;
+
+#[tokio::test]
+async fn group_by_text_exact_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let text_field = test.get_field(FieldType::RichText).await;
+  let scripts = vec![
+    GroupByFieldWithSetting {
+      field_id: text_field.id.clone(),
+      setting_content: r#"{"condition": 0}"#.to_string(),
+    },
+    // No status, A, AE, B, C, DA
+    AssertGroupCount(6),
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 0,
+    },
+    AssertGroupIDName {
+      group_index: 1,
+      group_id: "A".to_string(),
+      group_name: "A".to_string(),
+    },
+    // Rename the `A` to `B`, the group `A` is removed
+    UpdateGroupedCellWithData {
+      from_group_index: 1,
+      row_index: 0,
+      cell_data: "B".to_string(),
+    },
+    AssertGroupCount(5),
+    AssertGroupIDName {
+      group_index: 2,
+      group_id: "B".to_string(),
+      group_name: "B".to_string(),
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_by_text_first_letter_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let text_field = test.get_field(FieldType::RichText).await;
+  let scripts = vec![
+    GroupByFieldWithSetting {
+      field_id: text_field.id.clone(),
+      setting_content: r#"{"condition": 1}"#.to_string(),
+    },
+    // No status, A, B, C, D
+    AssertGroupCount(5),
+    AssertGroupIDName {
+      group_index: 1,
+      group_id: "A".to_string(),
+      group_name: "A".to_string(),
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+    AssertGroupIDName {
+      group_index: 4,
+      group_id: "D".to_string(),
+      group_name: "D".to_string(),
+    },
+    // The text can't be derived from the first letter, so the row stays in its group
+    MoveRowToGroup {
+      from_group_index: 1,
+      from_row_index: 0,
+      to_group_index: 2,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 1,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -52,7 +52,7 @@ impl DatabaseLayoutTest {
         let events = self
           .database_test
           .editor
-          .get_all_calendar_events(&self.database_test.view_id)
+          .get_all_calendar_events(&self.database_test.view_id, None)
           .await;
         assert_eq!(events.len(), expected);
       },
@@ -80,7 +80,7 @@ impl DatabaseLayoutTest {
         let events = self
           .database_test
           .editor
-          .get_all_calendar_events(&self.database_test.view_id)
+          .get_all_calendar_events(&self.database_test.view_id, None)
           .await;
         assert_eq!(events.len(), 5);
 
@@ -6,12 +6,15 @@ use strum::IntoEnumIterator;
 use flowy_database2::entities::FieldType;
 use flowy_database2::services::field::checklist_type_option::ChecklistTypeOption;
 use flowy_database2::services::field::{
-  DateFormat, DateTypeOption, FieldBuilder, MultiSelectTypeOption, SelectOption, SelectOptionColor,
-  SingleSelectTypeOption, TimeFormat, TimestampTypeOption,
+  DateFormat, DateTypeOption, FieldBuilder, FilesTypeOption, FormulaTypeOption, LookupTypeOption,
+  MultiSelectTypeOption, PersonMember, PersonTypeOption, RelationTypeOption, SelectOption,
+  SelectOptionColor, SingleSelectTypeOption, TimeFormat, TimestampTypeOption,
 };
 
 use crate::database::database_editor::TestRowBuilder;
-use crate::database::mock_data::{COMPLETED, FACEBOOK, GOOGLE, PAUSED, PLANNED, TWITTER};
+use crate::database::mock_data::{
+  COMPLETED, FACEBOOK, GOOGLE, LUCAS, NATHAN, PAUSED, PLANNED, TWITTER,
+};
 
 // Kanban board unit test mock data
 pub fn make_test_board() -> DatabaseData {
@@ -125,6 +128,57 @@ pub fn make_test_board() -> DatabaseData {
           .build();
         fields.push(checklist_field);
       },
+      FieldType::Formula => {
+        let formula_field = FieldBuilder::new(field_type.clone(), FormulaTypeOption::default())
+          .name("Formula")
+          .visibility(true)
+          .build();
+        fields.push(formula_field);
+      },
+      FieldType::Relation => {
+        let relation_field = FieldBuilder::new(field_type.clone(), RelationTypeOption::default())
+          .name("Related")
+          .visibility(true)
+          .build();
+        fields.push(relation_field);
+      },
+      FieldType::Lookup => {
+        let lookup_field = FieldBuilder::new(field_type.clone(), LookupTypeOption::default())
+          .name("Lookup")
+          .visibility(true)
+          .build();
+        fields.push(lookup_field);
+      },
+      FieldType::Person => {
+        let type_option = PersonTypeOption {
+          members: vec![
+            PersonMember {
+              uid: LUCAS,
+              name: "Lucas".to_string(),
+              email: "lucas@appflowy.io".to_string(),
+              ..Default::default()
+            },
+            PersonMember {
+              uid: NATHAN,
+              name: "Nathan".to_string(),
+              email: "nathan@appflowy.io".to_string(),
+              ..Default::default()
+            },
+          ],
+        };
+        let person_field = FieldBuilder::new(field_type.clone(), type_option)
+          .name("Assignee")
+          .visibility(true)
+          .build();
+        fields.push(person_field);
+      },
+      FieldType::Files => {
+        let files_field = FieldBuilder::new(field_type.clone(), FilesTypeOption::default())
+          .name("Attachments")
+          .visibility(true)
+          .build();
+        fields.push(files_field);
+      },
     }
   }
 
@@ -151,6 +205,7 @@ pub fn make_test_board() -> DatabaseData {
               .insert_multi_select_cell(|mut options| vec![options.remove(0), options.remove(0)]),
             FieldType::Checkbox => row_builder.insert_checkbox_cell("true"),
             FieldType::URL => row_builder.insert_url_cell("https://appflowy.io"),
+            FieldType::Person => row_builder.insert_person_cell(vec![LUCAS]),
             _ => "".to_owned(),
           };
         }
@@ -170,6 +225,7 @@ pub fn make_test_board() -> DatabaseData {
             FieldType::MultiSelect => row_builder
               .insert_multi_select_cell(|mut options| vec![options.remove(0), options.remove(0)]),
             FieldType::Checkbox => row_builder.insert_checkbox_cell("true"),
+            FieldType::Person => row_builder.insert_person_cell(vec![NATHAN]),
             _ => "".to_owned(),
           };
         }
@@ -193,6 +249,7 @@ pub fn make_test_board() -> DatabaseData {
             FieldType::URL => {
               row_builder.insert_url_cell("https://github.com/AppFlowy-IO/AppFlowy")
             },
+            FieldType::Person => row_builder.insert_person_cell(vec![LUCAS, NATHAN]),
             _ => "".to_owned(),
           };
         }
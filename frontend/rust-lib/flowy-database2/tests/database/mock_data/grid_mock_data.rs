@@ -6,12 +6,16 @@ use strum::IntoEnumIterator;
 use flowy_database2::entities::FieldType;
 use flowy_database2::services::field::checklist_type_option::ChecklistTypeOption;
 use flowy_database2::services::field::{
-  DateFormat, DateTypeOption, FieldBuilder, MultiSelectTypeOption, NumberFormat, NumberTypeOption,
-  SelectOption, SelectOptionColor, SingleSelectTypeOption, TimeFormat, TimestampTypeOption,
+  DateFormat, DateTypeOption, FieldBuilder, FilesTypeOption, FormulaTypeOption, LookupTypeOption,
+  MultiSelectTypeOption, NumberFormat, NumberTypeOption, PersonMember, PersonTypeOption,
+  RelationTypeOption, SelectOption, SelectOptionColor, SingleSelectTypeOption, TimeFormat,
+  TimestampTypeOption,
 };
 
 use crate::database::database_editor::TestRowBuilder;
-use crate::database::mock_data::{COMPLETED, FACEBOOK, GOOGLE, PAUSED, PLANNED, TWITTER};
+use crate::database::mock_data::{
+  COMPLETED, FACEBOOK, GOOGLE, LUCAS, NATHAN, PAUSED, PLANNED, TWITTER,
+};
 
 pub fn make_test_grid() -> DatabaseData {
   let mut fields = vec![];
@@ -128,6 +132,67 @@ pub fn make_test_grid() -> DatabaseData {
           .build();
         fields.push(checklist_field);
       },
+      FieldType::Formula => {
+        // The price field is created before the formula field
+        let price_field_id = fields
+          .iter()
+          .find(|field| FieldType::from(field.field_type).is_number())
+          .map(|field| field.id.clone())
+          .unwrap_or_default();
+        let type_option = FormulaTypeOption {
+          expression: format!("{{{}}} * 2", price_field_id),
+          date_format: DateFormat::US,
+        };
+        let formula_field = FieldBuilder::new(field_type.clone(), type_option)
+          .name("Total")
+          .visibility(true)
+          .build();
+        fields.push(formula_field);
+      },
+      FieldType::Relation => {
+        let relation_field = FieldBuilder::new(field_type.clone(), RelationTypeOption::default())
+          .name("Related")
+          .visibility(true)
+          .build();
+        fields.push(relation_field);
+      },
+      FieldType::Lookup => {
+        let lookup_field = FieldBuilder::new(field_type.clone(), LookupTypeOption::default())
+          .name("Lookup")
+          .visibility(true)
+          .build();
+        fields.push(lookup_field);
+      },
+      FieldType::Person => {
+        let type_option = PersonTypeOption {
+          members: vec![
+            PersonMember {
+              uid: LUCAS,
+              name: "Lucas".to_string(),
+              email: "lucas@appflowy.io".to_string(),
+              ..Default::default()
+            },
+            PersonMember {
+              uid: NATHAN,
+              name: "Nathan".to_string(),
+              email: "nathan@appflowy.io".to_string(),
+              ..Default::default()
+            },
+          ],
+        };
+        let person_field = FieldBuilder::new(field_type.clone(), type_option)
+          .name("Assignee")
+          .visibility(true)
+          .build();
+        fields.push(person_field);
+      },
+      FieldType::Files => {
+        let files_field = FieldBuilder::new(field_type.clone(), FilesTypeOption::default())
+          .name("Attachments")
+          .visibility(true)
+          .build();
+        fields.push(files_field);
+      },
     }
   }
 
@@ -154,6 +219,7 @@ pub fn make_test_grid() -> DatabaseData {
             FieldType::Checklist => {
               row_builder.insert_checklist_cell(vec!["First thing".to_string()])
             },
+            FieldType::Person => row_builder.insert_person_cell(vec![LUCAS]),
             _ => "".to_owned(),
           };
         }
@@ -188,6 +254,7 @@ pub fn make_test_grid() -> DatabaseData {
               vec![options.remove(1), options.remove(0), options.remove(0)]
             }),
             FieldType::Checkbox => row_builder.insert_checkbox_cell("false"),
+            FieldType::Person => row_builder.insert_person_cell(vec![LUCAS, NATHAN]),
             _ => "".to_owned(),
           };
         }
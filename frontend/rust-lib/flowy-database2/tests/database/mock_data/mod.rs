@@ -14,6 +14,9 @@ pub const COMPLETED: &str = "Completed";
 pub const PLANNED: &str = "Planned";
 pub const PAUSED: &str = "Paused";
 
+pub const LUCAS: i64 = 1;
+pub const NATHAN: i64 = 2;
+
 // pub const FIRST_THING: &str = "Wake up at 6:00 am";
 // pub const SECOND_THING: &str = "Get some coffee";
 // pub const THIRD_THING: &str = "Start working";
@@ -1,4 +1,5 @@
 mod block_test;
+mod calculations_test;
 mod cell_test;
 mod database_editor;
 mod field_settings_test;
@@ -1,7 +1,18 @@
-use flowy_database2::entities::FieldType;
+use collab_database::database::gen_database_view_id;
+use collab_database::fields::Field;
+use collab_database::rows::RowDetail;
+
+use flowy_database2::entities::{
+  CheckboxFilterConditionPB, CheckboxFilterPB, FieldType, FieldVisibility, UpdateFilterParams,
+  UpdateFilterPayloadPB, UpdateSortParams,
+};
 use flowy_database2::services::cell::stringify_cell_data;
-use flowy_database2::services::field::CHECK;
-use flowy_database2::services::share::csv::CSVFormat;
+use flowy_database2::services::field::{
+  CheckboxCellData, DateCellData, DateTypeOption, NumberCellData, CHECK,
+};
+use flowy_database2::services::field_settings::FieldSettingsChangesetParams;
+use flowy_database2::services::share::csv::{CSVExportOptions, CSVFormat};
+use flowy_database2::services::sort::SortCondition;
 
 use crate::database::database_editor::DatabaseEditorTest;
 
@@ -27,19 +38,94 @@ async fn export_csv_test() {
   let test = DatabaseEditorTest::new_grid().await;
   let database = test.editor.clone();
   let s = database.export_csv(CSVFormat::Original).await.unwrap();
-  let expected = r#"Name,Price,Time,Status,Platform,is urgent,link,TODO,Last Modified,Created At
-A,$1,2022/03/14,,"Google,Facebook",Yes,AppFlowy website - https://www.appflowy.io,,,
-,$2,2022/03/14,,"Google,Twitter",Yes,,,,
-C,$3,2022/03/14,Completed,"Facebook,Google,Twitter",No,,,,
-DA,$14,2022/11/17,Completed,,No,,,,
-AE,,2022/11/13,Planned,"Facebook,Twitter",No,,,,
-AE,$5,2022/12/25,Planned,Facebook,Yes,,,,
-CB,,,,,,,,,
+  let expected = r#"Name,Price,Time,Status,Platform,is urgent,link,TODO,Last Modified,Created At,Total,Related,Lookup,Assignee,Attachments
+A,$1,2022/03/14,,"Google,Facebook",Yes,AppFlowy website - https://www.appflowy.io,,,,2,,,Lucas,
+,$2,2022/03/14,,"Google,Twitter",Yes,,,,,4,,,,
+C,$3,2022/03/14,Completed,"Facebook,Google,Twitter",No,,,,,6,,,"Lucas,Nathan",
+DA,$14,2022/11/17,Completed,,No,,,,,28,,,,
+AE,,2022/11/13,Planned,"Facebook,Twitter",No,,,,,0,,,,
+AE,$5,2022/12/25,Planned,Facebook,Yes,,,,,10,,,,
+CB,,,,,,,,,,0,,,,
 "#;
   println!("{}", s);
   assert_eq!(s, expected);
 }
 
+#[tokio::test]
+async fn export_view_csv_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let time_field = test.get_first_field(FieldType::DateTime);
+  let price_field = test.get_first_field(FieldType::Number);
+  let checkbox_field = test.get_first_field(FieldType::Checkbox);
+  test
+    .editor
+    .update_field_settings_with_changeset(FieldSettingsChangesetParams {
+      view_id: test.view_id.clone(),
+      field_id: time_field.id.clone(),
+      visibility: Some(FieldVisibility::AlwaysHidden),
+      calculation_type: None,
+    })
+    .await
+    .unwrap();
+  let filter = UpdateFilterPayloadPB::new(
+    &test.view_id,
+    &checkbox_field,
+    CheckboxFilterPB {
+      condition: CheckboxFilterConditionPB::IsChecked,
+    },
+  );
+  let params: UpdateFilterParams = filter.try_into().unwrap();
+  test.editor.create_or_update_filter(params).await.unwrap();
+  test
+    .editor
+    .create_or_update_sort(UpdateSortParams {
+      view_id: test.view_id.clone(),
+      field_id: price_field.id.clone(),
+      sort_id: None,
+      field_type: FieldType::Number,
+      condition: SortCondition::Descending,
+      by_option_order: false,
+      empty_first: false,
+    })
+    .await
+    .unwrap();
+
+  let options = CSVExportOptions {
+    include_row_meta: true,
+  };
+  let s = test
+    .sdk
+    .database_manager
+    .export_view_csv(&test.view_id, options)
+    .await
+    .unwrap();
+  let mut reader = csv::Reader::from_reader(s.as_bytes());
+  let headers = reader.headers().unwrap().clone();
+  assert!(!headers.iter().any(|header| header == time_field.name));
+  assert_eq!(headers.get(0).unwrap(), "Name");
+  assert_eq!(headers.get(headers.len() - 4).unwrap(), "Row ID");
+  assert_eq!(headers.get(headers.len() - 1).unwrap(), "Document");
+
+  let rows = test.editor.get_rows(&test.view_id).await.unwrap();
+  let records = reader
+    .records()
+    .map(|record| record.unwrap())
+    .collect::<Vec<_>>();
+  assert_eq!(records.len(), 3);
+  let names = records
+    .iter()
+    .map(|record| record.get(0).unwrap().to_string())
+    .collect::<Vec<_>>();
+  assert_eq!(names, vec!["AE", "", "A"]);
+  for (record, row_detail) in records.iter().zip(rows.iter()) {
+    assert_eq!(
+      record.get(headers.len() - 4).unwrap(),
+      row_detail.row.id.as_str()
+    );
+    assert!(!record.get(headers.len() - 3).unwrap().is_empty());
+  }
+}
+
 #[tokio::test]
 async fn export_and_then_import_meta_csv_test() {
   let test = DatabaseEditorTest::new_grid().await;
@@ -62,6 +148,11 @@ async fn export_and_then_import_meta_csv_test() {
   assert_eq!(fields[7].field_type, 7);
   assert_eq!(fields[8].field_type, 8);
   assert_eq!(fields[9].field_type, 9);
+  assert_eq!(fields[10].field_type, 10);
+  assert_eq!(fields[11].field_type, 11);
+  assert_eq!(fields[12].field_type, 12);
+  assert_eq!(fields[13].field_type, 13);
+  assert_eq!(fields[14].field_type, 14);
 
   for field in fields {
     for (index, row_detail) in rows.iter().enumerate() {
@@ -99,6 +190,19 @@ async fn export_and_then_import_meta_csv_test() {
           FieldType::Checklist => {},
           FieldType::LastEditedTime => {},
           FieldType::CreatedTime => {},
+          FieldType::Formula => {
+            if index == 0 {
+              assert_eq!(s, "2");
+            }
+          },
+          FieldType::Relation => {},
+          FieldType::Lookup => {},
+          FieldType::Person => {
+            if index == 0 {
+              assert_eq!(s, "Lucas");
+            }
+          },
+          FieldType::Files => {},
         }
       } else {
         panic!(
@@ -180,6 +284,11 @@ async fn history_database_import_test() {
           FieldType::Checklist => {},
           FieldType::LastEditedTime => {},
           FieldType::CreatedTime => {},
+          FieldType::Formula => {},
+          FieldType::Relation => {},
+          FieldType::Lookup => {},
+          FieldType::Person => {},
+          FieldType::Files => {},
         }
       } else {
         panic!(
@@ -190,3 +299,196 @@ async fn history_database_import_test() {
     }
   }
 }
+
+#[tokio::test]
+async fn export_markdown_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let s = test
+    .sdk
+    .database_manager
+    .export_markdown(&test.view_id)
+    .await
+    .unwrap();
+  let lines = s.lines().collect::<Vec<_>>();
+  assert_eq!(
+    lines[0],
+    "| Name | Price | Time | Status | Platform | is urgent | link | TODO | Last Modified | Created At | Total | Related | Lookup | Assignee | Attachments |"
+  );
+  assert_eq!(lines[1], vec!["| ---"; 15].join(" ") + " |");
+  assert!(lines[2].starts_with("| A | $1 | 2022/03/14 |  | Google,Facebook | Yes |"));
+  assert_eq!(lines.len(), 9);
+}
+
+#[tokio::test]
+async fn export_and_then_import_json_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let price_field = test.get_first_field(FieldType::Number);
+  test
+    .editor
+    .create_or_update_sort(UpdateSortParams {
+      view_id: test.view_id.clone(),
+      field_id: price_field.id.clone(),
+      sort_id: None,
+      field_type: FieldType::Number,
+      condition: SortCondition::Descending,
+      by_option_order: false,
+      empty_first: false,
+    })
+    .await
+    .unwrap();
+  let json = test
+    .sdk
+    .database_manager
+    .export_json(&test.view_id)
+    .await
+    .unwrap();
+
+  let result = test
+    .sdk
+    .database_manager
+    .import_json(gen_database_view_id(), json)
+    .await
+    .unwrap();
+  let database = test.get_database(&result.database_id).await.unwrap();
+  let fields = database.get_fields(&result.view_id, None);
+  let original_fields = test.get_fields();
+  assert_eq!(fields.len(), original_fields.len());
+  for (field, original_field) in fields.iter().zip(original_fields.iter()) {
+    assert_eq!(field.id, original_field.id);
+    assert_eq!(field.name, original_field.name);
+    assert_eq!(field.field_type, original_field.field_type);
+  }
+  assert_eq!(database.get_all_sorts(&result.view_id).await.items.len(), 1);
+
+  let rows = database.get_rows(&result.view_id).await.unwrap();
+  let original_rows = test.editor.get_rows(&test.view_id).await.unwrap();
+  assert_eq!(rows.len(), original_rows.len());
+  for (row, original_row) in rows.iter().zip(original_rows.iter()) {
+    assert_ne!(row.row.id, original_row.row.id);
+    for field in fields.iter() {
+      let field_type = FieldType::from(field.field_type);
+      let s = |row: &RowDetail| {
+        row
+          .row
+          .cells
+          .get(&field.id)
+          .map(|cell| stringify_cell_data(cell, &field_type, &field_type, field))
+          .unwrap_or_default()
+      };
+      assert_eq!(s(row), s(original_row));
+    }
+  }
+}
+
+#[tokio::test]
+async fn export_json_twice_writes_the_same_text_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let export = || test.sdk.database_manager.export_json(&test.view_id);
+  let json = export().await.unwrap();
+  assert_eq!(json, export().await.unwrap());
+}
+
+#[tokio::test]
+async fn export_and_then_import_xlsx_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let bytes = test
+    .sdk
+    .database_manager
+    .export_xlsx(&test.view_id)
+    .await
+    .unwrap();
+
+  let results = test
+    .sdk
+    .database_manager
+    .import_xlsx(gen_database_view_id(), bytes)
+    .await
+    .unwrap();
+  assert_eq!(results.len(), 1);
+  let (_, result) = &results[0];
+  let database = test.get_database(&result.database_id).await.unwrap();
+  let fields = database.get_fields(&result.view_id, None);
+  let original_fields = test.get_fields();
+  assert_eq!(fields.len(), original_fields.len());
+  for field_type in [FieldType::Number, FieldType::DateTime, FieldType::Checkbox] {
+    let original_field = test.get_first_field(field_type.clone());
+    let field = fields
+      .iter()
+      .find(|field| field.name == original_field.name)
+      .unwrap();
+    assert_eq!(FieldType::from(field.field_type), field_type);
+  }
+
+  let rows = database.get_rows(&result.view_id).await.unwrap();
+  let original_rows = test.editor.get_rows(&test.view_id).await.unwrap();
+  assert_eq!(rows.len(), original_rows.len());
+  let field_pair = |field_type: FieldType| {
+    let original_field = test.get_first_field(field_type);
+    let field = fields
+      .iter()
+      .find(|field| field.name == original_field.name)
+      .unwrap()
+      .clone();
+    (field, original_field)
+  };
+  let (primary_field, original_primary_field) = field_pair(FieldType::RichText);
+  let (number_field, original_number_field) = field_pair(FieldType::Number);
+  let (date_field, original_date_field) = field_pair(FieldType::DateTime);
+  let (checkbox_field, original_checkbox_field) = field_pair(FieldType::Checkbox);
+  for (row, original_row) in rows.iter().zip(original_rows.iter()) {
+    let s = |row: &RowDetail, field: &Field| {
+      row
+        .row
+        .cells
+        .get(&field.id)
+        .map(|cell| stringify_cell_data(cell, &FieldType::RichText, &FieldType::RichText, field))
+        .unwrap_or_default()
+    };
+    assert_eq!(
+      s(row, &primary_field),
+      s(original_row, &original_primary_field)
+    );
+
+    let number = |row: &RowDetail, field: &Field| {
+      row
+        .row
+        .cells
+        .get(&field.id)
+        .and_then(|cell| NumberCellData::from(cell).0.parse::<f64>().ok())
+    };
+    assert_eq!(
+      number(row, &number_field),
+      number(original_row, &original_number_field)
+    );
+
+    // The dates are compared as they are shown, because the imported field has the local timezone
+    let date = |row: &RowDetail, field: &Field| {
+      let type_option = field
+        .get_type_option::<DateTypeOption>(FieldType::DateTime)
+        .unwrap_or_default();
+      row.row.cells.get(&field.id).and_then(|cell| {
+        let date = DateCellData::from(cell);
+        let date_time = type_option.local_date_time(date.timestamp?)?;
+        let time = date.include_time.then(|| date_time.time());
+        Some((date_time.date(), time))
+      })
+    };
+    assert_eq!(
+      date(row, &date_field),
+      date(original_row, &original_date_field)
+    );
+
+    let is_checked = |row: &RowDetail, field: &Field| {
+      row
+        .row
+        .cells
+        .get(&field.id)
+        .map(|cell| CheckboxCellData::from(cell).is_check())
+        .unwrap_or_default()
+    };
+    assert_eq!(
+      is_checked(row, &checkbox_field),
+      is_checked(original_row, &original_checkbox_field)
+    );
+  }
+}
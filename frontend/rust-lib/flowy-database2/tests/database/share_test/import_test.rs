@@ -0,0 +1,49 @@
+use collab_database::database::gen_database_view_id;
+
+use flowy_database2::entities::FieldType;
+use flowy_database2::services::cell::stringify_cell_data;
+use flowy_database2::services::share::csv::{CSVFormat, CSV_IMPORT_BATCH_SIZE};
+
+use crate::database::database_editor::DatabaseEditorTest;
+
+#[tokio::test]
+async fn import_csv_file_in_batches_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let mut content = "Name;Amount;Status\n".to_string();
+  for index in 0..CSV_IMPORT_BATCH_SIZE * 2 {
+    let status = if index % 2 == 0 { "Todo" } else { "Done" };
+    content.push_str(&format!("{};{};{}\n", index, index, status));
+  }
+  // A malformed line and a new option after the first batch
+  content.push_str("bad;1;Todo;extra\n");
+  content.push_str("last;1;Doing\n");
+  let file_path = std::env::temp_dir().join(format!("{}.csv", gen_database_view_id()));
+  std::fs::write(&file_path, content).unwrap();
+
+  let result = test
+    .import_file(file_path.to_str().unwrap().to_string(), CSVFormat::Original)
+    .await;
+  let _ = std::fs::remove_file(&file_path);
+  assert_eq!(result.report.imported_rows, CSV_IMPORT_BATCH_SIZE * 2 + 1);
+  assert_eq!(result.report.skipped_lines.len(), 1);
+  assert_eq!(
+    result.report.skipped_lines[0].line,
+    CSV_IMPORT_BATCH_SIZE as u64 * 2 + 2
+  );
+
+  let database = test.get_database(&result.database_id).await.unwrap();
+  let fields = database.get_fields(&result.view_id, None);
+  assert_eq!(FieldType::from(fields[1].field_type), FieldType::Number);
+  assert_eq!(
+    FieldType::from(fields[2].field_type),
+    FieldType::SingleSelect
+  );
+  let rows = database.get_rows(&result.view_id).await.unwrap();
+  assert_eq!(rows.len(), CSV_IMPORT_BATCH_SIZE * 2 + 1);
+
+  // The option found in the last batch is added to the field
+  let cell = rows.last().unwrap().row.cells.get(&fields[2].id).unwrap();
+  let field_type = FieldType::SingleSelect;
+  let s = stringify_cell_data(cell, &field_type, &field_type, &fields[2]);
+  assert_eq!(s, "Doing");
+}
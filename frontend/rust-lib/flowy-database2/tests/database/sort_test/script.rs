@@ -19,6 +19,12 @@ pub enum SortScript {
     field: Field,
     condition: SortCondition,
   },
+  InsertSortWithOptions {
+    field: Field,
+    condition: SortCondition,
+    by_option_order: bool,
+    empty_first: bool,
+  },
   DeleteSort {
     sort: Sort,
     sort_id: String,
@@ -64,22 +70,17 @@ impl DatabaseSortTest {
   pub async fn run_script(&mut self, script: SortScript) {
     match script {
       SortScript::InsertSort { condition, field } => {
-        self.recv = Some(
-          self
-            .editor
-            .subscribe_view_changed(&self.view_id)
-            .await
-            .unwrap(),
-        );
-        let params = UpdateSortParams {
-          view_id: self.view_id.clone(),
-          field_id: field.id.clone(),
-          sort_id: None,
-          field_type: FieldType::from(field.field_type),
-          condition,
-        };
-        let sort_rev = self.editor.create_or_update_sort(params).await.unwrap();
-        self.current_sort_rev = Some(sort_rev);
+        self.insert_sort(field, condition, false, false).await;
+      },
+      SortScript::InsertSortWithOptions {
+        field,
+        condition,
+        by_option_order,
+        empty_first,
+      } => {
+        self
+          .insert_sort(field, condition, by_option_order, empty_first)
+          .await;
       },
       SortScript::DeleteSort { sort, sort_id } => {
         self.recv = Some(
@@ -151,6 +152,33 @@ impl DatabaseSortTest {
       },
     }
   }
+
+  async fn insert_sort(
+    &mut self,
+    field: Field,
+    condition: SortCondition,
+    by_option_order: bool,
+    empty_first: bool,
+  ) {
+    self.recv = Some(
+      self
+        .editor
+        .subscribe_view_changed(&self.view_id)
+        .await
+        .unwrap(),
+    );
+    let params = UpdateSortParams {
+      view_id: self.view_id.clone(),
+      field_id: field.id.clone(),
+      sort_id: None,
+      field_type: FieldType::from(field.field_type),
+      condition,
+      by_option_order,
+      empty_first,
+    };
+    let sort_rev = self.editor.create_or_update_sort(params).await.unwrap();
+    self.current_sort_rev = Some(sort_rev);
+  }
 }
 
 async fn assert_sort_changed(
@@ -1,4 +1,10 @@
+use std::collections::HashMap;
+
+use collab_database::database::gen_row_id;
+use collab_database::rows::CreateRowParams;
+
 use flowy_database2::entities::FieldType;
+use flowy_database2::services::cell::CellBuilder;
 use flowy_database2::services::sort::SortCondition;
 
 use crate::database::sort_test::script::{DatabaseSortTest, SortScript::*};
@@ -377,3 +383,109 @@ async fn sort_multi_select_by_descending_test() {
   ];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn sort_multi_select_by_option_order_test() {
+  let mut test = DatabaseSortTest::new().await;
+  let multi_select = test.get_first_field(FieldType::MultiSelect);
+  // The options are arranged as Google, Facebook, Twitter
+  let scripts = vec![
+    InsertSortWithOptions {
+      field: multi_select.clone(),
+      condition: SortCondition::Ascending,
+      by_option_order: true,
+      empty_first: false,
+    },
+    AssertCellContentOrder {
+      field_id: multi_select.id.clone(),
+      orders: vec![
+        "Google,Facebook",
+        "Facebook,Google,Twitter",
+        "Google,Twitter",
+        "Facebook",
+        "Facebook,Twitter",
+        "",
+        "",
+      ],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_single_select_by_option_order_with_empty_first_test() {
+  let mut test = DatabaseSortTest::new().await;
+  let single_select = test.get_first_field(FieldType::SingleSelect);
+  let scripts = vec![
+    InsertSortWithOptions {
+      field: single_select.clone(),
+      condition: SortCondition::Descending,
+      by_option_order: true,
+      empty_first: true,
+    },
+    AssertCellContentOrder {
+      field_id: single_select.id.clone(),
+      orders: vec!["", "", "", "Planned", "Planned", "Completed", "Completed"],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_number_with_empty_first_test() {
+  let mut test = DatabaseSortTest::new().await;
+  let number_field = test.get_first_field(FieldType::Number);
+  let scripts = vec![
+    InsertSortWithOptions {
+      field: number_field.clone(),
+      condition: SortCondition::Ascending,
+      by_option_order: false,
+      empty_first: true,
+    },
+    AssertCellContentOrder {
+      field_id: number_field.id.clone(),
+      orders: vec!["", "", "$1", "$2", "$3", "$5", "$14"],
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn sort_equal_cells_by_creation_time_test() {
+  let mut test = DatabaseSortTest::new().await;
+  let text_field = test.get_first_field(FieldType::RichText);
+  // The row is appended to the view, but it's created before the other rows
+  let cells = CellBuilder::with_cells(
+    HashMap::from([(text_field.id.clone(), "A".to_string())]),
+    &[text_field.clone()],
+  )
+  .build();
+  let params = CreateRowParams {
+    id: gen_row_id(),
+    cells,
+    height: 60,
+    visibility: true,
+    prev_row_id: None,
+    timestamp: 0,
+  };
+  let row_detail = test
+    .editor
+    .create_row(&test.view_id, None, params)
+    .await
+    .unwrap()
+    .unwrap();
+  let scripts = vec![
+    InsertSort {
+      field: text_field.clone(),
+      condition: SortCondition::Ascending,
+    },
+    AssertCellContentOrder {
+      field_id: text_field.id.clone(),
+      orders: vec!["A", "A", "AE", "AE", "C", "CB", "DA", ""],
+    },
+  ];
+  test.run_scripts(scripts).await;
+
+  let rows = test.editor.get_rows(&test.view_id).await.unwrap();
+  assert_eq!(rows[0].row.id, row_detail.row.id);
+}
@@ -2,6 +2,8 @@ use collab_document::blocks::{Block, DocumentData, DocumentMeta};
 
 use crate::entities::{BlockPB, ChildrenPB, DocumentDataPB, MetaPB};
 
+const DELTA: &str = "delta";
+
 impl From<DocumentData> for DocumentDataPB {
   fn from(data: DocumentData) -> Self {
     let blocks = data
@@ -80,3 +82,48 @@ impl From<Vec<String>> for ChildrenPB {
     Self { children }
   }
 }
+
+/// Returns the text of the document, the text of each block on its own line in the order of the
+/// blocks. The formatting of the text is dropped.
+pub fn document_data_to_plain_text(data: &DocumentData) -> String {
+  let mut lines = vec![];
+  collect_block_text(data, &data.page_id, &mut lines);
+  lines.join("\n")
+}
+
+fn collect_block_text(data: &DocumentData, block_id: &str, lines: &mut Vec<String>) {
+  let block = match data.blocks.get(block_id) {
+    None => return,
+    Some(block) => block,
+  };
+
+  // The text of the block is kept in the text map, or in the data of the block for the
+  // documents created before the text map.
+  let delta = block
+    .external_id
+    .as_ref()
+    .and_then(|external_id| data.meta.text_map.as_ref()?.get(external_id).cloned())
+    .or_else(|| block.data.get(DELTA).map(|delta| delta.to_string()));
+  if let Some(text) = delta.map(|delta| delta_to_plain_text(&delta)) {
+    if !text.is_empty() {
+      lines.push(text);
+    }
+  }
+
+  if let Some(children) = data.meta.children_map.get(&block.children) {
+    for child_id in children {
+      collect_block_text(data, child_id, lines);
+    }
+  }
+}
+
+fn delta_to_plain_text(delta: &str) -> String {
+  serde_json::from_str::<Vec<serde_json::Value>>(delta)
+    .map(|ops| {
+      ops
+        .iter()
+        .filter_map(|op| op.get("insert")?.as_str())
+        .collect::<String>()
+    })
+    .unwrap_or_default()
+}
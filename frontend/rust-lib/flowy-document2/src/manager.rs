@@ -17,6 +17,7 @@ use flowy_error::{internal_error, FlowyError, FlowyResult};
 use flowy_storage::FileStorageService;
 
 use crate::document::MutexDocument;
+use crate::document_data::document_data_to_plain_text;
 use crate::entities::DocumentSnapshotPB;
 use crate::reminder::DocumentReminderAction;
 
@@ -129,6 +130,12 @@ impl DocumentManager {
       .map_err(internal_error)
   }
 
+  /// Returns the plain text of the document, see [document_data_to_plain_text].
+  pub async fn get_document_text(&self, doc_id: &str) -> FlowyResult<String> {
+    let data = self.get_document_data(doc_id).await?;
+    Ok(document_data_to_plain_text(&data))
+  }
+
   pub fn close_document(&self, doc_id: &str) -> FlowyResult<()> {
     self.documents.write().remove(doc_id);
     Ok(())
@@ -1,9 +1,13 @@
 use std::{collections::HashMap, vec};
 
-use collab_document::blocks::{Block, BlockAction, BlockActionPayload, BlockActionType};
+use collab_document::blocks::{
+  Block, BlockAction, BlockActionPayload, BlockActionType, DocumentData, DocumentMeta,
+};
 use collab_document::document_data::{default_document_data, PARAGRAPH_BLOCK_TYPE};
 use serde_json::{json, to_value, Value};
 
+use flowy_document2::document_data::document_data_to_plain_text;
+
 use crate::document::util::{gen_document_id, gen_id, DocumentTest};
 
 #[tokio::test]
@@ -215,3 +219,50 @@ async fn document_apply_update_action() {
   // close a document
   _ = test.close_document(&doc_id);
 }
+
+#[test]
+fn document_data_to_plain_text_test() {
+  let page_id = gen_id();
+  let block = |id: &str, children: &str, external_id: Option<&str>| Block {
+    id: id.to_string(),
+    ty: PARAGRAPH_BLOCK_TYPE.to_string(),
+    parent: page_id.clone(),
+    children: children.to_string(),
+    external_id: external_id.map(|id| id.to_string()),
+    external_type: external_id.map(|_| "text".to_string()),
+    data: HashMap::new(),
+  };
+  let blocks = HashMap::from([
+    (page_id.clone(), block(&page_id, "page_children", None)),
+    ("a".to_string(), block("a", "a_children", Some("text_a"))),
+    ("b".to_string(), block("b", "b_children", Some("text_b"))),
+    ("c".to_string(), block("c", "c_children", Some("text_c"))),
+  ]);
+  let children_map = HashMap::from([
+    (
+      "page_children".to_string(),
+      vec!["a".to_string(), "c".to_string()],
+    ),
+    ("a_children".to_string(), vec!["b".to_string()]),
+  ]);
+  let text_map = HashMap::from([
+    (
+      "text_a".to_string(),
+      r#"[{"insert":"Hello "},{"insert":"world","attributes":{"bold":true}}]"#.to_string(),
+    ),
+    ("text_b".to_string(), r#"[{"insert":"nested"}]"#.to_string()),
+    ("text_c".to_string(), r#"[{"insert":"last"}]"#.to_string()),
+  ]);
+  let data = DocumentData {
+    page_id,
+    blocks,
+    meta: DocumentMeta {
+      children_map,
+      text_map: Some(text_map),
+    },
+  };
+  assert_eq!(
+    document_data_to_plain_text(&data),
+    "Hello world\nnested\nlast"
+  );
+}
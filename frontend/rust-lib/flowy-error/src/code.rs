@@ -256,6 +256,12 @@ pub enum ErrorCode {
 
   #[error("Internal server error")]
   InternalServerError = 84,
+
+  #[error("Invalid formula")]
+  InvalidFormula = 85,
+
+  #[error("Invalid regular expression")]
+  InvalidRegex = 86,
 }
 
 impl ErrorCode {
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::FlowyError;
 
@@ -11,6 +13,11 @@ pub enum ImportTypePB {
   HistoryDatabase = 1,
   RawDatabase = 2,
   CSV = 3,
+  /// The database exported as JSON. The `view_layout` of the [ImportPB] should be the layout of
+  /// the exported database.
+  JSON = 4,
+  /// The workbook is imported with a grid view for each of its sheets
+  XLSX = 5,
 }
 
 impl From<ImportTypePB> for ImportType {
@@ -20,6 +27,8 @@ impl From<ImportTypePB> for ImportType {
       ImportTypePB::HistoryDatabase => ImportType::HistoryDatabase,
       ImportTypePB::RawDatabase => ImportType::RawDatabase,
       ImportTypePB::CSV => ImportType::CSV,
+      ImportTypePB::JSON => ImportType::JSON,
+      ImportTypePB::XLSX => ImportType::XLSX,
     }
   }
 }
@@ -30,6 +39,16 @@ impl Default for ImportTypePB {
   }
 }
 
+/// Overrides the field type that a column of the imported CSV is inferred as.
+#[derive(Clone, Debug, ProtoBuf, Default)]
+pub struct ImportFieldTypePB {
+  #[pb(index = 1)]
+  pub column_index: i32,
+
+  #[pb(index = 2)]
+  pub field_type: i64,
+}
+
 #[derive(Clone, Debug, ProtoBuf, Default)]
 pub struct ImportPB {
   #[pb(index = 1)]
@@ -49,6 +68,9 @@ pub struct ImportPB {
 
   #[pb(index = 6)]
   pub import_type: ImportTypePB,
+
+  #[pb(index = 7)]
+  pub field_types: Vec<ImportFieldTypePB>,
 }
 
 impl TryInto<ImportParams> for ImportPB {
@@ -74,6 +96,14 @@ impl TryInto<ImportParams> for ImportPB {
       ),
     };
 
+    let mut field_types = HashMap::new();
+    for field_type in self.field_types {
+      if field_type.column_index < 0 {
+        return Err(FlowyError::invalid_data().with_context("The column index is negative"));
+      }
+      field_types.insert(field_type.column_index as usize, field_type.field_type);
+    }
+
     Ok(ImportParams {
       parent_view_id,
       name,
@@ -81,6 +111,7 @@ impl TryInto<ImportParams> for ImportPB {
       file_path,
       view_layout: self.view_layout.into(),
       import_type: self.import_type.into(),
+      field_types,
     })
   }
 }
@@ -30,7 +30,7 @@ use crate::notification::{
   send_notification, send_workspace_notification, send_workspace_setting_notification,
   FolderNotification,
 };
-use crate::share::ImportParams;
+use crate::share::{ImportParams, ImportedView};
 use crate::user_default::DefaultFolderBuilder;
 use crate::view_operation::{create_view, FolderOperationHandler, FolderOperationHandlers};
 
@@ -860,43 +860,61 @@ impl FolderManager {
     let handler = self.get_handler(&import_data.view_layout)?;
     let view_id = gen_view_id().to_string();
     let uid = self.user.user_id()?;
+    let mut imported_views = vec![];
     if let Some(data) = import_data.data {
-      handler
+      imported_views = handler
         .import_from_bytes(
           uid,
           &view_id,
           &import_data.name,
-          import_data.import_type,
+          import_data.import_type.clone(),
           data,
+          import_data.field_types.clone(),
         )
         .await?;
     }
 
     if let Some(file_path) = import_data.file_path {
-      handler
-        .import_from_file_path(&view_id, &import_data.name, file_path)
+      imported_views = handler
+        .import_from_file_path(
+          &view_id,
+          &import_data.name,
+          import_data.import_type,
+          file_path,
+          import_data.field_types,
+        )
         .await?;
     }
 
-    let params = CreateViewParams {
-      parent_view_id: import_data.parent_view_id,
-      name: import_data.name,
-      desc: "".to_string(),
-      layout: import_data.view_layout.clone().into(),
-      initial_data: vec![],
+    let imported_views = std::iter::once(ImportedView {
       view_id,
-      meta: Default::default(),
-      set_as_current: false,
-      index: None,
-    };
-
-    let view = create_view(params, import_data.view_layout);
+      name: import_data.name,
+    })
+    .chain(imported_views)
+    .map(|imported_view| {
+      let params = CreateViewParams {
+        parent_view_id: import_data.parent_view_id.clone(),
+        name: imported_view.name,
+        desc: "".to_string(),
+        layout: import_data.view_layout.clone().into(),
+        initial_data: vec![],
+        view_id: imported_view.view_id,
+        meta: Default::default(),
+        set_as_current: false,
+        index: None,
+      };
+      create_view(params, import_data.view_layout.clone())
+    })
+    .collect::<Vec<View>>();
     self.with_folder(
       || (),
       |folder| {
-        folder.insert_view(view.clone(), None);
+        for view in imported_views.iter() {
+          folder.insert_view(view.clone(), None);
+        }
       },
     );
+    let view = imported_views[0].clone();
     notify_parent_view_did_change(self.mutex_folder.clone(), vec![view.parent_view_id.clone()]);
     Ok(view)
   }
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use collab_folder::core::ViewLayout;
 
 #[derive(Clone, Debug)]
@@ -6,6 +8,8 @@ pub enum ImportType {
   HistoryDatabase = 1,
   RawDatabase = 2,
   CSV = 3,
+  JSON = 4,
+  XLSX = 5,
 }
 
 #[derive(Clone, Debug)]
@@ -16,4 +20,14 @@ pub struct ImportParams {
   pub file_path: Option<String>,
   pub view_layout: ViewLayout,
   pub import_type: ImportType,
+  /// The field types of the columns of the imported CSV by the index of the column. The field
+  /// type of the other columns is inferred from their values.
+  pub field_types: HashMap<usize, i64>,
+}
+
+/// A view created by importing the data, besides the view that the data is imported as.
+#[derive(Clone, Debug)]
+pub struct ImportedView {
+  pub view_id: String,
+  pub name: String,
 }
@@ -13,7 +13,7 @@ use lib_infra::future::FutureResult;
 use lib_infra::util::timestamp;
 
 use crate::entities::{CreateViewParams, ViewLayoutPB};
-use crate::share::ImportType;
+use crate::share::{ImportType, ImportedView};
 
 pub type ViewData = Bytes;
 
@@ -208,7 +208,10 @@ pub trait FolderOperationHandler {
     layout: ViewLayout,
   ) -> FutureResult<(), FlowyError>;
 
-  /// Create a view by importing data
+  /// Create a view by importing data. The `field_types` override the field types of the imported
+  /// columns by their index, it's only used when importing a database. Returns the other views
+  /// created by the import besides the view of the `view_id`, like the views of the other sheets
+  /// of a workbook.
   fn import_from_bytes(
     &self,
     uid: i64,
@@ -216,15 +219,19 @@ pub trait FolderOperationHandler {
     name: &str,
     import_type: ImportType,
     bytes: Vec<u8>,
-  ) -> FutureResult<(), FlowyError>;
+    field_types: HashMap<usize, i64>,
+  ) -> FutureResult<Vec<ImportedView>, FlowyError>;
 
-  /// Create a view by importing data from a file
+  /// Create a view by importing data from a file. Returns the other views created by the import
+  /// besides the view of the `view_id`.
   fn import_from_file_path(
     &self,
     view_id: &str,
     name: &str,
+    import_type: ImportType,
     path: String,
-  ) -> FutureResult<(), FlowyError>;
+    field_types: HashMap<usize, i64>,
+  ) -> FutureResult<Vec<ImportedView>, FlowyError>;
 
   /// Called when the view is updated. The handler is the `old` registered handler.
   fn did_update_view(&self, _old: &View, _new: &View) -> FutureResult<(), FlowyError> {
@@ -187,6 +187,18 @@ where
     })
   }
 
+  fn get_workspace_members(
+    &self,
+    _workspace_id: String,
+  ) -> FutureResult<Vec<WorkspaceMember>, Error> {
+    // The members returned by the AppFlowy Cloud don't have the uid that the person cells store
+    FutureResult::new(async {
+      Err(anyhow!(
+        "Getting the workspace members is not supported by the AppFlowy Cloud yet"
+      ))
+    })
+  }
+
   fn get_user_awareness_updates(&self, _uid: i64) -> FutureResult<Vec<Vec<u8>>, Error> {
     // TODO(nathan): implement the RESTful API for this
     FutureResult::new(async { Ok(vec![]) })
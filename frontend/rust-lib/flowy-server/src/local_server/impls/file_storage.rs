@@ -0,0 +1,168 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use bytes::Bytes;
+use url::Url;
+
+use flowy_error::{ErrorCode, FlowyError};
+use flowy_storage::{FileStoragePlan, FileStorageService, ObjectValue, StorageObject};
+use lib_infra::future::FutureResult;
+
+/// Stores the objects in the local file system, so the files can be uploaded without being
+/// online. Each object is saved as `{root}/{workspace_id}/{object_id}/{file_name}` and the
+/// `file://` url of the file is used as the object url.
+pub(crate) struct LocalServerFileStorageImpl {
+  root: PathBuf,
+  storage_plan: Arc<dyn FileStoragePlan>,
+}
+
+impl LocalServerFileStorageImpl {
+  pub fn new(root: PathBuf, storage_plan: Arc<dyn FileStoragePlan>) -> Self {
+    Self { root, storage_plan }
+  }
+}
+
+impl FileStorageService for LocalServerFileStorageImpl {
+  fn create_object(&self, object: StorageObject) -> FutureResult<String, FlowyError> {
+    let root = self.root.clone();
+    let storage_plan = self.storage_plan.clone();
+    FutureResult::new(async move {
+      let file_name = sanitize_file_name(&object.file_name)?;
+      storage_plan.check_upload_object(&object).await?;
+
+      let dir = root
+        .join(&object.workspace_id)
+        .join(uuid::Uuid::new_v4().to_string());
+      tokio::fs::create_dir_all(&dir).await?;
+      let path = dir.join(file_name);
+      match object.value {
+        ObjectValue::File { file_path } => {
+          tokio::fs::copy(&file_path, &path).await?;
+        },
+        ObjectValue::Bytes { bytes, .. } => {
+          tokio::fs::write(&path, bytes).await?;
+        },
+      }
+
+      let url = Url::from_file_path(&path).map_err(|_| {
+        FlowyError::new(
+          ErrorCode::InvalidURL,
+          format!("Invalid file path: {:?}", path),
+        )
+      })?;
+      Ok(url.to_string())
+    })
+  }
+
+  fn delete_object_by_url(&self, object_url: String) -> FutureResult<(), FlowyError> {
+    let path = object_path_from_url(&self.root, &object_url);
+    FutureResult::new(async move {
+      let path = path?;
+      if path.exists() {
+        tokio::fs::remove_file(&path).await?;
+      }
+      // Remove the directory of the object, which only contains the deleted file.
+      if let Some(dir) = path.parent() {
+        let _ = tokio::fs::remove_dir(dir).await;
+      }
+      Ok(())
+    })
+  }
+
+  fn get_object_by_url(&self, object_url: String) -> FutureResult<Bytes, FlowyError> {
+    let path = object_path_from_url(&self.root, &object_url);
+    FutureResult::new(async move {
+      let bytes = tokio::fs::read(path?).await?;
+      Ok(Bytes::from(bytes))
+    })
+  }
+}
+
+/// Returns the name of the file without its directories, so the object can't be written outside
+/// of its directory. The names that don't refer to a file, e.g. `..`, are rejected.
+fn sanitize_file_name(file_name: &str) -> Result<&str, FlowyError> {
+  let name = file_name
+    .rsplit(['/', '\\', ':'])
+    .next()
+    .unwrap_or_default();
+  if name.is_empty() || name == "." || name == ".." {
+    return Err(FlowyError::new(
+      ErrorCode::InvalidParams,
+      format!("Invalid file name: {}", file_name),
+    ));
+  }
+  Ok(name)
+}
+
+/// Returns the path of the object. Only the files under the root directory can be accessed.
+fn object_path_from_url(root: &Path, object_url: &str) -> Result<PathBuf, FlowyError> {
+  let path = Url::parse(object_url)
+    .ok()
+    .and_then(|url| url.to_file_path().ok())
+    .ok_or_else(|| {
+      FlowyError::new(
+        ErrorCode::InvalidURL,
+        format!("Invalid object url: {}", object_url),
+      )
+    })?;
+
+  if !path.starts_with(root) || path.components().any(|c| c.as_os_str() == "..") {
+    return Err(FlowyError::new(
+      ErrorCode::InvalidURL,
+      format!("The object url: {} is not stored locally", object_url),
+    ));
+  }
+  Ok(path)
+}
+
+/// The local file storage is limited by the disk, so only the size of each file is checked.
+pub(crate) struct LocalFileStoragePlan {
+  maximum_file_size: u64,
+}
+
+impl LocalFileStoragePlan {
+  pub fn new(maximum_file_size: u64) -> Self {
+    Self { maximum_file_size }
+  }
+}
+
+impl Default for LocalFileStoragePlan {
+  fn default() -> Self {
+    // 20 MB
+    Self::new(20 * 1024 * 1024)
+  }
+}
+
+impl FileStoragePlan for LocalFileStoragePlan {
+  fn storage_size(&self) -> FutureResult<u64, FlowyError> {
+    FutureResult::new(async { Ok(u64::MAX) })
+  }
+
+  fn maximum_file_size(&self) -> FutureResult<u64, FlowyError> {
+    let maximum_file_size = self.maximum_file_size;
+    FutureResult::new(async move { Ok(maximum_file_size) })
+  }
+
+  fn check_upload_object(&self, object: &StorageObject) -> FutureResult<(), FlowyError> {
+    let maximum_file_size = self.maximum_file_size;
+    let file_size = match &object.value {
+      ObjectValue::File { file_path } => {
+        std::fs::metadata(file_path).map(|metadata| metadata.len())
+      },
+      ObjectValue::Bytes { bytes, .. } => Ok(bytes.len() as u64),
+    };
+    FutureResult::new(async move {
+      let file_size = file_size?;
+      if file_size > maximum_file_size {
+        return Err(FlowyError::new(
+          ErrorCode::ExcessStorageLimited,
+          format!(
+            "The file size: {} exceeds the maximum file size: {}",
+            file_size, maximum_file_size
+          ),
+        ));
+      }
+      Ok(())
+    })
+  }
+}
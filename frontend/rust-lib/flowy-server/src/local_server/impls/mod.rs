@@ -1,9 +1,11 @@
 pub(crate) use database::*;
 pub(crate) use document::*;
+pub(crate) use file_storage::*;
 pub(crate) use folder::*;
 pub(crate) use user::*;
 
 mod database;
 mod document;
+mod file_storage;
 mod folder;
 mod user;
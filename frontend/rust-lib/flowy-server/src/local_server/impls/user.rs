@@ -128,6 +128,15 @@ impl UserCloudService for LocalServerUserAuthServiceImpl {
     FutureResult::new(async { Ok(()) })
   }
 
+  fn get_workspace_members(
+    &self,
+    _workspace_id: String,
+  ) -> FutureResult<Vec<WorkspaceMember>, Error> {
+    // A local workspace can't be shared, so its only member is the current user, who is added
+    // by the caller.
+    FutureResult::new(async { Ok(vec![]) })
+  }
+
   fn get_user_awareness_updates(&self, _uid: i64) -> FutureResult<Vec<Vec<u8>>, Error> {
     FutureResult::new(async { Ok(vec![]) })
   }
@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use parking_lot::RwLock;
@@ -15,8 +16,8 @@ use flowy_user_deps::cloud::UserCloudService;
 use flowy_user_deps::entities::*;
 
 use crate::local_server::impls::{
-  LocalServerDatabaseCloudServiceImpl, LocalServerDocumentCloudServiceImpl,
-  LocalServerFolderCloudServiceImpl, LocalServerUserAuthServiceImpl,
+  LocalFileStoragePlan, LocalServerDatabaseCloudServiceImpl, LocalServerDocumentCloudServiceImpl,
+  LocalServerFileStorageImpl, LocalServerFolderCloudServiceImpl, LocalServerUserAuthServiceImpl,
 };
 use crate::AppFlowyServer;
 
@@ -28,13 +29,20 @@ pub trait LocalServerDB: Send + Sync + 'static {
 
 pub struct LocalServer {
   local_db: Arc<dyn LocalServerDB>,
+  file_storage: Arc<LocalServerFileStorageImpl>,
   stop_tx: RwLock<Option<mpsc::Sender<()>>>,
 }
 
 impl LocalServer {
-  pub fn new(local_db: Arc<dyn LocalServerDB>) -> Self {
+  /// The uploaded files are saved in the `file_storage_path` directory.
+  pub fn new(local_db: Arc<dyn LocalServerDB>, file_storage_path: PathBuf) -> Self {
+    let file_storage = Arc::new(LocalServerFileStorageImpl::new(
+      file_storage_path,
+      Arc::new(LocalFileStoragePlan::default()),
+    ));
     Self {
       local_db,
+      file_storage,
       stop_tx: Default::default(),
     }
   }
@@ -69,6 +77,6 @@ impl AppFlowyServer for LocalServer {
   }
 
   fn file_storage(&self) -> Option<Arc<dyn FileStorageService>> {
-    None
+    Some(self.file_storage.clone() as Arc<dyn FileStorageService>)
   }
 }
@@ -261,6 +261,18 @@ where
     todo!()
   }
 
+  fn get_workspace_members(
+    &self,
+    workspace_id: String,
+  ) -> FutureResult<Vec<WorkspaceMember>, Error> {
+    let try_get_postgrest = self.server.try_get_postgrest();
+    FutureResult::new(async move {
+      let postgrest = try_get_postgrest?;
+      let members = get_workspace_members(postgrest, &workspace_id).await?;
+      Ok(members)
+    })
+  }
+
   fn get_user_awareness_updates(&self, uid: i64) -> FutureResult<Vec<Vec<u8>>, Error> {
     let try_get_postgrest = self.server.try_get_weak_postgrest();
     let awareness_id = uid.to_string();
@@ -457,6 +469,37 @@ async fn get_user_workspaces(
     .await
 }
 
+/// The workspaces on supabase can't be shared, so the owner is the only member of a workspace
+async fn get_workspace_members(
+  postgrest: Arc<PostgresWrapper>,
+  workspace_id: &str,
+) -> Result<Vec<WorkspaceMember>, Error> {
+  let owners = postgrest
+    .from(WORKSPACE_TABLE)
+    .select("uid:owner_uid")
+    .eq(WORKSPACE_ID, workspace_id)
+    .execute()
+    .await?
+    .error_for_status()?
+    .get_value::<Vec<UidResponse>>()
+    .await?;
+
+  let mut members = vec![];
+  for owner in owners {
+    if let Some(profile) =
+      get_user_profile(postgrest.clone(), GetUserProfileParams::Uid(owner.uid)).await?
+    {
+      members.push(WorkspaceMember {
+        uid: profile.uid,
+        email: profile.email,
+        name: profile.name,
+        icon_url: "".to_string(),
+      });
+    }
+  }
+  Ok(members)
+}
+
 async fn update_user_profile(
   postgrest: Arc<PostgresWrapper>,
   params: UpdateUserProfileParams,
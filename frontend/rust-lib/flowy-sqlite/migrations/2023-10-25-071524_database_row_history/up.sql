@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE database_row_history (
+    id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+    database_id TEXT NOT NULL DEFAULT '',
+    row_id TEXT NOT NULL DEFAULT '',
+    field_id TEXT NOT NULL DEFAULT '',
+    uid BIGINT NOT NULL DEFAULT 0,
+    old_cell TEXT NOT NULL DEFAULT '',
+    new_cell TEXT NOT NULL DEFAULT '',
+    old_content TEXT NOT NULL DEFAULT '',
+    new_content TEXT NOT NULL DEFAULT '',
+    timestamp BIGINT NOT NULL DEFAULT 0
+);
+CREATE INDEX database_row_history_row_id ON database_row_history (row_id);
+-- The changes older than the retention period are deleted by their timestamp
+CREATE INDEX database_row_history_timestamp ON database_row_history (timestamp);
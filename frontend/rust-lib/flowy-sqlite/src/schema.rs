@@ -12,6 +12,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    database_row_history (id) {
+        id -> Text,
+        database_id -> Text,
+        row_id -> Text,
+        field_id -> Text,
+        uid -> BigInt,
+        old_cell -> Text,
+        new_cell -> Text,
+        old_content -> Text,
+        new_content -> Text,
+        timestamp -> BigInt,
+    }
+}
+
 diesel::table! {
     user_data_migration_records (id) {
         id -> Integer,
@@ -47,6 +62,7 @@ diesel::table! {
 
 diesel::allow_tables_to_appear_in_same_query!(
   collab_snapshot,
+  database_row_history,
   user_data_migration_records,
   user_table,
   user_workspace_table,
@@ -14,6 +14,7 @@ use lib_infra::future::FutureResult;
 
 use crate::entities::{
   AuthResponse, UpdateUserProfileParams, UserCredentials, UserProfile, UserWorkspace,
+  WorkspaceMember,
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -111,6 +112,12 @@ pub trait UserCloudService: Send + Sync + 'static {
     workspace_id: String,
   ) -> FutureResult<(), Error>;
 
+  /// Return the members of the workspace
+  fn get_workspace_members(
+    &self,
+    workspace_id: String,
+  ) -> FutureResult<Vec<WorkspaceMember>, Error>;
+
   fn get_user_awareness_updates(&self, uid: i64) -> FutureResult<Vec<Vec<u8>>, Error>;
 
   fn receive_realtime_event(&self, _json: Value) {}
@@ -182,6 +182,15 @@ impl UserWorkspace {
   }
 }
 
+/// A user who has access to a workspace
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct WorkspaceMember {
+  pub uid: i64,
+  pub email: String,
+  pub name: String,
+  pub icon_url: String,
+}
+
 #[derive(Serialize, Deserialize, Default, Debug, Clone)]
 pub struct UserProfile {
   #[serde(rename = "id")]
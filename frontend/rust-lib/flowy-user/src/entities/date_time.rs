@@ -1,3 +1,4 @@
+use chrono::Weekday;
 use serde::{Deserialize, Serialize};
 
 use flowy_derive::ProtoBuf_Enum;
@@ -77,3 +78,21 @@ impl UserTimeFormatPB {
     }
   }
 }
+
+#[derive(ProtoBuf_Enum, Serialize, Deserialize, Debug, Clone, Default, Copy)]
+pub enum UserFirstDayOfWeekPB {
+  Sunday = 0,
+  #[default]
+  Monday = 1,
+  Saturday = 6,
+}
+
+impl UserFirstDayOfWeekPB {
+  pub fn weekday(&self) -> Weekday {
+    match self {
+      UserFirstDayOfWeekPB::Sunday => Weekday::Sun,
+      UserFirstDayOfWeekPB::Monday => Weekday::Mon,
+      UserFirstDayOfWeekPB::Saturday => Weekday::Sat,
+    }
+  }
+}
@@ -7,7 +7,7 @@ use flowy_user_deps::cloud::UserCloudConfig;
 
 use crate::entities::EncryptionTypePB;
 
-use super::date_time::{UserDateFormatPB, UserTimeFormatPB};
+use super::date_time::{UserDateFormatPB, UserFirstDayOfWeekPB, UserTimeFormatPB};
 
 #[derive(ProtoBuf, Default, Debug, Clone)]
 pub struct UserPreferencesPB {
@@ -226,6 +226,12 @@ pub struct DateTimeSettingsPB {
 
   #[pb(index = 3)]
   pub timezone_id: String,
+
+  /// The first day of the week, used by the relative date filters, e.g. this week. The settings
+  /// saved before it was added start on Monday.
+  #[pb(index = 4)]
+  #[serde(default)]
+  pub first_day_of_week: UserFirstDayOfWeekPB,
 }
 
 impl std::default::Default for DateTimeSettingsPB {
@@ -234,6 +240,7 @@ impl std::default::Default for DateTimeSettingsPB {
       date_format: UserDateFormatPB::Friendly,
       time_format: UserTimeFormatPB::TwentyFourHour,
       timezone_id: "".to_owned(),
+      first_day_of_week: UserFirstDayOfWeekPB::Monday,
     }
   }
 }
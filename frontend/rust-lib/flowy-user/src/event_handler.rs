@@ -16,6 +16,7 @@ use crate::notification::{send_notification, UserNotification};
 use crate::services::cloud_config::{
   get_cloud_config, get_or_create_cloud_config, save_cloud_config,
 };
+use crate::services::date_time_settings::{self, save_date_time_settings};
 
 fn upgrade_manager(manager: AFPluginState<Weak<UserManager>>) -> FlowyResult<Arc<UserManager>> {
   let manager = manager
@@ -166,21 +167,13 @@ pub async fn get_appearance_setting(
   }
 }
 
-const DATE_TIME_SETTINGS_CACHE_KEY: &str = "date_time_settings";
-
 #[tracing::instrument(level = "debug", skip_all, err)]
 pub async fn set_date_time_settings(
   store_preferences: AFPluginState<Weak<StorePreferences>>,
   data: AFPluginData<DateTimeSettingsPB>,
 ) -> Result<(), FlowyError> {
   let store_preferences = upgrade_store_preferences(store_preferences)?;
-  let mut setting = data.into_inner();
-  if setting.timezone_id.is_empty() {
-    setting.timezone_id = "".to_string();
-  }
-
-  store_preferences.set_object(DATE_TIME_SETTINGS_CACHE_KEY, setting)?;
-  Ok(())
+  save_date_time_settings(&store_preferences, data.into_inner())
 }
 
 #[tracing::instrument(level = "debug", skip_all, err)]
@@ -188,22 +181,9 @@ pub async fn get_date_time_settings(
   store_preferences: AFPluginState<Weak<StorePreferences>>,
 ) -> DataResult<DateTimeSettingsPB, FlowyError> {
   let store_preferences = upgrade_store_preferences(store_preferences)?;
-  match store_preferences.get_str(DATE_TIME_SETTINGS_CACHE_KEY) {
-    None => data_result_ok(DateTimeSettingsPB::default()),
-    Some(s) => {
-      let setting = match serde_json::from_str(&s) {
-        Ok(setting) => setting,
-        Err(e) => {
-          tracing::error!(
-            "Deserialize DateTimeSettings failed: {:?}, fallback to default",
-            e
-          );
-          DateTimeSettingsPB::default()
-        },
-      };
-      data_result_ok(setting)
-    },
-  }
+  data_result_ok(date_time_settings::get_date_time_settings(
+    &store_preferences,
+  ))
 }
 
 const NOTIFICATION_SETTINGS_CACHE_KEY: &str = "notification_settings";
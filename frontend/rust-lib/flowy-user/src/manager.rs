@@ -18,7 +18,9 @@ use flowy_user_deps::cloud::UserUpdate;
 use flowy_user_deps::entities::*;
 use lib_infra::box_any::BoxAny;
 
-use crate::entities::{AuthStateChangedPB, AuthStatePB, UserProfilePB, UserSettingPB};
+use crate::entities::{
+  AuthStateChangedPB, AuthStatePB, DateTimeSettingsPB, UserProfilePB, UserSettingPB,
+};
 use crate::event_map::{DefaultUserStatusCallback, UserCloudServiceProvider, UserStatusCallback};
 use crate::migrations::historical_document::HistoricalEmptyDocumentMigration;
 use crate::migrations::migrate_to_new_user::migration_local_user_on_sign_up;
@@ -28,6 +30,7 @@ use crate::migrations::MigrationUser;
 use crate::services::cloud_config::get_cloud_config;
 use crate::services::collab_interact::{CollabInteract, DefaultCollabInteract};
 use crate::services::database::UserDB;
+use crate::services::date_time_settings;
 use crate::services::entities::{ResumableSignUp, Session};
 use crate::services::user_awareness::UserAwarenessDataSource;
 use crate::services::user_sql::{UserTable, UserTableChangeset};
@@ -531,6 +534,11 @@ impl UserManager {
     }
   }
 
+  /// Returns the date and time settings that the user picked, or the default ones.
+  pub fn get_date_time_settings(&self) -> DateTimeSettingsPB {
+    date_time_settings::get_date_time_settings(&self.store_preferences)
+  }
+
   /// Returns the current user session.
   pub fn get_session(&self) -> Result<Session, FlowyError> {
     if let Some(session) = (self.current_session.read()).clone() {
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use flowy_error::FlowyResult;
+use flowy_sqlite::kv::StorePreferences;
+
+use crate::entities::DateTimeSettingsPB;
+
+const DATE_TIME_SETTINGS_CACHE_KEY: &str = "date_time_settings";
+
+pub fn save_date_time_settings(
+  store_preference: &Arc<StorePreferences>,
+  mut setting: DateTimeSettingsPB,
+) -> FlowyResult<()> {
+  if setting.timezone_id.is_empty() {
+    setting.timezone_id = "".to_string();
+  }
+  store_preference.set_object(DATE_TIME_SETTINGS_CACHE_KEY, setting)?;
+  Ok(())
+}
+
+pub fn get_date_time_settings(store_preference: &Arc<StorePreferences>) -> DateTimeSettingsPB {
+  match store_preference.get_str(DATE_TIME_SETTINGS_CACHE_KEY) {
+    None => DateTimeSettingsPB::default(),
+    Some(s) => match serde_json::from_str(&s) {
+      Ok(setting) => setting,
+      Err(e) => {
+        tracing::error!(
+          "Deserialize DateTimeSettings failed: {:?}, fallback to default",
+          e
+        );
+        DateTimeSettingsPB::default()
+      },
+    },
+  }
+}
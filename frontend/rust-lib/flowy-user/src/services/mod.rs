@@ -1,6 +1,7 @@
 pub mod cloud_config;
 pub mod collab_interact;
 pub mod database;
+pub mod date_time_settings;
 pub mod entities;
 pub(crate) mod historical_user;
 pub(crate) mod user_awareness;
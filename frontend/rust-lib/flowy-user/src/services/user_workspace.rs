@@ -6,7 +6,7 @@ use collab_entity::{CollabObject, CollabType};
 use flowy_error::{FlowyError, FlowyResult};
 use flowy_sqlite::schema::user_workspace_table;
 use flowy_sqlite::{query_dsl::*, ConnectionPool, ExpressionMethods};
-use flowy_user_deps::entities::UserWorkspace;
+use flowy_user_deps::entities::{UserWorkspace, WorkspaceMember};
 
 use crate::entities::{RepeatedUserWorkspacePB, ResetWorkspacePB};
 use crate::manager::UserManager;
@@ -56,6 +56,38 @@ impl UserManager {
     Ok(())
   }
 
+  /// Returns the members of the current workspace. The current user is always one of the
+  /// members, even if the server doesn't support the workspace members.
+  pub async fn get_workspace_members(&self) -> FlowyResult<Vec<WorkspaceMember>> {
+    let session = self.get_session()?;
+    let mut members = match self
+      .cloud_services
+      .get_user_service()?
+      .get_workspace_members(session.user_workspace.id.clone())
+      .await
+    {
+      Ok(members) => members,
+      Err(err) => {
+        tracing::warn!("Failed to get the workspace members: {}", err);
+        vec![]
+      },
+    };
+
+    if !members.iter().any(|member| member.uid == session.user_id) {
+      let user_profile = self.get_user_profile(session.user_id).await?;
+      members.insert(
+        0,
+        WorkspaceMember {
+          uid: user_profile.uid,
+          email: user_profile.email,
+          name: user_profile.name,
+          icon_url: user_profile.icon_url,
+        },
+      );
+    }
+    Ok(members)
+  }
+
   pub fn get_user_workspace(&self, uid: i64, workspace_id: &str) -> Option<UserWorkspace> {
     let conn = self.db_connection(uid).ok()?;
     let row = user_workspace_table::dsl::user_workspace_table